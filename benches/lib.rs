@@ -27,6 +27,7 @@ criterion_main!(
     geometry::quaternion,
     linalg::bidiagonal,
     linalg::cholesky,
+    linalg::convolution,
     linalg::full_piv_lu,
     linalg::hessenberg,
     linalg::lu,
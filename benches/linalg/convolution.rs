@@ -0,0 +1,28 @@
+use na::{DMatrix, DVector};
+
+fn mat_convolve_full_512x512_15x15(bh: &mut criterion::Criterion) {
+    let m = crate::reproductible_dmatrix(512, 512);
+    let row_kernel = DVector::<f64>::new_random(15);
+    let col_kernel = DVector::<f64>::new_random(15);
+    let kernel: DMatrix<f64> = &col_kernel * row_kernel.transpose();
+    bh.bench_function("mat_convolve_full_512x512_15x15", move |bh| {
+        bh.iter(|| std::hint::black_box(m.mat_convolve_full(kernel.clone())))
+    });
+}
+
+fn mat_convolve_separable_512x512_15x15(bh: &mut criterion::Criterion) {
+    let m = crate::reproductible_dmatrix(512, 512);
+    let row_kernel = DVector::<f64>::new_random(15);
+    let col_kernel = DVector::<f64>::new_random(15);
+    bh.bench_function("mat_convolve_separable_512x512_15x15", move |bh| {
+        bh.iter(|| {
+            std::hint::black_box(m.mat_convolve_separable(row_kernel.clone(), col_kernel.clone()))
+        })
+    });
+}
+
+criterion_group!(
+    convolution,
+    mat_convolve_full_512x512_15x15,
+    mat_convolve_separable_512x512_15x15
+);
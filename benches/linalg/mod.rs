@@ -1,5 +1,6 @@
 pub use self::bidiagonal::bidiagonal;
 pub use self::cholesky::cholesky;
+pub use self::convolution::convolution;
 pub use self::full_piv_lu::full_piv_lu;
 pub use self::hessenberg::hessenberg;
 pub use self::lu::lu;
@@ -11,6 +12,7 @@ pub use self::symmetric_eigen::symmetric_eigen;
 
 mod bidiagonal;
 mod cholesky;
+mod convolution;
 mod full_piv_lu;
 mod hessenberg;
 mod lu;
@@ -1,4 +1,4 @@
-use na::{Matrix4, SymmetricEigen};
+use na::{Dynamic, Matrix3, Matrix4, SymmetricEigen, SymmetricEigenWorkspace};
 
 fn symmetric_eigen_decompose_4x4(bh: &mut criterion::Criterion) {
     let m = Matrix4::<f64>::new_random();
@@ -28,10 +28,58 @@ fn symmetric_eigen_decompose_200x200(bh: &mut criterion::Criterion) {
     });
 }
 
+fn symmetric_eigenvalues_100x100(bh: &mut criterion::Criterion) {
+    let m = crate::reproductible_dmatrix(100, 100);
+    bh.bench_function("symmetric_eigenvalues_100x100", move |bh| {
+        bh.iter(|| std::hint::black_box(m.symmetric_eigenvalues()))
+    });
+}
+
+fn symmetric_eigenvalues_200x200(bh: &mut criterion::Criterion) {
+    let m = crate::reproductible_dmatrix(200, 200);
+    bh.bench_function("symmetric_eigenvalues_200x200", move |bh| {
+        bh.iter(|| std::hint::black_box(m.symmetric_eigenvalues()))
+    });
+}
+
+fn symmetric_eigen_decompose_into_100x100(bh: &mut criterion::Criterion) {
+    let m = crate::reproductible_dmatrix(100, 100);
+    let mut eig = SymmetricEigen::new(m.clone());
+    let mut workspace = SymmetricEigenWorkspace::new(Dynamic::new(100));
+    bh.bench_function("symmetric_eigen_decompose_into_100x100", move |bh| {
+        bh.iter(|| eig.decompose_into(std::hint::black_box(&m), &mut workspace))
+    });
+}
+
+fn symmetric_eigen_loop_1000x3x3(bh: &mut criterion::Criterion) {
+    let mats: Vec<_> = (0..1000)
+        .map(|_| Matrix3::<f64>::new_random().hermitian_part())
+        .collect();
+    bh.bench_function("symmetric_eigen_loop_1000x3x3", move |bh| {
+        bh.iter(|| {
+            std::hint::black_box(mats.iter().map(|m| m.symmetric_eigen()).collect::<Vec<_>>())
+        })
+    });
+}
+
+fn symmetric_eigen_batch_1000x3x3(bh: &mut criterion::Criterion) {
+    let mats: Vec<_> = (0..1000)
+        .map(|_| Matrix3::<f64>::new_random().hermitian_part())
+        .collect();
+    bh.bench_function("symmetric_eigen_batch_1000x3x3", move |bh| {
+        bh.iter(|| std::hint::black_box(na::symmetric_eigen_batch(&mats)))
+    });
+}
+
 criterion_group!(
     symmetric_eigen,
     symmetric_eigen_decompose_4x4,
     symmetric_eigen_decompose_10x10,
     symmetric_eigen_decompose_100x100,
-    symmetric_eigen_decompose_200x200
+    symmetric_eigen_decompose_into_100x100,
+    symmetric_eigen_decompose_200x200,
+    symmetric_eigenvalues_100x100,
+    symmetric_eigenvalues_200x200,
+    symmetric_eigen_loop_1000x3x3,
+    symmetric_eigen_batch_1000x3x3
 );
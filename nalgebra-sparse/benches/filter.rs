@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate criterion;
+
+use criterion::Criterion;
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+
+/// A large sparse matrix with a pseudo-random pattern and roughly `nnz_per_row` entries per row.
+fn reproductible_csr(n: usize, nnz_per_row: usize) -> CsrMatrix<f64> {
+    // Simple linear congruential generator, so that the benchmark is reproductible.
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 33) as usize
+    };
+
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        for _ in 0..nnz_per_row {
+            coo.push(i, next() % n, (next() % 1000) as f64 / 1000.0);
+        }
+    }
+    CsrMatrix::from(&coo)
+}
+
+fn csr_filter_keep_all(bh: &mut Criterion) {
+    let a = reproductible_csr(200_000, 20);
+    bh.bench_function("csr_filter_keep_all_200000x200000", move |bh| {
+        bh.iter(|| a.filter(|_, _, v| !v.is_nan()))
+    });
+}
+
+fn csr_filter_keep_half(bh: &mut Criterion) {
+    let a = reproductible_csr(200_000, 20);
+    bh.bench_function("csr_filter_keep_half_200000x200000", move |bh| {
+        bh.iter(|| a.filter(|_, _, v| *v < 0.5))
+    });
+}
+
+criterion_group!(filter, csr_filter_keep_all, csr_filter_keep_half);
+criterion_main!(filter);
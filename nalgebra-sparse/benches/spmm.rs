@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate criterion;
+
+use criterion::Criterion;
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+
+/// A large sparse matrix with a pseudo-random pattern and roughly `nnz_per_row` entries per row.
+fn reproductible_csr(n: usize, nnz_per_row: usize, seed: u64) -> CsrMatrix<f64> {
+    // Simple linear congruential generator, so that the benchmark is reproductible.
+    let mut state = seed;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 33) as usize
+    };
+
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        for _ in 0..nnz_per_row {
+            coo.push(i, next() % n, (next() % 1000) as f64 / 1000.0);
+        }
+    }
+    CsrMatrix::from(&coo)
+}
+
+fn csr_spmm(bh: &mut Criterion) {
+    let a = reproductible_csr(20_000, 10, 0x2545_f491_4f6c_dd1d);
+    let b = reproductible_csr(20_000, 10, 0x9e37_79b9_7f4a_7c15);
+    bh.bench_function("csr_spmm_20000x20000", move |bh| bh.iter(|| a.spmm(&b)));
+}
+
+fn csr_mul_operator(bh: &mut Criterion) {
+    let a = reproductible_csr(20_000, 10, 0x2545_f491_4f6c_dd1d);
+    let b = reproductible_csr(20_000, 10, 0x9e37_79b9_7f4a_7c15);
+    bh.bench_function("csr_mul_operator_20000x20000", move |bh| {
+        bh.iter(|| &a * &b)
+    });
+}
+
+criterion_group!(spmm, csr_spmm, csr_mul_operator);
+criterion_main!(spmm);
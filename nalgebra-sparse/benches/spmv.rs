@@ -0,0 +1,72 @@
+#[macro_use]
+extern crate criterion;
+
+use criterion::Criterion;
+use nalgebra::DVector;
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use rayon::prelude::*;
+
+/// A large sparse matrix with a pseudo-random pattern and roughly `nnz_per_row` entries per row.
+fn reproductible_csr(n: usize, nnz_per_row: usize) -> CsrMatrix<f64> {
+    // Simple linear congruential generator, so that the benchmark is reproductible.
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let mut next = move || {
+        state = state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        (state >> 33) as usize
+    };
+
+    let mut coo = CooMatrix::new(n, n);
+    for i in 0..n {
+        for _ in 0..nnz_per_row {
+            coo.push(i, next() % n, (next() % 1000) as f64 / 1000.0);
+        }
+    }
+    CsrMatrix::from(&coo)
+}
+
+fn spmv_serial(bh: &mut Criterion) {
+    let a = reproductible_csr(200_000, 20);
+    let x = DVector::from_fn(a.ncols(), |i, _| i as f64);
+    let mut y = DVector::zeros(a.nrows());
+
+    bh.bench_function("spmv_serial_200000x200000", move |bh| {
+        bh.iter(|| {
+            for (y_i, row) in y.iter_mut().zip(a.row_iter()) {
+                *y_i = row
+                    .col_indices()
+                    .iter()
+                    .zip(row.values())
+                    .map(|(&j, a_ij)| a_ij * x[j])
+                    .sum::<f64>();
+            }
+        })
+    });
+}
+
+fn spmv_par_row_iter(bh: &mut Criterion) {
+    let a = reproductible_csr(200_000, 20);
+    let x = DVector::from_fn(a.ncols(), |i, _| i as f64);
+    let mut y = DVector::zeros(a.nrows());
+
+    bh.bench_function("spmv_par_row_iter_200000x200000", move |bh| {
+        bh.iter(|| {
+            y.as_mut_slice()
+                .par_iter_mut()
+                .zip(a.par_row_iter())
+                .for_each(|(y_i, row)| {
+                    *y_i = row
+                        .col_indices()
+                        .iter()
+                        .zip(row.values())
+                        .map(|(&j, a_ij)| a_ij * x[j])
+                        .sum::<f64>();
+                })
+        })
+    });
+}
+
+criterion_group!(spmv, spmv_serial, spmv_par_row_iter);
+criterion_main!(spmv);
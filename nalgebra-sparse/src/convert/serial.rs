@@ -50,6 +50,8 @@ where
 }
 
 /// Converts a [`CooMatrix`] to a [`CsrMatrix`].
+///
+/// Duplicate entries are summed, and the result has sorted indices in each lane.
 pub fn convert_coo_csr<T>(coo: &CooMatrix<T>) -> CsrMatrix<T>
 where
     T: Scalar + Zero,
@@ -124,6 +126,8 @@ where
 }
 
 /// Converts a [`CooMatrix`] to a [`CscMatrix`].
+///
+/// Duplicate entries are summed, and the result has sorted indices in each lane.
 pub fn convert_coo_csc<T>(coo: &CooMatrix<T>) -> CscMatrix<T>
 where
     T: Scalar + Zero,
@@ -1,6 +1,10 @@
 //! An implementation of the COO sparse matrix format.
 
+use crate::convert::serial::{convert_coo_csc, convert_coo_csr};
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
 use crate::SparseFormatError;
+use num_traits::Zero;
 
 /// A COO representation of a sparse matrix.
 ///
@@ -273,3 +277,45 @@ impl<T> CooMatrix<T> {
         (self.row_indices, self.col_indices, self.values)
     }
 }
+
+impl<T: na::Scalar + Zero> CooMatrix<T> {
+    /// Converts this matrix to the CSR format.
+    ///
+    /// The triplets may be given in any order, and may contain duplicates: the column indices of
+    /// each row are sorted, and the values of duplicate entries are summed. This is the usual
+    /// way of assembling a sparse matrix from the contributions of many small blocks, e.g. the
+    /// element matrices of a finite element discretization. Explicitly stored zeros are kept.
+    ///
+    /// This is equivalent to `CsrMatrix::from(&coo)`.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// ```
+    /// # use nalgebra_sparse::coo::CooMatrix;
+    /// let mut coo = CooMatrix::new(2, 2);
+    /// coo.push(1, 1, 1.0);
+    /// coo.push(0, 1, 2.0);
+    /// coo.push(1, 1, 3.0);
+    ///
+    /// let csr = coo.to_csr();
+    /// assert_eq!(csr.row_offsets(), &[0, 1, 2]);
+    /// assert_eq!(csr.col_indices(), &[1, 1]);
+    /// assert_eq!(csr.values(), &[2.0, 4.0]);
+    /// ```
+    #[must_use]
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        convert_coo_csr(self)
+    }
+
+    /// Converts this matrix to the CSC format.
+    ///
+    /// As with [`to_csr`](Self::to_csr), the row indices of each column are sorted and the values
+    /// of duplicate entries are summed.
+    ///
+    /// This is equivalent to `CscMatrix::from(&coo)`.
+    #[must_use]
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        convert_coo_csc(self)
+    }
+}
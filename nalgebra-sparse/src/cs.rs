@@ -1,13 +1,24 @@
-use std::mem::replace;
+use std::cmp::Ordering;
+use std::fmt;
+#[cfg(feature = "rayon")]
+use std::mem::take;
+use std::mem::{replace, swap};
 use std::ops::Range;
 
-use num_traits::One;
+use num_traits::{One, Zero};
 
-use nalgebra::Scalar;
+use nalgebra::{
+    ClosedAdd, ClosedDiv, ClosedMul, ClosedSub, ComplexField, DMatrix, DVector, RealField, Scalar,
+};
 
-use crate::pattern::SparsityPattern;
+use crate::ops::serial::cs::spmm_cs_prealloc;
+use crate::ops::serial::{spadd_pattern, spmm_csr_pattern, OperationError, OperationErrorKind};
+use crate::pattern::{SparsityPattern, SparsityPatternFormatError};
 use crate::{SparseEntry, SparseEntryMut};
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
 /// An abstract compressed matrix.
 ///
 /// For the time being, this is only used internally to share implementation between
@@ -60,185 +71,1655 @@ impl<T> CsMatrix<T> {
         )
     }
 
-    /// Returns the raw data represented as a tuple `(major_offsets, minor_indices, values)`.
-    #[inline]
-    pub fn cs_data_mut(&mut self) -> (&[usize], &[usize], &mut [T]) {
-        let pattern = &mut self.sparsity_pattern;
-        (
-            pattern.major_offsets(),
-            pattern.minor_indices(),
-            &mut self.values,
-        )
+    /// Returns the raw data represented as a tuple `(major_offsets, minor_indices, values)`.
+    #[inline]
+    pub fn cs_data_mut(&mut self) -> (&[usize], &[usize], &mut [T]) {
+        let pattern = &mut self.sparsity_pattern;
+        (
+            pattern.major_offsets(),
+            pattern.minor_indices(),
+            &mut self.values,
+        )
+    }
+
+    #[inline]
+    pub fn pattern_and_values_mut(&mut self) -> (&SparsityPattern, &mut [T]) {
+        (&self.sparsity_pattern, &mut self.values)
+    }
+
+    #[inline]
+    pub fn from_pattern_and_values(pattern: SparsityPattern, values: Vec<T>) -> Self {
+        assert_eq!(
+            pattern.nnz(),
+            values.len(),
+            "Internal error: consumers should verify shape compatibility."
+        );
+        Self {
+            sparsity_pattern: pattern,
+            values,
+        }
+    }
+
+    /// Internal method for simplifying access to a lane's data
+    #[inline]
+    #[must_use]
+    pub fn get_index_range(&self, row_index: usize) -> Option<Range<usize>> {
+        let row_begin = *self.sparsity_pattern.major_offsets().get(row_index)?;
+        let row_end = *self.sparsity_pattern.major_offsets().get(row_index + 1)?;
+        Some(row_begin..row_end)
+    }
+
+    pub fn take_pattern_and_values(self) -> (SparsityPattern, Vec<T>) {
+        (self.sparsity_pattern, self.values)
+    }
+
+    #[inline]
+    pub fn disassemble(self) -> (Vec<usize>, Vec<usize>, Vec<T>) {
+        let (offsets, indices) = self.sparsity_pattern.disassemble();
+        (offsets, indices, self.values)
+    }
+
+    #[inline]
+    pub fn into_pattern_and_values(self) -> (SparsityPattern, Vec<T>) {
+        (self.sparsity_pattern, self.values)
+    }
+
+    /// Returns an entry for the given major/minor indices, or `None` if the indices are out
+    /// of bounds.
+    #[must_use]
+    pub fn get_entry(&self, major_index: usize, minor_index: usize) -> Option<SparseEntry<T>> {
+        let row_range = self.get_index_range(major_index)?;
+        let (_, minor_indices, values) = self.cs_data();
+        let minor_indices = &minor_indices[row_range.clone()];
+        let values = &values[row_range];
+        get_entry_from_slices(
+            self.pattern().minor_dim(),
+            minor_indices,
+            values,
+            minor_index,
+        )
+    }
+
+    /// Returns a mutable entry for the given major/minor indices, or `None` if the indices are out
+    /// of bounds.
+    pub fn get_entry_mut(
+        &mut self,
+        major_index: usize,
+        minor_index: usize,
+    ) -> Option<SparseEntryMut<T>> {
+        let row_range = self.get_index_range(major_index)?;
+        let minor_dim = self.pattern().minor_dim();
+        let (_, minor_indices, values) = self.cs_data_mut();
+        let minor_indices = &minor_indices[row_range.clone()];
+        let values = &mut values[row_range];
+        get_mut_entry_from_slices(minor_dim, minor_indices, values, minor_index)
+    }
+
+    #[must_use]
+    pub fn get_lane(&self, index: usize) -> Option<CsLane<T>> {
+        let range = self.get_index_range(index)?;
+        let (_, minor_indices, values) = self.cs_data();
+        Some(CsLane {
+            minor_indices: &minor_indices[range.clone()],
+            values: &values[range],
+            minor_dim: self.pattern().minor_dim(),
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn get_lane_mut(&mut self, index: usize) -> Option<CsLaneMut<T>> {
+        let range = self.get_index_range(index)?;
+        let minor_dim = self.pattern().minor_dim();
+        let (_, minor_indices, values) = self.cs_data_mut();
+        Some(CsLaneMut {
+            minor_dim,
+            minor_indices: &minor_indices[range.clone()],
+            values: &mut values[range],
+        })
+    }
+
+    /// Scatters the explicitly stored entries of the given lane into a dense vector of length
+    /// `minor_dim`, or returns `None` if the lane index is out of bounds.
+    #[must_use]
+    pub fn lane_as_dense(&self, index: usize) -> Option<DVector<T>>
+    where
+        T: Scalar + Zero,
+    {
+        let lane = self.get_lane(index)?;
+        let mut dense = DVector::zeros(lane.minor_dim());
+        for (&j, v) in lane.minor_indices().iter().zip(lane.values()) {
+            dense[j] = v.inlined_clone();
+        }
+        Some(dense)
+    }
+
+    #[inline]
+    pub fn lane_iter(&self) -> CsLaneIter<T> {
+        CsLaneIter::new(self.pattern(), self.values())
+    }
+
+    #[inline]
+    pub fn lane_iter_mut(&mut self) -> CsLaneIterMut<T> {
+        CsLaneIterMut::new(&self.sparsity_pattern, &mut self.values)
+    }
+
+    /// Parallel iterator over the lanes of the matrix.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_lane_iter(&self) -> impl IndexedParallelIterator<Item = CsLane<'_, T>>
+    where
+        T: Sync,
+    {
+        (0..self.pattern().major_dim())
+            .into_par_iter()
+            .map(move |i| self.get_lane(i).unwrap())
+    }
+
+    /// Parallel iterator over mutable lanes of the matrix.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_lane_iter_mut(&mut self) -> impl ParallelIterator<Item = CsLaneMut<'_, T>>
+    where
+        T: Send,
+    {
+        self.par_indexed_lane_iter_mut().map(|(_, lane)| lane)
+    }
+
+    /// Parallel iterator over mutable lanes of the matrix, along with their major index.
+    #[cfg(feature = "rayon")]
+    #[inline]
+    pub fn par_indexed_lane_iter_mut(
+        &mut self,
+    ) -> impl ParallelIterator<Item = (usize, CsLaneMut<'_, T>)>
+    where
+        T: Send,
+    {
+        let chunk = CsLaneChunkMut {
+            lanes: 0..self.sparsity_pattern.major_dim(),
+            pattern: &self.sparsity_pattern,
+            values: &mut self.values,
+        };
+        rayon::iter::split(chunk, CsLaneChunkMut::split).flat_map_iter(CsLaneChunkMut::into_lanes)
+    }
+
+    /// Checks whether the sparsity pattern is structurally symmetric, i.e. whether `(j, i)` is
+    /// explicitly stored for every explicitly stored `(i, j)`.
+    ///
+    /// Always returns `false` for non-square matrices.
+    #[must_use]
+    pub fn is_pattern_symmetric(&self) -> bool {
+        let pattern = self.pattern();
+        pattern.major_dim() == pattern.minor_dim()
+            && pattern
+                .entries()
+                .all(|(i, j)| i == j || pattern.lane(j).binary_search(&i).is_ok())
+    }
+
+    /// The predicate is called as `predicate(major, minor, value)`. The public wrappers translate
+    /// this to `(row, col, value)`.
+    #[inline]
+    #[must_use]
+    pub fn filter<P>(&self, predicate: P) -> Self
+    where
+        T: Clone,
+        P: Fn(usize, usize, &T) -> bool,
+    {
+        let pattern = self.pattern();
+        let (major_dim, minor_dim) = (pattern.major_dim(), pattern.minor_dim());
+        let (offsets, indices) = (pattern.major_offsets(), pattern.minor_indices());
+        let mut new_values = Vec::with_capacity(self.values.len());
+
+        // Find the first entry rejected by the predicate. Until then, the new offsets and
+        // indices are identical to those of `self`, so we only need to collect values.
+        let mut first_rejected = None;
+        'outer: for i in 0..major_dim {
+            let lane = offsets[i]..offsets[i + 1];
+            for (&j, value) in indices[lane.clone()].iter().zip(&self.values[lane]) {
+                if predicate(i, j, value) {
+                    new_values.push(value.clone());
+                } else {
+                    // All the previous entries were kept, so this is the index of the entry.
+                    first_rejected = Some((i, new_values.len()));
+                    break 'outer;
+                }
+            }
+        }
+
+        let (first_lane, first_rejected_entry) = match first_rejected {
+            Some(first_rejected) => first_rejected,
+            // Nothing was removed, so the sparsity pattern can be reused without any check.
+            None => return Self::from_pattern_and_values(pattern.clone(), new_values),
+        };
+
+        let mut new_offsets = Vec::with_capacity(major_dim + 1);
+        new_offsets.extend_from_slice(&offsets[..=first_lane]);
+        let mut new_indices = indices[..first_rejected_entry].to_vec();
+
+        for i in first_lane..major_dim {
+            let lane = offsets[i].max(first_rejected_entry + 1)..offsets[i + 1];
+            for (&j, value) in indices[lane.clone()].iter().zip(&self.values[lane]) {
+                if predicate(i, j, value) {
+                    new_indices.push(j);
+                    new_values.push(value.clone());
+                }
+            }
+
+            new_offsets.push(new_indices.len());
+        }
+
+        // TODO: Avoid checks here
+        let new_pattern = SparsityPattern::try_from_offsets_and_indices(
+            major_dim,
+            minor_dim,
+            new_offsets,
+            new_indices,
+        )
+        .expect("Internal error: Sparsity pattern must always be valid.");
+
+        Self::from_pattern_and_values(new_pattern, new_values)
+    }
+
+    /// Removes the major lane `major` and the entries with the minor index `minor`, shifting the
+    /// subsequent lanes and minor indices down by one. An index set to `None` leaves the
+    /// corresponding dimension unchanged.
+    ///
+    /// Panics if an index is out of bounds.
+    #[must_use]
+    pub fn remove_lane_and_minor(&self, major: Option<usize>, minor: Option<usize>) -> Self
+    where
+        T: Clone,
+    {
+        let pattern = self.pattern();
+        let (major_dim, minor_dim) = (pattern.major_dim(), pattern.minor_dim());
+
+        if let Some(major) = major {
+            assert!(major < major_dim, "Lane index out of bounds.");
+        }
+        if let Some(minor) = minor {
+            assert!(minor < minor_dim, "Minor index out of bounds.");
+        }
+
+        let new_major_dim = major_dim - major.is_some() as usize;
+        let new_minor_dim = minor_dim - minor.is_some() as usize;
+        let mut new_offsets = Vec::with_capacity(new_major_dim + 1);
+        let mut new_indices = Vec::with_capacity(self.values.len());
+        let mut new_values = Vec::with_capacity(self.values.len());
+        new_offsets.push(0);
+
+        for (i, lane) in self.lane_iter().enumerate() {
+            if Some(i) == major {
+                continue;
+            }
+
+            for (&j, value) in lane.minor_indices().iter().zip(lane.values()) {
+                match minor {
+                    Some(minor) if j == minor => continue,
+                    Some(minor) if j > minor => new_indices.push(j - 1),
+                    _ => new_indices.push(j),
+                }
+                new_values.push(value.clone());
+            }
+
+            new_offsets.push(new_indices.len());
+        }
+
+        // Removing entries and shifting the subsequent indices keeps them sorted and unique.
+        let new_pattern = SparsityPattern::from_offsets_and_indices_unchecked(
+            new_major_dim,
+            new_minor_dim,
+            new_offsets,
+            new_indices,
+        );
+
+        Self::from_pattern_and_values(new_pattern, new_values)
+    }
+
+    /// Applies `f` to each explicitly stored value, keeping the sparsity pattern unchanged.
+    #[inline]
+    #[must_use]
+    pub fn map_values<U, F>(&self, f: F) -> CsMatrix<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        let values = self.values.iter().map(f).collect();
+        CsMatrix::from_pattern_and_values(self.sparsity_pattern.clone(), values)
+    }
+
+    /// Applies `f` to each explicitly stored value along with its major and minor indices,
+    /// keeping the sparsity pattern unchanged.
+    #[inline]
+    #[must_use]
+    pub fn map_with_indices<U, F>(&self, f: F) -> CsMatrix<U>
+    where
+        F: Fn(usize, usize, &T) -> U,
+    {
+        let mut values = Vec::with_capacity(self.values.len());
+        for (i, lane) in self.lane_iter().enumerate() {
+            for (&j, value) in lane.minor_indices().iter().zip(lane.values) {
+                values.push(f(i, j, value));
+            }
+        }
+
+        CsMatrix::from_pattern_and_values(self.sparsity_pattern.clone(), values)
+    }
+
+    /// Calls `f` with the major index, the minor index and the value of each explicitly stored
+    /// entry, along with the element of `dense` at its minor index, lane after lane.
+    ///
+    /// Panics if `dense.len()` differs from the minor dimension.
+    pub fn zip_lanes_with_dense<U, F>(&self, dense: &[U], mut f: F)
+    where
+        F: FnMut(usize, usize, &T, &U),
+    {
+        assert_eq!(
+            dense.len(),
+            self.pattern().minor_dim(),
+            "The length of the dense vector must match the minor dimension."
+        );
+
+        for (i, lane) in self.lane_iter().enumerate() {
+            for (&j, value) in lane.minor_indices().iter().zip(lane.values) {
+                f(i, j, value, &dense[j]);
+            }
+        }
+    }
+
+    /// Same as [`zip_lanes_with_dense`](Self::zip_lanes_with_dense), except that each entry is
+    /// given the element of `dense` at its major index.
+    ///
+    /// Panics if `dense.len()` differs from the major dimension.
+    pub fn zip_lanes_with_dense_major<U, F>(&self, dense: &[U], mut f: F)
+    where
+        F: FnMut(usize, usize, &T, &U),
+    {
+        assert_eq!(
+            dense.len(),
+            self.pattern().major_dim(),
+            "The length of the dense vector must match the major dimension."
+        );
+
+        for ((i, lane), x) in self.lane_iter().enumerate().zip(dense) {
+            for (&j, value) in lane.minor_indices().iter().zip(lane.values) {
+                f(i, j, value, x);
+            }
+        }
+    }
+
+    /// Returns the entries whose major index is at least their minor index, i.e. the lower
+    /// triangle when lanes are interpreted as rows.
+    ///
+    /// The diagonal is excluded unless `include_diagonal` is `true`.
+    #[must_use]
+    pub fn lower_triangle(&self, include_diagonal: bool) -> Self
+    where
+        T: Clone,
+    {
+        self.filter(|i, j, _| i > j || (include_diagonal && i == j))
+    }
+
+    /// Returns the entries whose major index is at most their minor index, i.e. the upper
+    /// triangle when lanes are interpreted as rows.
+    ///
+    /// The diagonal is excluded unless `include_diagonal` is `true`.
+    #[must_use]
+    pub fn upper_triangle(&self, include_diagonal: bool) -> Self
+    where
+        T: Clone,
+    {
+        self.filter(|i, j, _| i < j || (include_diagonal && i == j))
+    }
+
+    /// Returns the diagonal of the matrix as a sparse matrix.
+    #[must_use]
+    pub fn diagonal_as_matrix(&self) -> Self
+    where
+        T: Clone,
+    {
+        // TODO: This might be faster with a binary search for each diagonal entry
+        self.filter(|i, j, _| i == j)
+    }
+}
+
+impl<T: Scalar> CsMatrix<T> {
+    /// Returns the main diagonal of the matrix as a dense vector, with zeros for the diagonal
+    /// entries that are not explicitly stored.
+    ///
+    /// The diagonal has `min(major_dim, minor_dim)` entries.
+    #[must_use]
+    pub fn diagonal(&self) -> DVector<T>
+    where
+        T: Zero,
+    {
+        let n = self.pattern().major_dim().min(self.pattern().minor_dim());
+        DVector::from_fn(n, |i, _| match self.diagonal_local_index(i) {
+            Some(local_index) => self.get_lane(i).unwrap().values[local_index].inlined_clone(),
+            None => T::zero(),
+        })
+    }
+
+    /// The sum of the explicitly stored values, or zero if there are none.
+    #[must_use]
+    pub fn sum(&self) -> T
+    where
+        T: ClosedAdd + Zero,
+    {
+        self.values
+            .iter()
+            .fold(T::zero(), |acc, v| acc + v.inlined_clone())
+    }
+
+    /// Converts the matrix to a dense matrix of shape `(major_dim, minor_dim)`, with zeros for
+    /// the entries that are not explicitly stored.
+    #[must_use]
+    pub fn to_dense(&self) -> DMatrix<T>
+    where
+        T: Zero,
+    {
+        let mut dense = DMatrix::zeros(self.pattern().major_dim(), self.pattern().minor_dim());
+
+        for (major_idx, lane) in self.lane_iter().enumerate() {
+            for (minor_idx, value) in lane.iter() {
+                dense[(major_idx, minor_idx)] = value.inlined_clone();
+            }
+        }
+
+        dense
+    }
+
+    /// Overwrites the explicitly stored diagonal entries of the matrix with the entries of
+    /// `diagonal`.
+    ///
+    /// Returns an error, and leaves the matrix unchanged, if a diagonal entry is not explicitly
+    /// stored in the sparsity pattern.
+    ///
+    /// Panics if the length of `diagonal` differs from `min(major_dim, minor_dim)`.
+    pub fn set_diagonal_mut(&mut self, diagonal: &[T]) -> Result<(), OperationError> {
+        let n = self.pattern().major_dim().min(self.pattern().minor_dim());
+        assert_eq!(diagonal.len(), n, "Diagonal dimension mismatch.");
+
+        let local_indices = (0..n)
+            .map(|i| self.diagonal_local_index(i))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                OperationError::from_kind_and_message(
+                    OperationErrorKind::InvalidPattern,
+                    String::from("A diagonal entry is not explicitly stored in the matrix."),
+                )
+            })?;
+
+        for (i, (local_index, value)) in local_indices.into_iter().zip(diagonal).enumerate() {
+            let mut lane = self.get_lane_mut(i).unwrap();
+            lane.values_mut()[local_index] = value.inlined_clone();
+        }
+
+        Ok(())
+    }
+
+    /// Returns a matrix whose sparsity pattern is the union of the pattern of this matrix and of
+    /// its transpose.
+    ///
+    /// Entries absent from this matrix are explicitly stored with a zero value.
+    ///
+    /// Panics if the matrix is not square.
+    #[must_use]
+    pub fn symmetrize_pattern(&self) -> Self
+    where
+        T: Zero,
+    {
+        let pattern = self.pattern();
+        assert_eq!(
+            pattern.major_dim(),
+            pattern.minor_dim(),
+            "Only square matrices can have their pattern symmetrized."
+        );
+
+        let new_pattern = spadd_pattern(pattern, &pattern.transpose());
+        let mut new_values = Vec::with_capacity(new_pattern.nnz());
+
+        for (lane, new_minor_indices) in self
+            .lane_iter()
+            .zip((0..new_pattern.major_dim()).map(|i| new_pattern.lane(i)))
+        {
+            let mut entries = lane.minor_indices().iter().zip(lane.values()).peekable();
+            for j in new_minor_indices {
+                match entries.peek() {
+                    Some(&(minor_idx, value)) if minor_idx == j => {
+                        new_values.push(value.inlined_clone());
+                        let _ = entries.next();
+                    }
+                    _ => new_values.push(T::zero()),
+                }
+            }
+        }
+
+        Self::from_pattern_and_values(new_pattern, new_values)
+    }
+
+    /// The index of the `i`-th diagonal entry within its lane, if it is explicitly stored.
+    fn diagonal_local_index(&self, i: usize) -> Option<usize> {
+        self.pattern().lane(i).binary_search(&i).ok()
+    }
+
+    /// Computes the transpose of the matrix, with the roles of the major and minor
+    /// dimensions preserved.
+    ///
+    /// This requires rebuilding the sparsity pattern, which takes
+    /// `O(major_dim + minor_dim + nnz)` time.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let pattern = self.pattern();
+        let (major_dim, minor_dim) = (pattern.major_dim(), pattern.minor_dim());
+        let (offsets, indices, values) = transpose_cs(
+            major_dim,
+            minor_dim,
+            pattern.major_offsets(),
+            pattern.minor_indices(),
+            &self.values,
+        );
+
+        // TODO: Avoid checks here
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(minor_dim, major_dim, offsets, indices)
+                .expect("Internal error: Transposed sparsity pattern must always be valid.");
+        Self::from_pattern_and_values(pattern, values)
+    }
+
+    /// Applies the symmetric permutation `perm` to the rows and columns of the matrix.
+    ///
+    /// Entry `(i, j)` of the result is entry `(perm[i], perm[j])` of `self`, which holds
+    /// regardless of the storage orientation.
+    ///
+    /// Panics if the matrix is not square, or if `perm` is not a permutation of `0 .. n`.
+    #[must_use]
+    pub fn permute_symmetric(&self, perm: &[usize]) -> Self {
+        let n = self.pattern().major_dim();
+        assert_eq!(n, self.pattern().minor_dim(), "The matrix must be square.");
+        assert_eq!(
+            perm.len(),
+            n,
+            "The permutation must have one entry per lane."
+        );
+
+        let mut inverse = vec![usize::MAX; n];
+        for (new, &old) in perm.iter().enumerate() {
+            assert!(
+                old < n && inverse[old] == usize::MAX,
+                "The permutation must contain every index in 0 .. n exactly once."
+            );
+            inverse[old] = new;
+        }
+
+        let mut offsets = Vec::with_capacity(n + 1);
+        let mut indices = Vec::with_capacity(self.values.len());
+        let mut values = Vec::with_capacity(self.values.len());
+        let mut lane_entries = Vec::new();
+
+        offsets.push(0);
+        for &old in perm {
+            let range = self.get_index_range(old).unwrap();
+            lane_entries.clear();
+            lane_entries.extend(range.map(|k| (inverse[self.pattern().minor_indices()[k]], k)));
+            lane_entries.sort_unstable_by_key(|&(j, _)| j);
+
+            for &(j, k) in &lane_entries {
+                indices.push(j);
+                values.push(self.values[k].inlined_clone());
+            }
+            offsets.push(indices.len());
+        }
+
+        let pattern = SparsityPattern::try_from_offsets_and_indices(n, n, offsets, indices)
+            .expect("Internal error: Permuted sparsity pattern must always be valid.");
+        Self::from_pattern_and_values(pattern, values)
+    }
+
+    /// Multiplies every explicitly stored value by `factor`, in place.
+    #[inline]
+    pub fn scale_mut(&mut self, factor: T)
+    where
+        T: ClosedMul,
+    {
+        for value in &mut self.values {
+            *value *= factor.inlined_clone();
+        }
+    }
+
+    /// Divides the explicitly stored values of each lane by their sum, in place.
+    ///
+    /// Lanes without any explicitly stored value, or whose values sum to zero, are left untouched.
+    #[inline]
+    pub fn normalize_lanes_mut(&mut self)
+    where
+        T: ClosedAdd + ClosedDiv + Zero,
+    {
+        self.normalize_lanes_by(|values| {
+            values
+                .iter()
+                .fold(T::zero(), |sum, v| sum + v.inlined_clone())
+        })
+    }
+
+    /// Divides the explicitly stored values of each lane by `f` applied to these values, in
+    /// place.
+    ///
+    /// Lanes without any explicitly stored value, or for which `f` returns zero, are left
+    /// untouched.
+    pub fn normalize_lanes_by<F>(&mut self, f: F)
+    where
+        T: ClosedDiv + Zero,
+        F: Fn(&[T]) -> T,
+    {
+        for mut lane in self.lane_iter_mut() {
+            let values = lane.values_mut();
+            if values.is_empty() {
+                continue;
+            }
+
+            let norm = f(values);
+            if !norm.is_zero() {
+                for value in values {
+                    *value /= norm.inlined_clone();
+                }
+            }
+        }
+    }
+
+    /// Adds the explicitly stored values of `other` to those of `self`, in place.
+    ///
+    /// Panics if the sparsity patterns of `self` and `other` are not identical.
+    #[inline]
+    pub fn add_assign_same_pattern(&mut self, other: &CsMatrix<T>)
+    where
+        T: ClosedAdd,
+    {
+        assert!(
+            self.pattern() == other.pattern(),
+            "The sparsity patterns of both matrices must be identical."
+        );
+        for (value, other_value) in self.values.iter_mut().zip(&other.values) {
+            *value += other_value.inlined_clone();
+        }
+    }
+
+    /// Computes `y <- beta * y + alpha * A * x`, where `A` is this matrix interpreted with its
+    /// lanes as rows, without allocating.
+    ///
+    /// Panics if `x.len()` differs from the minor dimension or `y.len()` from the major
+    /// dimension.
+    pub fn spmv_into(&self, x: &[T], y: &mut [T], beta: T, alpha: T)
+    where
+        T: ClosedAdd + ClosedMul + Zero,
+    {
+        assert_eq!(
+            x.len(),
+            self.pattern().minor_dim(),
+            "The length of x must match the minor dimension."
+        );
+        assert_eq!(
+            y.len(),
+            self.pattern().major_dim(),
+            "The length of y must match the major dimension."
+        );
+
+        let minor_indices = self.pattern().minor_indices();
+
+        for (i, y_i) in y.iter_mut().enumerate() {
+            let range = self.get_index_range(i).unwrap();
+            let mut dot = T::zero();
+
+            for (&j, a_ij) in minor_indices[range.clone()].iter().zip(&self.values[range]) {
+                dot += a_ij.inlined_clone() * x[j].inlined_clone();
+            }
+
+            *y_i = beta.inlined_clone() * y_i.inlined_clone() + alpha.inlined_clone() * dot;
+        }
+    }
+
+    /// Computes `y <- beta * y + alpha * A^T * x`, where `A` is this matrix interpreted with its
+    /// lanes as rows, without allocating.
+    ///
+    /// Panics if `x.len()` differs from the major dimension or `y.len()` from the minor
+    /// dimension.
+    pub fn spmv_transpose_into(&self, x: &[T], y: &mut [T], beta: T, alpha: T)
+    where
+        T: ClosedAdd + ClosedMul,
+    {
+        assert_eq!(
+            x.len(),
+            self.pattern().major_dim(),
+            "The length of x must match the major dimension."
+        );
+        assert_eq!(
+            y.len(),
+            self.pattern().minor_dim(),
+            "The length of y must match the minor dimension."
+        );
+
+        for y_j in y.iter_mut() {
+            *y_j = beta.inlined_clone() * y_j.inlined_clone();
+        }
+
+        let minor_indices = self.pattern().minor_indices();
+
+        for (i, x_i) in x.iter().enumerate() {
+            let range = self.get_index_range(i).unwrap();
+            let gamma = alpha.inlined_clone() * x_i.inlined_clone();
+
+            for (&j, a_ij) in minor_indices[range.clone()].iter().zip(&self.values[range]) {
+                y[j] += a_ij.inlined_clone() * gamma.inlined_clone();
+            }
+        }
+    }
+
+    /// Computes `y <- beta * y + alpha * A * x`, where `A` is the symmetric matrix of which only
+    /// one triangle is stored in this matrix, without allocating.
+    ///
+    /// Every stored off-diagonal entry contributes both for itself and for its mirror, while
+    /// diagonal entries contribute once.
+    ///
+    /// Panics if the matrix is not square, or if the length of `x` or `y` does not match its
+    /// dimension.
+    pub fn spmv_symmetric_into(&self, x: &[T], y: &mut [T], beta: T, alpha: T)
+    where
+        T: ClosedAdd + ClosedMul,
+    {
+        let n = self.pattern().major_dim();
+        assert_eq!(n, self.pattern().minor_dim(), "The matrix must be square.");
+        assert_eq!(
+            x.len(),
+            n,
+            "The length of x must match the matrix dimension."
+        );
+        assert_eq!(
+            y.len(),
+            n,
+            "The length of y must match the matrix dimension."
+        );
+
+        for y_i in y.iter_mut() {
+            *y_i = beta.inlined_clone() * y_i.inlined_clone();
+        }
+
+        let minor_indices = self.pattern().minor_indices();
+
+        for i in 0..n {
+            let range = self.get_index_range(i).unwrap();
+            let gamma = alpha.inlined_clone() * x[i].inlined_clone();
+
+            for (&j, a_ij) in minor_indices[range.clone()].iter().zip(&self.values[range]) {
+                y[i] += alpha.inlined_clone() * a_ij.inlined_clone() * x[j].inlined_clone();
+
+                if i != j {
+                    y[j] += a_ij.inlined_clone() * gamma.inlined_clone();
+                }
+            }
+        }
+    }
+
+    /// Solves `L * x = b` in place by forward substitution, where `L` is the lower triangle of
+    /// this matrix interpreted with its lanes as rows.
+    ///
+    /// Entries above the diagonal are ignored. Returns an error if a diagonal entry is zero or
+    /// not explicitly stored, in which case `b` is left partially overwritten.
+    ///
+    /// Panics if the matrix is not square or if `b.len()` does not match its dimension.
+    pub fn solve_lower_triangular_into(&self, b: &mut [T]) -> Result<(), OperationError>
+    where
+        T: Zero + ClosedSub + ClosedMul + ClosedDiv,
+    {
+        self.solve_triangular_into(b, true, false)
+    }
+
+    /// Solves `U * x = b` in place by back substitution, where `U` is the upper triangle of this
+    /// matrix interpreted with its lanes as rows.
+    ///
+    /// Entries below the diagonal are ignored. Returns an error if a diagonal entry is zero or
+    /// not explicitly stored, in which case `b` is left partially overwritten.
+    ///
+    /// Panics if the matrix is not square or if `b.len()` does not match its dimension.
+    pub fn solve_upper_triangular_into(&self, b: &mut [T]) -> Result<(), OperationError>
+    where
+        T: Zero + ClosedSub + ClosedMul + ClosedDiv,
+    {
+        self.solve_triangular_into(b, false, false)
+    }
+
+    /// Solves `L^T * x = b` in place, where `L` is the lower triangle of this matrix interpreted
+    /// with its lanes as rows.
+    ///
+    /// See [`solve_lower_triangular_into`](Self::solve_lower_triangular_into) for the error
+    /// and panic conditions.
+    pub fn solve_lower_triangular_transpose_into(&self, b: &mut [T]) -> Result<(), OperationError>
+    where
+        T: Zero + ClosedSub + ClosedMul + ClosedDiv,
+    {
+        self.solve_triangular_into(b, true, true)
+    }
+
+    /// Solves `U^T * x = b` in place, where `U` is the upper triangle of this matrix interpreted
+    /// with its lanes as rows.
+    ///
+    /// See [`solve_upper_triangular_into`](Self::solve_upper_triangular_into) for the error
+    /// and panic conditions.
+    pub fn solve_upper_triangular_transpose_into(&self, b: &mut [T]) -> Result<(), OperationError>
+    where
+        T: Zero + ClosedSub + ClosedMul + ClosedDiv,
+    {
+        self.solve_triangular_into(b, false, true)
+    }
+
+    fn solve_triangular_into(
+        &self,
+        b: &mut [T],
+        lower: bool,
+        transpose: bool,
+    ) -> Result<(), OperationError>
+    where
+        T: Zero + ClosedSub + ClosedMul + ClosedDiv,
+    {
+        let n = self.pattern().major_dim();
+        assert_eq!(n, self.pattern().minor_dim(), "The matrix must be square.");
+        assert_eq!(
+            b.len(),
+            n,
+            "The length of b must match the matrix dimension."
+        );
+
+        // The transpose of a lower triangular matrix is upper triangular, and conversely.
+        let forward = lower != transpose;
+        let minor_indices = self.pattern().minor_indices();
+
+        for step in 0..n {
+            let i = if forward { step } else { n - 1 - step };
+            let range = self.get_index_range(i).unwrap();
+            let diag = self
+                .diagonal_local_index(i)
+                .map(|local_index| self.values[range.start + local_index].inlined_clone())
+                .filter(|diag| *diag != T::zero())
+                .ok_or_else(|| {
+                    OperationError::from_kind_and_message(
+                        OperationErrorKind::Singular,
+                        String::from("Matrix contains at least one diagonal entry that is zero."),
+                    )
+                })?;
+
+            let lane = minor_indices[range.clone()]
+                .iter()
+                .zip(&self.values[range])
+                .filter(|(&j, _)| if lower { j < i } else { j > i });
+
+            if transpose {
+                // Column-oriented substitution: the lane is a column of the transposed factor.
+                let x_i = b[i].inlined_clone() / diag;
+                for (&j, a_ij) in lane {
+                    b[j] -= a_ij.inlined_clone() * x_i.inlined_clone();
+                }
+                b[i] = x_i;
+            } else {
+                let mut x_i = b[i].inlined_clone();
+                for (&j, a_ij) in lane {
+                    x_i -= a_ij.inlined_clone() * b[j].inlined_clone();
+                }
+                b[i] = x_i / diag;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Computes the incomplete Cholesky factor with no fill-in, IC(0), of this symmetric
+    /// positive-definite matrix interpreted with its lanes as rows.
+    ///
+    /// The returned lower-triangular factor `L` has the sparsity pattern of the lower triangle
+    /// of this matrix, and satisfies `(L * L^T)_ij = A_ij` for every `(i, j)` in that pattern.
+    /// Only the lower triangle of this matrix is read.
+    ///
+    /// Returns `None` if a non-positive pivot is encountered, including when a diagonal entry
+    /// is not explicitly stored.
+    ///
+    /// Panics if the matrix is not square.
+    #[must_use]
+    pub fn incomplete_cholesky(&self) -> Option<Self>
+    where
+        T: RealField,
+    {
+        let n = self.pattern().major_dim();
+        assert_eq!(n, self.pattern().minor_dim(), "The matrix must be square.");
+
+        let mut factor = self.filter(|i, j, _| j <= i);
+        let (pattern, values) = factor.pattern_and_values_mut();
+        let (offsets, indices) = (pattern.major_offsets(), pattern.minor_indices());
+
+        for i in 0..n {
+            let (row_start, row_end) = (offsets[i], offsets[i + 1]);
+
+            // The diagonal is the last entry of each row of the lower triangle.
+            if row_start == row_end || indices[row_end - 1] != i {
+                return None;
+            }
+
+            for p in row_start..row_end {
+                let j = indices[p];
+
+                // Sparse dot product of the rows `i` and `j` of `L`, restricted to the columns
+                // before `j`. The entries of row `i` before `p` are exactly those columns.
+                let mut dot = T::zero();
+                let (mut a, mut b) = (row_start, offsets[j]);
+                let b_end = offsets[j + 1] - 1;
+                while a < p && b < b_end {
+                    match indices[a].cmp(&indices[b]) {
+                        Ordering::Less => a += 1,
+                        Ordering::Greater => b += 1,
+                        Ordering::Equal => {
+                            dot += values[a] * values[b];
+                            a += 1;
+                            b += 1;
+                        }
+                    }
+                }
+
+                if j < i {
+                    values[p] = (values[p] - dot) / values[b_end];
+                } else {
+                    let pivot = values[p] - dot;
+                    if pivot <= T::zero() {
+                        return None;
+                    }
+                    values[p] = pivot.sqrt();
+                }
+            }
+        }
+
+        Some(factor)
+    }
+
+    /// Estimates the eigenvalue of largest magnitude of this matrix interpreted with its lanes
+    /// as rows, and an associated unit eigenvector, with the power iteration.
+    ///
+    /// See the dense `SquareMatrix::power_iteration` for the convergence criterion and the
+    /// failure conditions.
+    ///
+    /// Panics if the matrix is not square or if `initial.len()` does not match its dimension.
+    #[must_use]
+    pub fn power_iteration(
+        &self,
+        initial: DVector<T>,
+        max_niter: usize,
+        eps: T,
+    ) -> Option<(T, DVector<T>)>
+    where
+        T: RealField,
+    {
+        self.power_iteration_impl(initial, max_niter, eps, false)
+    }
+
+    /// Same as [`power_iteration`](Self::power_iteration), but for the transpose of this matrix
+    /// interpreted with its lanes as rows.
+    #[must_use]
+    pub fn power_iteration_transpose(
+        &self,
+        initial: DVector<T>,
+        max_niter: usize,
+        eps: T,
+    ) -> Option<(T, DVector<T>)>
+    where
+        T: RealField,
+    {
+        self.power_iteration_impl(initial, max_niter, eps, true)
+    }
+
+    fn power_iteration_impl(
+        &self,
+        initial: DVector<T>,
+        max_niter: usize,
+        eps: T,
+        transpose: bool,
+    ) -> Option<(T, DVector<T>)>
+    where
+        T: RealField,
+    {
+        let n = self.pattern().major_dim();
+        assert_eq!(n, self.pattern().minor_dim(), "The matrix must be square.");
+        assert_eq!(
+            initial.len(),
+            n,
+            "The length of the initial vector must match the matrix dimension."
+        );
+
+        let mut x = initial.try_normalize(T::zero())?;
+        let mut y = x.clone();
+        let mut prev_rho = None;
+
+        for _ in 0..max_niter {
+            if transpose {
+                self.spmv_transpose_into(x.as_slice(), y.as_mut_slice(), T::zero(), T::one());
+            } else {
+                self.spmv_into(x.as_slice(), y.as_mut_slice(), T::zero(), T::one());
+            }
+
+            let rho = x.dot(&y);
+            let norm_squared = y.norm_squared();
+
+            if norm_squared.is_zero() {
+                return None;
+            }
+
+            // Since `x` has a unit norm, `|A * x - rho * x|² = |A * x|² - rho²`.
+            let residual_squared = norm_squared - rho * rho;
+            y.unscale_mut(norm_squared.sqrt());
+            swap(&mut x, &mut y);
+
+            if let Some(prev_rho) = prev_rho {
+                if (rho - prev_rho).abs() <= eps * rho.abs()
+                    && residual_squared <= eps * norm_squared
+                {
+                    return Some((rho, x));
+                }
+            }
+
+            prev_rho = Some(rho);
+        }
+
+        None
+    }
+}
+
+impl<T: Scalar> CsMatrix<T> {
+    /// Builds the block-diagonal matrix with the given blocks along its diagonal.
+    pub fn block_diagonal(blocks: &[&CsMatrix<T>]) -> Self {
+        Self::stack_along_major(blocks, true)
+    }
+
+    /// Stacks the blocks along the major dimension, i.e. the lanes of the result are the lanes
+    /// of each block, in order.
+    ///
+    /// Panics if the blocks do not all have the same minor dimension.
+    pub fn concat_major(blocks: &[&CsMatrix<T>]) -> Self {
+        if let Some(first) = blocks.first() {
+            let minor_dim = first.pattern().minor_dim();
+            assert!(
+                blocks.iter().all(|b| b.pattern().minor_dim() == minor_dim),
+                "All the blocks must have the same minor dimension."
+            );
+        }
+
+        Self::stack_along_major(blocks, false)
+    }
+
+    /// Stacks the blocks along the minor dimension, i.e. each lane of the result is the
+    /// concatenation of the corresponding lanes of each block.
+    ///
+    /// Panics if the blocks do not all have the same major dimension.
+    pub fn concat_minor(blocks: &[&CsMatrix<T>]) -> Self {
+        let major_dim = blocks.first().map_or(0, |b| b.pattern().major_dim());
+        assert!(
+            blocks.iter().all(|b| b.pattern().major_dim() == major_dim),
+            "All the blocks must have the same major dimension."
+        );
+
+        let minor_dim = blocks.iter().map(|b| b.pattern().minor_dim()).sum();
+        let nnz = blocks.iter().map(|b| b.values.len()).sum();
+        let mut offsets = Vec::with_capacity(major_dim + 1);
+        let mut indices = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+
+        offsets.push(0);
+        for i in 0..major_dim {
+            let mut minor_offset = 0;
+            for block in blocks {
+                let lane = block.get_lane(i).unwrap();
+                indices.extend(lane.minor_indices().iter().map(|j| j + minor_offset));
+                values.extend(lane.values().iter().cloned());
+                minor_offset += block.pattern().minor_dim();
+            }
+            offsets.push(indices.len());
+        }
+
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(major_dim, minor_dim, offsets, indices)
+                .expect("Internal error: Sparsity pattern must always be valid.");
+        Self::from_pattern_and_values(pattern, values)
+    }
+
+    /// Stacks the blocks along the major dimension, shifting their minor indices so that they
+    /// end up along the diagonal if `diagonal` is `true`.
+    fn stack_along_major(blocks: &[&CsMatrix<T>], diagonal: bool) -> Self {
+        let major_dim = blocks.iter().map(|b| b.pattern().major_dim()).sum();
+        let minor_dim = if diagonal {
+            blocks.iter().map(|b| b.pattern().minor_dim()).sum()
+        } else {
+            blocks.first().map_or(0, |b| b.pattern().minor_dim())
+        };
+        let nnz = blocks.iter().map(|b| b.values.len()).sum();
+        let mut offsets = Vec::with_capacity(major_dim + 1);
+        let mut indices = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+
+        offsets.push(0);
+        let mut minor_offset = 0;
+        for block in blocks {
+            let (block_offsets, block_indices, block_values) = block.cs_data();
+            offsets.extend(block_offsets[1..].iter().map(|o| o + indices.len()));
+            indices.extend(block_indices.iter().map(|j| j + minor_offset));
+            values.extend(block_values.iter().cloned());
+
+            if diagonal {
+                minor_offset += block.pattern().minor_dim();
+            }
+        }
+
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(major_dim, minor_dim, offsets, indices)
+                .expect("Internal error: Sparsity pattern must always be valid.");
+        Self::from_pattern_and_values(pattern, values)
+    }
+
+    /// Computes the Kronecker product of `self` and `other`.
+    ///
+    /// Lane `i * r + k` of the result, where `r` is the major dimension of `other`, is the
+    /// concatenation of lane `k` of `other` scaled by each entry of lane `i` of `self`.
+    pub fn kronecker(&self, other: &CsMatrix<T>) -> Self
+    where
+        T: ClosedMul,
+    {
+        let (p, q) = (self.pattern().major_dim(), self.pattern().minor_dim());
+        let (r, s) = (other.pattern().major_dim(), other.pattern().minor_dim());
+        let nnz = self.values.len() * other.values.len();
+        let mut offsets = Vec::with_capacity(p * r + 1);
+        let mut indices = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+
+        offsets.push(0);
+        for self_lane in self.lane_iter() {
+            for other_lane in other.lane_iter() {
+                for (&j, a_ij) in self_lane.minor_indices().iter().zip(self_lane.values()) {
+                    // The minor indices remain sorted because those of `other` are below `s`.
+                    for (&l, b_kl) in other_lane.minor_indices().iter().zip(other_lane.values()) {
+                        indices.push(j * s + l);
+                        values.push(a_ij.inlined_clone() * b_kl.inlined_clone());
+                    }
+                }
+                offsets.push(indices.len());
+            }
+        }
+
+        let pattern = SparsityPattern::try_from_offsets_and_indices(p * r, q * s, offsets, indices)
+            .expect("Internal error: Sparsity pattern must always be valid.");
+        Self::from_pattern_and_values(pattern, values)
+    }
+
+    /// Computes the product of `self` and `other`, interpreting both as CSR matrices.
+    ///
+    /// The sparsity pattern of the result is computed by `spmm_csr_pattern`, and its values by
+    /// the Gustavson kernel of `spmm_cs_prealloc`, so entries whose contributions cancel out are
+    /// explicitly stored as zeros.
+    ///
+    /// Panics if the minor dimension of `self` differs from the major dimension of `other`.
+    pub fn spmm(&self, other: &CsMatrix<T>) -> Self
+    where
+        T: ClosedAdd + ClosedMul + Zero + One,
+    {
+        assert_eq!(
+            self.pattern().minor_dim(),
+            other.pattern().major_dim(),
+            "The inner dimensions of the matrices must match."
+        );
+
+        let pattern = spmm_csr_pattern(self.pattern(), other.pattern());
+        let nnz = pattern.nnz();
+        let mut product = Self::from_pattern_and_values(pattern, vec![T::zero(); nnz]);
+        spmm_cs_prealloc(T::zero(), &mut product, T::one(), self, other)
+            .expect("Internal error: spmm_csr_pattern must hold the product.");
+        product
+    }
+
+    /// Computes the sum of `self` and `other`.
+    ///
+    /// The sparsity pattern of the result is the union of both patterns, and it is computed by
+    /// merging the sorted minor indices of each pair of lanes. The values of entries stored in
+    /// both matrices are summed.
+    ///
+    /// Panics if the dimensions of both matrices differ.
+    pub fn add(&self, other: &CsMatrix<T>) -> Self
+    where
+        T: ClosedAdd,
+    {
+        let (major_dim, minor_dim) = (self.pattern().major_dim(), self.pattern().minor_dim());
+        assert_eq!(
+            (major_dim, minor_dim),
+            (other.pattern().major_dim(), other.pattern().minor_dim()),
+            "The dimensions of both matrices must be identical."
+        );
+
+        let nnz = self.values.len() + other.values.len();
+        let mut offsets = Vec::with_capacity(major_dim + 1);
+        let mut indices = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+
+        offsets.push(0);
+        for (self_lane, other_lane) in self.lane_iter().zip(other.lane_iter()) {
+            let (a_indices, a_values) = (self_lane.minor_indices(), self_lane.values());
+            let (b_indices, b_values) = (other_lane.minor_indices(), other_lane.values());
+            let (mut p, mut q) = (0, 0);
+
+            while p < a_indices.len() && q < b_indices.len() {
+                match a_indices[p].cmp(&b_indices[q]) {
+                    Ordering::Less => {
+                        indices.push(a_indices[p]);
+                        values.push(a_values[p].inlined_clone());
+                        p += 1;
+                    }
+                    Ordering::Greater => {
+                        indices.push(b_indices[q]);
+                        values.push(b_values[q].inlined_clone());
+                        q += 1;
+                    }
+                    Ordering::Equal => {
+                        indices.push(a_indices[p]);
+                        values.push(a_values[p].inlined_clone() + b_values[q].inlined_clone());
+                        p += 1;
+                        q += 1;
+                    }
+                }
+            }
+
+            // At most one of the lanes has remaining entries.
+            indices.extend_from_slice(&a_indices[p..]);
+            values.extend(a_values[p..].iter().map(|v| v.inlined_clone()));
+            indices.extend_from_slice(&b_indices[q..]);
+            values.extend(b_values[q..].iter().map(|v| v.inlined_clone()));
+            offsets.push(indices.len());
+        }
+
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(major_dim, minor_dim, offsets, indices)
+                .expect("Internal error: Sparsity pattern must always be valid.");
+        Self::from_pattern_and_values(pattern, values)
     }
 
-    #[inline]
-    pub fn pattern_and_values_mut(&mut self) -> (&SparsityPattern, &mut [T]) {
-        (&self.sparsity_pattern, &mut self.values)
+    /// Adds `other` to `self`, in place.
+    ///
+    /// If both matrices share the same sparsity pattern, the values are added without
+    /// reallocating. Otherwise, `self` is replaced by [`add`](Self::add).
+    ///
+    /// Panics if the dimensions of both matrices differ.
+    pub fn add_assign(&mut self, other: &CsMatrix<T>)
+    where
+        T: ClosedAdd,
+    {
+        if self.pattern() == other.pattern() {
+            self.add_assign_same_pattern(other);
+        } else {
+            *self = CsMatrix::add(self, other);
+        }
     }
 
-    #[inline]
-    pub fn from_pattern_and_values(pattern: SparsityPattern, values: Vec<T>) -> Self {
+    /// Computes the element-wise (Hadamard) product of `self` and `other`.
+    ///
+    /// The sparsity pattern of the result is the intersection of both patterns, and it is
+    /// computed by merging the sorted minor indices of each pair of lanes.
+    ///
+    /// Panics if the dimensions of both matrices differ.
+    pub fn hadamard(&self, other: &CsMatrix<T>) -> Self
+    where
+        T: ClosedMul,
+    {
+        let (major_dim, minor_dim) = (self.pattern().major_dim(), self.pattern().minor_dim());
         assert_eq!(
-            pattern.nnz(),
-            values.len(),
-            "Internal error: consumers should verify shape compatibility."
+            (major_dim, minor_dim),
+            (other.pattern().major_dim(), other.pattern().minor_dim()),
+            "The dimensions of both matrices must be identical."
         );
-        Self {
-            sparsity_pattern: pattern,
-            values,
+
+        let nnz = self.values.len().min(other.values.len());
+        let mut offsets = Vec::with_capacity(major_dim + 1);
+        let mut indices = Vec::with_capacity(nnz);
+        let mut values = Vec::with_capacity(nnz);
+
+        offsets.push(0);
+        for (self_lane, other_lane) in self.lane_iter().zip(other.lane_iter()) {
+            let (a_indices, a_values) = (self_lane.minor_indices(), self_lane.values());
+            let (b_indices, b_values) = (other_lane.minor_indices(), other_lane.values());
+            let (mut p, mut q) = (0, 0);
+
+            while p < a_indices.len() && q < b_indices.len() {
+                match a_indices[p].cmp(&b_indices[q]) {
+                    Ordering::Less => p += 1,
+                    Ordering::Greater => q += 1,
+                    Ordering::Equal => {
+                        indices.push(a_indices[p]);
+                        values.push(a_values[p].inlined_clone() * b_values[q].inlined_clone());
+                        p += 1;
+                        q += 1;
+                    }
+                }
+            }
+            offsets.push(indices.len());
         }
+
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(major_dim, minor_dim, offsets, indices)
+                .expect("Internal error: Sparsity pattern must always be valid.");
+        Self::from_pattern_and_values(pattern, values)
     }
+}
 
-    /// Internal method for simplifying access to a lane's data
-    #[inline]
+impl<T: ComplexField> CsMatrix<T> {
+    /// The Frobenius norm of the matrix, i.e. the square root of the sum of the squared
+    /// magnitudes of the explicitly stored values.
     #[must_use]
-    pub fn get_index_range(&self, row_index: usize) -> Option<Range<usize>> {
-        let row_begin = *self.sparsity_pattern.major_offsets().get(row_index)?;
-        let row_end = *self.sparsity_pattern.major_offsets().get(row_index + 1)?;
-        Some(row_begin..row_end)
+    pub fn frobenius_norm(&self) -> T::RealField {
+        self.values
+            .iter()
+            .fold(T::RealField::zero(), |acc, v| {
+                acc + v.inlined_clone().modulus_squared()
+            })
+            .sqrt()
     }
 
-    pub fn take_pattern_and_values(self) -> (SparsityPattern, Vec<T>) {
-        (self.sparsity_pattern, self.values)
+    /// The largest magnitude of the explicitly stored values, or zero if there are none.
+    #[must_use]
+    pub fn max_norm(&self) -> T::RealField {
+        self.values.iter().fold(T::RealField::zero(), |acc, v| {
+            acc.max(v.inlined_clone().modulus())
+        })
     }
 
-    #[inline]
-    pub fn disassemble(self) -> (Vec<usize>, Vec<usize>, Vec<T>) {
-        let (offsets, indices) = self.sparsity_pattern.disassemble();
-        (offsets, indices, self.values)
-    }
+    /// The major and minor indices, and the value, of the explicitly stored entry with the
+    /// largest magnitude.
+    ///
+    /// Ties are resolved in favor of the first entry in storage order. NaN values are ignored,
+    /// and `None` is returned if there is no other explicitly stored value.
+    #[must_use]
+    pub fn max_abs_entry(&self) -> Option<(usize, usize, &T)> {
+        let (offsets, indices) = (
+            self.pattern().major_offsets(),
+            self.pattern().minor_indices(),
+        );
+        let mut max: Option<(usize, usize, T::RealField)> = None;
+
+        for i in 0..self.pattern().major_dim() {
+            for k in offsets[i]..offsets[i + 1] {
+                let modulus = self.values[k].inlined_clone().modulus();
+                let is_greater = match &max {
+                    Some((_, _, max_modulus)) => modulus > *max_modulus,
+                    // Written this way so that NaN values are ignored.
+                    None => modulus >= T::RealField::zero(),
+                };
+
+                if is_greater {
+                    max = Some((i, k, modulus));
+                }
+            }
+        }
 
-    #[inline]
-    pub fn into_pattern_and_values(self) -> (SparsityPattern, Vec<T>) {
-        (self.sparsity_pattern, self.values)
+        max.map(|(i, k, _)| (i, indices[k], &self.values[k]))
     }
 
-    /// Returns an entry for the given major/minor indices, or `None` if the indices are out
-    /// of bounds.
+    /// The largest sum of magnitudes over the lanes of the matrix.
     #[must_use]
-    pub fn get_entry(&self, major_index: usize, minor_index: usize) -> Option<SparseEntry<T>> {
-        let row_range = self.get_index_range(major_index)?;
-        let (_, minor_indices, values) = self.cs_data();
-        let minor_indices = &minor_indices[row_range.clone()];
-        let values = &values[row_range];
-        get_entry_from_slices(
-            self.pattern().minor_dim(),
-            minor_indices,
-            values,
-            minor_index,
-        )
+    pub fn max_lane_norm_1(&self) -> T::RealField {
+        self.lane_iter().fold(T::RealField::zero(), |acc, lane| {
+            let sum = lane.values().iter().fold(T::RealField::zero(), |sum, v| {
+                sum + v.inlined_clone().modulus()
+            });
+            acc.max(sum)
+        })
     }
 
-    /// Returns a mutable entry for the given major/minor indices, or `None` if the indices are out
-    /// of bounds.
-    pub fn get_entry_mut(
-        &mut self,
-        major_index: usize,
-        minor_index: usize,
-    ) -> Option<SparseEntryMut<T>> {
-        let row_range = self.get_index_range(major_index)?;
-        let minor_dim = self.pattern().minor_dim();
-        let (_, minor_indices, values) = self.cs_data_mut();
-        let minor_indices = &minor_indices[row_range.clone()];
-        let values = &mut values[row_range];
-        get_mut_entry_from_slices(minor_dim, minor_indices, values, minor_index)
+    /// The largest sum of magnitudes over the minor dimension of the matrix, i.e. over the
+    /// lanes of its transpose.
+    #[must_use]
+    pub fn max_minor_norm_1(&self) -> T::RealField {
+        let mut sums = vec![T::RealField::zero(); self.pattern().minor_dim()];
+        for (&j, v) in self.pattern().minor_indices().iter().zip(&self.values) {
+            sums[j] += v.inlined_clone().modulus();
+        }
+
+        sums.into_iter()
+            .fold(T::RealField::zero(), |acc, sum| acc.max(sum))
     }
 
+    /// Returns a copy of the matrix without the explicitly stored values of magnitude at most
+    /// `eps`.
     #[must_use]
-    pub fn get_lane(&self, index: usize) -> Option<CsLane<T>> {
-        let range = self.get_index_range(index)?;
-        let (_, minor_indices, values) = self.cs_data();
-        Some(CsLane {
-            minor_indices: &minor_indices[range.clone()],
-            values: &values[range],
-            minor_dim: self.pattern().minor_dim(),
-        })
+    pub fn prune(&self, eps: T::RealField) -> Self {
+        // Written as a negation so that NaN values are kept.
+        let is_small = |v: &T| v.inlined_clone().modulus() <= eps;
+        self.filter(|_, _, v| !is_small(v))
     }
 
-    #[inline]
+    /// The number of explicitly stored values of magnitude greater than `eps`, i.e. the number
+    /// of entries kept by `prune(eps)`.
     #[must_use]
-    pub fn get_lane_mut(&mut self, index: usize) -> Option<CsLaneMut<T>> {
-        let range = self.get_index_range(index)?;
-        let minor_dim = self.pattern().minor_dim();
-        let (_, minor_indices, values) = self.cs_data_mut();
-        Some(CsLaneMut {
-            minor_dim,
-            minor_indices: &minor_indices[range.clone()],
-            values: &mut values[range],
-        })
+    pub fn count_nonzero_above(&self, eps: T::RealField) -> usize {
+        // Written as a negation so that NaN values are counted, as they are kept by `prune`.
+        let is_small = |v: &T| v.inlined_clone().modulus() <= eps;
+        self.values.iter().filter(|v| !is_small(v)).count()
     }
 
-    #[inline]
-    pub fn lane_iter(&self) -> CsLaneIter<T> {
-        CsLaneIter::new(self.pattern(), self.values())
-    }
+    /// Same as `prune`, but compacts the indices and values in place.
+    pub fn prune_mut(&mut self, eps: T::RealField) {
+        let (major_dim, minor_dim) = (self.pattern().major_dim(), self.pattern().minor_dim());
+        let is_small = |v: &T| v.inlined_clone().modulus() <= eps;
+        let pattern = replace(&mut self.sparsity_pattern, SparsityPattern::zeros(0, 0));
+        let (mut offsets, mut indices) = pattern.disassemble();
+
+        let mut nnz = 0;
+        let mut lane_begin = 0;
+        for i in 0..major_dim {
+            let lane_end = offsets[i + 1];
+            for k in lane_begin..lane_end {
+                if !is_small(&self.values[k]) {
+                    indices[nnz] = indices[k];
+                    self.values.swap(nnz, k);
+                    nnz += 1;
+                }
+            }
+            offsets[i + 1] = nnz;
+            lane_begin = lane_end;
+        }
 
-    #[inline]
-    pub fn lane_iter_mut(&mut self) -> CsLaneIterMut<T> {
-        CsLaneIterMut::new(&self.sparsity_pattern, &mut self.values)
+        indices.truncate(nnz);
+        self.values.truncate(nnz);
+
+        // TODO: Avoid checks here
+        self.sparsity_pattern =
+            SparsityPattern::try_from_offsets_and_indices(major_dim, minor_dim, offsets, indices)
+                .expect("Internal error: Sparsity pattern must always be valid.");
     }
+}
 
-    #[inline]
-    #[must_use]
-    pub fn filter<P>(&self, predicate: P) -> Self
+impl<T: Scalar + ClosedAdd> CsMatrix<T> {
+    /// Constructs a matrix in a single pass from `(major, minor, value)` triplets sorted by major
+    /// index, then by minor index.
+    ///
+    /// The values of duplicate triplets are summed.
+    pub fn try_from_sorted_triplets<I>(
+        major_dim: usize,
+        minor_dim: usize,
+        triplets: I,
+    ) -> Result<Self, SortedTripletsError>
     where
-        T: Clone,
-        P: Fn(usize, usize, &T) -> bool,
+        I: IntoIterator<Item = (usize, usize, T)>,
     {
-        let (major_dim, minor_dim) = (self.pattern().major_dim(), self.pattern().minor_dim());
-        let mut new_offsets = Vec::with_capacity(self.pattern().major_dim() + 1);
-        let mut new_indices = Vec::new();
-        let mut new_values = Vec::new();
+        let triplets = triplets.into_iter();
+        let mut offsets = Vec::with_capacity(major_dim + 1);
+        let mut indices = Vec::with_capacity(triplets.size_hint().0);
+        let mut values: Vec<T> = Vec::with_capacity(triplets.size_hint().0);
+
+        offsets.push(0);
+        for (major_idx, minor_idx, value) in triplets {
+            if major_idx >= major_dim {
+                return Err(SortedTripletsError::MajorIndexOutOfBounds);
+            } else if minor_idx >= minor_dim {
+                return Err(SortedTripletsError::MinorIndexOutOfBounds);
+            }
 
-        new_offsets.push(0);
-        for (i, lane) in self.lane_iter().enumerate() {
-            for (&j, value) in lane.minor_indices().iter().zip(lane.values) {
-                if predicate(i, j, value) {
-                    new_indices.push(j);
-                    new_values.push(value.clone());
+            // The lane currently being filled is the one without an end offset yet.
+            let current_lane_idx = offsets.len() - 1;
+            if major_idx < current_lane_idx {
+                return Err(SortedTripletsError::Unsorted);
+            }
+
+            while offsets.len() - 1 < major_idx {
+                offsets.push(indices.len());
+            }
+
+            let lane_begin = offsets[major_idx];
+            match indices.last() {
+                Some(&last_minor_idx) if indices.len() > lane_begin => {
+                    if minor_idx < last_minor_idx {
+                        return Err(SortedTripletsError::Unsorted);
+                    } else if minor_idx == last_minor_idx {
+                        *values.last_mut().unwrap() += value;
+                    } else {
+                        indices.push(minor_idx);
+                        values.push(value);
+                    }
+                }
+                _ => {
+                    indices.push(minor_idx);
+                    values.push(value);
                 }
             }
+        }
 
-            new_offsets.push(new_indices.len());
+        while offsets.len() < major_dim + 1 {
+            offsets.push(indices.len());
         }
 
         // TODO: Avoid checks here
-        let new_pattern = SparsityPattern::try_from_offsets_and_indices(
-            major_dim,
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(major_dim, minor_dim, offsets, indices)
+                .expect("Internal error: Sparsity pattern must always be valid.");
+        Ok(Self::from_pattern_and_values(pattern, values))
+    }
+}
+
+/// Incrementally constructs a compressed matrix, one complete lane at a time.
+///
+/// Appending a lane costs amortized `O(nnz)` of the lane, since the offsets, minor indices and
+/// values are stored directly in their final layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsMatrixBuilder<T> {
+    minor_dim: usize,
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CsMatrixBuilder<T> {
+    /// Creates a builder for a matrix with the given minor dimension and no lane yet.
+    pub fn new(minor_dim: usize) -> Self {
+        Self::with_capacity(minor_dim, 0, 0)
+    }
+
+    /// Creates a builder with enough capacity for `major_dim` lanes with `nnz` entries in total.
+    pub fn with_capacity(minor_dim: usize, major_dim: usize, nnz: usize) -> Self {
+        let mut offsets = Vec::with_capacity(major_dim + 1);
+        offsets.push(0);
+
+        Self {
             minor_dim,
-            new_offsets,
-            new_indices,
-        )
-        .expect("Internal error: Sparsity pattern must always be valid.");
+            offsets,
+            indices: Vec::with_capacity(nnz),
+            values: Vec::with_capacity(nnz),
+        }
+    }
 
-        Self::from_pattern_and_values(new_pattern, new_values)
+    /// The number of lanes appended so far.
+    pub fn major_dim(&self) -> usize {
+        self.offsets.len() - 1
     }
 
-    /// Returns the diagonal of the matrix as a sparse matrix.
-    #[must_use]
-    pub fn diagonal_as_matrix(&self) -> Self
+    /// Appends a lane with the given entries.
+    ///
+    /// The minor indices must be strictly increasing and smaller than the minor dimension,
+    /// otherwise an error is returned and the builder is left unchanged.
+    ///
+    /// Panics if `minor_indices` and `values` do not have the same length.
+    pub fn push_lane(
+        &mut self,
+        minor_indices: &[usize],
+        values: &[T],
+    ) -> Result<(), SparsityPatternFormatError>
     where
         T: Clone,
     {
-        // TODO: This might be faster with a binary search for each diagonal entry
-        self.filter(|i, j, _| i == j)
+        assert_eq!(
+            minor_indices.len(),
+            values.len(),
+            "The numbers of minor indices and values must be the same."
+        );
+
+        for pair in minor_indices.windows(2) {
+            match pair[0].cmp(&pair[1]) {
+                Ordering::Less => {}
+                Ordering::Equal => return Err(SparsityPatternFormatError::DuplicateEntry),
+                Ordering::Greater => {
+                    return Err(SparsityPatternFormatError::NonmonotonicMinorIndices)
+                }
+            }
+        }
+
+        if matches!(minor_indices.last(), Some(&idx) if idx >= self.minor_dim) {
+            return Err(SparsityPatternFormatError::MinorIndexOutOfBounds);
+        }
+
+        self.indices.extend_from_slice(minor_indices);
+        self.values.extend_from_slice(values);
+        self.offsets.push(self.indices.len());
+        Ok(())
+    }
+
+    /// Constructs the matrix whose lanes are the lanes appended so far.
+    pub fn build(self) -> CsMatrix<T> {
+        let major_dim = self.major_dim();
+        // The lanes were validated when they were appended.
+        let pattern = SparsityPattern::from_offsets_and_indices_unchecked(
+            major_dim,
+            self.minor_dim,
+            self.offsets,
+            self.indices,
+        );
+        CsMatrix::from_pattern_and_values(pattern, self.values)
+    }
+}
+
+/// The pattern of an `n x n` diagonal matrix, with exactly one explicitly stored entry per lane.
+fn diagonal_pattern(n: usize) -> SparsityPattern {
+    let offsets: Vec<_> = (0..=n).collect();
+    let indices: Vec<_> = (0..n).collect();
+    SparsityPattern::from_offsets_and_indices_unchecked(n, n, offsets, indices)
+}
+
+impl<T: Scalar> CsMatrix<T> {
+    #[inline]
+    pub fn from_diagonal(diag: &[T]) -> Self {
+        Self::from_pattern_and_values(diagonal_pattern(diag.len()), diag.to_vec())
     }
 }
 
 impl<T: Scalar + One> CsMatrix<T> {
     #[inline]
     pub fn identity(n: usize) -> Self {
-        let offsets: Vec<_> = (0..=n).collect();
-        let indices: Vec<_> = (0..n).collect();
-        let values = vec![T::one(); n];
-
-        // TODO: We should skip checks here
-        let pattern =
-            SparsityPattern::try_from_offsets_and_indices(n, n, offsets, indices).unwrap();
-        Self::from_pattern_and_values(pattern, values)
+        Self::from_pattern_and_values(diagonal_pattern(n), vec![T::one(); n])
     }
 }
 
+/// Errors that can occur when constructing a compressed matrix from sorted triplets.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SortedTripletsError {
+    /// A major index is out of bounds.
+    MajorIndexOutOfBounds,
+    /// A minor index is out of bounds.
+    MinorIndexOutOfBounds,
+    /// The triplets are not sorted by major index, then by minor index.
+    Unsorted,
+}
+
 fn get_entry_from_slices<'a, T>(
     minor_dim: usize,
     minor_indices: &'a [usize],
@@ -375,6 +1856,65 @@ where
     }
 }
 
+/// A contiguous range of mutable lanes, recursively split in halves to be processed in parallel.
+#[cfg(feature = "rayon")]
+struct CsLaneChunkMut<'a, T> {
+    lanes: Range<usize>,
+    pattern: &'a SparsityPattern,
+    // The values of the lanes in `lanes` only.
+    values: &'a mut [T],
+}
+
+#[cfg(feature = "rayon")]
+impl<'a, T> CsLaneChunkMut<'a, T> {
+    fn split(self) -> (Self, Option<Self>) {
+        if self.lanes.len() <= 1 {
+            return (self, None);
+        }
+
+        let mid = self.lanes.start + self.lanes.len() / 2;
+        let offsets = self.pattern.major_offsets();
+        let (left, right) = self
+            .values
+            .split_at_mut(offsets[mid] - offsets[self.lanes.start]);
+
+        let left = Self {
+            lanes: self.lanes.start..mid,
+            pattern: self.pattern,
+            values: left,
+        };
+        let right = Self {
+            lanes: mid..self.lanes.end,
+            pattern: self.pattern,
+            values: right,
+        };
+        (left, Some(right))
+    }
+
+    fn into_lanes(self) -> impl Iterator<Item = (usize, CsLaneMut<'a, T>)> {
+        let Self {
+            lanes,
+            pattern,
+            mut values,
+        } = self;
+        let minor_dim = pattern.minor_dim();
+
+        lanes.map(move |i| {
+            let minor_indices = pattern.lane(i);
+            let remaining = take(&mut values);
+            let (values_in_lane, remaining) = remaining.split_at_mut(minor_indices.len());
+            values = remaining;
+
+            let lane = CsLaneMut {
+                minor_dim,
+                minor_indices,
+                values: values_in_lane,
+            };
+            (i, lane)
+        })
+    }
+}
+
 /// Implement the methods common to both CsLane and CsLaneMut. See the documentation for the
 /// methods delegated here by CsrMatrix and CscMatrix members for more information.
 macro_rules! impl_cs_lane_common_methods {
@@ -414,6 +1954,11 @@ macro_rules! impl_cs_lane_common_methods {
                     global_col_index,
                 )
             }
+
+            #[inline]
+            pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = (usize, &T)> {
+                self.minor_indices.iter().copied().zip(self.values.iter())
+            }
         }
     };
 }
@@ -430,6 +1975,13 @@ impl<'a, T> CsLaneMut<'a, T> {
         (self.minor_indices, self.values)
     }
 
+    pub fn iter_mut(&mut self) -> impl '_ + ExactSizeIterator<Item = (usize, &mut T)> {
+        self.minor_indices
+            .iter()
+            .copied()
+            .zip(self.values.iter_mut())
+    }
+
     #[must_use]
     pub fn get_entry_mut(&mut self, global_minor_index: usize) -> Option<SparseEntryMut<T>> {
         get_mut_entry_from_slices(
@@ -439,6 +1991,24 @@ impl<'a, T> CsLaneMut<'a, T> {
             global_minor_index,
         )
     }
+
+    /// Adds `dense[j]` to the explicitly stored value of every minor index `j` of the lane.
+    ///
+    /// Panics if `dense.len()` differs from the minor dimension.
+    pub fn scatter_add(&mut self, dense: &[T])
+    where
+        T: Scalar + ClosedAdd,
+    {
+        assert_eq!(
+            dense.len(),
+            self.minor_dim,
+            "The dense vector must have one entry per minor index of the lane."
+        );
+
+        for (&j, value) in self.minor_indices.iter().zip(self.values.iter_mut()) {
+            *value += dense[j].inlined_clone();
+        }
+    }
 }
 
 /// Helper struct for working with uninitialized data in vectors.
@@ -543,3 +2113,62 @@ pub fn convert_counts_to_offsets(counts: &mut [usize]) {
         offset += count;
     }
 }
+
+/// The maximum number of rows and columns of matrices displayed as a dense grid by the alternate
+/// `Display` format (`{:#}`) of CSR and CSC matrices.
+pub const DISPLAY_DENSE_MAX_DIM: usize = 16;
+
+/// Displays a sparse matrix in the given `format` (`"CSR"` or `"CSC"`) from its triplets.
+///
+/// A header with the dimensions and the number of explicitly stored entries is followed by one
+/// `(row, col) = value` line per entry, in the order given by `triplets`. With the alternate
+/// flag, matrices with at most `DISPLAY_DENSE_MAX_DIM` rows and columns are instead displayed as
+/// a dense grid in which entries that are not explicitly stored are shown as dots.
+pub fn fmt_sparse_matrix<'a, T: fmt::Display + 'a>(
+    f: &mut fmt::Formatter<'_>,
+    format: &str,
+    (nrows, ncols): (usize, usize),
+    nnz: usize,
+    triplets: impl Iterator<Item = (usize, usize, &'a T)>,
+) -> fmt::Result {
+    let plural = if nnz == 1 { "entry" } else { "entries" };
+    write!(
+        f,
+        "{}x{} {} matrix with {} explicitly stored {}",
+        nrows, ncols, format, nnz, plural
+    )?;
+
+    let precision = f.precision();
+    let fmt_value = |value: &T| match precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => format!("{}", value),
+    };
+
+    if f.alternate() && nrows <= DISPLAY_DENSE_MAX_DIM && ncols <= DISPLAY_DENSE_MAX_DIM {
+        let mut grid = vec![None; nrows * ncols];
+        for (i, j, v) in triplets {
+            grid[i * ncols + j] = Some(fmt_value(v));
+        }
+
+        let width = grid
+            .iter()
+            .map(|value| value.as_ref().map_or(1, |v| v.chars().count()))
+            .max()
+            .unwrap_or(1);
+
+        for row in grid.chunks(ncols.max(1)) {
+            writeln!(f)?;
+            for (j, value) in row.iter().enumerate() {
+                let separator = if j == 0 { "" } else { " " };
+                let value = value.as_deref().unwrap_or(".");
+                write!(f, "{}{:>width$}", separator, value, width = width)?;
+            }
+        }
+    } else {
+        for (i, j, v) in triplets {
+            write!(f, "\n({}, {}) = {}", i, j, fmt_value(v))?;
+        }
+    }
+
+    Ok(())
+}
@@ -2,16 +2,26 @@
 //!
 //! This is the module-level documentation. See [`CsrMatrix`] for the main documentation of the
 //! CSC implementation.
-use crate::cs::{CsLane, CsLaneIter, CsLaneIterMut, CsLaneMut, CsMatrix};
+use crate::cs::{
+    fmt_sparse_matrix, CsLane, CsLaneIter, CsLaneIterMut, CsLaneMut, CsMatrix, CsMatrixBuilder,
+};
 use crate::csc::CscMatrix;
+use crate::lanczos::Lanczos;
+use crate::ops::serial::OperationError;
 use crate::pattern::{SparsityPattern, SparsityPatternFormatError, SparsityPatternIter};
 use crate::{SparseEntry, SparseEntryMut, SparseFormatError, SparseFormatErrorKind};
 
-use nalgebra::Scalar;
-use num_traits::One;
+use nalgebra::{
+    ClosedAdd, ClosedDiv, ClosedMul, ClosedSub, ComplexField, DMatrix, DVector, RealField, Scalar,
+};
+use num_traits::{One, Zero};
 
+use std::fmt;
 use std::slice::{Iter, IterMut};
 
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
 /// A CSR representation of a sparse matrix.
 ///
 /// The Compressed Sparse Row (CSR) format is well-suited as a general-purpose storage format
@@ -138,6 +148,20 @@ impl<T> CsrMatrix<T> {
         }
     }
 
+    /// Constructs a CSR representation of the (square) diagonal matrix whose diagonal is `diag`.
+    ///
+    /// The matrix has exactly `diag.len()` explicitly stored entries, including the zeros of
+    /// `diag`.
+    #[inline]
+    pub fn from_diagonal(diag: &[T]) -> Self
+    where
+        T: Scalar,
+    {
+        Self {
+            cs: CsMatrix::from_diagonal(diag),
+        }
+    }
+
     /// Create a zero CSR matrix with no explicitly stored entries.
     pub fn zeros(nrows: usize, ncols: usize) -> Self {
         Self {
@@ -190,6 +214,239 @@ impl<T> CsrMatrix<T> {
         }
     }
 
+    /// Try to construct a CSR matrix from `(i, j, v)` triplets sorted by row index, then by column index.
+    ///
+    /// The matrix is assembled in a single pass over the triplets, in linear time. The values
+    /// of duplicate triplets are summed.
+    ///
+    /// An error is returned if a triplet has indices out of bounds, or if the triplets are not
+    /// sorted as described above. The triplets are consumed up to the first offending triplet.
+    pub fn try_from_sorted_triplets<I>(
+        nrows: usize,
+        ncols: usize,
+        triplets: I,
+    ) -> Result<Self, SparseFormatError>
+    where
+        T: Scalar + ClosedAdd,
+        I: IntoIterator<Item = (usize, usize, T)>,
+    {
+        use crate::cs::SortedTripletsError as E;
+        use SparseFormatErrorKind as K;
+
+        let cs = CsMatrix::try_from_sorted_triplets(nrows, ncols, triplets).map_err(
+            |err| match err {
+                E::MajorIndexOutOfBounds => SparseFormatError::from_kind_and_msg(
+                    K::IndexOutOfBounds,
+                    "Row index out of bounds.",
+                ),
+                E::MinorIndexOutOfBounds => SparseFormatError::from_kind_and_msg(
+                    K::IndexOutOfBounds,
+                    "Column index out of bounds.",
+                ),
+                E::Unsorted => SparseFormatError::from_kind_and_msg(
+                    K::InvalidStructure,
+                    "Triplets are not sorted by row index, then by column index.",
+                ),
+            },
+        )?;
+        Ok(Self { cs })
+    }
+
+    /// Constructs the block-diagonal matrix with the given blocks along its diagonal.
+    ///
+    /// The blocks can have arbitrary (and different) dimensions. Each block starts at the row
+    /// and column following the last row and column of the previous block.
+    pub fn block_diagonal(blocks: &[CsrMatrix<T>]) -> Self
+    where
+        T: Scalar,
+    {
+        let blocks: Vec<_> = blocks.iter().map(|b| &b.cs).collect();
+        Self {
+            cs: CsMatrix::block_diagonal(&blocks),
+        }
+    }
+
+    /// Stacks the given matrices vertically, i.e. on top of each other.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrices do not all have the same number of columns.
+    pub fn vstack(blocks: &[CsrMatrix<T>]) -> Self
+    where
+        T: Scalar,
+    {
+        assert!(
+            blocks.windows(2).all(|w| w[0].ncols() == w[1].ncols()),
+            "All the matrices must have the same number of columns."
+        );
+        let blocks: Vec<_> = blocks.iter().map(|b| &b.cs).collect();
+        Self {
+            cs: CsMatrix::concat_major(&blocks),
+        }
+    }
+
+    /// Stacks the given matrices horizontally, i.e. next to each other.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrices do not all have the same number of rows.
+    pub fn hstack(blocks: &[CsrMatrix<T>]) -> Self
+    where
+        T: Scalar,
+    {
+        assert!(
+            blocks.windows(2).all(|w| w[0].nrows() == w[1].nrows()),
+            "All the matrices must have the same number of rows."
+        );
+        let blocks: Vec<_> = blocks.iter().map(|b| &b.cs).collect();
+        Self {
+            cs: CsMatrix::concat_minor(&blocks),
+        }
+    }
+
+    /// Computes the Kronecker product of `self` and `other`.
+    ///
+    /// The result is the block matrix where the block `(i, j)` is `other` scaled by the entry
+    /// `(i, j)` of `self`. Its dimensions are the products of the dimensions of both matrices, and
+    /// its number of explicitly stored entries is the product of theirs.
+    #[must_use]
+    pub fn kronecker(&self, other: &CsrMatrix<T>) -> Self
+    where
+        T: Scalar + ClosedMul,
+    {
+        Self {
+            cs: self.cs.kronecker(&other.cs),
+        }
+    }
+
+    /// Computes the element-wise (Hadamard) product of `self` and `other`.
+    ///
+    /// Only the entries explicitly stored in both matrices are multiplied, so the sparsity
+    /// pattern of the result is the intersection of both patterns.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the dimensions of both matrices differ.
+    #[must_use]
+    pub fn hadamard(&self, other: &CsrMatrix<T>) -> Self
+    where
+        T: Scalar + ClosedMul,
+    {
+        Self {
+            cs: self.cs.hadamard(&other.cs),
+        }
+    }
+
+    /// Computes the matrix product `self * other` with Gustavson's algorithm.
+    ///
+    /// The sparsity pattern of the result is computed first, then each row of the result is
+    /// accumulated in a dense scratch vector and gathered into it. This gives the same result as
+    /// the `*` operator.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the number of columns of `self` differs from the number of rows of `other`.
+    #[must_use]
+    pub fn spmm(&self, other: &CsrMatrix<T>) -> Self
+    where
+        T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+    {
+        Self {
+            cs: self.cs.spmm(&other.cs),
+        }
+    }
+
+    /// The Frobenius norm of the matrix, i.e. the square root of the sum of the squared
+    /// magnitudes of its entries.
+    ///
+    /// This only reads the explicitly stored values, and does not depend on the sparsity pattern.
+    #[must_use]
+    pub fn frobenius_norm(&self) -> T::RealField
+    where
+        T: ComplexField,
+    {
+        self.cs.frobenius_norm()
+    }
+
+    /// The largest magnitude of the entries of the matrix, or zero if no entry is explicitly
+    /// stored.
+    #[must_use]
+    pub fn max_norm(&self) -> T::RealField
+    where
+        T: ComplexField,
+    {
+        self.cs.max_norm()
+    }
+
+    /// The induced 1-norm of the matrix, i.e. the largest sum of magnitudes over its columns.
+    ///
+    /// This is zero for matrices without any column.
+    #[must_use]
+    pub fn one_norm(&self) -> T::RealField
+    where
+        T: ComplexField,
+    {
+        self.cs.max_minor_norm_1()
+    }
+
+    /// The induced infinity-norm of the matrix, i.e. the largest sum of magnitudes over its rows.
+    ///
+    /// This is zero for matrices without any row.
+    #[must_use]
+    pub fn infinity_norm(&self) -> T::RealField
+    where
+        T: ComplexField,
+    {
+        self.cs.max_lane_norm_1()
+    }
+
+    /// The row index, column index and value of the explicitly stored entry with the largest
+    /// magnitude.
+    ///
+    /// Ties are resolved in favor of the first entry in row-major order. NaN values are
+    /// ignored, and `None` is returned if there is no other explicitly stored value.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// ```
+    /// # use nalgebra_sparse::{CooMatrix, CsrMatrix};
+    /// let mut coo = CooMatrix::new(3, 3);
+    /// coo.push(0, 1, 2.0);
+    /// coo.push(2, 0, -5.0);
+    /// coo.push(1, 2, 5.0);
+    /// let matrix = CsrMatrix::from(&coo);
+    /// assert_eq!(matrix.max_abs_entry(), Some((1, 2, &5.0)));
+    /// ```
+    #[must_use]
+    pub fn max_abs_entry(&self) -> Option<(usize, usize, &T)>
+    where
+        T: ComplexField,
+    {
+        self.cs.max_abs_entry()
+    }
+
+    /// The sum of the explicitly stored values, or zero if there are none.
+    #[must_use]
+    pub fn sum(&self) -> T
+    where
+        T: Scalar + ClosedAdd + Zero,
+    {
+        self.cs.sum()
+    }
+
+    /// The number of explicitly stored values of magnitude greater than `eps`.
+    ///
+    /// This is the number of non-zero entries of [`prune(eps)`](Self::prune), so NaN values are
+    /// counted.
+    #[must_use]
+    pub fn count_nonzero_above(&self, eps: T::RealField) -> usize
+    where
+        T: ComplexField,
+    {
+        self.cs.count_nonzero_above(eps)
+    }
+
     /// The number of rows in the matrix.
     #[inline]
     #[must_use]
@@ -300,6 +557,27 @@ impl<T> CsrMatrix<T> {
         }
     }
 
+    /// An iterator over the number of explicitly stored entries in each row.
+    ///
+    /// This only reads the row offsets, which makes it a cheap way to estimate the work
+    /// associated with each row, e.g. for load balancing.
+    #[inline]
+    pub fn row_nnz_iter(&self) -> impl '_ + ExactSizeIterator<Item = usize> {
+        self.pattern().lane_nnz_iter()
+    }
+
+    /// The largest number of explicitly stored entries in a single row.
+    #[must_use]
+    pub fn max_row_nnz(&self) -> usize {
+        self.pattern().max_lane_nnz()
+    }
+
+    /// The average number of explicitly stored entries per row.
+    #[must_use]
+    pub fn mean_row_nnz(&self) -> f64 {
+        self.pattern().mean_lane_nnz()
+    }
+
     /// Return the row at the given row index.
     ///
     /// Panics
@@ -329,6 +607,18 @@ impl<T> CsrMatrix<T> {
         self.cs.get_lane(index).map(|lane| CsrRow { lane })
     }
 
+    /// Returns the row at the given row index as a dense vector, or `None` if out of bounds.
+    ///
+    /// The vector has one entry per column of the matrix, and entries that are not explicitly
+    /// stored are zero.
+    #[must_use]
+    pub fn get_row_as_dense(&self, index: usize) -> Option<DVector<T>>
+    where
+        T: Scalar + Zero,
+    {
+        self.cs.lane_as_dense(index)
+    }
+
     /// Mutable row access for the given row index, or `None` if out of bounds.
     #[inline]
     #[must_use]
@@ -351,6 +641,28 @@ impl<T> CsrMatrix<T> {
         }
     }
 
+    /// A parallel iterator over rows in the matrix.
+    ///
+    /// Requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn par_row_iter(&self) -> impl IndexedParallelIterator<Item = CsrRow<'_, T>>
+    where
+        T: Sync,
+    {
+        self.cs.par_lane_iter().map(|lane| CsrRow { lane })
+    }
+
+    /// A parallel mutable iterator over rows in the matrix.
+    ///
+    /// Requires the `rayon` feature.
+    #[cfg(feature = "rayon")]
+    pub fn par_row_iter_mut(&mut self) -> impl ParallelIterator<Item = CsrRowMut<'_, T>>
+    where
+        T: Send,
+    {
+        self.cs.par_lane_iter_mut().map(|lane| CsrRowMut { lane })
+    }
+
     /// Disassembles the CSR matrix into its underlying offset, index and value arrays.
     ///
     /// If the matrix contains the sole reference to the sparsity pattern,
@@ -399,7 +711,10 @@ impl<T> CsrMatrix<T> {
 
     /// Reinterprets the CSR matrix as its transpose represented by a CSC matrix.
     ///
-    /// This operation does not touch the CSR data, and is effectively a no-op.
+    /// This operation does not touch the CSR data, and is effectively a no-op: it runs in
+    /// `O(1)` time and reuses the existing buffers. Use
+    /// [`transpose_to_same_format`](CsrMatrix::transpose_to_same_format) if the transpose is needed
+    /// in CSR format.
     pub fn transpose_as_csc(self) -> CscMatrix<T> {
         let (pattern, values) = self.cs.take_pattern_and_values();
         CscMatrix::try_from_pattern_and_values(pattern, values).unwrap()
@@ -468,6 +783,25 @@ impl<T> CsrMatrix<T> {
 
     /// Creates a sparse matrix that contains only the explicit entries decided by the
     /// given predicate.
+    ///
+    /// The predicate is called as `predicate(row, col, value)` for each explicitly stored entry.
+    /// This is the same order as for [`CscMatrix::filter`](crate::csc::CscMatrix::filter), so
+    /// the same predicate selects the same entries in both formats.
+    ///
+    /// Example
+    /// -------
+    /// ```
+    /// # use nalgebra_sparse::csr::CsrMatrix;
+    /// # use nalgebra::DMatrix;
+    /// let dense = DMatrix::from_row_slice(2, 3, &[1, 2, 3,
+    ///                                             4, 5, 6]);
+    /// let csr = CsrMatrix::from(&dense);
+    ///
+    /// // Keep the entries strictly above the diagonal.
+    /// let upper = csr.filter(|row, col, _| row < col);
+    /// assert_eq!(DMatrix::from(&upper), DMatrix::from_row_slice(2, 3, &[0, 2, 3,
+    ///                                                                   0, 0, 6]));
+    /// ```
     #[must_use]
     pub fn filter<P>(&self, predicate: P) -> Self
     where
@@ -481,6 +815,160 @@ impl<T> CsrMatrix<T> {
         }
     }
 
+    /// Creates a copy of this matrix without the row `row`.
+    ///
+    /// The subsequent rows are shifted up by one, and their entries are kept as-is.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if `row >= nrows`.
+    #[must_use]
+    pub fn remove_row(&self, row: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.remove_lane_and_minor(Some(row), None),
+        }
+    }
+
+    /// Creates a copy of this matrix without the column `col`.
+    ///
+    /// The subsequent columns are shifted left by one, and their entries are kept as-is.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if `col >= ncols`.
+    #[must_use]
+    pub fn remove_column(&self, col: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.remove_lane_and_minor(None, Some(col)),
+        }
+    }
+
+    /// Creates a copy of this square matrix without the row and the column `index`.
+    ///
+    /// This is typically used to impose a Dirichlet boundary condition on a degree of freedom
+    /// by elimination. The subsequent rows and columns are shifted by one, so the result is a
+    /// `(n - 1) × (n - 1)` matrix.
+    ///
+    /// Example
+    /// -------
+    /// ```
+    /// # use nalgebra_sparse::csr::CsrMatrix;
+    /// # use nalgebra::DMatrix;
+    /// let dense = DMatrix::from_row_slice(3, 3, &[ 2, -1,  0,
+    ///                                             -1,  2, -1,
+    ///                                              0, -1,  2]);
+    /// let csr = CsrMatrix::from(&dense);
+    ///
+    /// let reduced = csr.remove_row_and_column(1);
+    /// assert_eq!(reduced.nnz(), 2);
+    /// assert_eq!(DMatrix::from(&reduced), DMatrix::from_row_slice(2, 2, &[2, 0,
+    ///                                                                     0, 2]));
+    /// ```
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrix is not square, or if `index >= nrows`.
+    #[must_use]
+    pub fn remove_row_and_column(&self, index: usize) -> Self
+    where
+        T: Clone,
+    {
+        assert_eq!(self.nrows(), self.ncols(), "The matrix must be square.");
+        Self {
+            cs: self.cs.remove_lane_and_minor(Some(index), Some(index)),
+        }
+    }
+
+    /// Creates a sparse matrix without the explicitly stored entries of magnitude at most `eps`.
+    ///
+    /// This is typically used with a zero or tiny `eps` to drop the explicitly stored zeros left
+    /// by arithmetic operations. Entries whose value is NaN are kept.
+    #[must_use]
+    pub fn prune(&self, eps: T::RealField) -> Self
+    where
+        T: ComplexField,
+    {
+        Self {
+            cs: self.cs.prune(eps),
+        }
+    }
+
+    /// Removes the explicitly stored entries of magnitude at most `eps` in place.
+    ///
+    /// Same as [`prune`](Self::prune), except that the kept entries are compacted in the
+    /// existing buffers instead of being copied to new ones.
+    pub fn prune_mut(&mut self, eps: T::RealField)
+    where
+        T: ComplexField,
+    {
+        self.cs.prune_mut(eps)
+    }
+
+    /// Creates a sparse matrix with the same sparsity pattern, whose explicitly stored values
+    /// are obtained by applying `f` to the values of this matrix.
+    ///
+    /// The sparsity pattern is cloned, so explicitly stored entries mapped to zero are kept.
+    #[must_use]
+    pub fn map_values<U, F>(&self, f: F) -> CsrMatrix<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        CsrMatrix {
+            cs: self.cs.map_values(f),
+        }
+    }
+
+    /// Same as [`map_values`](CsrMatrix::map_values), except that `f` is also given the row and
+    /// column indices of each explicitly stored entry.
+    #[must_use]
+    pub fn map_with_indices<U, F>(&self, f: F) -> CsrMatrix<U>
+    where
+        F: Fn(usize, usize, &T) -> U,
+    {
+        CsrMatrix {
+            cs: self.cs.map_with_indices(f),
+        }
+    }
+
+    /// Calls `f` with the row index, the column index and the value of each explicitly stored
+    /// entry `(i, j)`, along with `dense[j]`.
+    ///
+    /// This is a fused, allocation-free traversal for gather-style operations: for instance,
+    /// accumulating `f(i, j, a_ij, x_j)` into `y[i]` generalizes the matrix-vector product
+    /// `y = A * x`, and masks or reweights the entries of `A` depending on `x`. The entries are
+    /// visited row by row.
+    ///
+    /// Example
+    /// -------
+    /// ```
+    /// # use nalgebra_sparse::{CooMatrix, CsrMatrix};
+    /// let coo = CooMatrix::try_from_triplets(2, 3, vec![0, 0, 1], vec![0, 2, 1], vec![1.0, 2.0, 3.0])
+    ///     .unwrap();
+    /// let a = CsrMatrix::from(&coo);
+    /// let x = [1.0, 10.0, 100.0];
+    ///
+    /// // y = A * x + b, in a single pass.
+    /// let mut y = vec![0.5, 0.5];
+    /// a.zip_with_dense(&x, |i, _, a_ij, x_j| y[i] += a_ij * x_j);
+    /// assert_eq!(y, vec![201.5, 30.5]);
+    /// ```
+    ///
+    /// Panics
+    /// ------
+    /// Panics if `dense.len()` is not `ncols`.
+    pub fn zip_with_dense<U, F>(&self, dense: &[U], f: F)
+    where
+        F: FnMut(usize, usize, &T, &U),
+    {
+        self.cs.zip_lanes_with_dense(dense, f)
+    }
+
     /// Returns a new matrix representing the upper triangular part of this matrix.
     ///
     /// The result includes the diagonal of the matrix.
@@ -489,7 +977,9 @@ impl<T> CsrMatrix<T> {
     where
         T: Clone,
     {
-        self.filter(|i, j, _| i <= j)
+        Self {
+            cs: self.cs.upper_triangle(true),
+        }
     }
 
     /// Returns a new matrix representing the lower triangular part of this matrix.
@@ -500,7 +990,37 @@ impl<T> CsrMatrix<T> {
     where
         T: Clone,
     {
-        self.filter(|i, j, _| i >= j)
+        Self {
+            cs: self.cs.lower_triangle(true),
+        }
+    }
+
+    /// Returns a new matrix representing the strictly upper triangular part of this matrix.
+    ///
+    /// Unlike [`upper_triangle`](Self::upper_triangle), the result excludes the diagonal, so
+    /// that `lower_triangle() + strict_upper_triangle()` is this matrix.
+    #[must_use]
+    pub fn strict_upper_triangle(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.upper_triangle(false),
+        }
+    }
+
+    /// Returns a new matrix representing the strictly lower triangular part of this matrix.
+    ///
+    /// Unlike [`lower_triangle`](Self::lower_triangle), the result excludes the diagonal, so
+    /// that `strict_lower_triangle() + upper_triangle()` is this matrix.
+    #[must_use]
+    pub fn strict_lower_triangle(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cs: self.cs.lower_triangle(false),
+        }
     }
 
     /// Returns the diagonal of the matrix as a sparse matrix.
@@ -514,13 +1034,338 @@ impl<T> CsrMatrix<T> {
         }
     }
 
+    /// Multiplies every explicitly stored value by `factor`, in place.
+    ///
+    /// The sparsity pattern is left untouched, so explicitly stored zeros are kept.
+    #[inline]
+    pub fn scale_mut(&mut self, factor: T)
+    where
+        T: Scalar + ClosedMul,
+    {
+        self.cs.scale_mut(factor)
+    }
+
+    /// Divides the explicitly stored values of each row by their sum, in place.
+    ///
+    /// This makes a matrix with non-negative entries row-stochastic, as used for Markov chains.
+    /// Empty rows, and rows whose values sum to zero, are left untouched.
+    #[inline]
+    pub fn normalize_rows_mut(&mut self)
+    where
+        T: Scalar + ClosedAdd + ClosedDiv + Zero,
+    {
+        self.cs.normalize_lanes_mut()
+    }
+
+    /// Divides the explicitly stored values of each row by `f` applied to these values, in
+    /// place.
+    ///
+    /// For instance, `f` can compute the L1, L2 or max norm of the values. Empty rows, and
+    /// rows for which `f` returns zero, are left untouched.
+    #[inline]
+    pub fn normalize_rows_by<F>(&mut self, f: F)
+    where
+        T: Scalar + ClosedDiv + Zero,
+        F: Fn(&[T]) -> T,
+    {
+        self.cs.normalize_lanes_by(f)
+    }
+
+    /// Adds the explicitly stored values of `other` to those of `self`, in place.
+    ///
+    /// This is cheaper than a general sparse addition since both matrices are required to
+    /// share the same sparsity pattern, which is typical of iterative solvers where only the
+    /// numerical values change between iterations.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the sparsity patterns of `self` and `other` are not identical.
+    #[inline]
+    pub fn add_assign_same_pattern(&mut self, other: &CsrMatrix<T>)
+    where
+        T: Scalar + ClosedAdd,
+    {
+        self.cs.add_assign_same_pattern(&other.cs)
+    }
+
+    /// Computes the sparse matrix-vector product `y <- beta * y + alpha * A * x`, where `A` is
+    /// this matrix, without allocating.
+    ///
+    /// This is useful in iterative solvers, where the product is computed at every iteration.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if `x.len()` is not `ncols` or `y.len()` is not `nrows`.
+    pub fn spmv_into(&self, x: &[T], y: &mut [T], beta: T, alpha: T)
+    where
+        T: Scalar + ClosedAdd + ClosedMul + Zero,
+    {
+        self.cs.spmv_into(x, y, beta, alpha)
+    }
+
+    /// Computes the sparse matrix-vector product `y <- beta * y + alpha * A * x`, where `A` is the
+    /// symmetric matrix of which only one triangle (either the lower or the upper one) is stored
+    /// in this matrix, without allocating.
+    ///
+    /// Every stored off-diagonal entry `(i, j)` contributes both for itself and for its mirror
+    /// `(j, i)`, while diagonal entries contribute once. If entries of both triangles are stored,
+    /// they are all accounted for twice.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrix is not square, or if `x.len()` or `y.len()` is not `nrows`.
+    pub fn spmv_symmetric_into(&self, x: &[T], y: &mut [T], beta: T, alpha: T)
+    where
+        T: Scalar + ClosedAdd + ClosedMul,
+    {
+        self.cs.spmv_symmetric_into(x, y, beta, alpha)
+    }
+
+    /// Starts the Lanczos iteration on this symmetric matrix, with the direction of `start` as the
+    /// first Lanczos vector.
+    ///
+    /// Unlike [`spmv_symmetric_into`](Self::spmv_symmetric_into), both triangles of the matrix
+    /// must be stored. If `reorthogonalize` is `true`, every Lanczos vector is orthogonalized
+    /// against all the previous ones. See [`Lanczos`] for details.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrix is not square, if `start.len()` is not `nrows`, or if `start` is zero.
+    pub fn lanczos(&self, start: &[T], reorthogonalize: bool) -> Lanczos<'_, T>
+    where
+        T: RealField,
+    {
+        Lanczos::new(&self.cs, start, reorthogonalize)
+    }
+
+    /// Solves `L * x = b` in place by forward substitution, where `L` is the lower triangle of
+    /// this matrix.
+    ///
+    /// Entries above the diagonal are ignored, so this matrix can for example be a sparse
+    /// Cholesky or LU factor.
+    ///
+    /// Errors
+    /// ------
+    /// Returns an error of kind [`Singular`](crate::ops::serial::OperationErrorKind::Singular)
+    /// if a diagonal entry is zero or not explicitly stored. In that case, `b` is left partially
+    /// overwritten.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrix is not square or if `b.len()` does not match its dimension.
+    pub fn solve_lower_triangular_into(&self, b: &mut [T]) -> Result<(), OperationError>
+    where
+        T: Scalar + Zero + ClosedSub + ClosedMul + ClosedDiv,
+    {
+        self.cs.solve_lower_triangular_into(b)
+    }
+
+    /// Solves `U * x = b` in place by back substitution, where `U` is the upper triangle of this
+    /// matrix.
+    ///
+    /// Entries below the diagonal are ignored.
+    ///
+    /// Errors
+    /// ------
+    /// Returns an error of kind [`Singular`](crate::ops::serial::OperationErrorKind::Singular)
+    /// if a diagonal entry is zero or not explicitly stored. In that case, `b` is left partially
+    /// overwritten.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrix is not square or if `b.len()` does not match its dimension.
+    pub fn solve_upper_triangular_into(&self, b: &mut [T]) -> Result<(), OperationError>
+    where
+        T: Scalar + Zero + ClosedSub + ClosedMul + ClosedDiv,
+    {
+        self.cs.solve_upper_triangular_into(b)
+    }
+
+    /// Computes the incomplete Cholesky factor with no fill-in, IC(0), of this symmetric
+    /// positive-definite matrix.
+    ///
+    /// The returned lower-triangular factor `L` has the sparsity pattern of the lower triangle
+    /// of this matrix, and satisfies `(L * L^T)_ij = A_ij` for every `(i, j)` in that pattern.
+    /// It is typically used as a preconditioner for the conjugate gradient method, by applying
+    /// [`solve_lower_triangular_into`](Self::solve_lower_triangular_into) with `L`, then
+    /// [`solve_upper_triangular_into`](Self::solve_upper_triangular_into) with `L^T`.
+    ///
+    /// Only the lower triangle of this matrix is read.
+    ///
+    /// Returns `None` if a non-positive pivot is encountered, including when a diagonal entry
+    /// is not explicitly stored.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrix is not square.
+    #[must_use]
+    pub fn incomplete_cholesky(&self) -> Option<Self>
+    where
+        T: RealField,
+    {
+        self.cs.incomplete_cholesky().map(|cs| Self { cs })
+    }
+
+    /// Estimates the eigenvalue of largest magnitude of this matrix, and an associated unit
+    /// eigenvector, with the power iteration.
+    ///
+    /// Each iteration costs one sparse matrix-vector product. The eigenvalue is estimated by the
+    /// Rayleigh quotient `ρ = x^T * A * x` of the current iterate, and the iteration stops as soon
+    /// as two successive estimates differ by at most `eps` times the magnitude of the latest one,
+    /// and the residual `|A * x - ρ * x|` is at most `sqrt(eps) * |A * x|`.
+    ///
+    /// Returns `None` if `initial` is zero, if an iterate is mapped to zero by this matrix, or if
+    /// the estimates did not converge after `max_niter` iterations (e.g. because the two
+    /// eigenvalues of largest magnitude are distinct but have the same modulus). Note that if
+    /// `initial` is exactly orthogonal to the dominant eigenvector, the iteration may converge
+    /// to another eigenpair instead.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrix is not square or if `initial.len()` does not match its dimension.
+    #[must_use]
+    pub fn power_iteration(
+        &self,
+        initial: DVector<T>,
+        max_niter: usize,
+        eps: T,
+    ) -> Option<(T, DVector<T>)>
+    where
+        T: RealField,
+    {
+        self.cs.power_iteration(initial, max_niter, eps)
+    }
+
+    /// Returns the main diagonal of the matrix as a dense vector.
+    ///
+    /// Diagonal entries that are not explicitly stored are zero. For rectangular matrices, the
+    /// diagonal has `min(nrows, ncols)` entries.
+    #[must_use]
+    pub fn diagonal(&self) -> DVector<T>
+    where
+        T: Scalar + Zero,
+    {
+        self.cs.diagonal()
+    }
+
+    /// Converts the matrix to a dense `nrows x ncols` matrix, with zeros for the entries that
+    /// are not explicitly stored.
+    ///
+    /// This is the same as `DMatrix::from(self)`, but only requires `T: Zero`.
+    #[must_use]
+    pub fn to_dense(&self) -> DMatrix<T>
+    where
+        T: Scalar + Zero,
+    {
+        self.cs.to_dense()
+    }
+
+    /// Overwrites the explicitly stored diagonal entries of the matrix with the entries of
+    /// `diagonal`.
+    ///
+    /// The sparsity pattern cannot be modified, so an error is returned if any of the diagonal
+    /// entries is not explicitly stored. In this case, the matrix is left unchanged.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the length of `diagonal` is not `min(nrows, ncols)`.
+    pub fn set_diagonal_mut(&mut self, diagonal: &[T]) -> Result<(), OperationError>
+    where
+        T: Scalar,
+    {
+        self.cs.set_diagonal_mut(diagonal)
+    }
+
+    /// Checks whether the sparsity pattern of the matrix is structurally symmetric, i.e. whether
+    /// the entry `(j, i)` is explicitly stored for every explicitly stored entry `(i, j)`.
+    ///
+    /// The stored values are not compared. Non-square matrices are never symmetric.
+    #[must_use]
+    pub fn is_pattern_symmetric(&self) -> bool {
+        self.cs.is_pattern_symmetric()
+    }
+
+    /// Returns a copy of this matrix whose sparsity pattern is the union of its own pattern and
+    /// of the pattern of its transpose.
+    ///
+    /// The new entries are explicitly stored zeros. This is a prerequisite for algorithms
+    /// that require a structurally symmetric pattern.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if the matrix is not square.
+    #[must_use]
+    pub fn symmetrize_pattern(&self) -> Self
+    where
+        T: Scalar + Zero,
+    {
+        Self {
+            cs: self.cs.symmetrize_pattern(),
+        }
+    }
+
     /// Compute the transpose of the matrix.
+    ///
+    /// This is the same as [`transpose_to_same_format`](CsrMatrix::transpose_to_same_format).
     #[must_use]
     pub fn transpose(&self) -> CsrMatrix<T>
     where
         T: Scalar,
     {
-        CscMatrix::from(self).transpose_as_csr()
+        self.transpose_to_same_format()
+    }
+
+    /// Compute the transpose of the matrix, represented in CSR format.
+    ///
+    /// Unlike [`transpose_as_csc`](CsrMatrix::transpose_as_csc), this rebuilds the sparsity pattern
+    /// and copies the values, which takes `O(nrows + ncols + nnz)` time.
+    #[must_use]
+    pub fn transpose_to_same_format(&self) -> CsrMatrix<T>
+    where
+        T: Scalar,
+    {
+        Self {
+            cs: self.cs.transpose(),
+        }
+    }
+
+    /// Applies the symmetric permutation `perm` to the rows and columns of the matrix.
+    ///
+    /// Entry `(i, j)` of the result is entry `(perm[i], perm[j])` of this matrix, i.e. the
+    /// result is `P * A * Pᵀ` where `P` is the permutation matrix whose `i`-th row is the
+    /// `perm[i]`-th row of the identity. This is typically used together with
+    /// [`SparsityPattern::reverse_cuthill_mckee`] to reduce the bandwidth of the matrix.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if the matrix is not square, or if `perm` is not a permutation of `0 .. n`.
+    #[must_use]
+    pub fn permute_symmetric(&self, perm: &[usize]) -> CsrMatrix<T>
+    where
+        T: Scalar,
+    {
+        Self {
+            cs: self.cs.permute_symmetric(perm),
+        }
+    }
+}
+
+/// Displays the dimensions and the number of explicitly stored entries of the matrix, followed
+/// by one `(row, col) = value` line per explicitly stored entry, row by row.
+///
+/// With the alternate flag (`{:#}`), matrices with at most 16 rows and columns are instead
+/// displayed as a dense grid, in which entries that are not explicitly stored are shown as dots.
+/// The precision flag, if any, is applied to each value.
+impl<T: fmt::Display> fmt::Display for CsrMatrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_sparse_matrix(
+            f,
+            "CSR",
+            (self.nrows(), self.ncols()),
+            self.nnz(),
+            self.triplet_iter(),
+        )
     }
 }
 
@@ -560,6 +1405,83 @@ fn pattern_format_error_to_csr_error(err: SparsityPatternFormatError) -> SparseF
     }
 }
 
+/// Incrementally constructs a CSR matrix, one complete row at a time.
+///
+/// This is the natural way of assembling a matrix whose rows are generated in order, e.g.
+/// from the adjacency lists of a graph. Unlike
+/// [`try_from_sorted_triplets`](CsrMatrix::try_from_sorted_triplets), each row is validated and
+/// appended as a whole, in amortized linear time in its number of entries.
+///
+/// Example
+/// -------
+/// ```
+/// # use nalgebra_sparse::csr::CsrMatrixBuilder;
+/// let mut builder = CsrMatrixBuilder::new(3);
+/// builder.push_row(&[0, 2], &[1.0, 2.0]).unwrap();
+/// builder.push_row(&[], &[]).unwrap();
+/// builder.push_row(&[1], &[3.0]).unwrap();
+///
+/// // Unsorted or out of bounds indices are rejected.
+/// assert!(builder.push_row(&[2, 0], &[4.0, 5.0]).is_err());
+/// assert!(builder.push_row(&[3], &[4.0]).is_err());
+///
+/// let matrix = builder.build();
+/// assert_eq!(matrix.nrows(), 3);
+/// assert_eq!(matrix.nnz(), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsrMatrixBuilder<T> {
+    builder: CsMatrixBuilder<T>,
+}
+
+impl<T> CsrMatrixBuilder<T> {
+    /// Creates a builder for a matrix with `ncols` columns and no row yet.
+    pub fn new(ncols: usize) -> Self {
+        Self {
+            builder: CsMatrixBuilder::new(ncols),
+        }
+    }
+
+    /// Creates a builder for a matrix with `ncols` columns, with enough capacity for `nrows`
+    /// rows with `nnz` explicitly stored entries in total.
+    pub fn with_capacity(ncols: usize, nrows: usize, nnz: usize) -> Self {
+        Self {
+            builder: CsMatrixBuilder::with_capacity(ncols, nrows, nnz),
+        }
+    }
+
+    /// The number of rows appended so far.
+    #[inline]
+    #[must_use]
+    pub fn nrows(&self) -> usize {
+        self.builder.major_dim()
+    }
+
+    /// Appends a row with the given col indices and values.
+    ///
+    /// An error is returned if the col indices are not strictly increasing or are out of
+    /// bounds, in which case the builder is left unchanged.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if `col_indices` and `values` do not have the same length.
+    pub fn push_row(&mut self, col_indices: &[usize], values: &[T]) -> Result<(), SparseFormatError>
+    where
+        T: Clone,
+    {
+        self.builder
+            .push_lane(col_indices, values)
+            .map_err(pattern_format_error_to_csr_error)
+    }
+
+    /// Constructs the matrix whose rows are the rows appended so far.
+    pub fn build(self) -> CsrMatrix<T> {
+        CsrMatrix {
+            cs: self.builder.build(),
+        }
+    }
+}
+
 /// Iterator type for iterating over triplets in a CSR matrix.
 #[derive(Debug)]
 pub struct CsrTripletIter<'a, T> {
@@ -670,6 +1592,13 @@ macro_rules! impl_csr_row_common_methods {
             pub fn get_entry(&self, global_col_index: usize) -> Option<SparseEntry<T>> {
                 self.lane.get_entry(global_col_index)
             }
+
+            /// An iterator over the `(column_index, &value)` pairs of the explicitly stored entries
+            /// in this row.
+            #[inline]
+            pub fn iter(&self) -> impl '_ + ExactSizeIterator<Item = (usize, &T)> {
+                self.lane.iter()
+            }
         }
     };
 }
@@ -700,6 +1629,31 @@ impl<'a, T> CsrRowMut<'a, T> {
     pub fn get_entry_mut(&mut self, global_col_index: usize) -> Option<SparseEntryMut<T>> {
         self.lane.get_entry_mut(global_col_index)
     }
+
+    /// An iterator over the `(column_index, &mut value)` pairs of the explicitly stored entries
+    /// in this row.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl '_ + ExactSizeIterator<Item = (usize, &mut T)> {
+        self.lane.iter_mut()
+    }
+
+    /// Adds `dense[j]` to the value of every explicitly stored entry of this row, where `j` is
+    /// the column index of the entry.
+    ///
+    /// Components of `dense` at positions without an explicitly stored entry are ignored. This is
+    /// the counterpart of the gather performed by sparse matrix-vector products.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if the length of `dense` differs from the number of columns of the matrix.
+    #[inline]
+    pub fn scatter_add(&mut self, dense: &[T])
+    where
+        T: Scalar + ClosedAdd,
+    {
+        self.lane.scatter_add(dense)
+    }
 }
 
 /// Row iterator for [CsrMatrix](struct.CsrMatrix.html).
@@ -730,3 +1684,62 @@ where
         self.lane_iter.next().map(|lane| CsrRowMut { lane })
     }
 }
+
+#[cfg(feature = "serde-serialize")]
+mod serde_serialize {
+    use super::CsrMatrix;
+    use serde::de::Error as DeserializeError;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    struct CsrMatrixSerializationData<'a, T> {
+        nrows: usize,
+        ncols: usize,
+        row_offsets: &'a [usize],
+        col_indices: &'a [usize],
+        values: &'a [T],
+    }
+
+    #[derive(Deserialize)]
+    struct CsrMatrixDeserializationData<T> {
+        nrows: usize,
+        ncols: usize,
+        row_offsets: Vec<usize>,
+        col_indices: Vec<usize>,
+        values: Vec<T>,
+    }
+
+    impl<T: Serialize> Serialize for CsrMatrix<T> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            CsrMatrixSerializationData {
+                nrows: self.nrows(),
+                ncols: self.ncols(),
+                row_offsets: self.row_offsets(),
+                col_indices: self.col_indices(),
+                values: self.values(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, T: Deserialize<'de>> Deserialize<'de> for CsrMatrix<T> {
+        fn deserialize<D>(deserializer: D) -> Result<CsrMatrix<T>, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            // The input is untrusted, so go through the checked constructor.
+            let data = CsrMatrixDeserializationData::deserialize(deserializer)?;
+            CsrMatrix::try_from_csr_data(
+                data.nrows,
+                data.ncols,
+                data.row_offsets,
+                data.col_indices,
+                data.values,
+            )
+            .map_err(DeserializeError::custom)
+        }
+    }
+}
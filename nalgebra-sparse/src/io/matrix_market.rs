@@ -0,0 +1,373 @@
+use crate::coo::CooMatrix;
+use nalgebra::RealField;
+use pest::iterators::Pair;
+use pest::Parser;
+use pest_derive::Parser;
+use std::error::Error;
+use std::fmt;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[derive(Parser)]
+#[grammar = "io/matrix_market.pest"]
+struct MatrixMarketParser;
+
+/// Errors produced when loading a matrix in the Matrix Market format.
+#[derive(Debug)]
+pub struct MatrixMarketError {
+    kind: MatrixMarketErrorKind,
+    message: String,
+}
+
+impl MatrixMarketError {
+    /// The type of error.
+    #[must_use]
+    pub fn kind(&self) -> &MatrixMarketErrorKind {
+        &self.kind
+    }
+
+    fn from_kind_and_message(kind: MatrixMarketErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+/// The type of error described by a [MatrixMarketError](struct.MatrixMarketError.html).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixMarketErrorKind {
+    /// Indicates that the input does not follow the syntax of the Matrix Market format.
+    ParsingError,
+
+    /// Indicates that the header line is malformed, or declares an unknown object, format,
+    /// field or symmetry.
+    InvalidHeader,
+
+    /// Indicates that the header declares a valid Matrix Market variant that is not supported,
+    /// e.g. a `complex` field or the `array` format.
+    NotSupported,
+
+    /// Indicates that the entries are not consistent with the header.
+    ///
+    /// For example, an index might be out of bounds, the number of entries might differ from
+    /// the declared count, or a symmetric matrix might have entries above the diagonal.
+    InvalidData,
+
+    /// Indicates that the file could not be read.
+    IoError,
+}
+
+impl fmt::Display for MatrixMarketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for MatrixMarketError {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Field {
+    Real,
+    Integer,
+    Pattern,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+fn invalid_data(message: impl Into<String>) -> MatrixMarketError {
+    MatrixMarketError::from_kind_and_message(MatrixMarketErrorKind::InvalidData, message)
+}
+
+fn parse_header(header: Pair<'_, Rule>) -> Result<(Field, Symmetry), MatrixMarketError> {
+    use MatrixMarketErrorKind::*;
+
+    let keywords: Vec<String> = header
+        .into_inner()
+        .map(|keyword| keyword.as_str().to_lowercase())
+        .collect();
+    let unsupported = |what: &str, keyword: &str| {
+        MatrixMarketError::from_kind_and_message(
+            NotSupported,
+            format!("Matrix Market {} `{}` is not supported", what, keyword),
+        )
+    };
+    let invalid = |what: &str, keyword: &str| {
+        MatrixMarketError::from_kind_and_message(
+            InvalidHeader,
+            format!("Unknown Matrix Market {} `{}`", what, keyword),
+        )
+    };
+
+    match keywords[0].as_str() {
+        "matrix" => {}
+        "vector" => return Err(unsupported("object", &keywords[0])),
+        other => return Err(invalid("object", other)),
+    }
+
+    match keywords[1].as_str() {
+        "coordinate" => {}
+        "array" => return Err(unsupported("format", &keywords[1])),
+        other => return Err(invalid("format", other)),
+    }
+
+    let field = match keywords[2].as_str() {
+        "real" | "double" => Field::Real,
+        "integer" => Field::Integer,
+        "pattern" => Field::Pattern,
+        "complex" => return Err(unsupported("field", &keywords[2])),
+        other => return Err(invalid("field", other)),
+    };
+
+    let symmetry = match keywords[3].as_str() {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        "hermitian" => return Err(unsupported("symmetry", &keywords[3])),
+        other => return Err(invalid("symmetry", other)),
+    };
+
+    if field == Field::Pattern && symmetry == Symmetry::SkewSymmetric {
+        return Err(invalid("symmetry for a pattern matrix", &keywords[3]));
+    }
+
+    Ok((field, symmetry))
+}
+
+fn parse_dimension(dim: Pair<'_, Rule>) -> Result<usize, MatrixMarketError> {
+    dim.as_str()
+        .parse()
+        .map_err(|_| invalid_data(format!("Integer `{}` is too large", dim.as_str())))
+}
+
+fn parse_value<T: RealField>(
+    field: Field,
+    value: Option<Pair<'_, Rule>>,
+) -> Result<T, MatrixMarketError> {
+    let value = match (field, value) {
+        (Field::Pattern, None) => return Ok(T::one()),
+        (Field::Pattern, Some(_)) => {
+            return Err(invalid_data(
+                "Entries of a pattern matrix must not have a value",
+            ))
+        }
+        (_, None) => return Err(invalid_data("Missing value for an entry")),
+        (_, Some(value)) => value.as_str(),
+    };
+
+    let parsed = if field == Field::Integer {
+        value.parse::<i64>().ok().map(|v| v as f64)
+    } else {
+        value.parse::<f64>().ok()
+    };
+
+    parsed
+        .map(nalgebra::convert)
+        .ok_or_else(|| invalid_data(format!("Invalid value `{}` for the matrix field", value)))
+}
+
+/// Parses a Matrix Market file described by the given string, and returns the corresponding
+/// sparse matrix in COO format.
+///
+/// The one-based indices of the file are converted to the zero-based indices of
+/// [`CooMatrix`]. For `symmetric` and `skew-symmetric` matrices, only the lower triangle is
+/// stored in the file, and the mirrored entries are added to the returned matrix. Entries of
+/// `pattern` matrices are set to one. Duplicate entries are kept as separate triplets, so they
+/// are summed on conversion to CSR or CSC.
+///
+/// Errors
+/// ------
+///
+/// Returns an error if the input is not a valid Matrix Market file, if it describes a variant
+/// of the format that is not supported (see the [module-level documentation](crate::io)), or
+/// if its entries are not consistent with its header.
+///
+/// Examples
+/// --------
+///
+/// ```
+/// use nalgebra_sparse::io::load_coo_from_matrix_market_str;
+///
+/// let data = "%%MatrixMarket matrix coordinate real symmetric
+/// % A 3x3 matrix with 3 stored entries.
+/// 3 3 3
+/// 1 1 2.0
+/// 2 1 -1.0
+/// 3 3 5e-1
+/// ";
+/// let coo = load_coo_from_matrix_market_str::<f64>(data).unwrap();
+///
+/// // The entry above the diagonal is reconstructed from the symmetry.
+/// assert_eq!(coo.nnz(), 4);
+/// let triplets: Vec<_> = coo.triplet_iter().map(|(i, j, v)| (i, j, *v)).collect();
+/// assert_eq!(triplets, vec![(0, 0, 2.0), (1, 0, -1.0), (0, 1, -1.0), (2, 2, 0.5)]);
+/// ```
+pub fn load_coo_from_matrix_market_str<T: RealField>(
+    data: &str,
+) -> Result<CooMatrix<T>, MatrixMarketError> {
+    let document = MatrixMarketParser::parse(Rule::Document, data)
+        .map_err(|e| {
+            MatrixMarketError::from_kind_and_message(
+                MatrixMarketErrorKind::ParsingError,
+                e.to_string(),
+            )
+        })?
+        .next()
+        .unwrap();
+
+    let mut field = Field::Real;
+    let mut symmetry = Symmetry::General;
+    let mut coo = CooMatrix::new(0, 0);
+    let mut declared_nnz = 0;
+    let mut num_entries = 0;
+
+    for pair in document.into_inner() {
+        match pair.as_rule() {
+            Rule::Header => {
+                let (f, s) = parse_header(pair)?;
+                field = f;
+                symmetry = s;
+            }
+            Rule::Shape => {
+                let mut inner = pair.into_inner();
+                let nrows = parse_dimension(inner.next().unwrap())?;
+                let ncols = parse_dimension(inner.next().unwrap())?;
+                declared_nnz = parse_dimension(inner.next().unwrap())?;
+
+                if symmetry != Symmetry::General && nrows != ncols {
+                    return Err(invalid_data(format!(
+                        "A matrix with {:?} symmetry must be square, but its shape is {}x{}",
+                        symmetry, nrows, ncols
+                    )));
+                }
+
+                coo = CooMatrix::new(nrows, ncols);
+                let capacity = if symmetry == Symmetry::General {
+                    declared_nnz
+                } else {
+                    2 * declared_nnz
+                };
+                coo.reserve(capacity.min(nrows.saturating_mul(ncols)));
+            }
+            Rule::Entry => {
+                let mut inner = pair.into_inner();
+                let i = parse_dimension(inner.next().unwrap())?;
+                let j = parse_dimension(inner.next().unwrap())?;
+                let v: T = parse_value(field, inner.next())?;
+
+                // NOTE: indices are 1-based.
+                if i == 0 || j == 0 || i > coo.nrows() || j > coo.ncols() {
+                    return Err(invalid_data(format!(
+                        "Entry ({}, {}) is out of bounds for a {}x{} matrix",
+                        i,
+                        j,
+                        coo.nrows(),
+                        coo.ncols()
+                    )));
+                }
+                let (i, j) = (i - 1, j - 1);
+
+                match symmetry {
+                    Symmetry::General => coo.push(i, j, v),
+                    Symmetry::Symmetric if i >= j => {
+                        coo.push(i, j, v);
+                        if i != j {
+                            coo.push(j, i, v);
+                        }
+                    }
+                    Symmetry::SkewSymmetric if i > j => {
+                        coo.push(i, j, v);
+                        coo.push(j, i, -v);
+                    }
+                    _ => {
+                        return Err(invalid_data(format!(
+                            "Entry ({}, {}) is not strictly below the diagonal of a matrix with \
+                             {:?} symmetry",
+                            i + 1,
+                            j + 1,
+                            symmetry
+                        )))
+                    }
+                }
+                num_entries += 1;
+            }
+            _ => {}
+        }
+    }
+
+    if num_entries != declared_nnz {
+        return Err(invalid_data(format!(
+            "The header declares {} entries, but {} entries were found",
+            declared_nnz, num_entries
+        )));
+    }
+
+    Ok(coo)
+}
+
+/// Parses a Matrix Market file at the given path, and returns the corresponding sparse matrix
+/// in COO format.
+///
+/// See [`load_coo_from_matrix_market_str`] for details.
+///
+/// Errors
+/// ------
+///
+/// Returns an error if the file cannot be read, or if its content is not a supported Matrix
+/// Market file.
+pub fn load_coo_from_matrix_market_file<T: RealField, P: AsRef<Path>>(
+    path: P,
+) -> Result<CooMatrix<T>, MatrixMarketError> {
+    let data = fs::read_to_string(path).map_err(|e| {
+        MatrixMarketError::from_kind_and_message(MatrixMarketErrorKind::IoError, e.to_string())
+    })?;
+    load_coo_from_matrix_market_str(&data)
+}
+
+/// Writes the given matrix in the Matrix Market format, as a `coordinate real general` matrix.
+///
+/// Every triplet of the COO matrix is written as a separate entry, with one-based indices.
+/// CSR and CSC matrices can be written by converting them to COO first.
+///
+/// Examples
+/// --------
+///
+/// ```
+/// use nalgebra_sparse::{coo::CooMatrix, csr::CsrMatrix};
+/// use nalgebra_sparse::io::{load_coo_from_matrix_market_str, save_coo_to_matrix_market_str};
+///
+/// let csr = CsrMatrix::identity(2);
+/// let data = save_coo_to_matrix_market_str(&CooMatrix::<f64>::from(&csr));
+/// assert_eq!(data, "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n2 2 1\n");
+/// assert_eq!(CsrMatrix::from(&load_coo_from_matrix_market_str(&data).unwrap()), csr);
+/// ```
+#[must_use]
+pub fn save_coo_to_matrix_market_str<T: RealField>(coo: &CooMatrix<T>) -> String {
+    let mut data = String::new();
+    // Writing to a `String` cannot fail.
+    writeln!(data, "%%MatrixMarket matrix coordinate real general").unwrap();
+    writeln!(data, "{} {} {}", coo.nrows(), coo.ncols(), coo.nnz()).unwrap();
+
+    for (i, j, v) in coo.triplet_iter() {
+        writeln!(data, "{} {} {}", i + 1, j + 1, v).unwrap();
+    }
+
+    data
+}
+
+/// Writes the given matrix in the Matrix Market format to the file at the given path.
+///
+/// See [`save_coo_to_matrix_market_str`] for details.
+pub fn save_coo_to_matrix_market_file<T: RealField, P: AsRef<Path>>(
+    coo: &CooMatrix<T>,
+    path: P,
+) -> std::io::Result<()> {
+    fs::write(path, save_coo_to_matrix_market_str(coo))
+}
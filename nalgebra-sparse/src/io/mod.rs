@@ -0,0 +1,21 @@
+//! Parsers and writers for sparse matrix file formats.
+//!
+//! ## Matrix Market
+//!
+//! The [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html) exchange format is
+//! supported by most sparse matrix software, including MATLAB, SciPy and the SuiteSparse
+//! Matrix Collection. Files can be read into a [`CooMatrix`](crate::coo::CooMatrix) with
+//! [`load_coo_from_matrix_market_str`] or [`load_coo_from_matrix_market_file`], and written
+//! with [`save_coo_to_matrix_market_str`] or [`save_coo_to_matrix_market_file`].
+//!
+//! Only the `coordinate` format of `matrix` objects is supported, with a `real`, `double`,
+//! `integer` or `pattern` field and a `general`, `symmetric` or `skew-symmetric` symmetry.
+//! This module requires the `io` feature.
+
+pub use self::matrix_market::{
+    load_coo_from_matrix_market_file, load_coo_from_matrix_market_str,
+    save_coo_to_matrix_market_file, save_coo_to_matrix_market_str, MatrixMarketError,
+    MatrixMarketErrorKind,
+};
+
+mod matrix_market;
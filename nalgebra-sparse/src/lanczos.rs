@@ -0,0 +1,171 @@
+//! Lanczos tridiagonalization of sparse symmetric matrices.
+//!
+//! The [`Lanczos`] iteration only accesses a matrix through matrix-vector products, which makes
+//! it suitable for large sparse symmetric matrices. After `k` steps, it produces the diagonal
+//! `alpha` and the off-diagonal `beta` of a `k × k` symmetric tridiagonal matrix whose
+//! eigenvalues, the Ritz values, approximate the extreme eigenvalues of the matrix. Since this
+//! tridiagonal matrix is small, its eigenvalues can be computed with the dense algorithms of
+//! `nalgebra`.
+//!
+//! ```
+//! # use nalgebra::DMatrix;
+//! # use nalgebra_sparse::{CooMatrix, CsrMatrix};
+//! let n = 100;
+//! let mut coo = CooMatrix::new(n, n);
+//! for i in 0..n {
+//!     coo.push(i, i, i as f64);
+//!     if i + 1 < n {
+//!         coo.push(i, i + 1, 1.0);
+//!         coo.push(i + 1, i, 1.0);
+//!     }
+//! }
+//! let csr = CsrMatrix::from(&coo);
+//!
+//! let start = vec![1.0; n];
+//! let (alpha, beta) = csr.lanczos(&start, true).tridiagonal(40);
+//!
+//! // The eigenvalues of the tridiagonal matrix approximate the extreme eigenvalues.
+//! let mut t = DMatrix::from_diagonal(&alpha);
+//! for i in 0..beta.len() {
+//!     t[(i + 1, i)] = beta[i];
+//!     t[(i, i + 1)] = beta[i];
+//! }
+//! let largest = t.symmetric_eigenvalues().max();
+//! let expected = DMatrix::from(&csr).symmetric_eigenvalues().max();
+//! assert!((largest - expected).abs() < 1.0e-6);
+//! ```
+
+use crate::cs::CsMatrix;
+use nalgebra::{DVector, RealField};
+
+/// The Lanczos iteration on a sparse symmetric matrix.
+///
+/// This is an iterator which performs one Lanczos step per call to `next` and yields the pair
+/// `(alpha_j, beta_j)`, where `alpha_j` is the `j`-th diagonal element of the tridiagonal matrix
+/// and `beta_j` the norm of the residual of the step, i.e., the element below it. The iteration
+/// stops after `n` steps for a `n × n` matrix, or earlier if the residual vanishes because the
+/// Lanczos vectors span an invariant subspace. In that case, the eigenvalues of the tridiagonal
+/// matrix are exact eigenvalues of the matrix.
+///
+/// Without reorthogonalization, only the last two Lanczos vectors are kept, but they gradually
+/// lose their orthogonality in finite precision, which shows as spurious copies of the converged
+/// Ritz values. With full reorthogonalization, every Lanczos vector is kept and orthogonalized
+/// against all the previous ones, at the cost of `O(n * k)` memory and `O(n * k²)` operations for
+/// `k` steps.
+///
+/// Created by [`CsrMatrix::lanczos`](crate::csr::CsrMatrix::lanczos) or
+/// [`CscMatrix::lanczos`](crate::csc::CscMatrix::lanczos).
+#[derive(Debug, Clone)]
+pub struct Lanczos<'a, T> {
+    matrix: &'a CsMatrix<T>,
+    q: DVector<T>,
+    q_prev: DVector<T>,
+    beta_prev: T,
+    basis: Option<Vec<DVector<T>>>,
+    steps: usize,
+    done: bool,
+}
+
+impl<'a, T: RealField> Lanczos<'a, T> {
+    /// Starts the Lanczos iteration on the symmetric matrix `matrix`, with the direction of
+    /// `start` as the first Lanczos vector.
+    ///
+    /// Since `matrix` is symmetric, its lanes can be interpreted either as rows or as columns.
+    pub(crate) fn new(matrix: &'a CsMatrix<T>, start: &[T], reorthogonalize: bool) -> Self {
+        let n = matrix.pattern().major_dim();
+        assert_eq!(
+            n,
+            matrix.pattern().minor_dim(),
+            "The matrix must be square."
+        );
+        assert_eq!(
+            start.len(),
+            n,
+            "The length of the starting vector must match the matrix dimension."
+        );
+
+        let q = DVector::from_column_slice(start);
+        let norm = q.norm();
+        assert!(
+            n == 0 || !norm.is_zero(),
+            "The starting vector must not be zero."
+        );
+        let q = if n == 0 { q } else { q.unscale(norm) };
+
+        Self {
+            matrix,
+            q_prev: DVector::zeros(n),
+            beta_prev: T::zero(),
+            basis: reorthogonalize.then(|| vec![q.clone()]),
+            q,
+            steps: 0,
+            done: n == 0,
+        }
+    }
+
+    /// Performs at most `k` Lanczos steps and returns the diagonal `alpha` and the off-diagonal
+    /// `beta` of the resulting symmetric tridiagonal matrix.
+    ///
+    /// If the iteration stops before `k` steps, `alpha` has as many elements as steps performed.
+    /// In any case, `beta` has one element less than `alpha`, or none if `alpha` is empty.
+    pub fn tridiagonal(self, k: usize) -> (DVector<T>, DVector<T>) {
+        let (alpha, mut beta): (Vec<_>, Vec<_>) = self.take(k).unzip();
+        let _ = beta.pop();
+
+        (DVector::from_vec(alpha), DVector::from_vec(beta))
+    }
+
+    /// The number of Lanczos steps performed so far.
+    #[must_use]
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+}
+
+impl<'a, T: RealField> Iterator for Lanczos<'a, T> {
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<(T, T)> {
+        if self.done {
+            return None;
+        }
+
+        let mut w = DVector::zeros(self.q.len());
+        self.matrix
+            .spmv_into(self.q.as_slice(), w.as_mut_slice(), T::zero(), T::one());
+        w.axpy(-self.beta_prev, &self.q_prev, T::one());
+        let alpha = w.dot(&self.q);
+        w.axpy(-alpha, &self.q, T::one());
+
+        if let Some(basis) = &self.basis {
+            // A single pass of Gram-Schmidt leaves `w` far from orthogonal to the basis when it
+            // is mostly cancelled, e.g. close to an invariant subspace, while a second pass is
+            // enough.
+            for _ in 0..2 {
+                for v in basis {
+                    let proj = w.dot(v);
+                    w.axpy(-proj, v, T::one());
+                }
+            }
+        }
+
+        let beta = w.norm();
+        self.steps += 1;
+
+        // The residual is negligible when compared to the column of the tridiagonal matrix.
+        let scale = alpha.abs() + self.beta_prev;
+        if self.steps == self.q.len() || beta <= T::default_epsilon() * scale {
+            self.done = true;
+        } else {
+            w.unscale_mut(beta);
+            self.q_prev = std::mem::replace(&mut self.q, w);
+            self.beta_prev = beta;
+
+            if let Some(basis) = &mut self.basis {
+                basis.push(self.q.clone());
+            }
+        }
+
+        Some((alpha, beta))
+    }
+}
@@ -23,6 +23,14 @@
 //!   `proptest-support` is enabled.
 //! - [matrixcompare support](https://crates.io/crates/matrixcompare) for effortless
 //!   (approximate) comparison of matrices in test code (requires the `compare` feature).
+//! - Parallel iteration over the rows of CSR matrices and the columns of CSC matrices
+//!   (requires the `rayon` feature).
+//! - [serde](https://serde.rs) serialization of sparsity patterns and CSR/CSC matrices, with
+//!   the format invariants checked on deserialization (requires the `serde-serialize` feature).
+//! - Reading and writing matrices in the [Matrix Market](`io`) format (requires the `io`
+//!   feature).
+//! - The [Lanczos](`lanczos`) tridiagonalization of sparse symmetric matrices, to approximate
+//!   their extreme eigenvalues.
 //!
 //! ## Current state
 //!
@@ -144,6 +152,9 @@ pub mod coo;
 pub mod csc;
 pub mod csr;
 pub mod factorization;
+#[cfg(feature = "io")]
+pub mod io;
+pub mod lanczos;
 pub mod ops;
 pub mod pattern;
 
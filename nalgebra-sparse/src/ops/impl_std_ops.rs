@@ -14,7 +14,7 @@ use nalgebra::{
     Scalar, U1,
 };
 use num_traits::{One, Zero};
-use std::ops::{Add, Div, DivAssign, Mul, MulAssign, Neg, Sub};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub};
 
 /// Helper macro for implementing binary operators for different matrix types
 /// See below for usage.
@@ -83,6 +83,33 @@ impl_sp_plus_minus!(CsrMatrix, spadd_csr_prealloc, -);
 impl_sp_plus_minus!(CscMatrix, spadd_csc_prealloc, +);
 impl_sp_plus_minus!(CscMatrix, spadd_csc_prealloc, -);
 
+/// Implements `a += b` for owned and borrowed `b`, for CsrMatrix or CscMatrix.
+macro_rules! impl_add_assign {
+    ($matrix_type:ident) => {
+        impl<'a, T> AddAssign<&'a $matrix_type<T>> for $matrix_type<T>
+        where
+            T: Scalar + ClosedAdd,
+        {
+            fn add_assign(&mut self, other: &'a $matrix_type<T>) {
+                // The sparsity pattern of `self` is extended with that of `other` if needed.
+                self.cs.add_assign(&other.cs);
+            }
+        }
+
+        impl<T> AddAssign<$matrix_type<T>> for $matrix_type<T>
+        where
+            T: Scalar + ClosedAdd,
+        {
+            fn add_assign(&mut self, other: $matrix_type<T>) {
+                *self += &other;
+            }
+        }
+    };
+}
+
+impl_add_assign!(CsrMatrix);
+impl_add_assign!(CscMatrix);
+
 macro_rules! impl_mul {
     ($($args:tt)*) => {
         impl_bin_op!(Mul, mul, $($args)*);
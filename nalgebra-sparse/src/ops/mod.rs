@@ -6,10 +6,10 @@
 //! offer more control over allocation, and allow fusing some low-level operations for higher
 //! performance.
 //!
-//! The available operations are organized by backend. Currently, only the [`serial`] backend
-//! is available. In the future, backends that expose parallel operations may become available.
-//! All `std::ops` implementations will remain single-threaded and powered by the
-//! `serial` backend.
+//! The available operations are organized by backend. The [`serial`] backend provides all
+//! operations, and the `parallel` backend, which requires the `rayon` feature, provides
+//! multi-threaded variants of some of them. All `std::ops` implementations are single-threaded
+//! and powered by the `serial` backend.
 //!
 //! Many routines are able to implicitly transpose matrices involved in the operation.
 //! For example, the routine [`spadd_csr_prealloc`](serial::spadd_csr_prealloc) performs the
@@ -81,8 +81,8 @@
 //! | Format   | AddAssign\<Matrix\> | MulAssign\<Matrix\> | MulAssign\<Scalar\> | Neg    |
 //! | -------- | -----------------   | -----------------   | ------------------- | ------ |
 //! | COO      |                     |                     |                     |        |
-//! | CSR      |                     |                     | x                   | x      |
-//! | CSC      |                     |                     | x                   | x      |
+//! | CSR      | x                   |                     | x                   | x      |
+//! | CSC      | x                   |                     | x                   | x      |
 //! |
 //! # Example usage
 //!
@@ -125,7 +125,10 @@
 //! always be verified by performance profiling!
 
 mod impl_std_ops;
+#[macro_use]
 pub mod serial;
+#[cfg(feature = "rayon")]
+pub mod parallel;
 
 /// Determines whether a matrix should be transposed in a given operation.
 ///
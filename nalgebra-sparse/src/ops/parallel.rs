@@ -0,0 +1,217 @@
+//! Parallel sparse matrix arithmetic routines.
+//!
+//! The routines of this module are multi-threaded with [`rayon`], and otherwise behave like their
+//! counterparts with the same name in the [`serial`](crate::ops::serial) module. Only sparse-sparse
+//! matrix multiplication is currently available. Its symbolic phase, [`spmm_csr_pattern`], runs
+//! the lane kernel of [`serial::spmm_csr_pattern`] on chunks of rows, and its numeric phase,
+//! [`spmm_csr_prealloc`], runs the lane kernel of [`serial::spmm_csr_prealloc`] with one dense
+//! accumulator per thread. To compute the values of a product whose pattern is already known,
+//! create a matrix with that pattern and call [`spmm_csr_prealloc`] with `beta = 0`.
+//!
+//! [`serial::spmm_csr_pattern`]: crate::ops::serial::spmm_csr_pattern
+//! [`serial::spmm_csr_prealloc`]: crate::ops::serial::spmm_csr_prealloc
+//!
+//! Requires the `rayon` feature.
+
+use crate::csc::CscMatrix;
+use crate::csr::CsrMatrix;
+use crate::ops::serial::cs::{spmm_cs_lane_prealloc, SpmmLaneWorkspace};
+use crate::ops::serial::pattern::spmm_csr_pattern_lanes;
+use crate::ops::serial::OperationError;
+use crate::ops::Op;
+use crate::pattern::SparsityPattern;
+use nalgebra::{ClosedAdd, ClosedMul, Scalar};
+use num_traits::{One, Zero};
+use rayon::prelude::*;
+
+use std::borrow::Cow;
+
+/// Sparse matrix multiplication pattern construction, `C <- A * B`.
+///
+/// Assumes that the sparsity patterns both represent CSR matrices, and the result is also
+/// represented as the sparsity pattern of a CSR matrix. The rows of `C` are computed in
+/// parallel.
+///
+/// # Panics
+///
+/// Panics if the patterns, when interpreted as CSR patterns, are not compatible for
+/// matrix multiplication.
+pub fn spmm_csr_pattern(a: &SparsityPattern, b: &SparsityPattern) -> SparsityPattern {
+    assert_eq!(
+        a.minor_dim(),
+        b.major_dim(),
+        "a and b must have compatible dimensions"
+    );
+
+    // Split the lanes in a few chunks per thread so that the work is balanced even if the
+    // lanes have very different numbers of entries, without allocating too many buffers.
+    let major_dim = a.major_dim();
+    let chunk_len = (major_dim / (4 * rayon::current_num_threads())).max(1);
+    // `usize::div_ceil` is not available on our MSRV.
+    #[allow(clippy::manual_div_ceil)]
+    let num_chunks = (major_dim + chunk_len - 1) / chunk_len;
+
+    let chunks: Vec<_> = (0..num_chunks)
+        .into_par_iter()
+        .map_init(
+            || vec![false; b.minor_dim()],
+            |visited, chunk| {
+                let start = chunk * chunk_len;
+                let lanes = start..(start + chunk_len).min(major_dim);
+                let mut offsets = Vec::with_capacity(lanes.len());
+                let mut indices = Vec::new();
+                spmm_csr_pattern_lanes(a, b, lanes, visited, &mut offsets, &mut indices);
+                (offsets, indices)
+            },
+        )
+        .collect();
+
+    // The offsets of each chunk are relative to the start of the chunk.
+    let nnz = chunks.iter().map(|(_, indices)| indices.len()).sum();
+    let mut offsets = Vec::with_capacity(major_dim + 1);
+    let mut indices = Vec::with_capacity(nnz);
+    offsets.push(0);
+
+    for (chunk_offsets, chunk_indices) in chunks {
+        let chunk_start = indices.len();
+        offsets.extend(chunk_offsets.into_iter().map(|offset| chunk_start + offset));
+        indices.extend(chunk_indices);
+    }
+
+    SparsityPattern::try_from_offsets_and_indices(major_dim, b.minor_dim(), offsets, indices)
+        .expect("Internal error: Invalid pattern during matrix multiplication pattern construction")
+}
+
+/// Sparse matrix multiplication pattern construction, `C <- A * B`.
+///
+/// Assumes that the sparsity patterns both represent CSC matrices, and the result is also
+/// represented as the sparsity pattern of a CSC matrix. The columns of `C` are computed in
+/// parallel.
+///
+/// # Panics
+///
+/// Panics if the patterns, when interpreted as CSC patterns, are not compatible for
+/// matrix multiplication.
+pub fn spmm_csc_pattern(a: &SparsityPattern, b: &SparsityPattern) -> SparsityPattern {
+    // See the serial version for why the order of a and b is switched.
+    spmm_csr_pattern(b, a)
+}
+
+/// Sparse-sparse matrix multiplication, `C <- beta * C + alpha * op(A) * op(B)`.
+///
+/// The rows of `C` are computed in parallel.
+///
+/// # Errors
+///
+/// If the pattern of `C` is not able to hold the result of the operation, an error is returned.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spmm_csr_prealloc<T>(
+    beta: T,
+    c: &mut CsrMatrix<T>,
+    alpha: T,
+    a: Op<&CsrMatrix<T>>,
+    b: Op<&CsrMatrix<T>>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spmm_dims!(c, a, b);
+
+    use Op::{NoOp, Transpose};
+
+    match (&a, &b) {
+        (NoOp(a), NoOp(b)) => c.cs.par_indexed_lane_iter_mut().try_for_each_init(
+            || SpmmLaneWorkspace::new(b.cs.pattern().minor_dim()),
+            |workspace, (i, mut c_lane_i)| {
+                let a_lane_i = a.cs.get_lane(i).unwrap();
+                spmm_cs_lane_prealloc(
+                    beta.inlined_clone(),
+                    &mut c_lane_i,
+                    alpha.inlined_clone(),
+                    &a_lane_i,
+                    &b.cs,
+                    workspace,
+                )
+            },
+        ),
+        _ => {
+            // Transposed matrices are precomputed, as in the serial version.
+            let a_ref: &CsrMatrix<T> = a.inner_ref();
+            let b_ref: &CsrMatrix<T> = b.inner_ref();
+            let (a, b) = {
+                use Cow::*;
+                match (&a, &b) {
+                    (NoOp(_), NoOp(_)) => unreachable!(),
+                    (Transpose(a), NoOp(_)) => (Owned(a.transpose()), Borrowed(b_ref)),
+                    (NoOp(_), Transpose(b)) => (Borrowed(a_ref), Owned(b.transpose())),
+                    (Transpose(a), Transpose(b)) => (Owned(a.transpose()), Owned(b.transpose())),
+                }
+            };
+
+            spmm_csr_prealloc(beta, c, alpha, NoOp(a.as_ref()), NoOp(b.as_ref()))
+        }
+    }
+}
+
+/// Sparse-sparse matrix multiplication, `C <- beta * C + alpha * op(A) * op(B)`.
+///
+/// The columns of `C` are computed in parallel.
+///
+/// # Errors
+///
+/// If the pattern of `C` is not able to hold the result of the operation, an error is returned.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved are not compatible with the expression.
+pub fn spmm_csc_prealloc<T>(
+    beta: T,
+    c: &mut CscMatrix<T>,
+    alpha: T,
+    a: Op<&CscMatrix<T>>,
+    b: Op<&CscMatrix<T>>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One + Send + Sync,
+{
+    assert_compatible_spmm_dims!(c, a, b);
+
+    use Op::{NoOp, Transpose};
+
+    match (&a, &b) {
+        // Note: We have to reverse the order for CSC matrices
+        (NoOp(a), NoOp(b)) => c.cs.par_indexed_lane_iter_mut().try_for_each_init(
+            || SpmmLaneWorkspace::new(a.cs.pattern().minor_dim()),
+            |workspace, (j, mut c_lane_j)| {
+                let b_lane_j = b.cs.get_lane(j).unwrap();
+                spmm_cs_lane_prealloc(
+                    beta.inlined_clone(),
+                    &mut c_lane_j,
+                    alpha.inlined_clone(),
+                    &b_lane_j,
+                    &a.cs,
+                    workspace,
+                )
+            },
+        ),
+        _ => {
+            // Transposed matrices are precomputed, as in the serial version.
+            let a_ref: &CscMatrix<T> = a.inner_ref();
+            let b_ref: &CscMatrix<T> = b.inner_ref();
+            let (a, b) = {
+                use Cow::*;
+                match (&a, &b) {
+                    (NoOp(_), NoOp(_)) => unreachable!(),
+                    (Transpose(a), NoOp(_)) => (Owned(a.transpose()), Borrowed(b_ref)),
+                    (NoOp(_), Transpose(b)) => (Borrowed(a_ref), Owned(b.transpose())),
+                    (Transpose(a), Transpose(b)) => (Owned(a.transpose()), Owned(b.transpose())),
+                }
+            };
+
+            spmm_csc_prealloc(beta, c, alpha, NoOp(a.as_ref()), NoOp(b.as_ref()))
+        }
+    }
+}
@@ -1,9 +1,10 @@
-use crate::cs::CsMatrix;
+use crate::cs::{CsLane, CsLaneMut, CsMatrix};
 use crate::ops::serial::{OperationError, OperationErrorKind};
 use crate::ops::Op;
 use crate::SparseEntryMut;
 use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, Scalar};
 use num_traits::{One, Zero};
+use std::mem::replace;
 
 fn spmm_cs_unexpected_entry() -> OperationError {
     OperationError::from_kind_and_message(
@@ -30,33 +31,102 @@ pub fn spmm_cs_prealloc<T>(
 where
     T: Scalar + ClosedAdd + ClosedMul + Zero + One,
 {
-    for i in 0..c.pattern().major_dim() {
+    let mut workspace = SpmmLaneWorkspace::new(b.pattern().minor_dim());
+
+    for (i, mut c_lane_i) in c.lane_iter_mut().enumerate() {
         let a_lane_i = a.get_lane(i).unwrap();
-        let mut c_lane_i = c.get_lane_mut(i).unwrap();
-        for c_ij in c_lane_i.values_mut() {
-            *c_ij = beta.inlined_clone() * c_ij.inlined_clone();
+        spmm_cs_lane_prealloc(
+            beta.inlined_clone(),
+            &mut c_lane_i,
+            alpha.inlined_clone(),
+            &a_lane_i,
+            b,
+            &mut workspace,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// The dense scratch space used by [`spmm_cs_lane_prealloc`] to accumulate a lane of the
+/// product.
+pub(crate) struct SpmmLaneWorkspace<T> {
+    /// The values of the lane being accumulated, indexed by minor index.
+    accumulator: Vec<T>,
+    /// Whether a minor index has been hit by the lane being accumulated.
+    visited: Vec<bool>,
+    /// The minor indices hit by the lane being accumulated, in no particular order.
+    touched: Vec<usize>,
+}
+
+impl<T: Scalar + Zero> SpmmLaneWorkspace<T> {
+    /// Creates a workspace for lanes of the given minor dimension.
+    pub(crate) fn new(minor_dim: usize) -> Self {
+        Self {
+            accumulator: vec![T::zero(); minor_dim],
+            visited: vec![false; minor_dim],
+            touched: Vec::new(),
         }
+    }
+}
+
+/// Computes the lane `c_lane_i <- beta * c_lane_i + alpha * a_lane_i * B`, where `a_lane_i` is
+/// the corresponding lane of `A` in [`spmm_cs_prealloc`].
+///
+/// This is the numeric phase of Gustavson's algorithm: the linear combination of the lanes of `B`
+/// selected by `a_lane_i` is accumulated in the dense `workspace`, then gathered into the
+/// (already known) pattern of `c_lane_i`. The workspace is left cleared for the next lane.
+pub(crate) fn spmm_cs_lane_prealloc<T>(
+    beta: T,
+    c_lane_i: &mut CsLaneMut<'_, T>,
+    alpha: T,
+    a_lane_i: &CsLane<'_, T>,
+    b: &CsMatrix<T>,
+    workspace: &mut SpmmLaneWorkspace<T>,
+) -> Result<(), OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    let SpmmLaneWorkspace {
+        accumulator,
+        visited,
+        touched,
+    } = workspace;
 
-        for (&k, a_ik) in a_lane_i.minor_indices().iter().zip(a_lane_i.values()) {
-            let b_lane_k = b.get_lane(k).unwrap();
-            let (mut c_lane_i_cols, mut c_lane_i_values) = c_lane_i.indices_and_values_mut();
-            let alpha_aik = alpha.inlined_clone() * a_ik.inlined_clone();
-            for (j, b_kj) in b_lane_k.minor_indices().iter().zip(b_lane_k.values()) {
-                // Determine the location in C to append the value
-                let (c_local_idx, _) = c_lane_i_cols
-                    .iter()
-                    .enumerate()
-                    .find(|(_, c_col)| *c_col == j)
-                    .ok_or_else(spmm_cs_unexpected_entry)?;
-
-                c_lane_i_values[c_local_idx] += alpha_aik.inlined_clone() * b_kj.inlined_clone();
-                c_lane_i_cols = &c_lane_i_cols[c_local_idx..];
-                c_lane_i_values = &mut c_lane_i_values[c_local_idx..];
+    for (&k, a_ik) in a_lane_i.minor_indices().iter().zip(a_lane_i.values()) {
+        let b_lane_k = b.get_lane(k).unwrap();
+        let alpha_aik = alpha.inlined_clone() * a_ik.inlined_clone();
+        for (&j, b_kj) in b_lane_k.minor_indices().iter().zip(b_lane_k.values()) {
+            accumulator[j] += alpha_aik.inlined_clone() * b_kj.inlined_clone();
+            if !visited[j] {
+                visited[j] = true;
+                touched.push(j);
             }
         }
     }
 
-    Ok(())
+    let (c_lane_i_cols, c_lane_i_values) = c_lane_i.indices_and_values_mut();
+    for (&j, c_ij) in c_lane_i_cols.iter().zip(c_lane_i_values) {
+        let c_ij_product = replace(&mut accumulator[j], T::zero());
+        *c_ij = beta.inlined_clone() * c_ij.inlined_clone() + c_ij_product;
+        visited[j] = false;
+    }
+
+    // The indices that are still marked as visited are not present in the pattern of `c`.
+    let mut found_unexpected_entry = false;
+    for j in touched.drain(..) {
+        if visited[j] {
+            found_unexpected_entry = true;
+            accumulator[j] = T::zero();
+            visited[j] = false;
+        }
+    }
+
+    if found_unexpected_entry {
+        Err(spmm_cs_unexpected_entry())
+    } else {
+        Ok(())
+    }
 }
 
 fn spadd_cs_unexpected_entry() -> OperationError {
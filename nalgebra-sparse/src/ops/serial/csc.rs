@@ -2,6 +2,7 @@ use crate::csc::CscMatrix;
 use crate::ops::serial::cs::{spadd_cs_prealloc, spmm_cs_dense, spmm_cs_prealloc};
 use crate::ops::serial::{OperationError, OperationErrorKind};
 use crate::ops::Op;
+use crate::pattern::SparsityPattern;
 use nalgebra::{ClosedAdd, ClosedMul, DMatrixSlice, DMatrixSliceMut, RealField, Scalar};
 use num_traits::{One, Zero};
 
@@ -112,6 +113,34 @@ where
     }
 }
 
+/// Sparse-sparse matrix multiplication `C <- op(A) * op(B)`, where `C` has the given pattern.
+///
+/// This is the numeric phase of the multiplication, whose symbolic phase is
+/// [`spmm_csc_pattern`](crate::ops::serial::spmm_csc_pattern). When the values of `A` and `B`
+/// change but not their patterns, the pattern of `C` can be computed once and reused.
+///
+/// # Errors
+///
+/// If `pattern` is not able to hold the result of the operation, an error is returned.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the matrices involved and of the pattern are not compatible with
+/// the expression.
+pub fn spmm_csc_numeric<T>(
+    a: Op<&CscMatrix<T>>,
+    b: Op<&CscMatrix<T>>,
+    pattern: SparsityPattern,
+) -> Result<CscMatrix<T>, OperationError>
+where
+    T: Scalar + ClosedAdd + ClosedMul + Zero + One,
+{
+    let nnz = pattern.nnz();
+    let mut c = CscMatrix::try_from_pattern_and_values(pattern, vec![T::zero(); nnz]).unwrap();
+    spmm_csc_prealloc(T::zero(), &mut c, T::one(), a, b)?;
+    Ok(c)
+}
+
 /// Solve the lower triangular system `op(L) X = B`.
 ///
 /// Only the lower triangular part of L is read, and the result is stored in B.
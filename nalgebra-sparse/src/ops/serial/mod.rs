@@ -7,6 +7,12 @@
 //! of the operation fits entirely in the output pattern. In the future, there will also be
 //! some operations which will be able to dynamically adapt the output pattern to fit the
 //! result, but these have yet to be implemented.
+//!
+//! Sparse-sparse matrix multiplication is also available as two separate phases: the symbolic
+//! phase, e.g. [`spmm_csr_pattern`], computes the sparsity pattern of the product, and the
+//! numeric phase, e.g. [`spmm_csr_numeric`], computes its values for a given pattern. This lets
+//! the pattern be computed once and reused when only the values of the factors change, which is
+//! common in nonlinear solvers.
 
 #[macro_use]
 macro_rules! assert_compatible_spmm_dims {
@@ -54,10 +60,10 @@ macro_rules! assert_compatible_spadd_dims {
     };
 }
 
-mod cs;
+pub(crate) mod cs;
 mod csc;
 mod csr;
-mod pattern;
+pub(crate) mod pattern;
 
 pub use csc::*;
 pub use csr::*;
@@ -88,7 +94,7 @@ pub enum OperationErrorKind {
 }
 
 impl OperationError {
-    fn from_kind_and_message(error_type: OperationErrorKind, message: String) -> Self {
+    pub(crate) fn from_kind_and_message(error_type: OperationErrorKind, message: String) -> Self {
         Self {
             error_kind: error_type,
             message,
@@ -1,6 +1,7 @@
 use crate::pattern::SparsityPattern;
 
 use std::iter;
+use std::ops::Range;
 
 /// Sparse matrix addition pattern construction, `C <- A + B`.
 ///
@@ -77,19 +78,43 @@ pub fn spmm_csr_pattern(a: &SparsityPattern, b: &SparsityPattern) -> SparsityPat
         "a and b must have compatible dimensions"
     );
 
-    let mut offsets = Vec::new();
+    let mut offsets = vec![0];
     let mut indices = Vec::new();
-    offsets.push(0);
+    let mut visited = vec![false; b.minor_dim()];
+    spmm_csr_pattern_lanes(
+        a,
+        b,
+        0..a.major_dim(),
+        &mut visited,
+        &mut offsets,
+        &mut indices,
+    );
+
+    SparsityPattern::try_from_offsets_and_indices(a.major_dim(), b.minor_dim(), offsets, indices)
+        .expect("Internal error: Invalid pattern during matrix multiplication pattern construction")
+}
 
-    // Keep a vector of whether we have visited a particular minor index when working
-    // on a major lane
+/// Computes the major lanes `lanes` of the pattern of `C <- A * B`, interpreting all patterns as
+/// CSR patterns.
+///
+/// The minor indices of each lane are appended to `indices`, and the length of `indices` after
+/// each lane is appended to `offsets`. `visited` must have the length of the minor dimension of
+/// `b`, and be filled with `false`, which it is again when this returns.
+pub(crate) fn spmm_csr_pattern_lanes(
+    a: &SparsityPattern,
+    b: &SparsityPattern,
+    lanes: Range<usize>,
+    visited: &mut [bool],
+    offsets: &mut Vec<usize>,
+    indices: &mut Vec<usize>,
+) {
+    // `visited` tracks whether we have visited a particular minor index when working on a major
+    // lane.
     // TODO: Consider using a bitvec or similar here to reduce pressure on memory
     // (would cut memory use to 1/8, which might help reduce cache misses)
-    let mut visited = vec![false; b.minor_dim()];
-
-    for i in 0..a.major_dim() {
+    for i in lanes {
         let a_lane_i = a.lane(i);
-        let c_lane_i_offset = *offsets.last().unwrap();
+        let c_lane_i_offset = indices.len();
         for &k in a_lane_i {
             let b_lane_k = b.lane(k);
 
@@ -112,9 +137,6 @@ pub fn spmm_csr_pattern(a: &SparsityPattern, b: &SparsityPattern) -> SparsityPat
 
         offsets.push(indices.len());
     }
-
-    SparsityPattern::try_from_offsets_and_indices(a.major_dim(), b.minor_dim(), offsets, indices)
-        .expect("Internal error: Invalid pattern during matrix multiplication pattern construction")
 }
 
 /// Iterate over the union of the two sets represented by sorted slices
@@ -1,6 +1,7 @@
 //! Sparsity patterns for CSR and CSC matrices.
 use crate::cs::transpose_cs;
 use crate::SparseFormatError;
+use std::collections::VecDeque;
 use std::error::Error;
 use std::fmt;
 
@@ -115,6 +116,60 @@ impl SparsityPattern {
         Some(&self.minor_indices()[offset_begin..offset_end])
     }
 
+    /// An iterator over the number of explicitly stored entries in each lane.
+    ///
+    /// Only the major offsets are read, so this is cheap even for very large patterns.
+    #[inline]
+    pub fn lane_nnz_iter(&self) -> impl '_ + ExactSizeIterator<Item = usize> {
+        self.major_offsets.windows(2).map(|w| w[1] - w[0])
+    }
+
+    /// The largest number of explicitly stored entries in a single lane.
+    ///
+    /// Returns zero if the pattern has no lanes.
+    #[must_use]
+    pub fn max_lane_nnz(&self) -> usize {
+        self.lane_nnz_iter().max().unwrap_or(0)
+    }
+
+    /// The average number of explicitly stored entries per lane.
+    ///
+    /// Returns zero if the pattern has no lanes.
+    #[must_use]
+    pub fn mean_lane_nnz(&self) -> f64 {
+        if self.major_dim() == 0 {
+            0.0
+        } else {
+            self.nnz() as f64 / self.major_dim() as f64
+        }
+    }
+
+    /// Constructs a sparsity pattern from the given dimensions, major offsets and minor indices,
+    /// without checking that they conform to the requirements.
+    ///
+    /// Only meant for patterns whose structure is valid by construction. The data is still
+    /// validated in debug builds.
+    pub(crate) fn from_offsets_and_indices_unchecked(
+        major_dim: usize,
+        minor_dim: usize,
+        major_offsets: Vec<usize>,
+        minor_indices: Vec<usize>,
+    ) -> Self {
+        debug_assert!(Self::try_from_offsets_and_indices(
+            major_dim,
+            minor_dim,
+            major_offsets.clone(),
+            minor_indices.clone()
+        )
+        .is_ok());
+
+        Self {
+            major_offsets,
+            minor_indices,
+            minor_dim,
+        }
+    }
+
     /// Try to construct a sparsity pattern from the given dimensions, major offsets
     /// and minor indices.
     ///
@@ -256,6 +311,95 @@ impl SparsityPattern {
         )
         .expect("Internal error: Transpose should never fail.")
     }
+
+    /// Computes the Reverse Cuthill–McKee (RCM) ordering of this square pattern.
+    ///
+    /// Reordering a sparse matrix with RCM reduces its bandwidth, which in turn typically
+    /// reduces the fill-in of direct solvers such as sparse Cholesky. The returned permutation
+    /// `perm` lists the lanes in their new order, i.e. lane `perm[k]` of the original pattern is
+    /// moved to position `k`. It can be applied to a matrix with
+    /// [`CsrMatrix::permute_symmetric`](crate::csr::CsrMatrix::permute_symmetric) or
+    /// [`CscMatrix::permute_symmetric`](crate::csc::CscMatrix::permute_symmetric).
+    ///
+    /// Only the pattern is used: it is interpreted as the adjacency of an undirected graph in
+    /// which an entry `(i, j)` or `(j, i)` with `i != j` connects `i` and `j`, so patterns that
+    /// are not symmetric are implicitly symmetrized, and diagonal entries are ignored.
+    ///
+    /// Each connected component is traversed breadth-first, starting from its unvisited lane of
+    /// minimum degree. The neighbors of each lane are visited by increasing degree, and all ties
+    /// (both for the starting lane and the neighbors) are broken by increasing lane index, so the
+    /// result is deterministic. The resulting Cuthill–McKee ordering is finally reversed.
+    ///
+    /// Panics
+    /// ------
+    ///
+    /// Panics if the pattern is not square.
+    ///
+    /// Examples
+    /// --------
+    ///
+    /// ```
+    /// # use nalgebra_sparse::pattern::SparsityPattern;
+    /// // The path 0 - 3 - 1 - 2, with a bandwidth of 3.
+    /// let offsets = vec![0, 1, 3, 4, 6];
+    /// let indices = vec![3, 2, 3, 1, 0, 1];
+    /// let pattern = SparsityPattern::try_from_offsets_and_indices(4, 4, offsets, indices).unwrap();
+    ///
+    /// // After reordering, the path is numbered consecutively and the bandwidth is 1.
+    /// assert_eq!(pattern.reverse_cuthill_mckee(), vec![2, 1, 3, 0]);
+    /// ```
+    #[must_use]
+    pub fn reverse_cuthill_mckee(&self) -> Vec<usize> {
+        let n = self.major_dim();
+        assert_eq!(n, self.minor_dim(), "The sparsity pattern must be square.");
+
+        let mut adjacency = vec![Vec::new(); n];
+        for (i, j) in self.entries().filter(|(i, j)| i != j) {
+            adjacency[i].push(j);
+            adjacency[j].push(i);
+        }
+
+        for neighbors in &mut adjacency {
+            neighbors.sort_unstable();
+            neighbors.dedup();
+        }
+
+        let degree: Vec<usize> = adjacency.iter().map(Vec::len).collect();
+        for neighbors in &mut adjacency {
+            // Stable sort, so that ties remain ordered by index.
+            neighbors.sort_by_key(|&j| degree[j]);
+        }
+
+        let mut candidates: Vec<usize> = (0..n).collect();
+        candidates.sort_by_key(|&i| degree[i]);
+
+        let mut visited = vec![false; n];
+        let mut order = Vec::with_capacity(n);
+        let mut queue = VecDeque::new();
+
+        for &start in &candidates {
+            if visited[start] {
+                continue;
+            }
+
+            visited[start] = true;
+            queue.push_back(start);
+
+            while let Some(i) = queue.pop_front() {
+                order.push(i);
+
+                for &j in &adjacency[i] {
+                    if !visited[j] {
+                        visited[j] = true;
+                        queue.push_back(j);
+                    }
+                }
+            }
+        }
+
+        order.reverse();
+        order
+    }
 }
 
 /// Error type for `SparsityPattern` format errors.
@@ -400,3 +544,56 @@ impl<'a> Iterator for SparsityPatternIter<'a> {
         }
     }
 }
+
+#[cfg(feature = "serde-serialize")]
+mod serde_serialize {
+    use super::SparsityPattern;
+    use serde::de::Error as DeserializeError;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize)]
+    struct SparsityPatternSerializationData<'a> {
+        major_offsets: &'a [usize],
+        minor_indices: &'a [usize],
+        minor_dim: usize,
+    }
+
+    #[derive(Deserialize)]
+    struct SparsityPatternDeserializationData {
+        major_offsets: Vec<usize>,
+        minor_indices: Vec<usize>,
+        minor_dim: usize,
+    }
+
+    impl Serialize for SparsityPattern {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            SparsityPatternSerializationData {
+                major_offsets: self.major_offsets(),
+                minor_indices: self.minor_indices(),
+                minor_dim: self.minor_dim(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for SparsityPattern {
+        fn deserialize<D>(deserializer: D) -> Result<SparsityPattern, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            // The input is untrusted, so go through the checked constructor.
+            let data = SparsityPatternDeserializationData::deserialize(deserializer)?;
+            let major_dim = data.major_offsets.len().saturating_sub(1);
+            SparsityPattern::try_from_offsets_and_indices(
+                major_dim,
+                data.minor_dim,
+                data.major_offsets,
+                data.minor_indices,
+            )
+            .map_err(DeserializeError::custom)
+        }
+    }
+}
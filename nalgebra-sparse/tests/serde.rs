@@ -0,0 +1,170 @@
+//! Serialization tests
+#![cfg(feature = "serde-serialize")]
+#[cfg(any(not(feature = "proptest-support"), not(feature = "compare")))]
+compile_error!("Tests must be run with features `proptest-support` and `compare`");
+
+#[macro_use]
+pub mod common;
+
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::pattern::SparsityPattern;
+use nalgebra_sparse::proptest::sparsity_pattern;
+
+use proptest::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::common::{csc_strategy, csr_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+
+fn json_roundtrip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let serialized = serde_json::to_string(value).unwrap();
+    serde_json::from_str(&serialized).unwrap()
+}
+
+#[test]
+fn sparsity_pattern_roundtrip() {
+    {
+        // Empty pattern
+        let pattern = SparsityPattern::zeros(0, 0);
+        assert_eq!(json_roundtrip(&pattern), pattern);
+    }
+
+    {
+        // Pattern without explicitly stored entries
+        let pattern = SparsityPattern::zeros(3, 4);
+        assert_eq!(json_roundtrip(&pattern), pattern);
+    }
+
+    {
+        // Pattern with empty lanes in the middle
+        let offsets = vec![0, 2, 2, 2, 3];
+        let indices = vec![0, 5, 1];
+        let pattern =
+            SparsityPattern::try_from_offsets_and_indices(4, 6, offsets, indices).unwrap();
+        assert_eq!(json_roundtrip(&pattern), pattern);
+    }
+}
+
+#[test]
+fn sparsity_pattern_deserialize_invalid_data() {
+    let invalid = [
+        // Missing the leading offset
+        r#"{"major_offsets":[],"minor_indices":[],"minor_dim":2}"#,
+        // Last offset does not match the number of indices
+        r#"{"major_offsets":[0,1,3],"minor_indices":[0,1],"minor_dim":2}"#,
+        // Non-monotonic offsets
+        r#"{"major_offsets":[0,2,1,2],"minor_indices":[0,1],"minor_dim":2}"#,
+        // Unsorted indices within a lane
+        r#"{"major_offsets":[0,2],"minor_indices":[1,0],"minor_dim":2}"#,
+        // Duplicate indices within a lane
+        r#"{"major_offsets":[0,2],"minor_indices":[1,1],"minor_dim":2}"#,
+        // Out-of-bounds index
+        r#"{"major_offsets":[0,1],"minor_indices":[2],"minor_dim":2}"#,
+    ];
+
+    for json in &invalid {
+        assert!(
+            serde_json::from_str::<SparsityPattern>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+}
+
+#[test]
+fn csr_roundtrip() {
+    {
+        // Empty matrix
+        let csr = CsrMatrix::<f64>::zeros(0, 0);
+        assert_eq!(json_roundtrip(&csr), csr);
+    }
+
+    {
+        // Matrix with empty rows in the middle
+        let offsets = vec![0, 2, 2, 2, 3];
+        let indices = vec![0, 5, 1];
+        let values = vec![1.5, -2.0, 3.25];
+        let csr = CsrMatrix::try_from_csr_data(4, 6, offsets, indices, values).unwrap();
+        assert_eq!(json_roundtrip(&csr), csr);
+    }
+}
+
+#[test]
+fn csr_deserialize_invalid_data() {
+    let invalid = [
+        // Unsorted column indices
+        r#"{"nrows":1,"ncols":2,"row_offsets":[0,2],"col_indices":[1,0],"values":[1,2]}"#,
+        // Out-of-bounds column index
+        r#"{"nrows":1,"ncols":2,"row_offsets":[0,1],"col_indices":[2],"values":[1]}"#,
+        // Wrong number of offsets
+        r#"{"nrows":2,"ncols":2,"row_offsets":[0,1],"col_indices":[0],"values":[1]}"#,
+        // Wrong number of values
+        r#"{"nrows":1,"ncols":2,"row_offsets":[0,1],"col_indices":[0],"values":[1,2]}"#,
+    ];
+
+    for json in &invalid {
+        assert!(
+            serde_json::from_str::<CsrMatrix<i32>>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+}
+
+#[test]
+fn csc_roundtrip() {
+    {
+        // Empty matrix
+        let csc = CscMatrix::<f64>::zeros(0, 0);
+        assert_eq!(json_roundtrip(&csc), csc);
+    }
+
+    {
+        // Matrix with empty columns in the middle
+        let offsets = vec![0, 2, 2, 2, 3];
+        let indices = vec![0, 5, 1];
+        let values = vec![1.5, -2.0, 3.25];
+        let csc = CscMatrix::try_from_csc_data(6, 4, offsets, indices, values).unwrap();
+        assert_eq!(json_roundtrip(&csc), csc);
+    }
+}
+
+#[test]
+fn csc_deserialize_invalid_data() {
+    let invalid = [
+        // Non-monotonic offsets
+        r#"{"nrows":2,"ncols":3,"col_offsets":[0,2,1,2],"row_indices":[0,1],"values":[1,2]}"#,
+        // Unsorted row indices
+        r#"{"nrows":2,"ncols":1,"col_offsets":[0,2],"row_indices":[1,0],"values":[1,2]}"#,
+        // Missing field
+        r#"{"nrows":2,"ncols":1,"col_offsets":[0,0],"row_indices":[]}"#,
+    ];
+
+    for json in &invalid {
+        assert!(
+            serde_json::from_str::<CscMatrix<i32>>(json).is_err(),
+            "{}",
+            json
+        );
+    }
+}
+
+proptest! {
+    #[test]
+    fn sparsity_pattern_roundtrip_proptest(
+        pattern in sparsity_pattern(PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ)
+    ) {
+        prop_assert_eq!(json_roundtrip(&pattern), pattern);
+    }
+
+    #[test]
+    fn csr_roundtrip_proptest(csr in csr_strategy()) {
+        prop_assert_eq!(json_roundtrip(&csr), csr);
+    }
+
+    #[test]
+    fn csc_roundtrip_proptest(csc in csc_strategy()) {
+        prop_assert_eq!(json_roundtrip(&csc), csc);
+    }
+}
@@ -344,3 +344,51 @@ fn coo_push_matrix_out_of_bounds_entries() {
         assert_panics!(CooMatrix::new(3, 3).push_matrix(2, 2, &inserted));
     }
 }
+
+#[test]
+fn coo_to_csr_and_csc_sum_duplicates() {
+    // Assemble the stiffness matrix of a 1D mesh with 4 nodes and 3 linear elements,
+    // visiting the elements in an arbitrary order so the triplets are unsorted.
+    let mut coo = CooMatrix::new(4, 4);
+    for &e in &[2, 0, 1] {
+        coo.push(e + 1, e + 1, 1.0);
+        coo.push(e, e + 1, -1.0);
+        coo.push(e + 1, e, -1.0);
+        coo.push(e, e, 1.0);
+    }
+    // An explicit zero in a lane that otherwise has no entries.
+    let mut coo_with_zero = coo.clone();
+    coo_with_zero.push(3, 0, 0.0);
+
+    let expected = DMatrix::from_row_slice(
+        4,
+        4,
+        &[
+            1.0, -1.0, 0.0, 0.0, -1.0, 2.0, -1.0, 0.0, 0.0, -1.0, 2.0, -1.0, 0.0, 0.0, -1.0, 1.0,
+        ],
+    );
+
+    let csr = coo.to_csr();
+    assert_eq!(csr.row_offsets(), &[0, 2, 5, 8, 10]);
+    assert_eq!(csr.col_indices(), &[0, 1, 0, 1, 2, 1, 2, 3, 2, 3]);
+    assert_eq!(DMatrix::from(&csr), expected);
+
+    let csc = coo.to_csc();
+    assert_eq!(csc.col_offsets(), &[0, 2, 5, 8, 10]);
+    assert_eq!(csc.row_indices(), &[0, 1, 0, 1, 2, 1, 2, 3, 2, 3]);
+    assert_eq!(DMatrix::from(&csc), expected);
+
+    let csr = coo_with_zero.to_csr();
+    assert_eq!(csr.nnz(), 11);
+    assert_eq!(csr.row(3).col_indices(), &[0, 2, 3]);
+    assert_eq!(csr.row(3).values(), &[0.0, -1.0, 1.0]);
+    assert_eq!(coo_with_zero.to_csc().col(0).row_indices(), &[0, 1, 3]);
+}
+
+#[test]
+fn coo_to_csr_and_csc_empty() {
+    let coo = CooMatrix::<f64>::new(3, 0);
+    assert_eq!(coo.to_csr().row_offsets(), &[0, 0, 0, 0]);
+    assert_eq!(coo.to_csc().col_offsets(), &[0]);
+    assert_eq!(CooMatrix::<f64>::new(0, 0).to_csr().nnz(), 0);
+}
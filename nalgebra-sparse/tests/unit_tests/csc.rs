@@ -1,12 +1,17 @@
-use nalgebra::DMatrix;
-use nalgebra_sparse::csc::CscMatrix;
+use nalgebra::{DMatrix, DVector};
+use nalgebra_sparse::csc::{CscMatrix, CscMatrixBuilder};
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::ops::serial::{spadd_pattern, OperationErrorKind};
 use nalgebra_sparse::{SparseEntry, SparseEntryMut, SparseFormatErrorKind};
 
+use nalgebra_sparse::proptest::{coo_with_duplicates, csc};
 use proptest::prelude::*;
 use proptest::sample::subsequence;
 
 use crate::assert_panics;
-use crate::common::csc_strategy;
+use crate::common::{
+    csc_strategy, PROPTEST_I32_VALUE_STRATEGY, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ,
+};
 
 use std::collections::HashSet;
 
@@ -299,6 +304,145 @@ fn csc_disassemble_avoids_clone_when_owned() {
     assert_eq!(values.as_ptr(), values_ptr);
 }
 
+#[test]
+fn csc_spmv_into_panics_on_dimension_mismatch() {
+    let csc = CscMatrix::<i32>::identity(3);
+    let mut y = vec![0; 3];
+    assert_panics!(csc.spmv_into(&[1, 2], &mut y.clone(), 0, 1));
+    assert_panics!(csc.spmv_into(&[1, 2, 3], &mut [0, 0], 0, 1));
+    csc.spmv_into(&[1, 2, 3], &mut y, 0, 2);
+    assert_eq!(y, vec![2, 4, 6]);
+}
+
+#[test]
+fn csc_solve_triangular_zero_diagonal() {
+    // [2 0 0]
+    // [1 0 0]
+    // [0 1 4]
+    let csc = CscMatrix::from(&DMatrix::from_row_slice(
+        3,
+        3,
+        &[2.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 4.0],
+    ));
+    let mut b = vec![1.0; 3];
+    let err = csc.solve_lower_triangular_into(&mut b).unwrap_err();
+    assert!(matches!(err.kind(), OperationErrorKind::Singular));
+    let err = csc.solve_upper_triangular_into(&mut b).unwrap_err();
+    assert!(matches!(err.kind(), OperationErrorKind::Singular));
+    assert_panics!(csc.solve_lower_triangular_into(&mut [1.0; 2]));
+
+    let identity = CscMatrix::<f64>::identity(3);
+    let mut b = vec![1.0, 2.0, 3.0];
+    identity.solve_upper_triangular_into(&mut b).unwrap();
+    assert_eq!(b, vec![1.0, 2.0, 3.0]);
+}
+
+#[test]
+fn csc_incomplete_cholesky_agrees_with_csr() {
+    // The 1D Laplacian with an extra coupling between the first and last unknowns, which makes
+    // the incomplete factor differ from the exact Cholesky factor.
+    let n = 6;
+    let dense = DMatrix::from_fn(n, n, |i, j| match (i.max(j) - i.min(j), i.max(j)) {
+        (0, _) => 3.0f64,
+        (1, _) => -1.0,
+        (d, m) if d == m && m == n - 1 => -1.0,
+        _ => 0.0,
+    });
+    let csc = CscMatrix::from(&dense).incomplete_cholesky().unwrap();
+    let csr = CsrMatrix::from(&dense).incomplete_cholesky().unwrap();
+    assert_eq!(DMatrix::from(&csc), DMatrix::from(&csr));
+    assert_eq!(
+        csc.pattern(),
+        CscMatrix::from(&dense).lower_triangle().pattern()
+    );
+
+    let lower = DMatrix::from(&csc);
+    let llt = &lower * lower.transpose();
+    for i in 0..n {
+        for j in 0..n {
+            if dense[(i, j)] != 0.0 {
+                assert!((llt[(i, j)] - dense[(i, j)]).abs() <= 1.0e-12);
+            }
+        }
+    }
+    assert!((llt - &dense).amax() > 1.0e-3);
+
+    assert!(CscMatrix::from(&(-dense)).incomplete_cholesky().is_none());
+}
+
+#[test]
+fn csc_power_iteration_non_symmetric() {
+    // Upper triangular, so the eigenvalues are the diagonal entries, and the dominant one is -5.
+    let dense = DMatrix::from_row_slice(
+        4,
+        4,
+        &[
+            1.0f64, 2.0, 0.0, 1.0, 0.0, -5.0, 1.0, 0.0, 0.0, 0.0, 3.0, 2.0, 0.0, 0.0, 0.0, 0.5,
+        ],
+    );
+    let csc = CscMatrix::from(&dense);
+    let initial = DVector::repeat(4, 1.0);
+
+    let (value, vector) = csc.power_iteration(initial.clone(), 1000, 1.0e-13).unwrap();
+    assert!((value + 5.0).abs() <= 1.0e-10);
+    assert!((&dense * &vector - &vector * value).norm() <= 1.0e-5);
+
+    // The CSC matrix must not be mistaken for its transpose, which has different eigenvectors.
+    let (csr_value, csr_vector) = CsrMatrix::from(&dense)
+        .power_iteration(initial, 1000, 1.0e-13)
+        .unwrap();
+    assert!((value - csr_value).abs() <= 1.0e-12);
+    assert!((vector - csr_vector).norm() <= 1.0e-6);
+}
+
+#[test]
+fn csc_display() {
+    // [1 0 2]
+    // [3 0 0]
+    let csc =
+        CscMatrix::try_from_csc_data(2, 3, vec![0, 2, 2, 3], vec![0, 1, 0], vec![1, 3, 2]).unwrap();
+    assert_eq!(
+        csc.to_string(),
+        "2x3 CSC matrix with 3 explicitly stored entries\n\
+         (0, 0) = 1\n\
+         (1, 0) = 3\n\
+         (0, 2) = 2"
+    );
+    assert_eq!(
+        format!("{:#}", csc),
+        "2x3 CSC matrix with 3 explicitly stored entries\n\
+         1 . 2\n\
+         3 . ."
+    );
+}
+
+#[test]
+fn csc_col_scatter_add() {
+    // [1 0 2]
+    // [3 0 0]
+    let mut csc =
+        CscMatrix::try_from_csc_data(2, 3, vec![0, 2, 2, 3], vec![0, 1, 0], vec![1, 3, 2]).unwrap();
+    csc.col_mut(0).scatter_add(&[10, 20]);
+    csc.col_mut(1).scatter_add(&[10, 20]);
+    csc.col_mut(2).scatter_add(&[10, 20]);
+    assert_eq!(csc.values(), &[11, 23, 12]);
+
+    assert_panics!({
+        let mut csc = csc.clone();
+        csc.col_mut(0).scatter_add(&[1, 2, 3]);
+    });
+}
+
+#[test]
+fn csc_to_dense_rectangular() {
+    // [1 0 2]
+    // [0 0 3]
+    let csc =
+        CscMatrix::try_from_csc_data(2, 3, vec![0, 1, 1, 3], vec![0, 0, 1], vec![1, 2, 3]).unwrap();
+    let dense = csc.to_dense();
+    assert_eq!(dense, DMatrix::from_row_slice(2, 3, &[1, 0, 2, 0, 0, 3]));
+}
+
 // Rustfmt makes this test much harder to read by expanding some of the one-liners to 4-liners,
 // so for now we skip rustfmt...
 #[rustfmt::skip]
@@ -528,6 +672,38 @@ fn csc_matrix_col_iter() {
     }
 }
 
+#[test]
+fn csc_remove_row_and_column() {
+    // The middle row and column are empty.
+    #[rustfmt::skip]
+    let dense = DMatrix::from_row_slice(3, 3, &[1, 0, 2,
+                                                0, 0, 0,
+                                                3, 0, 4]);
+    let csc = CscMatrix::from(&dense);
+
+    let reduced = csc.remove_row_and_column(1);
+    assert_eq!((reduced.nrows(), reduced.ncols(), reduced.nnz()), (2, 2, 4));
+    assert_eq!(
+        DMatrix::from(&reduced),
+        DMatrix::from_row_slice(2, 2, &[1, 2, 3, 4])
+    );
+
+    let reduced = csc.remove_row_and_column(0);
+    assert_eq!((reduced.nrows(), reduced.ncols(), reduced.nnz()), (2, 2, 1));
+    assert_eq!(
+        DMatrix::from(&reduced),
+        DMatrix::from_row_slice(2, 2, &[0, 0, 0, 4])
+    );
+
+    let single = CscMatrix::<i32>::identity(1).remove_row_and_column(0);
+    assert_eq!((single.nrows(), single.ncols(), single.nnz()), (0, 0, 0));
+
+    assert_panics!(csc.remove_row_and_column(3));
+    assert_panics!(csc.remove_row(3));
+    assert_panics!(csc.remove_column(3));
+    assert_panics!(CscMatrix::<i32>::zeros(2, 3).remove_row_and_column(0));
+}
+
 proptest! {
     #[test]
     fn csc_double_transpose_is_identity(csc in csc_strategy()) {
@@ -542,6 +718,121 @@ proptest! {
         prop_assert_eq!(csc.nnz(), csc_transpose.nnz());
     }
 
+    #[test]
+    fn csc_transpose_to_same_format_agrees_with_transpose_as_csr(csc in csc_strategy()) {
+        let transpose = csc.transpose_to_same_format();
+        prop_assert_eq!(transpose.nrows(), csc.ncols());
+        prop_assert_eq!(transpose.ncols(), csc.nrows());
+        prop_assert_eq!(&transpose, &csc.transpose());
+        prop_assert_eq!(CsrMatrix::from(&transpose), csc.clone().transpose_as_csr());
+    }
+
+    #[test]
+    fn csc_map_values_agrees_with_dense(csc in csc_strategy()) {
+        let mapped = csc.map_values(|&v| f64::from(v).abs());
+        prop_assert_eq!(mapped.pattern(), csc.pattern());
+        prop_assert_eq!(DMatrix::from(&mapped), DMatrix::from(&csc).map(|v| f64::from(v).abs()));
+    }
+
+    #[test]
+    fn csc_map_with_indices_agrees_with_triplets(csc in csc_strategy()) {
+        let mapped = csc.map_with_indices(|i, j, &v| (i, j, v));
+        prop_assert_eq!(mapped.pattern(), csc.pattern());
+        let triplets: Vec<_> = csc.triplet_iter().cloned_values().collect();
+        prop_assert_eq!(mapped.values(), &triplets[..]);
+    }
+
+    #[test]
+    fn csc_zip_with_dense_agrees_with_dense(csc in csc_strategy()) {
+        let x: Vec<i32> = (0..csc.ncols()).map(|j| j as i32 - 2).collect();
+        let mut visited = Vec::new();
+        let mut y = DVector::zeros(csc.nrows());
+        csc.zip_with_dense(&x, |i, j, &v, &x_j| {
+            visited.push((i, j, v));
+            y[i] += v * x_j;
+        });
+
+        let triplets: Vec<_> = csc.triplet_iter().cloned_values().collect();
+        prop_assert_eq!(visited, triplets);
+        prop_assert_eq!(y, DMatrix::from(&csc) * DVector::from_vec(x));
+    }
+
+    #[test]
+    fn csc_try_from_sorted_triplets_agrees_with_coo(coo in coo_with_duplicates(PROPTEST_I32_VALUE_STRATEGY, PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ, 2)) {
+        let mut triplets: Vec<_> = coo.triplet_iter().map(|(i, j, &v)| (i, j, v)).collect();
+        triplets.sort_by_key(|&(i, j, _)| (j, i));
+        let csc = CscMatrix::try_from_sorted_triplets(coo.nrows(), coo.ncols(), triplets).unwrap();
+        prop_assert_eq!(csc, CscMatrix::from(&coo));
+    }
+
+    #[test]
+    fn csc_builder_agrees_with_col_iter(csc in csc_strategy()) {
+        let mut builder = CscMatrixBuilder::new(csc.nrows());
+        for col in csc.col_iter() {
+            prop_assert!(builder.push_col(col.row_indices(), col.values()).is_ok());
+        }
+        prop_assert_eq!(builder.build(), csc);
+    }
+
+    #[test]
+    fn csc_col_nnz_agrees_with_col_iter(csc in csc_strategy()) {
+        let nnz: Vec<_> = csc.col_iter().map(|lane| lane.nnz()).collect();
+        prop_assert_eq!(csc.col_nnz_iter().collect::<Vec<_>>(), nnz.clone());
+        prop_assert_eq!(csc.max_col_nnz(), nnz.iter().copied().max().unwrap_or(0));
+        prop_assert_eq!(csc.col_nnz_iter().sum::<usize>(), csc.nnz());
+    }
+
+    #[test]
+    fn csc_col_iter_entries_agree_with_triplets(csc in csc_strategy()) {
+        let entries: Vec<_> = csc
+            .col_iter()
+            .enumerate()
+            .flat_map(|(j, lane)| lane.iter().map(move |(i, &v)| (i, j, v)).collect::<Vec<_>>())
+            .collect();
+        let triplets: Vec<_> = csc.triplet_iter().cloned_values().collect();
+        prop_assert_eq!(entries, triplets);
+
+        let mut doubled = csc.clone();
+        for mut lane in doubled.col_iter_mut() {
+            prop_assert_eq!(lane.iter_mut().len(), lane.nnz());
+            for (_, v) in lane.iter_mut() {
+                *v *= 2;
+            }
+        }
+        prop_assert_eq!(doubled, csc * 2);
+    }
+
+    #[test]
+    fn csc_block_diagonal_agrees_with_dense(a in csc_strategy(), b in csc_strategy()) {
+        let (a_dense, b_dense) = (DMatrix::from(&a), DMatrix::from(&b));
+        let mut expected = DMatrix::zeros(a.nrows() + b.nrows(), a.ncols() + b.ncols());
+        expected.slice_mut((0, 0), a_dense.shape()).copy_from(&a_dense);
+        expected.slice_mut(a_dense.shape(), b_dense.shape()).copy_from(&b_dense);
+
+        let block_diagonal = CscMatrix::block_diagonal(&[a.clone(), b.clone()]);
+        prop_assert_eq!(DMatrix::from(&block_diagonal), expected);
+        prop_assert_eq!(block_diagonal.nnz(), a.nnz() + b.nnz());
+    }
+
+    #[test]
+    fn csc_stack_agrees_with_dense(csc in csc_strategy()) {
+        let dense = DMatrix::from(&csc);
+        let (nrows, ncols) = dense.shape();
+        let blocks = [csc.clone(), &csc * 2, csc.clone()];
+
+        let mut expected = DMatrix::zeros(3 * nrows, ncols);
+        for (k, block) in blocks.iter().enumerate() {
+            expected.rows_mut(k * nrows, nrows).copy_from(&DMatrix::from(block));
+        }
+        prop_assert_eq!(DMatrix::from(&CscMatrix::vstack(&blocks)), expected);
+
+        let mut expected = DMatrix::zeros(nrows, 3 * ncols);
+        for (k, block) in blocks.iter().enumerate() {
+            expected.columns_mut(k * ncols, ncols).copy_from(&DMatrix::from(block));
+        }
+        prop_assert_eq!(DMatrix::from(&CscMatrix::hstack(&blocks)), expected);
+    }
+
     #[test]
     fn csc_filter(
         (csc, triplet_subset)
@@ -569,6 +860,66 @@ proptest! {
         prop_assert_eq!(filtered_triplets, triplet_subset);
     }
 
+    #[test]
+    fn csc_filter_passes_row_then_col(csc in csc_strategy()) {
+        let dense = DMatrix::from(&csc);
+        let lower = DMatrix::from_fn(dense.nrows(), dense.ncols(), |i, j| if i > j { dense[(i, j)] } else { 0 });
+        let first_row = DMatrix::from_fn(dense.nrows(), dense.ncols(), |i, j| if i == 0 { dense[(i, j)] } else { 0 });
+
+        prop_assert_eq!(DMatrix::from(&csc.filter(|row, col, _| row > col)), lower);
+        prop_assert_eq!(DMatrix::from(&csc.filter(|row, _, _| row == 0)), first_row);
+    }
+
+    #[test]
+    fn csc_remove_row_agrees_with_dense(
+        (csc, index) in csc_strategy()
+            .prop_filter("matrix must have rows", |csc| csc.nrows() > 0)
+            .prop_flat_map(|csc| {
+                let nrows = csc.nrows();
+                (Just(csc), 0..nrows)
+            })
+    ) {
+        let reduced = csc.remove_row(index);
+        let removed_nnz = csc.triplet_iter().filter(|(i, _, _)| *i == index).count();
+
+        prop_assert_eq!(DMatrix::from(&reduced), DMatrix::from(&csc).remove_row(index));
+        prop_assert_eq!(reduced.nnz(), csc.nnz() - removed_nnz);
+    }
+
+    #[test]
+    fn csc_remove_column_agrees_with_dense(
+        (csc, index) in csc_strategy()
+            .prop_filter("matrix must have columns", |csc| csc.ncols() > 0)
+            .prop_flat_map(|csc| {
+                let ncols = csc.ncols();
+                (Just(csc), 0..ncols)
+            })
+    ) {
+        let reduced = csc.remove_column(index);
+        let removed_nnz = csc.triplet_iter().filter(|(_, j, _)| *j == index).count();
+
+        prop_assert_eq!(DMatrix::from(&reduced), DMatrix::from(&csc).remove_column(index));
+        prop_assert_eq!(reduced.nnz(), csc.nnz() - removed_nnz);
+    }
+
+    #[test]
+    fn csc_remove_row_and_column_agrees_with_dense(
+        (csc, index) in (1..=*PROPTEST_MATRIX_DIM.end())
+            .prop_flat_map(|n| (csc(PROPTEST_I32_VALUE_STRATEGY, n, n, PROPTEST_MAX_NNZ), 0..n))
+    ) {
+        let reduced = csc.remove_row_and_column(index);
+        let removed_nnz = csc.triplet_iter().filter(|(i, j, _)| *i == index || *j == index).count();
+
+        prop_assert_eq!(DMatrix::from(&reduced), DMatrix::from(&csc).remove_row(index).remove_column(index));
+        prop_assert_eq!(reduced.nnz(), csc.nnz() - removed_nnz);
+    }
+
+    #[test]
+    fn csc_filter_keeping_everything_is_identity(csc in csc_strategy()) {
+        prop_assert_eq!(csc.filter(|_, _, _| true), csc.clone());
+        prop_assert_eq!(csc.filter(|_, _, _| false).nnz(), 0);
+    }
+
     #[test]
     fn csc_lower_triangle_agrees_with_dense(csc in csc_strategy()) {
         let csc_lower_triangle = csc.lower_triangle();
@@ -583,6 +934,31 @@ proptest! {
         prop_assert!(csc_upper_triangle.nnz() <= csc.nnz());
     }
 
+    #[test]
+    fn csc_strict_triangles_agree_with_dense(csc in csc_strategy()) {
+        let mut strict_lower = DMatrix::from(&csc).lower_triangle();
+        strict_lower.fill_diagonal(0);
+        let mut strict_upper = DMatrix::from(&csc).upper_triangle();
+        strict_upper.fill_diagonal(0);
+
+        prop_assert_eq!(DMatrix::from(&csc.strict_lower_triangle()), strict_lower);
+        prop_assert_eq!(DMatrix::from(&csc.strict_upper_triangle()), strict_upper);
+    }
+
+    #[test]
+    fn csc_triangles_reconstruct_symmetric_matrix(
+        csc in PROPTEST_MATRIX_DIM
+            .prop_flat_map(|n| csc(PROPTEST_I32_VALUE_STRATEGY, n, n, PROPTEST_MAX_NNZ))
+    ) {
+        let symmetric = &csc + &csc.transpose();
+        let lower = symmetric.lower_triangle();
+        let strict_upper = symmetric.strict_upper_triangle();
+
+        prop_assert_eq!(lower.nnz() + strict_upper.nnz(), symmetric.nnz());
+        prop_assert_eq!(&lower + &strict_upper, symmetric.clone());
+        prop_assert_eq!(&symmetric.strict_lower_triangle() + &symmetric.upper_triangle(), symmetric);
+    }
+
     #[test]
     fn csc_diagonal_as_csc(csc in csc_strategy()) {
         let d = csc.diagonal_as_csc();
@@ -596,10 +972,304 @@ proptest! {
         prop_assert_eq!(d_entries, csc_diagonal_entries);
     }
 
+    #[test]
+    fn csc_spmv_into_agrees_with_dense(
+        (csc, x, y, beta, alpha) in csc_strategy().prop_flat_map(|csc| {
+            let (nrows, ncols) = (csc.nrows(), csc.ncols());
+            (
+                Just(csc),
+                proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, ncols),
+                proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, nrows),
+                PROPTEST_I32_VALUE_STRATEGY,
+                PROPTEST_I32_VALUE_STRATEGY,
+            )
+        })
+    ) {
+        let dense = DMatrix::from(&csc);
+        let expected = DVector::from_column_slice(&y) * beta
+            + dense * DVector::from_column_slice(&x) * alpha;
+
+        let mut y = y;
+        csc.spmv_into(&x, &mut y, beta, alpha);
+        prop_assert_eq!(DVector::from_column_slice(&y), expected);
+    }
+
+    #[test]
+    fn csc_spmv_symmetric_into_agrees_with_dense(
+        (csc, x, y, beta, alpha) in csc_strategy()
+            .prop_filter("matrix must be square", |m| m.nrows() == m.ncols())
+            .prop_flat_map(|csc| {
+                let n = csc.nrows();
+                (
+                    Just(csc),
+                    proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, n),
+                    proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, n),
+                    PROPTEST_I32_VALUE_STRATEGY,
+                    PROPTEST_I32_VALUE_STRATEGY,
+                )
+            })
+    ) {
+        // Store only one triangle, and compare against the full symmetric matrix.
+        let lower = csc.lower_triangle();
+        let dense_lower = DMatrix::from(&lower);
+        let full = &dense_lower + dense_lower.transpose()
+            - DMatrix::from_diagonal(&dense_lower.diagonal());
+        let expected = DVector::from_column_slice(&y) * beta
+            + full * DVector::from_column_slice(&x) * alpha;
+
+        let mut y_lower = y.clone();
+        lower.spmv_symmetric_into(&x, &mut y_lower, beta, alpha);
+        prop_assert_eq!(DVector::from_column_slice(&y_lower), expected.clone());
+
+        let mut y_upper = y;
+        lower.transpose().spmv_symmetric_into(&x, &mut y_upper, beta, alpha);
+        prop_assert_eq!(DVector::from_column_slice(&y_upper), expected);
+    }
+
+    #[test]
+    fn csc_kronecker_agrees_with_dense(a in csc_strategy(), b in csc_strategy()) {
+        let kron = a.kronecker(&b);
+        prop_assert_eq!(kron.nnz(), a.nnz() * b.nnz());
+        prop_assert_eq!(DMatrix::from(&kron), DMatrix::from(&a).kronecker(&DMatrix::from(&b)));
+    }
+
+    #[test]
+    fn csc_spmm_agrees_with_dense(
+        (a, b) in csc_strategy()
+            .prop_flat_map(|a| {
+                let b = csc(PROPTEST_I32_VALUE_STRATEGY, a.ncols(), PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ);
+                (Just(a), b)
+            })
+    ) {
+        let product = a.spmm(&b);
+        prop_assert_eq!(DMatrix::from(&product), DMatrix::from(&a) * DMatrix::from(&b));
+        prop_assert_eq!(product, &a * &b);
+    }
+
+    #[test]
+    fn csc_hadamard_agrees_with_dense(
+        (a, b) in csc_strategy()
+            .prop_flat_map(|a| {
+                let b = csc(PROPTEST_I32_VALUE_STRATEGY, a.nrows(), a.ncols(), PROPTEST_MAX_NNZ);
+                (Just(a), b)
+            })
+    ) {
+        let product = a.hadamard(&b);
+        prop_assert_eq!(
+            DMatrix::from(&product),
+            DMatrix::from(&a).component_mul(&DMatrix::from(&b))
+        );
+
+        let a_entries: HashSet<_> = a.triplet_iter().map(|(i, j, _)| (i, j)).collect();
+        let b_entries: HashSet<_> = b.triplet_iter().map(|(i, j, _)| (i, j)).collect();
+        let product_entries: HashSet<_> = product.triplet_iter().map(|(i, j, _)| (i, j)).collect();
+        prop_assert_eq!(product.nnz(), product_entries.len());
+        prop_assert_eq!(product_entries, &a_entries & &b_entries);
+    }
+
+    #[test]
+    fn csc_norms_agree_with_dense(m in csc_strategy()) {
+        let m = m.map_values(|&v| v as f64);
+        let dense = DMatrix::from(&m);
+        let max_sum = |sums: Vec<f64>| sums.into_iter().fold(0.0, f64::max);
+
+        prop_assert!((m.frobenius_norm() - dense.norm()).abs() <= 1.0e-12);
+        prop_assert_eq!(m.max_norm(), dense.iter().fold(0.0, |acc, v| v.abs().max(acc)));
+        prop_assert_eq!(m.one_norm(), max_sum(dense.column_iter().map(|c| c.abs().sum()).collect()));
+        prop_assert_eq!(m.infinity_norm(), max_sum(dense.row_iter().map(|r| r.abs().sum()).collect()));
+    }
+
+    #[test]
+    fn csc_prune_removes_injected_zeros(m in csc_strategy()) {
+        // Explicitly store zeros at every other entry, on top of the zeros possibly generated.
+        let m = m.map_with_indices(|i, j, &v| if (i + j) % 2 == 0 { 0.0 } else { v as f64 });
+        let dense = DMatrix::from(&m);
+        let minimal_nnz = dense.iter().filter(|v| **v != 0.0).count();
+
+        let pruned = m.prune(0.0);
+        prop_assert_eq!(pruned.nnz(), minimal_nnz);
+        prop_assert_eq!(DMatrix::from(&pruned), dense);
+
+        let mut pruned_mut = m.clone();
+        pruned_mut.prune_mut(0.0);
+        prop_assert_eq!(pruned_mut, pruned);
+    }
+
+    #[test]
+    fn csc_value_reductions_agree_with_dense(m in csc_strategy()) {
+        let m = m.map_values(|&v| v as f64);
+        let dense = DMatrix::from(&m);
+        prop_assert_eq!(m.sum(), dense.sum());
+        prop_assert_eq!(m.count_nonzero_above(2.0), m.prune(2.0).nnz());
+
+        match m.max_abs_entry() {
+            Some((i, j, &v)) => {
+                prop_assert_eq!(dense[(i, j)], v);
+                prop_assert_eq!(v.abs(), dense.amax());
+                // Ties are resolved in favor of the first entry in column-major order
+                let (i0, j0, _) = m.triplet_iter().find(|(_, _, w)| w.abs() == v.abs()).unwrap();
+                prop_assert_eq!((i0, j0), (i, j));
+            }
+            None => prop_assert_eq!(m.nnz(), 0),
+        }
+    }
+
+    #[test]
+    fn csc_normalize_cols_sums_to_one(m in csc_strategy()) {
+        let mut m = m.map_values(|&v| (v.abs() + 1) as f64);
+        m.normalize_cols_mut();
+        for col in m.col_iter().filter(|col| col.nnz() > 0) {
+            prop_assert!((col.values().iter().sum::<f64>() - 1.0).abs() <= 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn csc_get_col_as_dense_agrees_with_dense(m in csc_strategy()) {
+        let dense = DMatrix::from(&m);
+        for i in 0..m.ncols() {
+            prop_assert_eq!(m.get_col_as_dense(i), Some(dense.column(i).into_owned()));
+        }
+        prop_assert_eq!(m.get_col_as_dense(m.ncols()), None);
+    }
+
+    #[test]
+    fn csc_permute_symmetric_agrees_with_dense(
+        (m, perm) in csc_strategy()
+            .prop_filter("matrix must be square", |m| m.nrows() == m.ncols())
+            .prop_flat_map(|m| {
+                let perm = Just((0..m.nrows()).collect::<Vec<_>>()).prop_shuffle();
+                (Just(m), perm)
+            })
+    ) {
+        let permuted = m.permute_symmetric(&perm);
+        let dense = DMatrix::from(&m);
+        let n = m.nrows();
+        prop_assert_eq!(permuted.nnz(), m.nnz());
+        prop_assert_eq!(
+            DMatrix::from(&permuted),
+            DMatrix::from_fn(n, n, |i, j| dense[(perm[i], perm[j])])
+        );
+    }
+
+    #[test]
+    fn csc_solve_triangular_agrees_with_dense(
+        (csc, x) in csc_strategy()
+            .prop_filter("matrix must be square", |m| m.nrows() == m.ncols())
+            .prop_flat_map(|csc| {
+                let n = csc.nrows();
+                (Just(csc), proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, n))
+            })
+    ) {
+        // Make the matrix diagonally dominant so that both triangles are well-conditioned.
+        let n = csc.nrows();
+        let a = &csc.map_values(|&v| v as f64) + &(CscMatrix::identity(n) * 100.0);
+        let x = DVector::from_iterator(n, x.into_iter().map(|v| v as f64));
+
+        let mut b = (DMatrix::from(&a.lower_triangle()) * &x).as_slice().to_vec();
+        a.solve_lower_triangular_into(&mut b).unwrap();
+        prop_assert!((DVector::from_vec(b) - &x).amax() <= 1.0e-10);
+
+        let mut b = (DMatrix::from(&a.upper_triangle()) * &x).as_slice().to_vec();
+        a.solve_upper_triangular_into(&mut b).unwrap();
+        prop_assert!((DVector::from_vec(b) - &x).amax() <= 1.0e-10);
+    }
+
+    #[test]
+    fn csc_to_dense_agrees_with_from(csc in csc_strategy()) {
+        let dense = csc.to_dense();
+        prop_assert_eq!(dense.shape(), (csc.nrows(), csc.ncols()));
+        prop_assert_eq!(dense, DMatrix::from(&csc));
+    }
+
+    #[test]
+    fn csc_diagonal_agrees_with_dense(csc in csc_strategy()) {
+        let dense = DMatrix::from(&csc);
+        let n = dense.nrows().min(dense.ncols());
+        prop_assert_eq!(csc.diagonal(), DVector::from_fn(n, |i, _| dense[(i, i)]));
+    }
+
+    #[test]
+    fn csc_symmetrize_pattern(csc in csc_strategy().prop_filter("square", |m| m.nrows() == m.ncols())) {
+        let symmetrized = csc.symmetrize_pattern();
+        prop_assert!(symmetrized.is_pattern_symmetric());
+        prop_assert_eq!(DMatrix::from(&symmetrized), DMatrix::from(&csc));
+        prop_assert_eq!(symmetrized.pattern(), &spadd_pattern(csc.pattern(), &csc.pattern().transpose()));
+        prop_assert_eq!(csc.is_pattern_symmetric(), symmetrized.nnz() == csc.nnz());
+    }
+
     #[test]
     fn csc_identity(n in 0 ..= 6usize) {
         let csc = CscMatrix::<i32>::identity(n);
         prop_assert_eq!(csc.nnz(), n);
         prop_assert_eq!(DMatrix::from(&csc), DMatrix::identity(n, n));
     }
+
+    #[test]
+    fn csc_identity_spmv_is_identity(x in proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, 0 ..= 6)) {
+        let csc = CscMatrix::<i32>::identity(x.len());
+        let mut y = vec![0; x.len()];
+        csc.spmv_into(&x, &mut y, 0, 1);
+        prop_assert_eq!(y, x);
+    }
+
+    #[test]
+    fn csc_from_diagonal(diag in proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, 0 ..= 6)) {
+        let n = diag.len();
+        let csc = CscMatrix::from_diagonal(&diag);
+        prop_assert_eq!(csc.nnz(), n);
+        prop_assert_eq!(DMatrix::from(&csc), DMatrix::from_diagonal(&DVector::from_vec(diag.clone())));
+
+        let x: Vec<i32> = (0..n as i32).collect();
+        let mut y = vec![0; n];
+        csc.spmv_into(&x, &mut y, 0, 1);
+        prop_assert_eq!(y, diag.iter().zip(&x).map(|(d, x)| d * x).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn csc_scale_mut_agrees_with_dense(csc in csc_strategy(), factor in -5 ..= 5i32) {
+        let mut scaled = csc.clone();
+        scaled.scale_mut(factor);
+        prop_assert_eq!(scaled.pattern(), csc.pattern());
+        prop_assert_eq!(DMatrix::from(&scaled), DMatrix::from(&csc) * factor);
+    }
+
+    #[test]
+    fn csc_add_assign_same_pattern_agrees_with_dense(csc in csc_strategy()) {
+        let mut sum = csc.clone();
+        let mut other = csc.clone();
+        other.scale_mut(3);
+        sum.add_assign_same_pattern(&other);
+        prop_assert_eq!(sum.pattern(), csc.pattern());
+        prop_assert_eq!(DMatrix::from(&sum), DMatrix::from(&csc) * 4);
+    }
+}
+
+#[cfg(feature = "rayon")]
+proptest! {
+    #[test]
+    fn csc_par_col_iter_agrees_with_col_iter(csc in csc_strategy()) {
+        use rayon::prelude::*;
+
+        let par_cols: Vec<_> = csc.par_col_iter().collect();
+        let cols: Vec<_> = csc.col_iter().collect();
+        prop_assert_eq!(par_cols, cols);
+    }
+
+    #[test]
+    fn csc_par_col_iter_mut_agrees_with_col_iter_mut(csc in csc_strategy()) {
+        use rayon::prelude::*;
+
+        let mut par_csc = csc.clone();
+        let par_indices: Vec<_> = par_csc
+            .par_col_iter_mut()
+            .map(|mut col| {
+                col.values_mut().iter_mut().for_each(|v| *v *= 2);
+                col.row_indices().to_vec()
+            })
+            .collect();
+        let indices: Vec<_> = csc.col_iter().map(|col| col.row_indices().to_vec()).collect();
+
+        prop_assert_eq!(par_indices, indices);
+        prop_assert_eq!(par_csc, csc * 2);
+    }
 }
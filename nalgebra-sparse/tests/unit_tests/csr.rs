@@ -1,12 +1,18 @@
-use nalgebra::DMatrix;
-use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra::{DMatrix, DVector};
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csc::CscMatrix;
+use nalgebra_sparse::csr::{CsrMatrix, CsrMatrixBuilder};
+use nalgebra_sparse::ops::serial::{spadd_pattern, OperationErrorKind};
 use nalgebra_sparse::{SparseEntry, SparseEntryMut, SparseFormatErrorKind};
 
+use nalgebra_sparse::proptest::{coo_with_duplicates, csr};
 use proptest::prelude::*;
 use proptest::sample::subsequence;
 
 use crate::assert_panics;
-use crate::common::csr_strategy;
+use crate::common::{
+    csr_strategy, PROPTEST_I32_VALUE_STRATEGY, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ,
+};
 
 use std::collections::HashSet;
 
@@ -299,6 +305,567 @@ fn csr_disassemble_avoids_clone_when_owned() {
     assert_eq!(values.as_ptr(), values_ptr);
 }
 
+#[test]
+fn csr_add_assign_same_pattern_panics_on_pattern_mismatch() {
+    let mut a = CsrMatrix::try_from_csr_data(2, 3, vec![0, 1, 2], vec![0, 2], vec![1, 2]).unwrap();
+    let b = CsrMatrix::try_from_csr_data(2, 3, vec![0, 1, 2], vec![1, 2], vec![1, 2]).unwrap();
+    assert_panics!(a.clone().add_assign_same_pattern(&b));
+
+    a.add_assign_same_pattern(&a.clone());
+    assert_eq!(a.values(), &[2, 4]);
+}
+
+#[test]
+fn csr_add_assign_extends_pattern() {
+    // Stiffness matrix of a 1D mesh with 3 nodes, plus a lumped mass matrix and a coupling term
+    // which is not in the pattern of either.
+    let stiffness = CsrMatrix::try_from_csr_data(
+        3,
+        3,
+        vec![0, 2, 5, 7],
+        vec![0, 1, 0, 1, 2, 1, 2],
+        vec![1.0, -1.0, -1.0, 2.0, -1.0, -1.0, 1.0],
+    )
+    .unwrap();
+    let mass = CsrMatrix::try_from_csr_data(
+        3,
+        3,
+        vec![0, 2, 3, 4],
+        vec![0, 2, 1, 2],
+        vec![0.5, 0.25, 1.0, 0.5],
+    )
+    .unwrap();
+
+    let mut operator = stiffness.clone();
+    operator += &mass;
+    assert_eq!(operator.row_offsets(), &[0, 3, 6, 8]);
+    assert_eq!(operator.col_indices(), &[0, 1, 2, 0, 1, 2, 1, 2]);
+    assert_eq!(
+        operator.values(),
+        &[1.5, -1.0, 0.25, -1.0, 3.0, -1.0, -1.0, 1.5]
+    );
+
+    let mut empty = CsrMatrix::zeros(3, 3);
+    empty += stiffness.clone();
+    assert_eq!(empty, stiffness);
+
+    assert_panics!({
+        let mut mismatched = CsrMatrix::<f64>::zeros(3, 2);
+        mismatched += &stiffness;
+    });
+}
+
+#[test]
+fn csr_hadamard_masks_entries() {
+    // [1 2 0]   [5 0 6]   [5 0 0]
+    // [0 3 4] ∘ [0 7 0] = [0 21 0]
+    let a = CsrMatrix::try_from_csr_data(2, 3, vec![0, 2, 4], vec![0, 1, 1, 2], vec![1, 2, 3, 4])
+        .unwrap();
+    let b =
+        CsrMatrix::try_from_csr_data(2, 3, vec![0, 2, 3], vec![0, 2, 1], vec![5, 6, 7]).unwrap();
+    let product = a.hadamard(&b);
+    assert_eq!(product.row_offsets(), &[0, 1, 2]);
+    assert_eq!(product.col_indices(), &[0, 1]);
+    assert_eq!(product.values(), &[5, 21]);
+    assert_eq!(b.hadamard(&a), product);
+
+    assert_eq!(a.hadamard(&CsrMatrix::zeros(2, 3)).nnz(), 0);
+    assert_panics!(a.hadamard(&CsrMatrix::zeros(3, 2)));
+}
+
+#[test]
+fn csr_prune_drops_small_entries() {
+    // [1.0  0.0  1e-9]
+    // [0.0 -2.0  0.0 ]
+    // [NaN  0.0  3.0 ]
+    let mut m = CsrMatrix::try_from_csr_data(
+        3,
+        3,
+        vec![0, 3, 5, 8],
+        vec![0, 1, 2, 0, 1, 0, 1, 2],
+        vec![1.0, 0.0, 1e-9, 0.0, -2.0, f64::NAN, 0.0, 3.0],
+    )
+    .unwrap();
+
+    let pruned = m.prune(1e-6);
+    assert_eq!(pruned.row_offsets(), &[0, 1, 2, 4]);
+    assert_eq!(pruned.col_indices(), &[0, 1, 0, 2]);
+    assert_eq!(pruned.values()[..2], [1.0, -2.0]);
+    assert!(pruned.values()[2].is_nan());
+    assert_eq!(pruned.values()[3], 3.0);
+    assert_eq!(m.prune(0.0).nnz(), 5);
+
+    m.prune_mut(1e-6);
+    assert_eq!(m.row_offsets(), pruned.row_offsets());
+    assert_eq!(m.col_indices(), pruned.col_indices());
+    assert_eq!(m.values()[3], 3.0);
+}
+
+#[test]
+fn csr_value_reductions() {
+    // [1.0  0.0  -3.0]
+    // [NaN  3.0   0.0]
+    let m = CsrMatrix::try_from_csr_data(
+        2,
+        3,
+        vec![0, 3, 5],
+        vec![0, 1, 2, 0, 1],
+        vec![1.0, 0.0, -3.0, f64::NAN, 3.0],
+    )
+    .unwrap();
+
+    // The first of the tied entries is returned, and NaN is ignored.
+    assert_eq!(m.max_abs_entry(), Some((0, 2, &-3.0)));
+    assert_eq!(m.count_nonzero_above(1.0), 3);
+    assert_eq!(m.count_nonzero_above(0.0), 4);
+    assert!(m.sum().is_nan());
+
+    let m = m.filter(|i, _, _| i == 0);
+    assert_eq!(m.sum(), -2.0);
+
+    let nan = CsrMatrix::try_from_csr_data(1, 1, vec![0, 1], vec![0], vec![f64::NAN]).unwrap();
+    assert_eq!(nan.max_abs_entry(), None);
+    assert_eq!(CsrMatrix::<f64>::zeros(2, 2).max_abs_entry(), None);
+    assert_eq!(CsrMatrix::<i32>::zeros(2, 2).sum(), 0);
+}
+
+#[test]
+fn csr_spmm_panics_on_dimension_mismatch() {
+    let a = CsrMatrix::<f64>::identity(3);
+    assert_eq!(a.spmm(&CsrMatrix::zeros(3, 2)).ncols(), 2);
+    assert_panics!(a.spmm(&CsrMatrix::zeros(2, 3)));
+}
+
+#[test]
+fn csr_normalize_rows() {
+    // [1 3 0]
+    // [0 0 0]
+    // [2 0 -2]
+    let mut m = CsrMatrix::try_from_csr_data(
+        3,
+        3,
+        vec![0, 2, 2, 4],
+        vec![0, 1, 0, 2],
+        vec![1.0, 3.0, 2.0, -2.0],
+    )
+    .unwrap();
+
+    let mut by_max = m.clone();
+    by_max.normalize_rows_by(|values| values.iter().fold(0.0, |acc, v: &f64| acc.max(v.abs())));
+    assert_eq!(by_max.values(), &[1.0 / 3.0, 1.0, 1.0, -1.0]);
+
+    // The last row sums to zero, so it is left untouched.
+    m.normalize_rows_mut();
+    assert_eq!(m.row_offsets(), &[0, 2, 2, 4]);
+    assert_eq!(m.values(), &[0.25, 0.75, 2.0, -2.0]);
+}
+
+#[test]
+fn csr_permute_symmetric_reverse_cuthill_mckee() {
+    // Arrow matrix with a dense first row and column, with a bandwidth of 3. RCM moves the
+    // hub lane towards the middle, which reduces the bandwidth to 2.
+    let mut coo = CooMatrix::new(4, 4);
+    for i in 0..4 {
+        coo.push(i, i, 4);
+        if i > 0 {
+            coo.push(0, i, 1);
+            coo.push(i, 0, 1);
+        }
+    }
+    let csr = CsrMatrix::from(&coo);
+
+    let perm = csr.pattern().reverse_cuthill_mckee();
+    assert_eq!(perm, vec![3, 2, 0, 1]);
+    let permuted = csr.permute_symmetric(&perm);
+    assert_eq!(
+        DMatrix::from(&permuted),
+        DMatrix::from_row_slice(4, 4, &[4, 0, 1, 0, 0, 4, 1, 0, 1, 1, 4, 1, 0, 0, 1, 4])
+    );
+
+    assert_panics!(csr.permute_symmetric(&[0, 1, 2]));
+    assert_panics!(csr.permute_symmetric(&[0, 1, 1, 2]));
+    assert_panics!(CsrMatrix::<i32>::zeros(2, 3).permute_symmetric(&[0, 1]));
+}
+
+#[test]
+fn csr_display() {
+    // [1.5 0 2  ]
+    // [0   0 -10]
+    let csr =
+        CsrMatrix::try_from_csr_data(2, 3, vec![0, 2, 3], vec![0, 2, 2], vec![1.5, 2.0, -10.0])
+            .unwrap();
+    assert_eq!(
+        csr.to_string(),
+        "2x3 CSR matrix with 3 explicitly stored entries\n\
+         (0, 0) = 1.5\n\
+         (0, 2) = 2\n\
+         (1, 2) = -10"
+    );
+    assert_eq!(
+        format!("{:#}", csr),
+        "2x3 CSR matrix with 3 explicitly stored entries\n\
+         1.5   .   2\n  \
+         .   . -10"
+    );
+    assert_eq!(
+        format!("{:.1}", csr),
+        "2x3 CSR matrix with 3 explicitly stored entries\n\
+         (0, 0) = 1.5\n\
+         (0, 2) = 2.0\n\
+         (1, 2) = -10.0"
+    );
+
+    let identity = CsrMatrix::<i32>::identity(1);
+    assert_eq!(
+        format!("{:#}", identity),
+        "1x1 CSR matrix with 1 explicitly stored entry\n1"
+    );
+
+    // Large matrices are displayed as triplets even with the alternate flag.
+    let identity = CsrMatrix::<i32>::identity(17);
+    let display = format!("{:#}", identity);
+    assert!(display.starts_with("17x17 CSR matrix with 17 explicitly stored entries\n(0, 0) = 1\n"));
+    assert_eq!(display.lines().count(), 18);
+
+    assert_eq!(
+        CsrMatrix::<i32>::zeros(0, 0).to_string(),
+        "0x0 CSR matrix with 0 explicitly stored entries"
+    );
+}
+
+#[test]
+fn csr_row_scatter_add() {
+    // [1 0 2]
+    // [0 3 0]
+    let mut csr =
+        CsrMatrix::try_from_csr_data(2, 3, vec![0, 2, 3], vec![0, 2, 1], vec![1, 2, 3]).unwrap();
+    csr.row_mut(0).scatter_add(&[10, 20, 30]);
+    assert_eq!(csr.values(), &[11, 32, 3]);
+    csr.row_mut(1).scatter_add(&[10, 20, 30]);
+    assert_eq!(csr.values(), &[11, 32, 23]);
+
+    assert_panics!({
+        let mut csr = csr.clone();
+        csr.row_mut(0).scatter_add(&[1, 2]);
+    });
+}
+
+#[test]
+fn csr_set_diagonal_mut() {
+    // 2x3 matrix with an explicitly stored zero on the diagonal
+    let mut csr =
+        CsrMatrix::try_from_csr_data(2, 3, vec![0, 2, 4], vec![0, 2, 1, 2], vec![1, 2, 0, 4])
+            .unwrap();
+    assert_eq!(csr.diagonal(), DVector::from_column_slice(&[1, 0]));
+
+    csr.set_diagonal_mut(&[5, 6]).unwrap();
+    assert_eq!(csr.diagonal(), DVector::from_column_slice(&[5, 6]));
+    assert_eq!(csr.values(), &[5, 2, 6, 4]);
+
+    // The (1, 1) entry is not explicitly stored
+    let mut csr =
+        CsrMatrix::try_from_csr_data(2, 3, vec![0, 2, 3], vec![0, 2, 2], vec![1, 2, 4]).unwrap();
+    let error = csr.set_diagonal_mut(&[5, 6]).unwrap_err();
+    assert!(matches!(error.kind(), OperationErrorKind::InvalidPattern));
+    assert_eq!(csr.values(), &[1, 2, 4]);
+    assert_panics!(csr.clone().set_diagonal_mut(&[5]));
+}
+
+#[test]
+fn csr_is_pattern_symmetric() {
+    // [1 2 0]
+    // [0 3 0]
+    // [4 0 5]
+    let csr = CsrMatrix::try_from_csr_data(
+        3,
+        3,
+        vec![0, 2, 3, 5],
+        vec![0, 1, 1, 0, 2],
+        vec![1, 2, 3, 4, 5],
+    )
+    .unwrap();
+    assert!(!csr.is_pattern_symmetric());
+
+    let symmetrized = csr.symmetrize_pattern();
+    assert!(symmetrized.is_pattern_symmetric());
+    assert_eq!(symmetrized.row_offsets(), &[0, 3, 5, 7]);
+    assert_eq!(symmetrized.col_indices(), &[0, 1, 2, 0, 1, 0, 2]);
+    assert_eq!(symmetrized.values(), &[1, 2, 0, 0, 3, 4, 5]);
+
+    // Non-square matrices are never symmetric
+    assert!(!CsrMatrix::<i32>::zeros(2, 3).is_pattern_symmetric());
+    assert_panics!(CsrMatrix::<i32>::zeros(2, 3).symmetrize_pattern());
+}
+
+#[test]
+fn csr_matrix_try_from_sorted_triplets() {
+    // Duplicates are summed, and empty rows are allowed
+    let triplets = vec![
+        (0, 1, 1),
+        (0, 1, 2),
+        (0, 3, 3),
+        (2, 0, 4),
+        (2, 2, 5),
+        (2, 2, 6),
+    ];
+    let csr = CsrMatrix::try_from_sorted_triplets(4, 4, triplets).unwrap();
+    assert_eq!(csr.row_offsets(), &[0, 2, 2, 4, 4]);
+    assert_eq!(csr.col_indices(), &[1, 3, 0, 2]);
+    assert_eq!(csr.values(), &[3, 3, 4, 11]);
+
+    let csr = CsrMatrix::<i32>::try_from_sorted_triplets(2, 3, vec![]).unwrap();
+    assert_eq!(csr, CsrMatrix::zeros(2, 3));
+
+    let unsorted_rows = vec![(1, 0, 1), (0, 1, 2)];
+    let unsorted_cols = vec![(0, 2, 1), (0, 1, 2)];
+    let row_out_of_bounds = vec![(0, 0, 1), (2, 1, 2)];
+    let col_out_of_bounds = vec![(0, 0, 1), (1, 3, 2)];
+    for (triplets, kind) in [
+        (unsorted_rows, SparseFormatErrorKind::InvalidStructure),
+        (unsorted_cols, SparseFormatErrorKind::InvalidStructure),
+        (row_out_of_bounds, SparseFormatErrorKind::IndexOutOfBounds),
+        (col_out_of_bounds, SparseFormatErrorKind::IndexOutOfBounds),
+    ] {
+        let error = CsrMatrix::try_from_sorted_triplets(2, 3, triplets).unwrap_err();
+        assert_eq!(error.kind(), &kind);
+    }
+}
+
+#[test]
+fn csr_matrix_builder() {
+    let mut builder = CsrMatrixBuilder::with_capacity(4, 3, 4);
+    builder.push_row(&[1, 3], &[1, 2]).unwrap();
+    builder.push_row(&[], &[]).unwrap();
+
+    // Invalid rows are rejected and leave the builder unchanged
+    for (cols, kind) in [
+        (vec![2, 1], SparseFormatErrorKind::InvalidStructure),
+        (vec![1, 1], SparseFormatErrorKind::DuplicateEntry),
+        (vec![0, 4], SparseFormatErrorKind::IndexOutOfBounds),
+    ] {
+        let error = builder.push_row(&cols, &[5, 6]).unwrap_err();
+        assert_eq!(error.kind(), &kind);
+    }
+    assert_eq!(builder.nrows(), 2);
+
+    builder.push_row(&[0, 2], &[3, 4]).unwrap();
+    let csr = builder.build();
+    assert_eq!(csr.nrows(), 3);
+    assert_eq!(csr.ncols(), 4);
+    assert_eq!(csr.row_offsets(), &[0, 2, 2, 4]);
+    assert_eq!(csr.col_indices(), &[1, 3, 0, 2]);
+    assert_eq!(csr.values(), &[1, 2, 3, 4]);
+
+    assert_eq!(
+        CsrMatrixBuilder::<i32>::new(3).build(),
+        CsrMatrix::zeros(0, 3)
+    );
+    assert_panics!(CsrMatrixBuilder::new(3).push_row(&[0, 1], &[1]));
+}
+
+#[test]
+fn csr_block_diagonal_and_stacks_edge_cases() {
+    assert_eq!(
+        CsrMatrix::<i32>::block_diagonal(&[]),
+        CsrMatrix::zeros(0, 0)
+    );
+    assert_eq!(CsrMatrix::<i32>::vstack(&[]), CsrMatrix::zeros(0, 0));
+    assert_eq!(CsrMatrix::<i32>::hstack(&[]), CsrMatrix::zeros(0, 0));
+
+    let a = CsrMatrix::<i32>::identity(2);
+    let b = CsrMatrix::<i32>::zeros(3, 1);
+    assert_panics!(CsrMatrix::vstack(&[a.clone(), b.clone()]));
+    assert_panics!(CsrMatrix::hstack(&[a.clone(), b.clone()]));
+
+    let block_diagonal = CsrMatrix::block_diagonal(&[a, b, CsrMatrix::identity(1)]);
+    assert_eq!(block_diagonal.nrows(), 6);
+    assert_eq!(block_diagonal.ncols(), 4);
+    assert_eq!(block_diagonal.row_offsets(), &[0, 1, 2, 2, 2, 2, 3]);
+    assert_eq!(block_diagonal.col_indices(), &[0, 1, 3]);
+}
+
+#[test]
+fn csr_spmv_into_panics_on_dimension_mismatch() {
+    let csr = CsrMatrix::<i32>::identity(3);
+    let mut y = vec![0; 3];
+    assert_panics!(csr.spmv_into(&[1, 2], &mut y.clone(), 0, 1));
+    assert_panics!(csr.spmv_into(&[1, 2, 3], &mut [0, 0], 0, 1));
+    csr.spmv_into(&[1, 2, 3], &mut y, 0, 2);
+    assert_eq!(y, vec![2, 4, 6]);
+}
+
+#[test]
+fn csr_solve_triangular_zero_diagonal() {
+    // [2 0 0]
+    // [1 0 0]
+    // [0 1 4]
+    let csr = CsrMatrix::from(&DMatrix::from_row_slice(
+        3,
+        3,
+        &[2.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 4.0],
+    ));
+    let mut b = vec![1.0; 3];
+    let err = csr.solve_lower_triangular_into(&mut b).unwrap_err();
+    assert!(matches!(err.kind(), OperationErrorKind::Singular));
+    let err = csr.solve_upper_triangular_into(&mut b).unwrap_err();
+    assert!(matches!(err.kind(), OperationErrorKind::Singular));
+    assert_panics!(csr.solve_lower_triangular_into(&mut [1.0; 2]));
+
+    let identity = CsrMatrix::<f64>::identity(3);
+    let mut b = vec![1.0, 2.0, 3.0];
+    identity.solve_upper_triangular_into(&mut b).unwrap();
+    assert_eq!(b, vec![1.0, 2.0, 3.0]);
+}
+
+/// The 5-point finite-difference Laplacian on an `m x m` grid.
+fn poisson_2d(m: usize) -> CsrMatrix<f64> {
+    let mut coo = CooMatrix::new(m * m, m * m);
+    for i in 0..m {
+        for j in 0..m {
+            let k = i * m + j;
+            coo.push(k, k, 4.0);
+            if i > 0 {
+                coo.push(k, k - m, -1.0);
+            }
+            if i + 1 < m {
+                coo.push(k, k + m, -1.0);
+            }
+            if j > 0 {
+                coo.push(k, k - 1, -1.0);
+            }
+            if j + 1 < m {
+                coo.push(k, k + 1, -1.0);
+            }
+        }
+    }
+    CsrMatrix::from(&coo)
+}
+
+/// Runs the preconditioned conjugate gradient method and returns the number of iterations needed
+/// to reduce the residual norm by a factor `1.0e-10`.
+fn conjugate_gradient_iterations(
+    a: &CsrMatrix<f64>,
+    b: &[f64],
+    precondition: impl Fn(&mut [f64]),
+) -> usize {
+    let dot = |u: &[f64], v: &[f64]| u.iter().zip(v).map(|(u, v)| u * v).sum::<f64>();
+    let n = b.len();
+    let tol = 1.0e-10 * dot(b, b).sqrt();
+    let mut x = vec![0.0; n];
+    let mut r = b.to_vec();
+    let mut z = r.clone();
+    precondition(&mut z);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+    let mut ap = vec![0.0; n];
+
+    for iter in 1..=10 * n {
+        a.spmv_into(&p, &mut ap, 0.0, 1.0);
+        let alpha = rz / dot(&p, &ap);
+        for k in 0..n {
+            x[k] += alpha * p[k];
+            r[k] -= alpha * ap[k];
+        }
+
+        if dot(&r, &r).sqrt() <= tol {
+            return iter;
+        }
+
+        z.copy_from_slice(&r);
+        precondition(&mut z);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for k in 0..n {
+            p[k] = z[k] + beta * p[k];
+        }
+    }
+
+    panic!("The conjugate gradient method did not converge.");
+}
+
+#[test]
+fn csr_incomplete_cholesky_poisson() {
+    let a = poisson_2d(12);
+    let l = a.incomplete_cholesky().unwrap();
+    let lt = l.transpose();
+    assert_eq!(l.pattern(), a.lower_triangle().pattern());
+
+    // L * L^T matches A on the sparsity pattern of A.
+    let llt = DMatrix::from(&l) * DMatrix::from(&lt);
+    for (i, j, &a_ij) in a.triplet_iter() {
+        assert!((llt[(i, j)] - a_ij).abs() <= 1.0e-12);
+    }
+
+    let b: Vec<f64> = (0..a.nrows()).map(|i| (i % 7) as f64 - 3.0).collect();
+    let plain = conjugate_gradient_iterations(&a, &b, |_| {});
+    let preconditioned = conjugate_gradient_iterations(&a, &b, |z| {
+        l.solve_lower_triangular_into(z).unwrap();
+        lt.solve_upper_triangular_into(z).unwrap();
+    });
+    assert!(
+        2 * preconditioned < plain,
+        "IC(0) did not reduce the iteration count enough: {} vs {}",
+        preconditioned,
+        plain
+    );
+}
+
+#[test]
+fn csr_power_iteration_poisson() {
+    // The eigenvalues of the 2D Laplacian are `4 - 2 cos(iπ / (m + 1)) - 2 cos(jπ / (m + 1))`.
+    let m = 4;
+    let a = poisson_2d(m);
+    let expected = 4.0 + 4.0 * (std::f64::consts::PI / (m + 1) as f64).cos();
+    let initial = DVector::from_fn(m * m, |i, _| 1.0 + (i % 3) as f64);
+
+    let (value, vector) = a.power_iteration(initial.clone(), 1000, 1.0e-12).unwrap();
+    assert!((value - expected).abs() <= 1.0e-10);
+    assert!((vector.norm() - 1.0).abs() <= 1.0e-12);
+    assert!((DMatrix::from(&a) * &vector - &vector * value).norm() <= 1.0e-5);
+
+    let (dense_value, _) = DMatrix::from(&a)
+        .power_iteration(initial, 1000, 1.0e-12)
+        .unwrap();
+    assert!((value - dense_value).abs() <= 1.0e-12);
+
+    assert!(a
+        .power_iteration(DVector::zeros(m * m), 1000, 1.0e-12)
+        .is_none());
+    assert!(CsrMatrix::<f64>::zeros(3, 3)
+        .power_iteration(DVector::repeat(3, 1.0), 1000, 1.0e-12)
+        .is_none());
+}
+
+#[test]
+fn csr_incomplete_cholesky_non_positive_pivot() {
+    let indefinite = CsrMatrix::from(&DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 2.0, 1.0]));
+    assert!(indefinite.incomplete_cholesky().is_none());
+
+    let missing_diagonal = CsrMatrix::from(&DMatrix::from_row_slice(2, 2, &[1.0, 0.0, 0.0, 0.0]));
+    assert!(missing_diagonal.incomplete_cholesky().is_none());
+
+    // Without fill-in, IC(0) of a tridiagonal matrix is its exact Cholesky factor.
+    let tridiagonal = DMatrix::from_fn(5, 5, |i, j| match i.max(j) - i.min(j) {
+        0 => 2.0,
+        1 => -1.0,
+        _ => 0.0,
+    });
+    let l = CsrMatrix::from(&tridiagonal).incomplete_cholesky().unwrap();
+    let exact = tridiagonal.cholesky().unwrap().l();
+    assert!((DMatrix::from(&l) - exact).amax() <= 1.0e-12);
+}
+
+#[test]
+fn csr_to_dense_rectangular() {
+    // [1 0 2]
+    // [0 0 3]
+    let csr =
+        CsrMatrix::try_from_csr_data(2, 3, vec![0, 2, 3], vec![0, 2, 2], vec![1, 2, 3]).unwrap();
+    let dense = csr.to_dense();
+    assert_eq!(dense, DMatrix::from_row_slice(2, 3, &[1, 0, 2, 0, 0, 3]));
+}
+
 // Rustfmt makes this test much harder to read by expanding some of the one-liners to 4-liners,
 // so for now we skip rustfmt...
 #[rustfmt::skip]
@@ -524,6 +1091,38 @@ fn csr_matrix_row_iter() {
     }
 }
 
+#[test]
+fn csr_remove_row_and_column() {
+    // The middle row and column are empty.
+    #[rustfmt::skip]
+    let dense = DMatrix::from_row_slice(3, 3, &[1, 0, 2,
+                                                0, 0, 0,
+                                                3, 0, 4]);
+    let csr = CsrMatrix::from(&dense);
+
+    let reduced = csr.remove_row_and_column(1);
+    assert_eq!((reduced.nrows(), reduced.ncols(), reduced.nnz()), (2, 2, 4));
+    assert_eq!(
+        DMatrix::from(&reduced),
+        DMatrix::from_row_slice(2, 2, &[1, 2, 3, 4])
+    );
+
+    let reduced = csr.remove_row_and_column(0);
+    assert_eq!((reduced.nrows(), reduced.ncols(), reduced.nnz()), (2, 2, 1));
+    assert_eq!(
+        DMatrix::from(&reduced),
+        DMatrix::from_row_slice(2, 2, &[0, 0, 0, 4])
+    );
+
+    let single = CsrMatrix::<i32>::identity(1).remove_row_and_column(0);
+    assert_eq!((single.nrows(), single.ncols(), single.nnz()), (0, 0, 0));
+
+    assert_panics!(csr.remove_row_and_column(3));
+    assert_panics!(csr.remove_row(3));
+    assert_panics!(csr.remove_column(3));
+    assert_panics!(CsrMatrix::<i32>::zeros(2, 3).remove_row_and_column(0));
+}
+
 proptest! {
     #[test]
     fn csr_double_transpose_is_identity(csr in csr_strategy()) {
@@ -538,6 +1137,121 @@ proptest! {
         prop_assert_eq!(csr.nnz(), csr_transpose.nnz());
     }
 
+    #[test]
+    fn csr_transpose_to_same_format_agrees_with_transpose_as_csc(csr in csr_strategy()) {
+        let transpose = csr.transpose_to_same_format();
+        prop_assert_eq!(transpose.nrows(), csr.ncols());
+        prop_assert_eq!(transpose.ncols(), csr.nrows());
+        prop_assert_eq!(&transpose, &csr.transpose());
+        prop_assert_eq!(CscMatrix::from(&transpose), csr.clone().transpose_as_csc());
+    }
+
+    #[test]
+    fn csr_map_values_agrees_with_dense(csr in csr_strategy()) {
+        let mapped = csr.map_values(|&v| f64::from(v).abs());
+        prop_assert_eq!(mapped.pattern(), csr.pattern());
+        prop_assert_eq!(DMatrix::from(&mapped), DMatrix::from(&csr).map(|v| f64::from(v).abs()));
+    }
+
+    #[test]
+    fn csr_map_with_indices_agrees_with_triplets(csr in csr_strategy()) {
+        let mapped = csr.map_with_indices(|i, j, &v| (i, j, v));
+        prop_assert_eq!(mapped.pattern(), csr.pattern());
+        let triplets: Vec<_> = csr.triplet_iter().cloned_values().collect();
+        prop_assert_eq!(mapped.values(), &triplets[..]);
+    }
+
+    #[test]
+    fn csr_zip_with_dense_agrees_with_dense(csr in csr_strategy()) {
+        let x: Vec<i32> = (0..csr.ncols()).map(|j| j as i32 - 2).collect();
+        let mut visited = Vec::new();
+        let mut y = DVector::zeros(csr.nrows());
+        csr.zip_with_dense(&x, |i, j, &v, &x_j| {
+            visited.push((i, j, v));
+            y[i] += v * x_j;
+        });
+
+        let triplets: Vec<_> = csr.triplet_iter().cloned_values().collect();
+        prop_assert_eq!(visited, triplets);
+        prop_assert_eq!(y, DMatrix::from(&csr) * DVector::from_vec(x));
+    }
+
+    #[test]
+    fn csr_try_from_sorted_triplets_agrees_with_coo(coo in coo_with_duplicates(PROPTEST_I32_VALUE_STRATEGY, PROPTEST_MATRIX_DIM, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ, 2)) {
+        let mut triplets: Vec<_> = coo.triplet_iter().map(|(i, j, &v)| (i, j, v)).collect();
+        triplets.sort_by_key(|&(i, j, _)| (i, j));
+        let csr = CsrMatrix::try_from_sorted_triplets(coo.nrows(), coo.ncols(), triplets).unwrap();
+        prop_assert_eq!(csr, CsrMatrix::from(&coo));
+    }
+
+    #[test]
+    fn csr_builder_agrees_with_row_iter(csr in csr_strategy()) {
+        let mut builder = CsrMatrixBuilder::new(csr.ncols());
+        for row in csr.row_iter() {
+            prop_assert!(builder.push_row(row.col_indices(), row.values()).is_ok());
+        }
+        prop_assert_eq!(builder.build(), csr);
+    }
+
+    #[test]
+    fn csr_row_nnz_agrees_with_row_iter(csr in csr_strategy()) {
+        let nnz: Vec<_> = csr.row_iter().map(|lane| lane.nnz()).collect();
+        prop_assert_eq!(csr.row_nnz_iter().collect::<Vec<_>>(), nnz.clone());
+        prop_assert_eq!(csr.max_row_nnz(), nnz.iter().copied().max().unwrap_or(0));
+        prop_assert_eq!(csr.row_nnz_iter().sum::<usize>(), csr.nnz());
+    }
+
+    #[test]
+    fn csr_row_iter_entries_agree_with_triplets(csr in csr_strategy()) {
+        let entries: Vec<_> = csr
+            .row_iter()
+            .enumerate()
+            .flat_map(|(i, lane)| lane.iter().map(move |(j, &v)| (i, j, v)).collect::<Vec<_>>())
+            .collect();
+        let triplets: Vec<_> = csr.triplet_iter().cloned_values().collect();
+        prop_assert_eq!(entries, triplets);
+
+        let mut doubled = csr.clone();
+        for mut lane in doubled.row_iter_mut() {
+            prop_assert_eq!(lane.iter_mut().len(), lane.nnz());
+            for (_, v) in lane.iter_mut() {
+                *v *= 2;
+            }
+        }
+        prop_assert_eq!(doubled, csr * 2);
+    }
+
+    #[test]
+    fn csr_block_diagonal_agrees_with_dense(a in csr_strategy(), b in csr_strategy()) {
+        let (a_dense, b_dense) = (DMatrix::from(&a), DMatrix::from(&b));
+        let mut expected = DMatrix::zeros(a.nrows() + b.nrows(), a.ncols() + b.ncols());
+        expected.slice_mut((0, 0), a_dense.shape()).copy_from(&a_dense);
+        expected.slice_mut(a_dense.shape(), b_dense.shape()).copy_from(&b_dense);
+
+        let block_diagonal = CsrMatrix::block_diagonal(&[a.clone(), b.clone()]);
+        prop_assert_eq!(DMatrix::from(&block_diagonal), expected);
+        prop_assert_eq!(block_diagonal.nnz(), a.nnz() + b.nnz());
+    }
+
+    #[test]
+    fn csr_stack_agrees_with_dense(csr in csr_strategy()) {
+        let dense = DMatrix::from(&csr);
+        let (nrows, ncols) = dense.shape();
+        let blocks = [csr.clone(), &csr * 2, csr.clone()];
+
+        let mut expected = DMatrix::zeros(3 * nrows, ncols);
+        for (k, block) in blocks.iter().enumerate() {
+            expected.rows_mut(k * nrows, nrows).copy_from(&DMatrix::from(block));
+        }
+        prop_assert_eq!(DMatrix::from(&CsrMatrix::vstack(&blocks)), expected);
+
+        let mut expected = DMatrix::zeros(nrows, 3 * ncols);
+        for (k, block) in blocks.iter().enumerate() {
+            expected.columns_mut(k * ncols, ncols).copy_from(&DMatrix::from(block));
+        }
+        prop_assert_eq!(DMatrix::from(&CsrMatrix::hstack(&blocks)), expected);
+    }
+
     #[test]
     fn csr_filter(
         (csr, triplet_subset)
@@ -565,6 +1279,66 @@ proptest! {
         prop_assert_eq!(filtered_triplets, triplet_subset);
     }
 
+    #[test]
+    fn csr_filter_passes_row_then_col(csr in csr_strategy()) {
+        let dense = DMatrix::from(&csr);
+        let lower = DMatrix::from_fn(dense.nrows(), dense.ncols(), |i, j| if i > j { dense[(i, j)] } else { 0 });
+        let first_row = DMatrix::from_fn(dense.nrows(), dense.ncols(), |i, j| if i == 0 { dense[(i, j)] } else { 0 });
+
+        prop_assert_eq!(DMatrix::from(&csr.filter(|row, col, _| row > col)), lower);
+        prop_assert_eq!(DMatrix::from(&csr.filter(|row, _, _| row == 0)), first_row);
+    }
+
+    #[test]
+    fn csr_remove_row_agrees_with_dense(
+        (csr, index) in csr_strategy()
+            .prop_filter("matrix must have rows", |csr| csr.nrows() > 0)
+            .prop_flat_map(|csr| {
+                let nrows = csr.nrows();
+                (Just(csr), 0..nrows)
+            })
+    ) {
+        let reduced = csr.remove_row(index);
+        let removed_nnz = csr.triplet_iter().filter(|(i, _, _)| *i == index).count();
+
+        prop_assert_eq!(DMatrix::from(&reduced), DMatrix::from(&csr).remove_row(index));
+        prop_assert_eq!(reduced.nnz(), csr.nnz() - removed_nnz);
+    }
+
+    #[test]
+    fn csr_remove_column_agrees_with_dense(
+        (csr, index) in csr_strategy()
+            .prop_filter("matrix must have columns", |csr| csr.ncols() > 0)
+            .prop_flat_map(|csr| {
+                let ncols = csr.ncols();
+                (Just(csr), 0..ncols)
+            })
+    ) {
+        let reduced = csr.remove_column(index);
+        let removed_nnz = csr.triplet_iter().filter(|(_, j, _)| *j == index).count();
+
+        prop_assert_eq!(DMatrix::from(&reduced), DMatrix::from(&csr).remove_column(index));
+        prop_assert_eq!(reduced.nnz(), csr.nnz() - removed_nnz);
+    }
+
+    #[test]
+    fn csr_remove_row_and_column_agrees_with_dense(
+        (csr, index) in (1..=*PROPTEST_MATRIX_DIM.end())
+            .prop_flat_map(|n| (csr(PROPTEST_I32_VALUE_STRATEGY, n, n, PROPTEST_MAX_NNZ), 0..n))
+    ) {
+        let reduced = csr.remove_row_and_column(index);
+        let removed_nnz = csr.triplet_iter().filter(|(i, j, _)| *i == index || *j == index).count();
+
+        prop_assert_eq!(DMatrix::from(&reduced), DMatrix::from(&csr).remove_row(index).remove_column(index));
+        prop_assert_eq!(reduced.nnz(), csr.nnz() - removed_nnz);
+    }
+
+    #[test]
+    fn csr_filter_keeping_everything_is_identity(csr in csr_strategy()) {
+        prop_assert_eq!(csr.filter(|_, _, _| true), csr.clone());
+        prop_assert_eq!(csr.filter(|_, _, _| false).nnz(), 0);
+    }
+
     #[test]
     fn csr_lower_triangle_agrees_with_dense(csr in csr_strategy()) {
         let csr_lower_triangle = csr.lower_triangle();
@@ -579,6 +1353,31 @@ proptest! {
         prop_assert!(csr_upper_triangle.nnz() <= csr.nnz());
     }
 
+    #[test]
+    fn csr_strict_triangles_agree_with_dense(csr in csr_strategy()) {
+        let mut strict_lower = DMatrix::from(&csr).lower_triangle();
+        strict_lower.fill_diagonal(0);
+        let mut strict_upper = DMatrix::from(&csr).upper_triangle();
+        strict_upper.fill_diagonal(0);
+
+        prop_assert_eq!(DMatrix::from(&csr.strict_lower_triangle()), strict_lower);
+        prop_assert_eq!(DMatrix::from(&csr.strict_upper_triangle()), strict_upper);
+    }
+
+    #[test]
+    fn csr_triangles_reconstruct_symmetric_matrix(
+        csr in PROPTEST_MATRIX_DIM
+            .prop_flat_map(|n| csr(PROPTEST_I32_VALUE_STRATEGY, n, n, PROPTEST_MAX_NNZ))
+    ) {
+        let symmetric = &csr + &csr.transpose();
+        let lower = symmetric.lower_triangle();
+        let strict_upper = symmetric.strict_upper_triangle();
+
+        prop_assert_eq!(lower.nnz() + strict_upper.nnz(), symmetric.nnz());
+        prop_assert_eq!(&lower + &strict_upper, symmetric.clone());
+        prop_assert_eq!(&symmetric.strict_lower_triangle() + &symmetric.upper_triangle(), symmetric);
+    }
+
     #[test]
     fn csr_diagonal_as_csr(csr in csr_strategy()) {
         let d = csr.diagonal_as_csr();
@@ -592,10 +1391,304 @@ proptest! {
         prop_assert_eq!(d_entries, csr_diagonal_entries);
     }
 
+    #[test]
+    fn csr_spmv_into_agrees_with_dense(
+        (csr, x, y, beta, alpha) in csr_strategy().prop_flat_map(|csr| {
+            let (nrows, ncols) = (csr.nrows(), csr.ncols());
+            (
+                Just(csr),
+                proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, ncols),
+                proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, nrows),
+                PROPTEST_I32_VALUE_STRATEGY,
+                PROPTEST_I32_VALUE_STRATEGY,
+            )
+        })
+    ) {
+        let dense = DMatrix::from(&csr);
+        let expected = DVector::from_column_slice(&y) * beta
+            + dense * DVector::from_column_slice(&x) * alpha;
+
+        let mut y = y;
+        csr.spmv_into(&x, &mut y, beta, alpha);
+        prop_assert_eq!(DVector::from_column_slice(&y), expected);
+    }
+
+    #[test]
+    fn csr_spmv_symmetric_into_agrees_with_dense(
+        (csr, x, y, beta, alpha) in csr_strategy()
+            .prop_filter("matrix must be square", |m| m.nrows() == m.ncols())
+            .prop_flat_map(|csr| {
+                let n = csr.nrows();
+                (
+                    Just(csr),
+                    proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, n),
+                    proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, n),
+                    PROPTEST_I32_VALUE_STRATEGY,
+                    PROPTEST_I32_VALUE_STRATEGY,
+                )
+            })
+    ) {
+        // Store only one triangle, and compare against the full symmetric matrix.
+        let lower = csr.lower_triangle();
+        let dense_lower = DMatrix::from(&lower);
+        let full = &dense_lower + dense_lower.transpose()
+            - DMatrix::from_diagonal(&dense_lower.diagonal());
+        let expected = DVector::from_column_slice(&y) * beta
+            + full * DVector::from_column_slice(&x) * alpha;
+
+        let mut y_lower = y.clone();
+        lower.spmv_symmetric_into(&x, &mut y_lower, beta, alpha);
+        prop_assert_eq!(DVector::from_column_slice(&y_lower), expected.clone());
+
+        let mut y_upper = y;
+        lower.transpose().spmv_symmetric_into(&x, &mut y_upper, beta, alpha);
+        prop_assert_eq!(DVector::from_column_slice(&y_upper), expected);
+    }
+
+    #[test]
+    fn csr_kronecker_agrees_with_dense(a in csr_strategy(), b in csr_strategy()) {
+        let kron = a.kronecker(&b);
+        prop_assert_eq!(kron.nnz(), a.nnz() * b.nnz());
+        prop_assert_eq!(DMatrix::from(&kron), DMatrix::from(&a).kronecker(&DMatrix::from(&b)));
+    }
+
+    #[test]
+    fn csr_spmm_agrees_with_dense(
+        (a, b) in csr_strategy()
+            .prop_flat_map(|a| {
+                let b = csr(PROPTEST_I32_VALUE_STRATEGY, a.ncols(), PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ);
+                (Just(a), b)
+            })
+    ) {
+        let product = a.spmm(&b);
+        prop_assert_eq!(DMatrix::from(&product), DMatrix::from(&a) * DMatrix::from(&b));
+        prop_assert_eq!(product, &a * &b);
+    }
+
+    #[test]
+    fn csr_hadamard_agrees_with_dense(
+        (a, b) in csr_strategy()
+            .prop_flat_map(|a| {
+                let b = csr(PROPTEST_I32_VALUE_STRATEGY, a.nrows(), a.ncols(), PROPTEST_MAX_NNZ);
+                (Just(a), b)
+            })
+    ) {
+        let product = a.hadamard(&b);
+        prop_assert_eq!(
+            DMatrix::from(&product),
+            DMatrix::from(&a).component_mul(&DMatrix::from(&b))
+        );
+
+        let a_entries: HashSet<_> = a.triplet_iter().map(|(i, j, _)| (i, j)).collect();
+        let b_entries: HashSet<_> = b.triplet_iter().map(|(i, j, _)| (i, j)).collect();
+        let product_entries: HashSet<_> = product.triplet_iter().map(|(i, j, _)| (i, j)).collect();
+        prop_assert_eq!(product.nnz(), product_entries.len());
+        prop_assert_eq!(product_entries, &a_entries & &b_entries);
+    }
+
+    #[test]
+    fn csr_norms_agree_with_dense(m in csr_strategy()) {
+        let m = m.map_values(|&v| v as f64);
+        let dense = DMatrix::from(&m);
+        let max_sum = |sums: Vec<f64>| sums.into_iter().fold(0.0, f64::max);
+
+        prop_assert!((m.frobenius_norm() - dense.norm()).abs() <= 1.0e-12);
+        prop_assert_eq!(m.max_norm(), dense.iter().fold(0.0, |acc, v| v.abs().max(acc)));
+        prop_assert_eq!(m.one_norm(), max_sum(dense.column_iter().map(|c| c.abs().sum()).collect()));
+        prop_assert_eq!(m.infinity_norm(), max_sum(dense.row_iter().map(|r| r.abs().sum()).collect()));
+    }
+
+    #[test]
+    fn csr_prune_removes_injected_zeros(m in csr_strategy()) {
+        // Explicitly store zeros at every other entry, on top of the zeros possibly generated.
+        let m = m.map_with_indices(|i, j, &v| if (i + j) % 2 == 0 { 0.0 } else { v as f64 });
+        let dense = DMatrix::from(&m);
+        let minimal_nnz = dense.iter().filter(|v| **v != 0.0).count();
+
+        let pruned = m.prune(0.0);
+        prop_assert_eq!(pruned.nnz(), minimal_nnz);
+        prop_assert_eq!(DMatrix::from(&pruned), dense);
+
+        let mut pruned_mut = m.clone();
+        pruned_mut.prune_mut(0.0);
+        prop_assert_eq!(pruned_mut, pruned);
+    }
+
+    #[test]
+    fn csr_value_reductions_agree_with_dense(m in csr_strategy()) {
+        let m = m.map_values(|&v| v as f64);
+        let dense = DMatrix::from(&m);
+        prop_assert_eq!(m.sum(), dense.sum());
+        prop_assert_eq!(m.count_nonzero_above(2.0), m.prune(2.0).nnz());
+
+        match m.max_abs_entry() {
+            Some((i, j, &v)) => {
+                prop_assert_eq!(dense[(i, j)], v);
+                prop_assert_eq!(v.abs(), dense.amax());
+                // Ties are resolved in favor of the first entry in row-major order
+                let (i0, j0, _) = m.triplet_iter().find(|(_, _, w)| w.abs() == v.abs()).unwrap();
+                prop_assert_eq!((i0, j0), (i, j));
+            }
+            None => prop_assert_eq!(m.nnz(), 0),
+        }
+    }
+
+    #[test]
+    fn csr_normalize_rows_sums_to_one(m in csr_strategy()) {
+        let mut m = m.map_values(|&v| (v.abs() + 1) as f64);
+        m.normalize_rows_mut();
+        for row in m.row_iter().filter(|row| row.nnz() > 0) {
+            prop_assert!((row.values().iter().sum::<f64>() - 1.0).abs() <= 1.0e-12);
+        }
+    }
+
+    #[test]
+    fn csr_get_row_as_dense_agrees_with_dense(m in csr_strategy()) {
+        let dense = DMatrix::from(&m);
+        for i in 0..m.nrows() {
+            prop_assert_eq!(m.get_row_as_dense(i), Some(dense.row(i).transpose()));
+        }
+        prop_assert_eq!(m.get_row_as_dense(m.nrows()), None);
+    }
+
+    #[test]
+    fn csr_permute_symmetric_agrees_with_dense(
+        (m, perm) in csr_strategy()
+            .prop_filter("matrix must be square", |m| m.nrows() == m.ncols())
+            .prop_flat_map(|m| {
+                let perm = Just((0..m.nrows()).collect::<Vec<_>>()).prop_shuffle();
+                (Just(m), perm)
+            })
+    ) {
+        let permuted = m.permute_symmetric(&perm);
+        let dense = DMatrix::from(&m);
+        let n = m.nrows();
+        prop_assert_eq!(permuted.nnz(), m.nnz());
+        prop_assert_eq!(
+            DMatrix::from(&permuted),
+            DMatrix::from_fn(n, n, |i, j| dense[(perm[i], perm[j])])
+        );
+    }
+
+    #[test]
+    fn csr_solve_triangular_agrees_with_dense(
+        (csr, x) in csr_strategy()
+            .prop_filter("matrix must be square", |m| m.nrows() == m.ncols())
+            .prop_flat_map(|csr| {
+                let n = csr.nrows();
+                (Just(csr), proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, n))
+            })
+    ) {
+        // Make the matrix diagonally dominant so that both triangles are well-conditioned.
+        let n = csr.nrows();
+        let a = &csr.map_values(|&v| v as f64) + &(CsrMatrix::identity(n) * 100.0);
+        let x = DVector::from_iterator(n, x.into_iter().map(|v| v as f64));
+
+        let mut b = (DMatrix::from(&a.lower_triangle()) * &x).as_slice().to_vec();
+        a.solve_lower_triangular_into(&mut b).unwrap();
+        prop_assert!((DVector::from_vec(b) - &x).amax() <= 1.0e-10);
+
+        let mut b = (DMatrix::from(&a.upper_triangle()) * &x).as_slice().to_vec();
+        a.solve_upper_triangular_into(&mut b).unwrap();
+        prop_assert!((DVector::from_vec(b) - &x).amax() <= 1.0e-10);
+    }
+
+    #[test]
+    fn csr_to_dense_agrees_with_from(csr in csr_strategy()) {
+        let dense = csr.to_dense();
+        prop_assert_eq!(dense.shape(), (csr.nrows(), csr.ncols()));
+        prop_assert_eq!(dense, DMatrix::from(&csr));
+    }
+
+    #[test]
+    fn csr_diagonal_agrees_with_dense(csr in csr_strategy()) {
+        let dense = DMatrix::from(&csr);
+        let n = dense.nrows().min(dense.ncols());
+        prop_assert_eq!(csr.diagonal(), DVector::from_fn(n, |i, _| dense[(i, i)]));
+    }
+
+    #[test]
+    fn csr_symmetrize_pattern(csr in csr_strategy().prop_filter("square", |m| m.nrows() == m.ncols())) {
+        let symmetrized = csr.symmetrize_pattern();
+        prop_assert!(symmetrized.is_pattern_symmetric());
+        prop_assert_eq!(DMatrix::from(&symmetrized), DMatrix::from(&csr));
+        prop_assert_eq!(symmetrized.pattern(), &spadd_pattern(csr.pattern(), &csr.pattern().transpose()));
+        prop_assert_eq!(csr.is_pattern_symmetric(), symmetrized.nnz() == csr.nnz());
+    }
+
     #[test]
     fn csr_identity(n in 0 ..= 6usize) {
         let csr = CsrMatrix::<i32>::identity(n);
         prop_assert_eq!(csr.nnz(), n);
         prop_assert_eq!(DMatrix::from(&csr), DMatrix::identity(n, n));
     }
+
+    #[test]
+    fn csr_identity_spmv_is_identity(x in proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, 0 ..= 6)) {
+        let csr = CsrMatrix::<i32>::identity(x.len());
+        let mut y = vec![0; x.len()];
+        csr.spmv_into(&x, &mut y, 0, 1);
+        prop_assert_eq!(y, x);
+    }
+
+    #[test]
+    fn csr_from_diagonal(diag in proptest::collection::vec(PROPTEST_I32_VALUE_STRATEGY, 0 ..= 6)) {
+        let n = diag.len();
+        let csr = CsrMatrix::from_diagonal(&diag);
+        prop_assert_eq!(csr.nnz(), n);
+        prop_assert_eq!(DMatrix::from(&csr), DMatrix::from_diagonal(&DVector::from_vec(diag.clone())));
+
+        let x: Vec<i32> = (0..n as i32).collect();
+        let mut y = vec![0; n];
+        csr.spmv_into(&x, &mut y, 0, 1);
+        prop_assert_eq!(y, diag.iter().zip(&x).map(|(d, x)| d * x).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn csr_scale_mut_agrees_with_dense(csr in csr_strategy(), factor in -5 ..= 5i32) {
+        let mut scaled = csr.clone();
+        scaled.scale_mut(factor);
+        prop_assert_eq!(scaled.pattern(), csr.pattern());
+        prop_assert_eq!(DMatrix::from(&scaled), DMatrix::from(&csr) * factor);
+    }
+
+    #[test]
+    fn csr_add_assign_same_pattern_agrees_with_dense(csr in csr_strategy()) {
+        let mut sum = csr.clone();
+        let mut other = csr.clone();
+        other.scale_mut(3);
+        sum.add_assign_same_pattern(&other);
+        prop_assert_eq!(sum.pattern(), csr.pattern());
+        prop_assert_eq!(DMatrix::from(&sum), DMatrix::from(&csr) * 4);
+    }
+}
+
+#[cfg(feature = "rayon")]
+proptest! {
+    #[test]
+    fn csr_par_row_iter_agrees_with_row_iter(csr in csr_strategy()) {
+        use rayon::prelude::*;
+
+        let par_rows: Vec<_> = csr.par_row_iter().collect();
+        let rows: Vec<_> = csr.row_iter().collect();
+        prop_assert_eq!(par_rows, rows);
+    }
+
+    #[test]
+    fn csr_par_row_iter_mut_agrees_with_row_iter_mut(csr in csr_strategy()) {
+        use rayon::prelude::*;
+
+        let mut par_csr = csr.clone();
+        let par_indices: Vec<_> = par_csr
+            .par_row_iter_mut()
+            .map(|mut row| {
+                row.values_mut().iter_mut().for_each(|v| *v *= 2);
+                row.col_indices().to_vec()
+            })
+            .collect();
+        let indices: Vec<_> = csr.row_iter().map(|row| row.col_indices().to_vec()).collect();
+
+        prop_assert_eq!(par_indices, indices);
+        prop_assert_eq!(par_csr, csr * 2);
+    }
 }
@@ -0,0 +1,164 @@
+use crate::common::{value_strategy, PROPTEST_MATRIX_DIM, PROPTEST_MAX_NNZ};
+use nalgebra::{DMatrix, DVector};
+use nalgebra_sparse::proptest::csr;
+use nalgebra_sparse::{CooMatrix, CscMatrix, CsrMatrix};
+use proptest::prelude::*;
+
+use crate::assert_panics;
+
+/// The symmetric tridiagonal matrix with the diagonal `alpha` and the off-diagonal `beta`.
+fn tridiagonal_matrix(alpha: &DVector<f64>, beta: &DVector<f64>) -> DMatrix<f64> {
+    let mut t = DMatrix::from_diagonal(alpha);
+    for (i, b) in beta.iter().enumerate() {
+        t[(i + 1, i)] = *b;
+        t[(i, i + 1)] = *b;
+    }
+    t
+}
+
+fn sorted(v: DVector<f64>) -> Vec<f64> {
+    let mut v: Vec<_> = v.iter().copied().collect();
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    v
+}
+
+#[test]
+fn lanczos_full_run_recovers_eigenvalues() {
+    let diag = [4.0f64, -1.0, 2.5, 7.0, 0.5];
+    let csr = CsrMatrix::from_diagonal(&diag);
+    let start = [1.0, 2.0, -1.0, 1.0, 3.0];
+
+    for &reorthogonalize in &[false, true] {
+        let mut lanczos = csr.lanczos(&start, reorthogonalize);
+        let first = lanczos.next().unwrap();
+        assert_eq!(lanczos.steps(), 1);
+
+        // The first diagonal element is the Rayleigh quotient of the starting vector.
+        let q = DVector::from_column_slice(&start).normalize();
+        let expected = q.dot(&(DMatrix::from(&csr) * &q));
+        assert!((first.0 - expected).abs() < 1.0e-12);
+
+        let (alpha, beta) = csr.lanczos(&start, reorthogonalize).tridiagonal(10);
+        assert_eq!(alpha.len(), 5);
+        assert_eq!(beta.len(), 4);
+
+        let ritz = sorted(tridiagonal_matrix(&alpha, &beta).symmetric_eigenvalues());
+        let expected = sorted(DVector::from_column_slice(&diag));
+        for (r, e) in ritz.iter().zip(&expected) {
+            assert!((r - e).abs() < 1.0e-10);
+        }
+    }
+}
+
+#[test]
+fn lanczos_stops_on_invariant_subspace() {
+    let csr = CsrMatrix::from_diagonal(&[3.0f64, 1.0, 2.0, 5.0]);
+
+    // The starting vector only has components along two eigenvectors.
+    let mut lanczos = csr.lanczos(&[1.0, 0.0, 1.0, 0.0], true);
+    assert!(lanczos.next().is_some());
+    let (_, beta) = lanczos.next().unwrap();
+    assert!(beta.abs() < 1.0e-12);
+    assert!(lanczos.next().is_none());
+    assert_eq!(lanczos.steps(), 2);
+
+    let (alpha, beta) = csr.lanczos(&[1.0, 0.0, 1.0, 0.0], true).tridiagonal(4);
+    assert_eq!(alpha.len(), 2);
+    assert_eq!(beta.len(), 1);
+    let ritz = sorted(tridiagonal_matrix(&alpha, &beta).symmetric_eigenvalues());
+    assert!((ritz[0] - 2.0).abs() < 1.0e-12);
+    assert!((ritz[1] - 3.0).abs() < 1.0e-12);
+
+    let (alpha, beta) = CsrMatrix::<f64>::identity(0)
+        .lanczos(&[], false)
+        .tridiagonal(3);
+    assert_eq!((alpha.len(), beta.len()), (0, 0));
+}
+
+#[test]
+fn lanczos_csr_and_csc_agree() {
+    let mut coo = CooMatrix::new(6, 6);
+    for i in 0..6 {
+        coo.push(i, i, 2.0 + i as f64);
+        if i + 2 < 6 {
+            coo.push(i, i + 2, -1.0);
+            coo.push(i + 2, i, -1.0);
+        }
+    }
+    let csr = CsrMatrix::from(&coo);
+    let csc = CscMatrix::from(&coo);
+    let start = [1.0, -1.0, 2.0, 0.5, 1.0, 3.0];
+
+    let csr_steps: Vec<_> = csr.lanczos(&start, true).collect();
+    let csc_steps: Vec<_> = csc.lanczos(&start, true).collect();
+    assert_eq!(csr_steps.len(), csc_steps.len());
+    for (a, b) in csr_steps.iter().zip(&csc_steps) {
+        assert!((a.0 - b.0).abs() < 1.0e-12);
+        assert!((a.1 - b.1).abs() < 1.0e-12);
+    }
+}
+
+#[test]
+fn lanczos_invalid_input_panics() {
+    let csr = CsrMatrix::<f64>::identity(3);
+    assert_panics!(csr.lanczos(&[0.0, 0.0, 0.0], false));
+    assert_panics!(csr.lanczos(&[1.0, 0.0], false));
+
+    let rect = CsrMatrix::<f64>::zeros(2, 3);
+    assert_panics!(rect.lanczos(&[1.0, 0.0], false));
+}
+
+#[test]
+fn lanczos_reorthogonalizes_near_breakdown() {
+    // The Krylov subspace has dimension 3, but the residual of the third step is slightly
+    // larger than the breakdown threshold, so the iteration continues from a mostly cancelled
+    // residual.
+    let mut coo = CooMatrix::new(5, 5);
+    coo.push(0, 0, -0.37877639232835014);
+    coo.push(0, 1, -3.0442929880229235);
+    coo.push(1, 0, -3.0442929880229235);
+    let csr = CsrMatrix::from(&coo);
+    let start = [
+        1.6471418578573265,
+        1.6707614512497062,
+        1.0,
+        1.3961508414186647,
+        1.976892952557879,
+    ];
+
+    let eigenvalues = DMatrix::from(&csr).symmetric_eigenvalues();
+    let (alpha, beta) = csr.lanczos(&start, true).tridiagonal(5);
+
+    for ritz in tridiagonal_matrix(&alpha, &beta)
+        .symmetric_eigenvalues()
+        .iter()
+    {
+        assert!(eigenvalues.iter().any(|e| (e - ritz).abs() <= 1.0e-10));
+    }
+}
+
+proptest! {
+    #[test]
+    fn lanczos_ritz_values_are_eigenvalues(
+        (csr, start) in (1..=*PROPTEST_MATRIX_DIM.end())
+            .prop_flat_map(|n| csr(value_strategy::<f64>(), n, n, PROPTEST_MAX_NNZ))
+            .prop_flat_map(|csr| {
+                let n = csr.nrows();
+                // Symmetrize the matrix.
+                let csr = &csr + &csr.transpose();
+                (Just(csr), proptest::collection::vec(1.0 ..= 2.0f64, n))
+            })
+    ) {
+        let dense = DMatrix::from(&csr);
+        let eigenvalues = dense.symmetric_eigenvalues();
+        let tol = 1.0e-8 * (1.0 + dense.norm());
+        let (alpha, beta) = csr.lanczos(&start, true).tridiagonal(csr.nrows());
+
+        prop_assert!(alpha.len() <= csr.nrows());
+        prop_assert_eq!(beta.len(), alpha.len().saturating_sub(1));
+
+        for ritz in tridiagonal_matrix(&alpha, &beta).symmetric_eigenvalues().iter() {
+            prop_assert!(eigenvalues.iter().any(|e| (e - ritz).abs() <= tol));
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use nalgebra::DMatrix;
+use nalgebra_sparse::coo::CooMatrix;
+use nalgebra_sparse::csr::CsrMatrix;
+use nalgebra_sparse::io::{
+    load_coo_from_matrix_market_file, load_coo_from_matrix_market_str,
+    save_coo_to_matrix_market_file, save_coo_to_matrix_market_str, MatrixMarketErrorKind,
+};
+use proptest::prelude::*;
+
+use crate::common::csr_strategy;
+
+fn load_dense(data: &str) -> DMatrix<f64> {
+    DMatrix::from(&load_coo_from_matrix_market_str::<f64>(data).unwrap())
+}
+
+fn load_error_kind(data: &str) -> MatrixMarketErrorKind {
+    load_coo_from_matrix_market_str::<f64>(data)
+        .unwrap_err()
+        .kind()
+        .clone()
+}
+
+#[test]
+fn matrix_market_general_real() {
+    let data = "%%MatrixMarket matrix coordinate real general
+%=================================================================================
+% A comment block, followed by an empty line and comment lines with leading spaces.
+%
+   % indented comment
+3 4 5
+1 1 1.5
+3 4 -2e3
+
+2 2 +.25
+1 4 7
+1 1 0.5
+";
+    let expected = DMatrix::from_row_slice(
+        3,
+        4,
+        &[
+            2.0, 0.0, 0.0, 7.0, 0.0, 0.25, 0.0, 0.0, 0.0, 0.0, 0.0, -2000.0,
+        ],
+    );
+    assert_eq!(load_dense(data), expected);
+
+    // Duplicates are kept as separate triplets.
+    let coo = load_coo_from_matrix_market_str::<f64>(data).unwrap();
+    assert_eq!(coo.nnz(), 5);
+    assert_eq!(coo.row_indices(), &[0, 2, 1, 0, 0]);
+    assert_eq!(coo.col_indices(), &[0, 3, 1, 3, 0]);
+}
+
+#[test]
+fn matrix_market_header_variants() {
+    // Keywords are case-insensitive, and CRLF line endings are accepted.
+    let data = "%%MatrixMarket MATRIX Coordinate Double General\r\n2 2 1\r\n2 1 3.0\r\n";
+    assert_eq!(
+        load_dense(data),
+        DMatrix::from_row_slice(2, 2, &[0.0, 0.0, 3.0, 0.0])
+    );
+
+    // Integer field.
+    let data = "%%MatrixMarket matrix coordinate integer general\n2 2 2\n1 1 -3\n2 2 4";
+    assert_eq!(
+        load_dense(data),
+        DMatrix::from_row_slice(2, 2, &[-3.0, 0.0, 0.0, 4.0])
+    );
+
+    // Pattern field.
+    let data = "%%MatrixMarket matrix coordinate pattern general\n2 3 2\n1 3\n2 1\n";
+    assert_eq!(
+        load_dense(data),
+        DMatrix::from_row_slice(2, 3, &[0.0, 0.0, 1.0, 1.0, 0.0, 0.0])
+    );
+
+    // Empty matrices.
+    let data = "%%MatrixMarket matrix coordinate real general\n0 0 0\n";
+    assert_eq!(load_dense(data), DMatrix::zeros(0, 0));
+    let data = "%%MatrixMarket matrix coordinate real general\n3 2 0\n";
+    assert_eq!(load_dense(data), DMatrix::zeros(3, 2));
+}
+
+#[test]
+fn matrix_market_symmetric() {
+    let data = "%%MatrixMarket matrix coordinate real symmetric
+3 3 4
+1 1 4.0
+2 1 -1.0
+3 2 -2.0
+3 3 5.0
+";
+    let expected =
+        DMatrix::from_row_slice(3, 3, &[4.0, -1.0, 0.0, -1.0, 0.0, -2.0, 0.0, -2.0, 5.0]);
+    assert_eq!(load_dense(data), expected);
+
+    let data = "%%MatrixMarket matrix coordinate pattern symmetric\n2 2 1\n2 1\n";
+    assert_eq!(
+        load_dense(data),
+        DMatrix::from_row_slice(2, 2, &[0.0, 1.0, 1.0, 0.0])
+    );
+}
+
+#[test]
+fn matrix_market_skew_symmetric() {
+    let data = "%%MatrixMarket matrix coordinate real skew-symmetric
+3 3 2
+2 1 1.5
+3 1 -2.0
+";
+    let expected = DMatrix::from_row_slice(3, 3, &[0.0, -1.5, 2.0, 1.5, 0.0, 0.0, -2.0, 0.0, 0.0]);
+    assert_eq!(load_dense(data), expected);
+}
+
+#[test]
+fn matrix_market_invalid_header() {
+    use MatrixMarketErrorKind::*;
+
+    let header_only = |header: &str| format!("{}\n1 1 1\n1 1 1.0\n", header);
+
+    assert_eq!(
+        load_error_kind(&header_only(
+            "%%MatrixMarket matrix coordinate complex general"
+        )),
+        NotSupported
+    );
+    assert_eq!(
+        load_error_kind(&header_only("%%MatrixMarket matrix array real general")),
+        NotSupported
+    );
+    assert_eq!(
+        load_error_kind(&header_only(
+            "%%MatrixMarket matrix coordinate real hermitian"
+        )),
+        NotSupported
+    );
+    assert_eq!(
+        load_error_kind(&header_only(
+            "%%MatrixMarket matrix coordinate reel general"
+        )),
+        InvalidHeader
+    );
+    assert_eq!(
+        load_error_kind(&header_only(
+            "%%MatrixMarket tensor coordinate real general"
+        )),
+        InvalidHeader
+    );
+    assert_eq!(
+        load_error_kind(&header_only(
+            "%%MatrixMarket matrix coordinate pattern skew-symmetric"
+        )),
+        InvalidHeader
+    );
+    assert_eq!(
+        load_error_kind(&header_only("%%MatrixMarket matrix coordinate real")),
+        ParsingError
+    );
+    assert_eq!(
+        load_error_kind(&header_only("% matrix coordinate real general")),
+        ParsingError
+    );
+}
+
+#[test]
+fn matrix_market_invalid_data() {
+    use MatrixMarketErrorKind::*;
+
+    let general = |body: &str| format!("%%MatrixMarket matrix coordinate real general\n{}", body);
+    let symmetric =
+        |body: &str| format!("%%MatrixMarket matrix coordinate real symmetric\n{}", body);
+
+    // Zero-based or out-of-bounds indices
+    assert_eq!(load_error_kind(&general("2 2 1\n0 1 1.0\n")), InvalidData);
+    assert_eq!(load_error_kind(&general("2 2 1\n1 3 1.0\n")), InvalidData);
+    assert_eq!(load_error_kind(&general("2 2 1\n3 1 1.0\n")), InvalidData);
+
+    // Entry count does not match the header
+    assert_eq!(load_error_kind(&general("2 2 2\n1 1 1.0\n")), InvalidData);
+    assert_eq!(load_error_kind(&general("2 2 0\n1 1 1.0\n")), InvalidData);
+
+    // Missing or extraneous values
+    assert_eq!(load_error_kind(&general("2 2 1\n1 1\n")), InvalidData);
+    assert_eq!(
+        load_error_kind("%%MatrixMarket matrix coordinate pattern general\n2 2 1\n1 1 1.0\n"),
+        InvalidData
+    );
+    assert_eq!(
+        load_error_kind("%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 1 1.5\n"),
+        InvalidData
+    );
+
+    // Entries above the diagonal of symmetric matrices, or non-square symmetric matrices
+    assert_eq!(load_error_kind(&symmetric("2 2 1\n1 2 1.0\n")), InvalidData);
+    assert_eq!(load_error_kind(&symmetric("2 3 1\n1 1 1.0\n")), InvalidData);
+    assert_eq!(
+        load_error_kind("%%MatrixMarket matrix coordinate real skew-symmetric\n2 2 1\n1 1 1.0\n"),
+        InvalidData
+    );
+
+    // Malformed lines
+    assert_eq!(load_error_kind(&general("2 2\n")), ParsingError);
+    assert_eq!(load_error_kind(&general("2 2 1\n1 1 abc\n")), ParsingError);
+    assert_eq!(
+        load_error_kind(&general("2 2 1\n1.5 1 1.0\n")),
+        ParsingError
+    );
+    assert_eq!(
+        load_error_kind(&general("2 2 1\n1 1 1.0 2.0\n")),
+        ParsingError
+    );
+}
+
+#[test]
+fn matrix_market_file_roundtrip() {
+    let mut coo = CooMatrix::new(3, 2);
+    coo.push(2, 1, 0.1);
+    coo.push(0, 0, -1.0e-20);
+    coo.push(2, 1, 3.0);
+
+    let path = std::env::temp_dir().join(format!(
+        "nalgebra_sparse_matrix_market_{}.mtx",
+        std::process::id()
+    ));
+    save_coo_to_matrix_market_file(&coo, &path).unwrap();
+    let loaded = load_coo_from_matrix_market_file::<f64, _>(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), coo);
+
+    let missing = load_coo_from_matrix_market_file::<f64, _>(path);
+    assert_eq!(missing.unwrap_err().kind(), &MatrixMarketErrorKind::IoError);
+}
+
+proptest! {
+    #[test]
+    fn matrix_market_roundtrip(csr in csr_strategy()) {
+        let csr = csr.map_values(|v| f64::from(*v) / 3.0);
+        let coo = CooMatrix::from(&csr);
+        let data = save_coo_to_matrix_market_str(&coo);
+        let loaded = load_coo_from_matrix_market_str::<f64>(&data).unwrap();
+        prop_assert_eq!(&loaded, &coo);
+        prop_assert_eq!(CsrMatrix::from(&loaded), csr);
+    }
+}
@@ -3,6 +3,9 @@ mod convert_serial;
 mod coo;
 mod csc;
 mod csr;
+mod lanczos;
+#[cfg(feature = "io")]
+mod matrix_market;
 mod ops;
 mod pattern;
 mod proptest;
@@ -4,9 +4,12 @@ use crate::common::{
 };
 use nalgebra_sparse::csc::CscMatrix;
 use nalgebra_sparse::csr::CsrMatrix;
+#[cfg(feature = "rayon")]
+use nalgebra_sparse::ops::parallel;
 use nalgebra_sparse::ops::serial::{
-    spadd_csc_prealloc, spadd_csr_prealloc, spadd_pattern, spmm_csc_dense, spmm_csc_prealloc,
-    spmm_csr_dense, spmm_csr_pattern, spmm_csr_prealloc, spsolve_csc_lower_triangular,
+    spadd_csc_prealloc, spadd_csr_prealloc, spadd_pattern, spmm_csc_dense, spmm_csc_numeric,
+    spmm_csc_pattern, spmm_csc_prealloc, spmm_csr_dense, spmm_csr_numeric, spmm_csr_pattern,
+    spmm_csr_prealloc, spsolve_csc_lower_triangular,
 };
 use nalgebra_sparse::ops::Op;
 use nalgebra_sparse::pattern::SparsityPattern;
@@ -350,6 +353,33 @@ fn dense_gemm<'a>(
     }
 }
 
+#[test]
+fn spmm_csr_numeric_reuses_pattern() {
+    let a = CsrMatrix::from(&DMatrix::from_row_slice(2, 3, &[1, 0, 2, 0, 3, 0]));
+    let b = CsrMatrix::from(&DMatrix::from_row_slice(3, 2, &[0, 4, 5, 0, 6, 0]));
+    let pattern = spmm_csr_pattern(a.pattern(), b.pattern());
+
+    let c = spmm_csr_numeric(Op::NoOp(&a), Op::NoOp(&b), pattern.clone()).unwrap();
+    assert_eq!(c, &a * &b);
+
+    // Only the values change, so the pattern can be reused
+    let a2 = CsrMatrix::try_from_pattern_and_values(a.pattern().clone(), vec![-1, 7, 2]).unwrap();
+    let c2 = spmm_csr_numeric(Op::NoOp(&a2), Op::NoOp(&b), pattern.clone()).unwrap();
+    assert_eq!(c2, &a2 * &b);
+    assert_eq!(c2.pattern(), &pattern);
+
+    let c_t = spmm_csr_numeric(Op::Transpose(&b), Op::Transpose(&a), pattern.transpose());
+    assert_eq!(c_t.unwrap(), (&a * &b).transpose());
+}
+
+#[test]
+fn spmm_csr_numeric_fails_if_pattern_is_too_small() {
+    let a = CsrMatrix::from(&DMatrix::from_row_slice(2, 2, &[1, 2, 0, 3]));
+    let pattern =
+        SparsityPattern::try_from_offsets_and_indices(2, 2, vec![0, 1, 2], vec![0, 1]).unwrap();
+    assert!(spmm_csr_numeric(Op::NoOp(&a), Op::NoOp(&a), pattern).is_err());
+}
+
 proptest! {
     #[test]
     fn spmm_csr_dense_agrees_with_dense_result(
@@ -488,6 +518,38 @@ proptest! {
         prop_assert_eq!(c_ref_ref.pattern(), &c_pattern);
     }
 
+    #[test]
+    fn csr_add_assign_csr(
+        // a and b have the same dimensions
+        (a, b)
+        in csr_strategy()
+            .prop_flat_map(|a| {
+                let b = csr(PROPTEST_I32_VALUE_STRATEGY, a.nrows(), a.ncols(), PROPTEST_MAX_NNZ);
+                (Just(a), b)
+            }))
+    {
+        // See comments in csr_add_csr for rationale for checking the pattern this way
+        let c_dense = DMatrix::from(&a) + DMatrix::from(&b);
+        let c_dense_pattern = dense_csr_pattern(a.pattern()) + dense_csr_pattern(b.pattern());
+        let c_pattern = CsrMatrix::from(&c_dense_pattern).pattern().clone();
+
+        let mut c_ref = a.clone();
+        c_ref += &b;
+        prop_assert_eq!(&DMatrix::from(&c_ref), &c_dense);
+        prop_assert_eq!(c_ref.pattern(), &c_pattern);
+        prop_assert_eq!(&c_ref, &(&a + &b));
+
+        let mut c_owned = a.clone();
+        c_owned += b.clone();
+        prop_assert_eq!(&c_owned, &c_ref);
+
+        // Adding a matrix with the same pattern keeps the pattern.
+        let mut c_same = a.clone();
+        c_same += &a;
+        prop_assert_eq!(c_same.pattern(), a.pattern());
+        prop_assert_eq!(DMatrix::from(&c_same), DMatrix::from(&a) * 2);
+    }
+
     #[test]
     fn csr_sub_csr(
         // a and b have the same dimensions
@@ -566,6 +628,73 @@ proptest! {
         prop_assert_eq!(&DMatrix::from(&c_sparse), &c_dense);
     }
 
+    #[test]
+    fn spmm_csr_numeric_test(SpmmCsrArgs { c, a, b, .. } in spmm_csr_prealloc_args_strategy()) {
+        // The pattern of `c` is the pattern of op(A) * op(B)
+        let c_sparse = spmm_csr_numeric(a.as_ref(), b.as_ref(), c.pattern().clone()).unwrap();
+
+        let op_a_dense = match a {
+            Op::NoOp(ref a) => DMatrix::from(a),
+            Op::Transpose(ref a) => DMatrix::from(a).transpose(),
+        };
+        let op_b_dense = match b {
+            Op::NoOp(ref b) => DMatrix::from(b),
+            Op::Transpose(ref b) => DMatrix::from(b).transpose(),
+        };
+
+        prop_assert_eq!(c_sparse.pattern(), c.pattern());
+        prop_assert_eq!(DMatrix::from(&c_sparse), op_a_dense * op_b_dense);
+    }
+
+    #[test]
+    fn spmm_csc_numeric_test(SpmmCscArgs { c, a, b, .. } in spmm_csc_prealloc_args_strategy()) {
+        let c_sparse = spmm_csc_numeric(a.as_ref(), b.as_ref(), c.pattern().clone()).unwrap();
+
+        let op_a_dense = match a {
+            Op::NoOp(ref a) => DMatrix::from(a),
+            Op::Transpose(ref a) => DMatrix::from(a).transpose(),
+        };
+        let op_b_dense = match b {
+            Op::NoOp(ref b) => DMatrix::from(b),
+            Op::Transpose(ref b) => DMatrix::from(b).transpose(),
+        };
+
+        prop_assert_eq!(c_sparse.pattern(), c.pattern());
+        prop_assert_eq!(DMatrix::from(&c_sparse), op_a_dense * op_b_dense);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_spmm_pattern_agrees_with_serial((a, b) in spmm_csr_pattern_strategy()) {
+        prop_assert_eq!(parallel::spmm_csr_pattern(&a, &b), spmm_csr_pattern(&a, &b));
+        // Interpreted as CSC patterns, (b, a) are compatible for multiplication
+        prop_assert_eq!(parallel::spmm_csc_pattern(&b, &a), spmm_csc_pattern(&b, &a));
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_spmm_csr_agrees_with_serial(SpmmCsrArgs { c, beta, alpha, a, b }
+        in spmm_csr_prealloc_args_strategy()
+    ) {
+        let mut c_serial = c.clone();
+        spmm_csr_prealloc(beta, &mut c_serial, alpha, a.as_ref(), b.as_ref()).unwrap();
+        let mut c_parallel = c.clone();
+        parallel::spmm_csr_prealloc(beta, &mut c_parallel, alpha, a.as_ref(), b.as_ref()).unwrap();
+        prop_assert_eq!(&c_parallel, &c_serial);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn parallel_spmm_csc_agrees_with_serial(SpmmCscArgs { c, beta, alpha, a, b }
+        in spmm_csc_prealloc_args_strategy()
+    ) {
+        let mut c_serial = c.clone();
+        spmm_csc_prealloc(beta, &mut c_serial, alpha, a.as_ref(), b.as_ref()).unwrap();
+        let mut c_parallel = c.clone();
+        parallel::spmm_csc_prealloc(beta, &mut c_parallel, alpha, a.as_ref(), b.as_ref()).unwrap();
+        prop_assert_eq!(&c_parallel, &c_serial);
+    }
+
     #[test]
     fn spmm_csr_prealloc_panics_on_dim_mismatch(
         (alpha, beta, c, a, b)
@@ -941,6 +1070,38 @@ proptest! {
         prop_assert_eq!(c_ref_ref.pattern(), &c_pattern);
     }
 
+    #[test]
+    fn csc_add_assign_csc(
+        // a and b have the same dimensions
+        (a, b)
+        in csc_strategy()
+            .prop_flat_map(|a| {
+                let b = csc(PROPTEST_I32_VALUE_STRATEGY, a.nrows(), a.ncols(), PROPTEST_MAX_NNZ);
+                (Just(a), b)
+            }))
+    {
+        // See comments in csc_add_csc for rationale for checking the pattern this way
+        let c_dense = DMatrix::from(&a) + DMatrix::from(&b);
+        let c_dense_pattern = dense_csc_pattern(a.pattern()) + dense_csc_pattern(b.pattern());
+        let c_pattern = CscMatrix::from(&c_dense_pattern).pattern().clone();
+
+        let mut c_ref = a.clone();
+        c_ref += &b;
+        prop_assert_eq!(&DMatrix::from(&c_ref), &c_dense);
+        prop_assert_eq!(c_ref.pattern(), &c_pattern);
+        prop_assert_eq!(&c_ref, &(&a + &b));
+
+        let mut c_owned = a.clone();
+        c_owned += b.clone();
+        prop_assert_eq!(&c_owned, &c_ref);
+
+        // Adding a matrix with the same pattern keeps the pattern.
+        let mut c_same = a.clone();
+        c_same += &a;
+        prop_assert_eq!(c_same.pattern(), a.pattern());
+        prop_assert_eq!(DMatrix::from(&c_same), DMatrix::from(&a) * 2);
+    }
+
     #[test]
     fn csc_sub_csc(
         // a and b have the same dimensions
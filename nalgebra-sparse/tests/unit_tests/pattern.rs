@@ -152,3 +152,121 @@ fn sparsity_pattern_try_from_invalid_data() {
         assert_eq!(pattern, Err(SparsityPatternFormatError::DuplicateEntry));
     }
 }
+
+#[test]
+fn sparsity_pattern_lane_nnz() {
+    let offsets = vec![0, 2, 2, 5];
+    let indices = vec![0, 5, 1, 2, 3];
+    let pattern = SparsityPattern::try_from_offsets_and_indices(3, 6, offsets, indices).unwrap();
+    assert_eq!(pattern.lane_nnz_iter().collect::<Vec<_>>(), vec![2, 0, 3]);
+    assert_eq!(pattern.lane_nnz_iter().len(), 3);
+    assert_eq!(pattern.max_lane_nnz(), 3);
+    assert_eq!(pattern.mean_lane_nnz(), 5.0 / 3.0);
+
+    let pattern = SparsityPattern::zeros(0, 6);
+    assert_eq!(pattern.lane_nnz_iter().count(), 0);
+    assert_eq!(pattern.max_lane_nnz(), 0);
+    assert_eq!(pattern.mean_lane_nnz(), 0.0);
+}
+
+/// The bandwidth of `pattern` after applying the symmetric permutation `perm`.
+fn permuted_bandwidth(pattern: &SparsityPattern, perm: &[usize]) -> usize {
+    let mut inverse = vec![0; perm.len()];
+    for (new, &old) in perm.iter().enumerate() {
+        inverse[old] = new;
+    }
+
+    pattern
+        .entries()
+        .map(|(i, j)| (inverse[i] as isize - inverse[j] as isize).unsigned_abs())
+        .max()
+        .unwrap_or(0)
+}
+
+fn is_permutation(perm: &[usize]) -> bool {
+    let mut sorted = perm.to_vec();
+    sorted.sort_unstable();
+    sorted.into_iter().eq(0..perm.len())
+}
+
+#[test]
+fn sparsity_pattern_reverse_cuthill_mckee_ordering() {
+    // Path 0 - 3 - 1 - 2: starts at the lowest-index endpoint of minimum degree (2).
+    let offsets = vec![0, 1, 3, 4, 6];
+    let indices = vec![3, 2, 3, 1, 0, 1];
+    let pattern = SparsityPattern::try_from_offsets_and_indices(4, 4, offsets, indices).unwrap();
+    assert_eq!(pattern.reverse_cuthill_mckee(), vec![2, 1, 3, 0]);
+    assert_eq!(permuted_bandwidth(&pattern, &[2, 1, 3, 0]), 1);
+
+    // Star centered at 0, plus the isolated lane 4 and diagonal entries which are ignored.
+    // The isolated lane has the smallest degree so it is visited first, then the star is
+    // traversed from 1 and the remaining leaves, all of degree 1, are visited by index.
+    let offsets = vec![0, 4, 6, 7, 8, 9];
+    let indices = vec![0, 1, 2, 3, 0, 1, 0, 0, 4];
+    let pattern = SparsityPattern::try_from_offsets_and_indices(5, 5, offsets, indices).unwrap();
+    assert_eq!(pattern.reverse_cuthill_mckee(), vec![3, 2, 0, 1, 4]);
+
+    // Unsymmetric patterns are symmetrized: 0 -> 2 and 1 -> 2 form the path 0 - 2 - 1.
+    let offsets = vec![0, 1, 2, 2];
+    let indices = vec![2, 2];
+    let pattern = SparsityPattern::try_from_offsets_and_indices(3, 3, offsets, indices).unwrap();
+    assert_eq!(pattern.reverse_cuthill_mckee(), vec![1, 2, 0]);
+
+    assert!(SparsityPattern::zeros(0, 0)
+        .reverse_cuthill_mckee()
+        .is_empty());
+    assert_eq!(
+        SparsityPattern::zeros(3, 3).reverse_cuthill_mckee(),
+        vec![2, 1, 0]
+    );
+}
+
+#[test]
+fn sparsity_pattern_reverse_cuthill_mckee_reduces_bandwidth() {
+    // The 5-point stencil on a 6x6 grid, with its nodes numbered in a scrambled order.
+    let n = 6;
+    let scramble = |i: usize| (i * 11) % (n * n);
+    let mut lanes = vec![Vec::new(); n * n];
+    for x in 0..n {
+        for y in 0..n {
+            let i = scramble(x * n + y);
+            lanes[i].push(i);
+            if x + 1 < n {
+                let j = scramble((x + 1) * n + y);
+                lanes[i].push(j);
+                lanes[j].push(i);
+            }
+            if y + 1 < n {
+                let j = scramble(x * n + y + 1);
+                lanes[i].push(j);
+                lanes[j].push(i);
+            }
+        }
+    }
+
+    let mut offsets = vec![0];
+    let mut indices = Vec::new();
+    for mut lane in lanes {
+        lane.sort_unstable();
+        indices.extend(lane);
+        offsets.push(indices.len());
+    }
+    let pattern =
+        SparsityPattern::try_from_offsets_and_indices(n * n, n * n, offsets, indices).unwrap();
+
+    let identity: Vec<_> = (0..n * n).collect();
+    let perm = pattern.reverse_cuthill_mckee();
+    assert!(is_permutation(&perm));
+
+    let original = permuted_bandwidth(&pattern, &identity);
+    let reordered = permuted_bandwidth(&pattern, &perm);
+    assert!(reordered <= original);
+    // A level-by-level traversal of the grid needs at most two diagonals per level.
+    assert!(reordered <= 2 * n, "bandwidth {} is too large", reordered);
+}
+
+#[test]
+#[should_panic]
+fn sparsity_pattern_reverse_cuthill_mckee_non_square() {
+    let _ = SparsityPattern::zeros(2, 3).reverse_cuthill_mckee();
+}
@@ -1243,6 +1243,109 @@ where
     {
         self.xxgerx(alpha, x, y, beta, SimdComplexField::simd_conjugate)
     }
+
+    /// Computes `self = alpha * x * x.transpose() + self`, where `self` is a **symmetric** matrix.
+    ///
+    /// This is the symmetric rank-1 update (BLAS `syr`). Only the lower-triangular (including the
+    /// diagonal) part of `self` is read/written, which takes about half the operations of
+    /// `.ger(alpha, x, x, 1.0)`. Use `.fill_upper_triangle_with_lower_triangle()` afterwards if the
+    /// upper-triangular part is needed too.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Vector2};
+    /// let mut mat = Matrix2::identity();
+    /// let vec = Vector2::new(1.0, 2.0);
+    /// let expected = vec * vec.transpose() * 10.0 + mat;
+    /// mat.m12 = 99999.99999; // This component is on the upper-triangular part and will not be read/written.
+    ///
+    /// mat.syr(10.0, &vec);
+    /// assert_eq!(mat.lower_triangle(), expected.lower_triangle());
+    /// assert_eq!(mat.m12, 99999.99999); // This was untouched.
+    ///
+    /// mat.fill_upper_triangle_with_lower_triangle();
+    /// assert_eq!(mat, expected);
+    /// ```
+    #[inline]
+    pub fn syr<D2: Dim, SB>(&mut self, alpha: T, x: &Vector<T, D2, SB>)
+    where
+        T: One,
+        SB: Storage<T, D2>,
+        ShapeConstraint: DimEq<R1, D2> + DimEq<C1, D2>,
+    {
+        self.xxgerx(alpha, x, x, T::one(), |e| e)
+    }
+
+    /// Computes `self = alpha * x * x.transpose() + beta * self`, where `self` is a **symmetric**
+    /// matrix.
+    ///
+    /// This is the symmetric rank-k update (BLAS `syrk`). If `beta` is zero, `self` is never read.
+    /// Only the lower-triangular (including the diagonal) part of `self` is read/written, which
+    /// takes about half the operations of `.gemm(alpha, x, &x.transpose(), beta)`. Use
+    /// `.fill_upper_triangle_with_lower_triangle()` afterwards if the upper-triangular part is
+    /// needed too.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// # use nalgebra::{Matrix2, Matrix2x3};
+    /// let mut mat = Matrix2::identity();
+    /// let x = Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                        4.0, 5.0, 6.0);
+    /// let expected = x * x.transpose() * 10.0 + mat * 5.0;
+    /// mat.m12 = 99999.99999; // This component is on the upper-triangular part and will not be read/written.
+    ///
+    /// mat.syrk(10.0, &x, 5.0);
+    /// assert_eq!(mat.lower_triangle(), expected.lower_triangle());
+    /// assert_eq!(mat.m12, 99999.99999); // This was untouched.
+    ///
+    /// mat.fill_upper_triangle_with_lower_triangle();
+    /// assert_eq!(mat, expected);
+    /// ```
+    pub fn syrk<R2: Dim, C2: Dim, SB>(&mut self, alpha: T, x: &Matrix<T, R2, C2, SB>, beta: T)
+    where
+        T: One,
+        SB: Storage<T, R2, C2>,
+        ShapeConstraint: DimEq<R1, R2> + DimEq<C1, R2>,
+    {
+        let dim1 = self.nrows();
+        let (dim2, k) = x.shape();
+
+        assert!(
+            self.is_square(),
+            "Symmetric rank-k update: the input matrix must be square."
+        );
+        assert!(dim1 == dim2, "syrk: dimensions mismatch.");
+
+        for j in 0..dim1 {
+            let subdim = Dynamic::new(dim1 - j);
+            // TODO: avoid bound checks.
+            let mut col = self.generic_slice_mut((j, j), (subdim, Const::<1>));
+
+            if k == 0 {
+                if beta.is_zero() {
+                    col.fill(T::zero());
+                } else {
+                    col *= beta.inlined_clone();
+                }
+            }
+
+            for l in 0..k {
+                let val = unsafe { x.get_unchecked((j, l)).inlined_clone() };
+                let b = if l == 0 {
+                    beta.inlined_clone()
+                } else {
+                    T::one()
+                };
+                col.axpy(
+                    alpha.inlined_clone() * val,
+                    &x.generic_slice((j, l), (subdim, Const::<1>)),
+                    b,
+                );
+            }
+        }
+    }
 }
 
 impl<T, D1: Dim, S: StorageMut<T, D1, D1>> SquareMatrix<T, D1, S>
@@ -657,6 +657,22 @@ impl<T: Scalar, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     }
 
     /// Transposes `self` and store the result into `out`.
+    ///
+    /// The matrix `self` does not have to be square: `out` only needs to have the transposed
+    /// shape. This avoids allocating a new matrix when the same output buffer can be reused.
+    ///
+    /// # Panics
+    /// Panics if the shape of `out` is not the transposed shape of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2x3};
+    /// let m = Matrix2x3::new(1, 2, 3,
+    ///                        4, 5, 6);
+    /// let mut out = DMatrix::zeros(3, 2);
+    /// m.transpose_to(&mut out);
+    /// assert_eq!(out, DMatrix::from_row_slice(3, 2, &[1, 4, 2, 5, 3, 6]));
+    /// ```
     #[inline]
     pub fn transpose_to<R2, C2, SB>(&self, out: &mut Matrix<T, R2, C2, SB>)
     where
@@ -1313,6 +1329,21 @@ impl<T: Scalar, D: Dim, S: StorageMut<T, D, D>> Matrix<T, D, D, S> {
 
 impl<T: SimdComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     /// Takes the adjoint (aka. conjugate-transpose) of `self` and store the result into `out`.
+    ///
+    /// The matrix `self` does not have to be square: `out` only needs to have the transposed
+    /// shape. This avoids allocating a new matrix when the same output buffer can be reused.
+    ///
+    /// # Panics
+    /// Panics if the shape of `out` is not the transposed shape of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Complex, Matrix1x2, Matrix2x1};
+    /// let m = Matrix1x2::new(Complex::new(1.0, 2.0), Complex::new(3.0, -4.0));
+    /// let mut out = Matrix2x1::zeros();
+    /// m.adjoint_to(&mut out);
+    /// assert_eq!(out, Matrix2x1::new(Complex::new(1.0, -2.0), Complex::new(3.0, 4.0)));
+    /// ```
     #[inline]
     pub fn adjoint_to<R2, C2, SB>(&self, out: &mut Matrix<T, R2, C2, SB>)
     where
@@ -126,6 +126,38 @@ impl<T: ComplexField, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
     {
         (self.ad_mul(self)).is_identity(eps)
     }
+
+    /// Checks that this matrix is square and equal to its adjoint, within a relative error
+    /// of `eps`. Entries close to zero are instead compared with an absolute tolerance of `eps`.
+    ///
+    /// For real matrices, this checks that the matrix is symmetric; for complex matrices, that it
+    /// is hermitian.
+    #[inline]
+    #[must_use]
+    pub fn is_symmetric(&self, eps: T::Epsilon) -> bool
+    where
+        T: RelativeEq,
+        T::Epsilon: Copy,
+    {
+        if !self.is_square() {
+            return false;
+        }
+
+        for j in 0..self.ncols() {
+            for i in j..self.nrows() {
+                if !relative_eq!(
+                    self[(i, j)],
+                    self[(j, i)].conjugate(),
+                    epsilon = eps,
+                    max_relative = eps
+                ) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
 }
 
 impl<T: RealField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
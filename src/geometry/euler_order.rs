@@ -0,0 +1,73 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+/// The sequence of elementary rotations described by a set of Euler angles.
+///
+/// The variant name lists the axes in the order their rotation matrices are multiplied: the
+/// angles `(a, b, c)` with `EulerOrder::ZYX` describe the rotation `Rz(a) * Ry(b) * Rx(c)`.
+/// This is the same as rotating by `a` about `Z`, then by `b` about the rotated `Y` axis,
+/// then by `c` about the twice-rotated `X` axis (intrinsic rotations), or equivalently as
+/// rotating by `c` about the fixed `X` axis, then by `b` about the fixed `Y` axis, then by `a`
+/// about the fixed `Z` axis (extrinsic rotations).
+///
+/// The first six variants are Tait–Bryan angles (three distinct axes), and the last six are
+/// proper Euler angles (the first and last axes are the same).
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    /// `Rx(a) * Ry(b) * Rz(c)`.
+    XYZ,
+    /// `Rx(a) * Rz(b) * Ry(c)`.
+    XZY,
+    /// `Ry(a) * Rx(b) * Rz(c)`.
+    YXZ,
+    /// `Ry(a) * Rz(b) * Rx(c)`.
+    YZX,
+    /// `Rz(a) * Rx(b) * Ry(c)`.
+    ZXY,
+    /// `Rz(a) * Ry(b) * Rx(c)`, i.e., (yaw, pitch, roll) as used by
+    /// [`Rotation3::from_euler_angles`](crate::Rotation3::from_euler_angles).
+    ZYX,
+    /// `Rx(a) * Ry(b) * Rx(c)`.
+    XYX,
+    /// `Rx(a) * Rz(b) * Rx(c)`.
+    XZX,
+    /// `Ry(a) * Rx(b) * Ry(c)`.
+    YXY,
+    /// `Ry(a) * Rz(b) * Ry(c)`.
+    YZY,
+    /// `Rz(a) * Rx(b) * Rz(c)`.
+    ZXZ,
+    /// `Rz(a) * Ry(b) * Rz(c)`.
+    ZYZ,
+}
+
+impl EulerOrder {
+    /// The indices of the three rotation axes, in order.
+    #[inline]
+    #[must_use]
+    pub fn axes(self) -> [usize; 3] {
+        match self {
+            EulerOrder::XYZ => [0, 1, 2],
+            EulerOrder::XZY => [0, 2, 1],
+            EulerOrder::YXZ => [1, 0, 2],
+            EulerOrder::YZX => [1, 2, 0],
+            EulerOrder::ZXY => [2, 0, 1],
+            EulerOrder::ZYX => [2, 1, 0],
+            EulerOrder::XYX => [0, 1, 0],
+            EulerOrder::XZX => [0, 2, 0],
+            EulerOrder::YXY => [1, 0, 1],
+            EulerOrder::YZY => [1, 2, 1],
+            EulerOrder::ZXZ => [2, 0, 2],
+            EulerOrder::ZYZ => [2, 1, 2],
+        }
+    }
+
+    /// Whether the first and last rotation axes are the same (proper Euler angles).
+    #[inline]
+    #[must_use]
+    pub fn is_proper(self) -> bool {
+        let axes = self.axes();
+        axes[0] == axes[2]
+    }
+}
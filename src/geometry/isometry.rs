@@ -14,7 +14,7 @@ use simba::scalar::{RealField, SubsetOf};
 use simba::simd::SimdRealField;
 
 use crate::base::allocator::Allocator;
-use crate::base::dimension::{DimNameAdd, DimNameSum, U1};
+use crate::base::dimension::{DimMin, DimNameAdd, DimNameSum, U1};
 use crate::base::storage::Owned;
 use crate::base::{Const, DefaultAllocator, OMatrix, SVector, Scalar, Unit};
 use crate::geometry::{AbstractRotation, Point, Translation};
@@ -556,6 +556,67 @@ impl<T: SimdRealField, R, const D: usize> Isometry<T, R, D> {
     }
 }
 
+/// # Conversion from a matrix
+impl<T: RealField, R, const D: usize> Isometry<T, R, D>
+where
+    R: AbstractRotation<T, D>,
+{
+    /// Extracts the isometry represented by the homogeneous transformation matrix `m`.
+    ///
+    /// Returns `None` unless `m` is a rigid-body transformation within the tolerance `eps`, i.e.,
+    /// unless its bottom row is `[0, ..., 0, 1]` up to an absolute error of `eps`, and its
+    /// upper-left `D × D` block is orthogonal, with a relative error of `eps`, and has a positive
+    /// determinant. In particular, `None` is returned if `m` encodes a scaling, a shear, a
+    /// reflection, or a projection. The rotation is extracted from the upper-left block, and the
+    /// translation from the last column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f64;
+    /// # use nalgebra::{Isometry3, Matrix4, Vector3};
+    /// let iso = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::y() * f64::consts::FRAC_PI_3);
+    /// let m = iso.to_homogeneous();
+    /// let extracted = Isometry3::try_from_homogeneous(&m, 1.0e-7).unwrap();
+    /// assert_relative_eq!(extracted, iso, epsilon = 1.0e-7);
+    ///
+    /// // A scaling is not a rigid-body transformation.
+    /// let scaled = m * Matrix4::new_scaling(2.0);
+    /// assert!(Isometry3::try_from_homogeneous(&scaled, 1.0e-7).is_none());
+    /// ```
+    #[must_use]
+    pub fn try_from_homogeneous(
+        m: &OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+        eps: T,
+    ) -> Option<Self>
+    where
+        Const<D>: DimNameAdd<U1> + DimMin<Const<D>, Output = Const<D>>,
+        R: SubsetOf<OMatrix<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>>,
+        DefaultAllocator: Allocator<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>,
+    {
+        let block = m.fixed_slice::<D, D>(0, 0);
+        let bottom = m.fixed_slice::<1, D>(D, 0);
+
+        if bottom.iter().any(|e| e.abs() > eps)
+            || (m[(D, D)] - T::one()).abs() > eps
+            || !block.is_special_orthogonal(eps)
+        {
+            return None;
+        }
+
+        let mut rotation =
+            OMatrix::<T, DimNameSum<Const<D>, U1>, DimNameSum<Const<D>, U1>>::identity();
+        rotation.fixed_slice_mut::<D, D>(0, 0).copy_from(&block);
+        let translation = Translation::from(m.fixed_slice::<D, 1>(0, D).into_owned());
+
+        Some(Self::from_parts(
+            translation,
+            crate::convert_unchecked(rotation),
+        ))
+    }
+}
+
 impl<T: SimdRealField, R, const D: usize> Eq for Isometry<T, R, D> where
     R: AbstractRotation<T, D> + Eq
 {
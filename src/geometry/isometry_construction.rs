@@ -10,15 +10,15 @@ use rand::{
     Rng,
 };
 
-use simba::scalar::SupersetOf;
+use simba::scalar::{RealField, SupersetOf};
 use simba::simd::SimdRealField;
 
-use crate::base::{Vector2, Vector3};
+use crate::base::{Matrix4, Vector2, Vector3};
 
 use crate::{
     AbstractRotation, Isometry, Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3, Point,
-    Point3, Rotation, Rotation3, Scalar, Translation, Translation2, Translation3, UnitComplex,
-    UnitQuaternion,
+    Point3, Quaternion, Rotation, Rotation3, Scalar, Translation, Translation2, Translation3,
+    UnitComplex, UnitQuaternion,
 };
 
 impl<T: SimdRealField, R: AbstractRotation<T, D>, const D: usize> Isometry<T, R, D>
@@ -80,7 +80,7 @@ where
 }
 
 #[cfg(feature = "rand-no-std")]
-impl<T: crate::RealField, R, const D: usize> Distribution<Isometry<T, R, D>> for Standard
+impl<T: RealField, R, const D: usize> Distribution<Isometry<T, R, D>> for Standard
 where
     R: AbstractRotation<T, D>,
     Standard: Distribution<T> + Distribution<R>,
@@ -276,7 +276,8 @@ macro_rules! look_at_isometry_construction_impl(
         ///   * eye - The observer position.
         ///   * target - The target position.
         ///   * up - Vertical direction. The only requirement of this parameter is to not be collinear
-        ///   to `eye - at`. Non-collinearity is not checked.
+        ///   to `eye - at`. This is a precondition that is not checked: collinear vectors result
+        ///   in an isometry filled with NaNs. Use `try_face_towards` if the inputs may be degenerate.
         ///
         /// # Example
         ///
@@ -327,7 +328,8 @@ macro_rules! look_at_isometry_construction_impl(
         ///   * eye - The eye position.
         ///   * target - The target position.
         ///   * up - A vector approximately aligned with required the vertical axis. The only
-        ///   requirement of this parameter is to not be collinear to `target - eye`.
+        ///   requirement of this parameter is to not be collinear to `target - eye`. This is not
+        ///   checked, see `try_look_at_rh` for a checked version.
         ///
         /// # Example
         ///
@@ -370,7 +372,8 @@ macro_rules! look_at_isometry_construction_impl(
         ///   * eye - The eye position.
         ///   * target - The target position.
         ///   * up - A vector approximately aligned with required the vertical axis. The only
-        ///   requirement of this parameter is to not be collinear to `target - eye`.
+        ///   requirement of this parameter is to not be collinear to `target - eye`. This is not
+        ///   checked, see `try_look_at_lh` for a checked version.
         ///
         /// # Example
         ///
@@ -405,6 +408,107 @@ macro_rules! look_at_isometry_construction_impl(
     }
 );
 
+macro_rules! try_look_at_isometry_construction_impl(
+    ($RotId: ident < $($RotParams: ident),*>) => {
+        /// Creates an isometry that corresponds to the local frame of an observer standing at the
+        /// point `eye` and looking toward `target`, or `None` if `up` is collinear to `target - eye`.
+        ///
+        /// This is the checked version of `face_towards`. `None` is returned if the norm of
+        /// `target - eye`, or of the cross product of `up` and `target - eye`, is smaller or equal
+        /// to `eps`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # #[macro_use] extern crate approx;
+        /// # use nalgebra::{Isometry3, IsometryMatrix3, Point3, Vector3};
+        /// let eye = Point3::new(1.0, 2.0, 3.0);
+        /// let target = Point3::new(2.0, 2.0, 3.0);
+        ///
+        /// let iso = Isometry3::try_face_towards(&eye, &target, &Vector3::y(), 1.0e-6).unwrap();
+        /// assert_relative_eq!(iso * Vector3::z(), Vector3::x());
+        /// assert!(Isometry3::try_face_towards(&eye, &target, &Vector3::x(), 1.0e-6).is_none());
+        ///
+        /// let iso = IsometryMatrix3::try_face_towards(&eye, &target, &Vector3::y(), 1.0e-6).unwrap();
+        /// assert_relative_eq!(iso * Vector3::z(), Vector3::x());
+        /// assert!(IsometryMatrix3::try_face_towards(&eye, &eye, &Vector3::y(), 1.0e-6).is_none());
+        /// ```
+        #[inline]
+        pub fn try_face_towards(eye:    &Point3<T>,
+                                target: &Point3<T>,
+                                up:     &Vector3<T>,
+                                eps:    T)
+                                -> Option<Self> {
+            let rotation = $RotId::try_face_towards(&(target - eye), up, eps)?;
+
+            Some(Self::from_parts(Translation::from(eye.coords.clone()), rotation))
+        }
+
+        /// Builds a right-handed look-at view matrix, or `None` if `up` is collinear to
+        /// `target - eye` or if `eye` and `target` coincide.
+        ///
+        /// This is the same as `try_look_at_rh` with a tolerance of `sqrt(ε) * ‖up‖`, where `ε` is
+        /// the machine epsilon, so that callers can fall back to a default orientation without
+        /// choosing a tolerance themselves.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # #[macro_use] extern crate approx;
+        /// # use nalgebra::{Isometry3, Point3, Vector3};
+        /// let eye = Point3::new(1.0, 2.0, 3.0);
+        /// let target = Point3::new(1.0, 5.0, 3.0);
+        ///
+        /// assert!(Isometry3::try_look_at(&eye, &target, &Vector3::y()).is_none());
+        ///
+        /// let view = Isometry3::try_look_at(&eye, &target, &Vector3::z()).unwrap();
+        /// assert_relative_eq!(view, Isometry3::look_at_rh(&eye, &target, &Vector3::z()));
+        /// ```
+        #[inline]
+        pub fn try_look_at(eye:    &Point3<T>,
+                           target: &Point3<T>,
+                           up:     &Vector3<T>)
+                           -> Option<Self> {
+            let eps = up.norm() * T::default_epsilon().sqrt();
+            Self::try_look_at_rh(eye, target, up, eps)
+        }
+
+        /// Builds a right-handed look-at view matrix, or `None` if `up` is collinear to
+        /// `target - eye`.
+        ///
+        /// This is the checked version of `look_at_rh`. See `try_face_towards` for the meaning of
+        /// `eps`.
+        #[inline]
+        pub fn try_look_at_rh(eye:    &Point3<T>,
+                              target: &Point3<T>,
+                              up:     &Vector3<T>,
+                              eps:    T)
+                              -> Option<Self> {
+            let rotation = $RotId::try_look_at_rh(&(target - eye), up, eps)?;
+            let trans    = &rotation * (-eye);
+
+            Some(Self::from_parts(Translation::from(trans.coords), rotation))
+        }
+
+        /// Builds a left-handed look-at view matrix, or `None` if `up` is collinear to
+        /// `target - eye`.
+        ///
+        /// This is the checked version of `look_at_lh`. See `try_face_towards` for the meaning of
+        /// `eps`.
+        #[inline]
+        pub fn try_look_at_lh(eye:    &Point3<T>,
+                              target: &Point3<T>,
+                              up:     &Vector3<T>,
+                              eps:    T)
+                              -> Option<Self> {
+            let rotation = $RotId::try_look_at_lh(&(target - eye), up, eps)?;
+            let trans    = &rotation * (-eye);
+
+            Some(Self::from_parts(Translation::from(trans.coords), rotation))
+        }
+    }
+);
+
 /// # Construction from a 3D vector and/or an axis-angle
 impl<T: SimdRealField> Isometry3<T>
 where
@@ -466,3 +570,83 @@ where
 {
     look_at_isometry_construction_impl!(Rotation3<T>);
 }
+
+/// # Checked construction from a 3D eye position and target point
+impl<T: RealField> Isometry3<T> {
+    try_look_at_isometry_construction_impl!(UnitQuaternion<T>);
+}
+
+impl<T: RealField> IsometryMatrix3<T> {
+    try_look_at_isometry_construction_impl!(Rotation3<T>);
+}
+
+/// # Averaging
+impl<T: RealField> Isometry3<T> {
+    /// Computes the weighted mean of a set of isometries.
+    ///
+    /// The translation parts are averaged linearly. The rotation part is the chordal L2 mean of
+    /// the rotations, i.e., the rotation `R` minimizing `Σ wᵢ ‖R - Rᵢ‖²` (Frobenius norm). It is
+    /// obtained from the eigenvector associated to the largest eigenvalue of `Σ wᵢ qᵢ qᵢᵀ` where
+    /// the `qᵢ` are the unit quaternions of the rotations. This is insensitive to the sign of the
+    /// quaternions, and always yields a valid rotation.
+    ///
+    /// If `weights` is `None`, all the isometries are given the same weight.
+    ///
+    /// Returns `None` if `transforms` is empty, if `weights` does not have the same length as
+    /// `transforms`, if any weight is negative, or if all the weights are zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Isometry3, Vector3};
+    /// let iso1 = Isometry3::new(Vector3::new(0.0, 0.0, 0.0), Vector3::z() * 0.1);
+    /// let iso2 = Isometry3::new(Vector3::new(2.0, 4.0, 6.0), Vector3::z() * 0.3);
+    ///
+    /// let mean = Isometry3::mean(&[iso1, iso2], None).unwrap();
+    /// let expected = Isometry3::new(Vector3::new(1.0, 2.0, 3.0), Vector3::z() * 0.2);
+    /// assert_relative_eq!(mean, expected, epsilon = 1.0e-7);
+    ///
+    /// let mean = Isometry3::mean(&[iso1, iso2], Some(&[3.0, 1.0])).unwrap();
+    /// assert_relative_eq!(mean.translation.vector, Vector3::new(0.5, 1.0, 1.5));
+    /// assert!(Isometry3::mean(&[iso1, iso2], Some(&[1.0])).is_none());
+    /// ```
+    pub fn mean(transforms: &[Self], weights: Option<&[T]>) -> Option<Self> {
+        if transforms.is_empty() || matches!(weights, Some(w) if w.len() != transforms.len()) {
+            return None;
+        }
+
+        let mut total_weight = T::zero();
+        let mut translation = Vector3::zeros();
+        let mut quaternions_matrix = Matrix4::zeros();
+
+        for (i, iso) in transforms.iter().enumerate() {
+            let weight = weights.map_or_else(T::one, |w| w[i]);
+
+            if weight < T::zero() {
+                return None;
+            }
+
+            let q = iso.rotation.as_vector();
+            total_weight += weight;
+            translation += iso.translation.vector * weight;
+            quaternions_matrix += q * q.transpose() * weight;
+        }
+
+        if total_weight.is_zero() {
+            return None;
+        }
+
+        let eigen = quaternions_matrix.symmetric_eigen();
+        let q = eigen
+            .eigenvectors
+            .column(eigen.eigenvalues.imax())
+            .into_owned();
+        let rotation = UnitQuaternion::new_normalize(Quaternion::from(q));
+
+        Some(Self::from_parts(
+            Translation::from(translation / total_weight),
+            rotation,
+        ))
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3, RealField, SimdRealField};
+use crate::{
+    Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3, RealField, Rotation3, SimdRealField,
+    UnitQuaternion, Vector3, Vector6,
+};
 
 /// # Interpolation
 impl<T: SimdRealField> Isometry3<T> {
@@ -8,6 +11,9 @@ impl<T: SimdRealField> Isometry3<T> {
     /// Panics if the angle between both rotations is 180 degrees (in which case the interpolation
     /// is not well-defined). Use `.try_lerp_slerp` instead to avoid the panic.
     ///
+    /// The interpolation parameter `t` is not clamped: `t = 0` yields `self`, `t = 1` yields
+    /// `other`, and values outside of `[0, 1]` extrapolate beyond these endpoints.
+    ///
     /// # Examples:
     ///
     /// ```
@@ -42,6 +48,9 @@ impl<T: SimdRealField> Isometry3<T> {
     /// Retuns `None` if the angle between both rotations is 180 degrees (in which case the interpolation
     /// is not well-defined).
     ///
+    /// The interpolation parameter `t` is not clamped: `t = 0` yields `self`, `t = 1` yields
+    /// `other`, and values outside of `[0, 1]` extrapolate beyond these endpoints.
+    ///
     /// # Examples:
     ///
     /// ```
@@ -78,6 +87,9 @@ impl<T: SimdRealField> IsometryMatrix3<T> {
     /// Panics if the angle between both rotations is 180 degrees (in which case the interpolation
     /// is not well-defined). Use `.try_lerp_slerp` instead to avoid the panic.
     ///
+    /// The interpolation parameter `t` is not clamped: `t = 0` yields `self`, `t = 1` yields
+    /// `other`, and values outside of `[0, 1]` extrapolate beyond these endpoints.
+    ///
     /// # Examples:
     ///
     /// ```
@@ -112,6 +124,9 @@ impl<T: SimdRealField> IsometryMatrix3<T> {
     /// Retuns `None` if the angle between both rotations is 180 degrees (in which case the interpolation
     /// is not well-defined).
     ///
+    /// The interpolation parameter `t` is not clamped: `t = 0` yields `self`, `t = 1` yields
+    /// `other`, and values outside of `[0, 1]` extrapolate beyond these endpoints.
+    ///
     /// # Examples:
     ///
     /// ```
@@ -148,6 +163,9 @@ impl<T: SimdRealField> Isometry2<T> {
     /// Panics if the angle between both rotations is 180 degrees (in which case the interpolation
     /// is not well-defined). Use `.try_lerp_slerp` instead to avoid the panic.
     ///
+    /// The interpolation parameter `t` is not clamped: `t = 0` yields `self`, `t = 1` yields
+    /// `other`, and values outside of `[0, 1]` extrapolate beyond these endpoints.
+    ///
     /// # Examples:
     ///
     /// ```
@@ -185,6 +203,9 @@ impl<T: SimdRealField> IsometryMatrix2<T> {
     /// Panics if the angle between both rotations is 180 degrees (in which case the interpolation
     /// is not well-defined). Use `.try_lerp_slerp` instead to avoid the panic.
     ///
+    /// The interpolation parameter `t` is not clamped: `t = 0` yields `self`, `t = 1` yields
+    /// `other`, and values outside of `[0, 1]` extrapolate beyond these endpoints.
+    ///
     /// # Examples:
     ///
     /// ```
@@ -214,3 +235,125 @@ impl<T: SimdRealField> IsometryMatrix2<T> {
         Self::from_parts(tr.into(), rot)
     }
 }
+
+/// Whether the rotation angle `theta` is small enough for the closed-form screw coefficients to
+/// be replaced by their Taylor expansions.
+///
+/// The closed forms suffer from catastrophic cancellation as `theta` goes to zero, while the
+/// expansions truncated after the `θ⁴` term have an error of order `θ⁶`. Both are balanced for
+/// `θ ≈ ε^(1/8)`, where `ε` is the machine epsilon.
+fn screw_use_series<T: RealField>(theta: T) -> bool {
+    theta * theta <= T::default_epsilon().sqrt().sqrt()
+}
+
+/// The coefficients `(a, b)` such that `V = I + a·[ω]ₓ + b·[ω]ₓ²` is the left Jacobian of the
+/// rotation with scaled axis `ω` and angle `theta = ‖ω‖`.
+fn screw_jacobian_coefficients<T: RealField>(theta: T) -> (T, T) {
+    let theta2 = theta * theta;
+
+    if screw_use_series(theta) {
+        // a = 1/2 - θ²/24 + θ⁴/720 and b = 1/6 - θ²/120 + θ⁴/5040.
+        let a = crate::convert::<f64, T>(0.5)
+            - theta2
+                * (crate::convert::<f64, T>(1.0 / 24.0) - theta2 * crate::convert(1.0 / 720.0));
+        let b = crate::convert::<f64, T>(1.0 / 6.0)
+            - theta2
+                * (crate::convert::<f64, T>(1.0 / 120.0) - theta2 * crate::convert(1.0 / 5040.0));
+        (a, b)
+    } else {
+        let half_sin = (theta * crate::convert(0.5)).sin();
+        // NOTE: 1 - cos(θ) = 2 sin²(θ/2) does not suffer from catastrophic cancellation.
+        let a = half_sin * half_sin * crate::convert(2.0) / theta2;
+        let b = (theta - theta.sin()) / (theta2 * theta);
+        (a, b)
+    }
+}
+
+/// Computes the translation `V·v` of the rigid motion with twist `(v, ω)`.
+fn screw_exp_translation<T: RealField>(v: &Vector3<T>, omega: &Vector3<T>) -> Vector3<T> {
+    let (a, b) = screw_jacobian_coefficients(omega.norm());
+    let omega_v = omega.cross(v);
+    v + omega_v * a + omega.cross(&omega_v) * b
+}
+
+/// Computes the linear part `V⁻¹·t` of the twist of the rigid motion with rotation scaled axis
+/// `ω` and translation `t`.
+fn screw_log_translation<T: RealField>(t: &Vector3<T>, omega: &Vector3<T>) -> Vector3<T> {
+    let theta = omega.norm();
+    let theta2 = theta * theta;
+    let c = if screw_use_series(theta) {
+        // c = 1/12 + θ²/720 + θ⁴/30240.
+        crate::convert::<f64, T>(1.0 / 12.0)
+            + theta2
+                * (crate::convert::<f64, T>(1.0 / 720.0) + theta2 * crate::convert(1.0 / 30240.0))
+    } else {
+        let half_theta = theta * crate::convert(0.5);
+        (T::one() - half_theta / half_theta.tan()) / theta2
+    };
+    let omega_t = omega.cross(t);
+    let half: T = crate::convert(0.5);
+    t - omega_t * half + omega.cross(&omega_t) * c
+}
+
+macro_rules! screw_motion_impl(
+    ($RotId: ident) => {
+        /// The twist coordinates of this isometry, i.e., its logarithm on the rigid motion group.
+        ///
+        /// The returned vector stores the linear part `v` in its first three components, and the
+        /// rotation scaled axis `ω` in its last three components. It is such that
+        /// `Self::exp(self.log()) == self`.
+        ///
+        /// The rotation angle is taken in `[0, π]`. When it is close to zero, the coefficients
+        /// coupling the linear part to the rotation are evaluated with their Taylor expansions, so
+        /// the result degrades gracefully to a pure translation.
+        /// When it is equal to `π`, both rotation directions describe the same isometry and one
+        /// of them is picked arbitrarily.
+        #[inline]
+        #[must_use]
+        pub fn log(&self) -> Vector6<T> {
+            let omega = UnitQuaternion::from(self.rotation.clone()).scaled_axis();
+            let v = screw_log_translation(&self.translation.vector, &omega);
+            Vector6::new(v.x, v.y, v.z, omega.x, omega.y, omega.z)
+        }
+
+        /// Builds the isometry corresponding to the screw motion with twist coordinates `twist`,
+        /// i.e., the exponential of `twist` on the rigid motion group.
+        ///
+        /// The first three components of `twist` are the linear part `v`, and its last three
+        /// components are the rotation scaled axis `ω`. This is the inverse of `.log()`. When
+        /// `‖ω‖` is close to zero, this degrades gracefully to a translation by `v`.
+        ///
+        /// Note that this differs from `Self::new(translation, axisangle)` which does not couple
+        /// the rotation and translation parts.
+        #[inline]
+        pub fn exp(twist: &Vector6<T>) -> Self {
+            let v = twist.fixed_rows::<3>(0).into_owned();
+            let omega = twist.fixed_rows::<3>(3).into_owned();
+            let translation = screw_exp_translation(&v, &omega);
+
+            Self::from_parts(translation.into(), $RotId::from_scaled_axis(omega))
+        }
+
+        /// Raises this isometry to a floating power, following a constant-velocity screw motion.
+        ///
+        /// This computes `Self::exp(&(self.log() * n))`. In particular, `self.powf(0.0)` is the
+        /// identity and `self.powf(1.0)` is `self`. Screw interpolation from `a` to `b` is
+        /// obtained with `a * (a.inverse() * b).powf(t)`.
+        ///
+        /// See `.log()` for the handling of rotation angles close to zero or equal to `π`.
+        #[inline]
+        #[must_use]
+        pub fn powf(&self, n: T) -> Self {
+            Self::exp(&(self.log() * n))
+        }
+    }
+);
+
+/// # Screw motion
+impl<T: RealField> Isometry3<T> {
+    screw_motion_impl!(UnitQuaternion);
+}
+
+impl<T: RealField> IsometryMatrix3<T> {
+    screw_motion_impl!(Rotation3);
+}
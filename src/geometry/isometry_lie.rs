@@ -0,0 +1,67 @@
+use crate::{
+    Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3, Matrix3, Matrix6, RealField, Rotation2,
+    Rotation3,
+};
+
+macro_rules! adjoint_3d_impl(
+    () => {
+        /// The adjoint representation of this isometry, i.e., the matrix mapping twists
+        /// expressed in the local frame of this isometry to twists expressed in its parent frame.
+        ///
+        /// With the twist layout `(v, ω)` of `.log()` and `Self::exp`, this is the block matrix
+        /// `[R, [t]ₓ·R; 0, R]`, where `R` is the rotation matrix, `t` the translation, and `[t]ₓ`
+        /// its cross-product matrix. It is such that `Self::exp(&(self.adjoint() * twist))` is
+        /// equal to `self * Self::exp(twist) * self.inverse()`, and
+        /// `(a * b).adjoint() == a.adjoint() * b.adjoint()`.
+        #[inline]
+        #[must_use]
+        pub fn adjoint(&self) -> Matrix6<T> {
+            let r = Rotation3::from(self.rotation.clone()).into_inner();
+            let tr = self.translation.vector.cross_matrix() * r;
+            let mut res = Matrix6::zeros();
+            res.fixed_slice_mut::<3, 3>(0, 0).copy_from(&r);
+            res.fixed_slice_mut::<3, 3>(0, 3).copy_from(&tr);
+            res.fixed_slice_mut::<3, 3>(3, 3).copy_from(&r);
+            res
+        }
+    }
+);
+
+/// # Adjoint representation
+impl<T: RealField> Isometry3<T> {
+    adjoint_3d_impl!();
+}
+
+impl<T: RealField> IsometryMatrix3<T> {
+    adjoint_3d_impl!();
+}
+
+macro_rules! adjoint_2d_impl(
+    () => {
+        /// The adjoint representation of this isometry, i.e., the matrix mapping twists
+        /// expressed in the local frame of this isometry to twists expressed in its parent frame.
+        ///
+        /// With the twist layout `(vx, vy, ω)`, this is the block matrix `[R, (ty, -tx)ᵀ; 0, 1]`,
+        /// where `R` is the rotation matrix and `t` the translation. It is such that
+        /// `(a * b).adjoint() == a.adjoint() * b.adjoint()`.
+        #[inline]
+        #[must_use]
+        pub fn adjoint(&self) -> Matrix3<T> {
+            let r = Rotation2::from(self.rotation.clone()).into_inner();
+            let t = &self.translation.vector;
+            Matrix3::new(
+                r.m11, r.m12, t.y,
+                r.m21, r.m22, -t.x,
+                T::zero(), T::zero(), T::one(),
+            )
+        }
+    }
+);
+
+impl<T: RealField> Isometry2<T> {
+    adjoint_2d_impl!();
+}
+
+impl<T: RealField> IsometryMatrix2<T> {
+    adjoint_2d_impl!();
+}
@@ -22,6 +22,8 @@ mod rotation_ops;
 mod rotation_simba; // TODO: implement Rotation methods.
 mod rotation_specialization;
 
+mod euler_order;
+
 mod quaternion;
 mod quaternion_construction;
 mod quaternion_conversion;
@@ -53,6 +55,7 @@ mod isometry_alias;
 mod isometry_construction;
 mod isometry_conversion;
 mod isometry_interpolation;
+mod isometry_lie;
 mod isometry_ops;
 mod isometry_simba;
 
@@ -85,6 +88,8 @@ pub use self::point_alias::*;
 pub use self::rotation::*;
 pub use self::rotation_alias::*;
 
+pub use self::euler_order::EulerOrder;
+
 pub use self::quaternion::*;
 
 pub use self::dual_quaternion::*;
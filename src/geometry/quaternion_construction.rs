@@ -568,8 +568,9 @@ where
     /// # Arguments
     ///   * dir - The look direction. It does not need to be normalized.
     ///   * up - The vertical direction. It does not need to be normalized.
-    ///   The only requirement of this parameter is to not be collinear to `dir`. Non-collinearity
-    ///   is not checked.
+    ///   The only requirement of this parameter is to not be collinear to `dir`. This is a
+    ///   precondition that is not checked: collinear vectors result in a quaternion filled with
+    ///   NaNs. Use [`UnitQuaternion::try_face_towards`] if the inputs may be degenerate.
     ///
     /// # Example
     /// ```
@@ -611,6 +612,7 @@ where
     ///   * dir − The view direction. It does not need to be normalized.
     ///   * up - A vector approximately aligned with required the vertical axis. It does not need
     ///   to be normalized. The only requirement of this parameter is to not be collinear to `dir`.
+    ///   This is not checked, see [`UnitQuaternion::try_look_at_rh`] for a checked version.
     ///
     /// # Example
     /// ```
@@ -641,7 +643,8 @@ where
     /// # Arguments
     ///   * dir − The view direction. It does not need to be normalized.
     ///   * up - A vector approximately aligned with required the vertical axis. The only
-    ///   requirement of this parameter is to not be collinear to `dir`.
+    ///   requirement of this parameter is to not be collinear to `dir`. This is not checked,
+    ///   see [`UnitQuaternion::try_look_at_lh`] for a checked version.
     ///
     /// # Example
     /// ```
@@ -841,6 +844,73 @@ where
     }
 }
 
+impl<T: RealField> UnitQuaternion<T> {
+    /// Creates an unit quaternion that corresponds to the local frame of an observer standing at
+    /// the origin and looking toward `dir`, or `None` if `dir` and `up` are collinear.
+    ///
+    /// This is the checked version of [`UnitQuaternion::face_towards`]. `None` is returned if the
+    /// norm of `dir`, or of the cross product of `up` and `dir`, is smaller or equal to `eps`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{UnitQuaternion, Vector3};
+    /// let dir = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// let q = UnitQuaternion::try_face_towards(&dir, &Vector3::y(), 1.0e-6).unwrap();
+    /// assert_relative_eq!(q * Vector3::z(), dir.normalize());
+    /// assert!(UnitQuaternion::try_face_towards(&dir, &-dir, 1.0e-6).is_none());
+    /// ```
+    #[inline]
+    pub fn try_face_towards<SB, SC>(
+        dir: &Vector<T, U3, SB>,
+        up: &Vector<T, U3, SC>,
+        eps: T,
+    ) -> Option<Self>
+    where
+        SB: Storage<T, U3>,
+        SC: Storage<T, U3>,
+    {
+        Rotation3::try_face_towards(dir, up, eps).map(|rot| Self::from_rotation_matrix(&rot))
+    }
+
+    /// Builds a right-handed look-at view matrix without translation, or `None` if `dir` and
+    /// `up` are collinear.
+    ///
+    /// This is the checked version of [`UnitQuaternion::look_at_rh`]. See
+    /// [`UnitQuaternion::try_face_towards`] for the meaning of `eps`.
+    #[inline]
+    pub fn try_look_at_rh<SB, SC>(
+        dir: &Vector<T, U3, SB>,
+        up: &Vector<T, U3, SC>,
+        eps: T,
+    ) -> Option<Self>
+    where
+        SB: Storage<T, U3>,
+        SC: Storage<T, U3>,
+    {
+        Self::try_face_towards(&-dir, up, eps).map(|q| q.inverse())
+    }
+
+    /// Builds a left-handed look-at view matrix without translation, or `None` if `dir` and
+    /// `up` are collinear.
+    ///
+    /// This is the checked version of [`UnitQuaternion::look_at_lh`]. See
+    /// [`UnitQuaternion::try_face_towards`] for the meaning of `eps`.
+    #[inline]
+    pub fn try_look_at_lh<SB, SC>(
+        dir: &Vector<T, U3, SB>,
+        up: &Vector<T, U3, SC>,
+        eps: T,
+    ) -> Option<Self>
+    where
+        SB: Storage<T, U3>,
+        SC: Storage<T, U3>,
+    {
+        Self::try_face_towards(dir, up, eps).map(|q| q.inverse())
+    }
+}
+
 impl<T: SimdRealField> One for UnitQuaternion<T>
 where
     T::Element: SimdRealField,
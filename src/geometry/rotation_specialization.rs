@@ -19,7 +19,7 @@ use crate::base::dimension::{U1, U2, U3};
 use crate::base::storage::Storage;
 use crate::base::{Matrix2, Matrix3, SMatrix, SVector, Unit, Vector, Vector1, Vector2, Vector3};
 
-use crate::geometry::{Rotation2, Rotation3, UnitComplex, UnitQuaternion};
+use crate::geometry::{EulerOrder, Rotation2, Rotation3, UnitComplex, UnitQuaternion};
 
 /*
  *
@@ -440,6 +440,43 @@ where
             cp * cr,
         ))
     }
+
+    /// Creates a new rotation from Euler angles following the given convention.
+    ///
+    /// The angles `(a, b, c)` are the angles of the elementary rotations about the three
+    /// axes of `order`, and the resulting rotation is their product in that order. For
+    /// example, `EulerOrder::ZYX` yields `Rz(a) * Ry(b) * Rx(c)`. See [`EulerOrder`] for the
+    /// interpretation in terms of intrinsic and extrinsic rotations.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{EulerOrder, Rotation3, Vector3};
+    /// let rot = Rotation3::from_euler_angles_ordered(0.1, 0.2, 0.3, EulerOrder::XYZ);
+    /// let expected = Rotation3::from_axis_angle(&Vector3::x_axis(), 0.1)
+    ///     * Rotation3::from_axis_angle(&Vector3::y_axis(), 0.2)
+    ///     * Rotation3::from_axis_angle(&Vector3::z_axis(), 0.3);
+    /// assert_relative_eq!(rot, expected, epsilon = 1.0e-7);
+    ///
+    /// // `from_euler_angles(roll, pitch, yaw)` uses the `ZYX` convention.
+    /// let rot = Rotation3::from_euler_angles_ordered(0.3, 0.2, 0.1, EulerOrder::ZYX);
+    /// assert_relative_eq!(rot, Rotation3::from_euler_angles(0.1, 0.2, 0.3), epsilon = 1.0e-7);
+    /// ```
+    pub fn from_euler_angles_ordered(a: T, b: T, c: T, order: EulerOrder) -> Self {
+        let elementary = |axis: usize, angle: T| {
+            let (s, c) = angle.simd_sin_cos();
+            let (j, k) = ((axis + 1) % 3, (axis + 2) % 3);
+            let mut m = Matrix3::identity();
+            m[(j, j)] = c;
+            m[(j, k)] = -s;
+            m[(k, j)] = s;
+            m[(k, k)] = c;
+            m
+        };
+
+        let [i, j, k] = order.axes();
+        Self::from_matrix_unchecked(elementary(i, a) * elementary(j, b) * elementary(k, c))
+    }
 }
 
 /// # Construction from a 3D eye position and target point
@@ -455,7 +492,9 @@ where
     /// # Arguments
     ///   * dir - The look direction, that is, direction the matrix `z` axis will be aligned with.
     ///   * up - The vertical direction. The only requirement of this parameter is to not be
-    ///   collinear to `dir`. Non-collinearity is not checked.
+    ///   collinear to `dir`. This is a precondition that is not checked: collinear vectors
+    ///   result in a rotation filled with NaNs. Use [`Rotation3::try_face_towards`] if the
+    ///   inputs may be degenerate.
     ///
     /// # Example
     /// ```
@@ -502,7 +541,8 @@ where
     /// # Arguments
     ///   * dir - The direction toward which the camera looks.
     ///   * up - A vector approximately aligned with required the vertical axis. The only
-    ///   requirement of this parameter is to not be collinear to `dir`.
+    ///   requirement of this parameter is to not be collinear to `dir`. This is not checked,
+    ///   see [`Rotation3::try_look_at_rh`] for a checked version.
     ///
     /// # Example
     /// ```
@@ -533,7 +573,8 @@ where
     /// # Arguments
     ///   * dir - The direction toward which the camera looks.
     ///   * up - A vector approximately aligned with required the vertical axis. The only
-    ///   requirement of this parameter is to not be collinear to `dir`.
+    ///   requirement of this parameter is to not be collinear to `dir`. This is not checked,
+    ///   see [`Rotation3::try_look_at_lh`] for a checked version.
     ///
     /// # Example
     /// ```
@@ -556,6 +597,80 @@ where
     }
 }
 
+/// # Checked construction from a 3D eye position and target point
+impl<T: RealField> Rotation3<T> {
+    /// Creates a rotation that corresponds to the local frame of an observer standing at the
+    /// origin and looking toward `dir`, or `None` if `dir` and `up` are collinear.
+    ///
+    /// This is the checked version of [`Rotation3::face_towards`]. `None` is returned if the
+    /// norm of `dir`, or of the cross product of `up` and `dir`, is smaller or equal to `eps`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let dir = Vector3::new(1.0, 2.0, 3.0);
+    ///
+    /// let rot = Rotation3::try_face_towards(&dir, &Vector3::y(), 1.0e-6).unwrap();
+    /// assert_relative_eq!(rot * Vector3::z(), dir.normalize());
+    /// assert!(Rotation3::try_face_towards(&dir, &(dir * 2.0), 1.0e-6).is_none());
+    /// ```
+    #[inline]
+    pub fn try_face_towards<SB, SC>(
+        dir: &Vector<T, U3, SB>,
+        up: &Vector<T, U3, SC>,
+        eps: T,
+    ) -> Option<Self>
+    where
+        SB: Storage<T, U3>,
+        SC: Storage<T, U3>,
+    {
+        let zaxis = dir.try_normalize(eps)?;
+        let xaxis = up.cross(&zaxis).try_normalize(eps)?;
+        let yaxis = zaxis.cross(&xaxis).normalize();
+
+        Some(Self::from_matrix_unchecked(SMatrix::<T, 3, 3>::new(
+            xaxis.x, yaxis.x, zaxis.x, xaxis.y, yaxis.y, zaxis.y, xaxis.z, yaxis.z, zaxis.z,
+        )))
+    }
+
+    /// Builds a right-handed look-at view matrix without translation, or `None` if `dir` and
+    /// `up` are collinear.
+    ///
+    /// This is the checked version of [`Rotation3::look_at_rh`]. See
+    /// [`Rotation3::try_face_towards`] for the meaning of `eps`.
+    #[inline]
+    pub fn try_look_at_rh<SB, SC>(
+        dir: &Vector<T, U3, SB>,
+        up: &Vector<T, U3, SC>,
+        eps: T,
+    ) -> Option<Self>
+    where
+        SB: Storage<T, U3>,
+        SC: Storage<T, U3>,
+    {
+        Self::try_face_towards(&dir.neg(), up, eps).map(|rot| rot.inverse())
+    }
+
+    /// Builds a left-handed look-at view matrix without translation, or `None` if `dir` and
+    /// `up` are collinear.
+    ///
+    /// This is the checked version of [`Rotation3::look_at_lh`]. See
+    /// [`Rotation3::try_face_towards`] for the meaning of `eps`.
+    #[inline]
+    pub fn try_look_at_lh<SB, SC>(
+        dir: &Vector<T, U3, SB>,
+        up: &Vector<T, U3, SC>,
+        eps: T,
+    ) -> Option<Self>
+    where
+        SB: Storage<T, U3>,
+        SC: Storage<T, U3>,
+    {
+        Self::try_face_towards(dir, up, eps).map(|rot| rot.inverse())
+    }
+}
+
 /// # Construction from an existing 3D matrix or rotations
 impl<T: SimdRealField> Rotation3<T>
 where
@@ -763,6 +878,9 @@ where
 impl<T: SimdRealField> Rotation3<T> {
     /// The rotation angle in [0; pi].
     ///
+    /// The angle is computed from both the sine and the cosine encoded in the rotation matrix,
+    /// so it remains accurate near 0 and pi, where `acos((trace - 1) / 2)` loses precision.
+    ///
     /// # Example
     /// ```
     /// # #[macro_use] extern crate approx;
@@ -770,21 +888,32 @@ impl<T: SimdRealField> Rotation3<T> {
     /// let axis = Unit::new_normalize(Vector3::new(1.0, 2.0, 3.0));
     /// let rot = Rotation3::from_axis_angle(&axis, 1.78);
     /// assert_relative_eq!(rot.angle(), 1.78);
+    ///
+    /// let rot = Rotation3::from_axis_angle(&axis, 1.0e-9);
+    /// assert_relative_eq!(rot.angle(), 1.0e-9, max_relative = 1.0e-6);
     /// ```
     #[inline]
     #[must_use]
     pub fn angle(&self) -> T {
-        ((self.matrix()[(0, 0)] + self.matrix()[(1, 1)] + self.matrix()[(2, 2)] - T::one())
-            / crate::convert(2.0))
-        .simd_acos()
+        let m = self.matrix();
+        let skew = self.skew_axis();
+        // `skew` has norm `2 * sin(angle)` and `trace - 1 == 2 * cos(angle)`.
+        let sin2 = (skew.x * skew.x + skew.y * skew.y + skew.z * skew.z).simd_sqrt();
+        let cos2 = m[(0, 0)] + m[(1, 1)] + m[(2, 2)] - T::one();
+
+        sin2.simd_atan2(cos2)
     }
 
     /// The rotation axis. Returns `None` if the rotation angle is zero or PI.
     ///
+    /// For angles close to (but not exactly equal to) pi, the axis is extracted from the
+    /// symmetric part of the rotation matrix, so it remains accurate. Use
+    /// [`Rotation3::to_axis_angle`] to also obtain an axis for rotations by exactly pi.
+    ///
     /// # Example
     /// ```
     /// # #[macro_use] extern crate approx;
-    /// # use nalgebra::{Rotation3, Vector3, Unit};
+    /// # use nalgebra::{Matrix3, Rotation3, Vector3, Unit};
     /// let axis = Unit::new_normalize(Vector3::new(1.0, 2.0, 3.0));
     /// let angle = 1.2;
     /// let rot = Rotation3::from_axis_angle(&axis, angle);
@@ -793,6 +922,10 @@ impl<T: SimdRealField> Rotation3<T> {
     /// // Case with a zero angle.
     /// let rot = Rotation3::from_axis_angle(&axis, 0.0);
     /// assert!(rot.axis().is_none());
+    ///
+    /// // Case with an angle of pi.
+    /// let rot = Rotation3::from_matrix_unchecked(Matrix3::from_diagonal(&Vector3::new(1.0, -1.0, -1.0)));
+    /// assert!(rot.axis().is_none());
     /// ```
     #[inline]
     #[must_use]
@@ -800,13 +933,88 @@ impl<T: SimdRealField> Rotation3<T> {
     where
         T: RealField,
     {
-        let axis = SVector::<T, 3>::new(
-            self.matrix()[(2, 1)] - self.matrix()[(1, 2)],
-            self.matrix()[(0, 2)] - self.matrix()[(2, 0)],
-            self.matrix()[(1, 0)] - self.matrix()[(0, 1)],
-        );
+        if self.skew_axis().norm() <= T::default_epsilon() {
+            None
+        } else {
+            self.stable_axis()
+        }
+    }
 
-        Unit::try_new(axis, T::default_epsilon())
+    /// The rotation axis and angle in [0, pi] of this rotation.
+    ///
+    /// Unlike [`Rotation3::axis_angle`], this never fails: the axis is arbitrary (the `x` axis)
+    /// for a rotation by a zero angle, and either `axis` or `-axis` is returned for a rotation by
+    /// exactly pi. Both the axis and the angle are extracted with numerically stable formulas,
+    /// and remain accurate near 0 and pi. This is the inverse of [`Rotation3::from_axis_angle`].
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f64;
+    /// # use nalgebra::{Rotation3, Vector3, Unit};
+    /// let axis = Unit::new_normalize(Vector3::new(1.0, 2.0, 3.0));
+    /// let rot = Rotation3::from_axis_angle(&axis, f64::consts::PI);
+    /// let (found_axis, found_angle) = rot.to_axis_angle();
+    ///
+    /// assert_relative_eq!(found_angle, f64::consts::PI);
+    /// assert!(relative_eq!(found_axis, axis) || relative_eq!(found_axis, -axis));
+    /// assert_relative_eq!(Rotation3::from_axis_angle(&found_axis, found_angle), rot);
+    ///
+    /// // Case with a zero angle.
+    /// let (found_axis, found_angle) = Rotation3::<f64>::identity().to_axis_angle();
+    /// assert_eq!(found_angle, 0.0);
+    /// assert_eq!(Rotation3::from_axis_angle(&found_axis, found_angle), Rotation3::identity());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn to_axis_angle(&self) -> (Unit<Vector3<T>>, T)
+    where
+        T: RealField,
+    {
+        let axis = self.stable_axis().unwrap_or_else(Vector3::x_axis);
+        (axis, self.angle())
+    }
+
+    /// The rotation axis, or `None` if the rotation angle is zero.
+    ///
+    /// For angles close to pi, the axis is extracted from the symmetric part of the rotation
+    /// matrix instead of its skew-symmetric part, which vanishes at pi.
+    fn stable_axis(&self) -> Option<Unit<Vector3<T>>>
+    where
+        T: RealField,
+    {
+        let m = self.matrix();
+        let skew = self.skew_axis();
+        let cos = (m[(0, 0)] + m[(1, 1)] + m[(2, 2)] - T::one()) * crate::convert(0.5);
+
+        if cos >= T::zero() {
+            return Unit::try_new(skew, T::default_epsilon());
+        }
+
+        // (R + Rᵀ) / 2 - cos(angle) * I == (1 - cos(angle)) * axis * axisᵀ, and its column with
+        // the largest diagonal entry is the best-conditioned multiple of the axis.
+        let sym =
+            (m + m.transpose()) * crate::convert::<_, T>(0.5) - Matrix3::from_diagonal_element(cos);
+        let mut axis = sym.column(sym.diagonal().imax()).into_owned();
+
+        // The sign of the axis is only determined by the (possibly tiny) skew-symmetric part.
+        if axis.dot(&skew) < T::zero() {
+            axis.neg_mut();
+        }
+
+        Some(Unit::new_normalize(axis))
+    }
+
+    /// The vector `2 * sin(angle) * axis` read from the skew-symmetric part of this rotation.
+    #[inline]
+    fn skew_axis(&self) -> Vector3<T> {
+        let m = self.matrix();
+
+        Vector3::new(
+            m[(2, 1)] - m[(1, 2)],
+            m[(0, 2)] - m[(2, 0)],
+            m[(1, 0)] - m[(0, 1)],
+        )
     }
 
     /// The rotation axis multiplied by the rotation angle.
@@ -926,6 +1134,165 @@ impl<T: SimdRealField> Rotation3<T> {
             )
         }
     }
+
+    /// Euler angles `(a, b, c)` of this rotation following the given convention, such that
+    /// `Rotation3::from_euler_angles_ordered(a, b, c, order)` is equal to `self`.
+    ///
+    /// The first and last angles are in `[-pi; pi]`. The middle angle is in `[-pi/2; pi/2]`
+    /// for Tait–Bryan conventions (e.g. `EulerOrder::ZYX`), and in `[0; pi]` for proper Euler
+    /// conventions (e.g. `EulerOrder::ZYZ`).
+    ///
+    /// Gimbal lock
+    /// -----------
+    /// When the middle angle is `±pi/2` (Tait–Bryan) or `0` or `pi` (proper Euler), the first
+    /// and last axes are aligned and only the sum or difference of the first and last angles is
+    /// determined. In that case, the first angle is set to zero and the last angle carries the
+    /// whole rotation about the aligned axes, so the returned angles still reproduce `self`.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use std::f64;
+    /// # use nalgebra::{EulerOrder, Rotation3};
+    /// let rot = Rotation3::from_euler_angles_ordered(0.1, 0.2, 0.3, EulerOrder::ZYZ);
+    /// let (a, b, c) = rot.euler_angles_ordered(EulerOrder::ZYZ);
+    /// assert_relative_eq!(a, 0.1, epsilon = 1.0e-7);
+    /// assert_relative_eq!(b, 0.2, epsilon = 1.0e-7);
+    /// assert_relative_eq!(c, 0.3, epsilon = 1.0e-7);
+    ///
+    /// // Gimbal lock: the first angle is set to zero.
+    /// let rot = Rotation3::from_euler_angles_ordered(0.1, f64::consts::FRAC_PI_2, 0.3, EulerOrder::XYZ);
+    /// let (a, b, c) = rot.euler_angles_ordered(EulerOrder::XYZ);
+    /// assert_eq!(a, 0.0);
+    /// assert_relative_eq!(b, f64::consts::FRAC_PI_2, epsilon = 1.0e-7);
+    /// assert_relative_eq!(c, 0.4, epsilon = 1.0e-7);
+    /// ```
+    #[must_use]
+    pub fn euler_angles_ordered(&self, order: EulerOrder) -> (T, T, T)
+    where
+        T: RealField,
+    {
+        let [i, j, _] = order.axes();
+        let k = 3 - i - j;
+        // The sign of the permutation `(i, j, k)`.
+        let s = if j == (i + 1) % 3 {
+            T::one()
+        } else {
+            -T::one()
+        };
+        let m = self.matrix();
+        let eps = T::default_epsilon();
+
+        let (a, b) = if order.is_proper() {
+            let sin_b = (m[(i, j)] * m[(i, j)] + m[(i, k)] * m[(i, k)]).sqrt();
+            let a = if sin_b > eps {
+                m[(j, i)].atan2(-s * m[(k, i)])
+            } else {
+                T::zero()
+            };
+            (a, sin_b.atan2(m[(i, i)]))
+        } else {
+            let cos_b = (m[(i, i)] * m[(i, i)] + m[(i, j)] * m[(i, j)]).sqrt();
+            let a = if cos_b > eps {
+                (-s * m[(j, k)]).atan2(m[(k, k)])
+            } else {
+                T::zero()
+            };
+            (a, (s * m[(i, k)]).atan2(cos_b))
+        };
+
+        // Row `j` of `R_i(a)ᵀ * self` only depends on the last angle, which is thus recovered
+        // consistently with `a` even when `a` is ill-determined.
+        let (sa, ca) = a.sin_cos();
+        let row_j = |col: usize| ca * m[(j, col)] + s * sa * m[(k, col)];
+        let c = if order.is_proper() {
+            (-s * row_j(k)).atan2(row_j(j))
+        } else {
+            (s * row_j(i)).atan2(row_j(j))
+        };
+
+        (a, b, c)
+    }
+}
+
+/// # Cayley transform
+impl<T: SimdRealField> Rotation3<T> {
+    /// Builds the rotation `(I - S)⁻¹ * (I + S)` obtained by the Cayley transform of the
+    /// skew-symmetric matrix `S`.
+    ///
+    /// If `S` is the cross-product matrix of the vector `c`, this is the rotation about `c` with
+    /// angle `2 * atan(‖c‖)`. It is computed in closed form, without any trigonometric function
+    /// nor matrix inversion, which makes it a cheap parametrization of rotations for
+    /// optimization. Only the skew-symmetric part `(skew - skewᵀ) / 2` of `skew` is read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Rotation3, Vector3};
+    /// let c = Vector3::new(0.1f64, 0.2, 0.3);
+    /// let skew = c.cross_matrix();
+    /// let rot = Rotation3::from_cayley(&skew);
+    ///
+    /// let expected = (Matrix3::identity() - skew).try_inverse().unwrap() * (Matrix3::identity() + skew);
+    /// assert_relative_eq!(*rot.matrix(), expected, epsilon = 1.0e-7);
+    /// assert_relative_eq!(rot.angle(), 2.0 * c.norm().atan(), epsilon = 1.0e-7);
+    /// assert_relative_eq!(rot.to_cayley(), skew, epsilon = 1.0e-7);
+    /// ```
+    #[must_use]
+    pub fn from_cayley(skew: &Matrix3<T>) -> Self {
+        let half: T = crate::convert(0.5);
+        let c = Vector3::new(
+            skew[(2, 1)] - skew[(1, 2)],
+            skew[(0, 2)] - skew[(2, 0)],
+            skew[(1, 0)] - skew[(0, 1)],
+        ) * half;
+        let cx = c.cross_matrix();
+        let factor = (T::one() + T::one()) / (T::one() + c.norm_squared());
+
+        Self::from_matrix_unchecked(Matrix3::identity() + (cx + cx * cx) * factor)
+    }
+
+    /// The skew-symmetric matrix `S = (R - I) * (R + I)⁻¹` such that
+    /// `Rotation3::from_cayley(&S) == self`.
+    ///
+    /// This is the cross-product matrix of `tan(angle / 2) * axis`. The Cayley transform is not
+    /// defined for rotations with an angle of `π`, for which the returned matrix is not finite:
+    /// use [`Rotation3::try_to_cayley`] to detect this case.
+    #[inline]
+    #[must_use]
+    pub fn to_cayley(&self) -> Matrix3<T> {
+        let denom = T::one() + self.matrix().trace();
+        (self.skew_axis() / denom).cross_matrix()
+    }
+
+    /// The skew-symmetric matrix `S` such that `Rotation3::from_cayley(&S) == self`, or `None`
+    /// if the rotation angle is too close to `π`.
+    ///
+    /// `None` is returned if `1 + trace`, i.e. `2 + 2 * cos(angle)`, is smaller or equal to `eps`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Rotation3, Vector3};
+    /// let rot = Rotation3::from_axis_angle(&Vector3::x_axis(), std::f64::consts::PI);
+    /// assert!(rot.try_to_cayley(1.0e-7).is_none());
+    ///
+    /// let rot = Rotation3::from_axis_angle(&Vector3::x_axis(), 1.0);
+    /// assert!(rot.try_to_cayley(1.0e-7).is_some());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn try_to_cayley(&self, eps: T) -> Option<Matrix3<T>>
+    where
+        T: RealField,
+    {
+        let denom = T::one() + self.matrix().trace();
+
+        if denom <= eps {
+            None
+        } else {
+            Some((self.skew_axis() / denom).cross_matrix())
+        }
+    }
 }
 
 #[cfg(feature = "rand-no-std")]
@@ -0,0 +1,187 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{DMatrix, Matrix, Scalar};
+
+/// An error that occurred while reading a dense matrix from delimited text.
+///
+/// Line and column numbers are 1-based, and count every line of the input, including the header
+/// and empty lines.
+#[derive(Debug)]
+pub enum CsvError {
+    /// Reading from the underlying reader failed.
+    Io(io::Error),
+    /// A field could not be parsed as a matrix component.
+    InvalidValue {
+        /// The line of the field.
+        line: usize,
+        /// The position of the field within its line.
+        column: usize,
+        /// The text of the field, without its surrounding whitespaces.
+        value: String,
+    },
+    /// A line does not have the same number of fields as the first data line.
+    RaggedRow {
+        /// The offending line.
+        line: usize,
+        /// The number of fields of the first data line.
+        expected: usize,
+        /// The number of fields of the offending line.
+        found: usize,
+    },
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Io(err) => write!(f, "failed to read the matrix: {}", err),
+            CsvError::InvalidValue {
+                line,
+                column,
+                value,
+            } => write!(
+                f,
+                "line {}, column {}: unable to parse {:?} as a matrix component",
+                line, column, value
+            ),
+            CsvError::RaggedRow {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {}: expected {} fields, like the first data line, but found {}",
+                line, expected, found
+            ),
+        }
+    }
+}
+
+impl Error for CsvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(err: io::Error) -> Self {
+        CsvError::Io(err)
+    }
+}
+
+impl<T: Scalar + FromStr> DMatrix<T> {
+    /// Reads a dense matrix from delimited text, e.g. CSV (`','`) or TSV (`'\t'`).
+    ///
+    /// Each non-empty line is a row of the matrix, whose components are separated by
+    /// `delimiter` and parsed with [`FromStr`] after trimming their surrounding whitespaces. The
+    /// number of columns is given by the first data line, and every other line must have as many
+    /// fields. If `has_header` is `true`, the first non-empty line is skipped. Empty lines are
+    /// ignored, and an input without data lines yields a `0 × 0` matrix.
+    ///
+    /// The input is read line by line, so `reader` does not need to be buffered. Quoted fields
+    /// are not supported.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2x3};
+    /// let data = "x,y,z\n1,2,3\n4,5,6\n";
+    /// let m = DMatrix::<f64>::from_csv_reader(data.as_bytes(), ',', true).unwrap();
+    /// assert_eq!(m, Matrix2x3::new(1.0, 2.0, 3.0,
+    ///                              4.0, 5.0, 6.0));
+    ///
+    /// let err = DMatrix::<f64>::from_csv_reader("1\t2\n3\tfour\n".as_bytes(), '\t', false);
+    /// assert_eq!(
+    ///     err.unwrap_err().to_string(),
+    ///     "line 2, column 2: unable to parse \"four\" as a matrix component"
+    /// );
+    /// ```
+    pub fn from_csv_reader<R: Read>(
+        reader: R,
+        delimiter: char,
+        has_header: bool,
+    ) -> Result<Self, CsvError> {
+        let mut skip_header = has_header;
+        let mut ncols = None;
+        let mut nrows = 0;
+        let mut data = Vec::new();
+
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let line = line.trim_end_matches('\r');
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if skip_header {
+                skip_header = false;
+                continue;
+            }
+
+            let start = data.len();
+            for (j, field) in line.split(delimiter).enumerate() {
+                let field = field.trim();
+                let value = field.parse().map_err(|_| CsvError::InvalidValue {
+                    line: i + 1,
+                    column: j + 1,
+                    value: field.to_string(),
+                })?;
+                data.push(value);
+            }
+
+            let found = data.len() - start;
+            let expected = *ncols.get_or_insert(found);
+
+            if found != expected {
+                return Err(CsvError::RaggedRow {
+                    line: i + 1,
+                    expected,
+                    found,
+                });
+            }
+
+            nrows += 1;
+        }
+
+        Ok(DMatrix::from_row_slice(nrows, ncols.unwrap_or(0), &data))
+    }
+}
+
+impl<T: Scalar + fmt::Display, R: Dim, C: Dim, S: Storage<T, R, C>> Matrix<T, R, C, S> {
+    /// Writes this matrix as delimited text, e.g. CSV (`','`) or TSV (`'\t'`).
+    ///
+    /// Each row is written on its own line, terminated by `'\n'`, with its components formatted
+    /// with [`Display`](fmt::Display) and separated by `delimiter`. No header is written. The
+    /// output can be read back with [`DMatrix::from_csv_reader`], which is exact for floats since
+    /// their `Display` implementation round-trips.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix2x3;
+    /// let m = Matrix2x3::new(1.5, 2.0, 3.0,
+    ///                        4.0, 5.0, -6.25);
+    /// let mut out = Vec::new();
+    /// m.to_csv_writer(&mut out, ',').unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), "1.5,2,3\n4,5,-6.25\n");
+    /// ```
+    pub fn to_csv_writer<W: Write>(&self, mut writer: W, delimiter: char) -> io::Result<()> {
+        for row in self.row_iter() {
+            for (j, value) in row.iter().enumerate() {
+                if j != 0 {
+                    write!(writer, "{}", delimiter)?;
+                }
+                write!(writer, "{}", value)?;
+            }
+            writeln!(writer)?;
+        }
+
+        writer.flush()
+    }
+}
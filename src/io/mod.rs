@@ -1,5 +1,7 @@
 //! Parsers for various matrix formats.
 
+pub use self::csv::CsvError;
 pub use self::matrix_market::{cs_matrix_from_matrix_market, cs_matrix_from_matrix_market_str};
 
+mod csv;
 mod matrix_market;
@@ -0,0 +1,332 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use simba::scalar::RealField;
+
+use crate::base::{DMatrix, DVector, Matrix, Scalar};
+use crate::dimension::{Dim, Dynamic};
+use crate::storage::Storage;
+
+use crate::linalg::{SymmetricEigen, SymmetricEigenConfig};
+
+/// A square matrix whose only non-zero elements lie within a given distance of its diagonal.
+///
+/// Only the `2 * bandwidth + 1` diagonals of the band are stored, in the layout of LAPACK's
+/// general band storage: the element at `(i, j)` is stored at `(bandwidth + i - j, j)` of a
+/// `(2 * bandwidth + 1) × n` dense matrix.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BandedMatrix<T: Scalar> {
+    bandwidth: usize,
+    data: DMatrix<T>,
+}
+
+impl<T: Scalar + Zero> BandedMatrix<T> {
+    /// Creates a `dim × dim` banded matrix filled with zeros.
+    #[inline]
+    pub fn zeros(dim: usize, bandwidth: usize) -> Self {
+        Self {
+            bandwidth,
+            data: DMatrix::zeros(2 * bandwidth + 1, dim),
+        }
+    }
+
+    /// Creates a banded matrix from the elements of `m` within `bandwidth` of its diagonal.
+    ///
+    /// The elements of `m` outside of the band are ignored.
+    ///
+    /// # Panics
+    /// Panics if `m` is not square.
+    pub fn from_matrix<R: Dim, C: Dim, S: Storage<T, R, C>>(
+        m: &Matrix<T, R, C, S>,
+        bandwidth: usize,
+    ) -> Self {
+        assert!(
+            m.is_square(),
+            "Unable to create a banded matrix from a non-square matrix."
+        );
+
+        let dim = m.nrows();
+        let mut res = Self::zeros(dim, bandwidth);
+
+        for j in 0..dim {
+            let start = j.saturating_sub(bandwidth);
+            let end = (j + bandwidth + 1).min(dim);
+
+            for i in start..end {
+                res.data[(bandwidth + i - j, j)] = m[(i, j)].clone();
+            }
+        }
+
+        res
+    }
+
+    /// The number of rows, and of columns, of this matrix.
+    #[inline]
+    #[must_use]
+    pub fn dim(&self) -> usize {
+        self.data.ncols()
+    }
+
+    /// The largest distance between the diagonal and a possibly non-zero element.
+    #[inline]
+    #[must_use]
+    pub fn bandwidth(&self) -> usize {
+        self.bandwidth
+    }
+
+    /// The element at `(i, j)`, which is zero if it lies outside of the band.
+    ///
+    /// # Panics
+    /// Panics if `(i, j)` is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, i: usize, j: usize) -> T {
+        assert!(
+            i < self.dim() && j < self.dim(),
+            "Banded matrix index out of bounds."
+        );
+        self.band_index(i, j)
+            .map(|ij| self.data[ij].clone())
+            .unwrap_or_else(T::zero)
+    }
+
+    /// A mutable reference to the element at `(i, j)`, or `None` if it lies outside of the band.
+    ///
+    /// # Panics
+    /// Panics if `(i, j)` is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        assert!(
+            i < self.dim() && j < self.dim(),
+            "Banded matrix index out of bounds."
+        );
+        let ij = self.band_index(i, j)?;
+        Some(&mut self.data[ij])
+    }
+
+    /// The dense matrix with the same elements as this banded matrix.
+    #[must_use]
+    pub fn to_matrix(&self) -> DMatrix<T> {
+        DMatrix::from_fn(self.dim(), self.dim(), |i, j| self.get(i, j))
+    }
+
+    #[inline]
+    fn band_index(&self, i: usize, j: usize) -> Option<(usize, usize)> {
+        if i + self.bandwidth >= j && j + self.bandwidth >= i {
+            Some((self.bandwidth + i - j, j))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: RealField> BandedMatrix<T> {
+    /// Computes the eigendecomposition of this symmetric banded matrix.
+    ///
+    /// Only the lower-triangular part of the band (including its diagonal) is read.
+    #[must_use]
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T, Dynamic> {
+        self.try_symmetric_eigen(T::default_epsilon(), 0).unwrap()
+    }
+
+    /// Computes the eigendecomposition of this symmetric banded matrix with user-specified
+    /// convergence parameters.
+    ///
+    /// The band is first reduced to a tridiagonal matrix with Givens rotations, each rotation
+    /// being chased down the band so that the fill-in never exceeds one extra diagonal. The
+    /// resulting tridiagonal matrix is then diagonalized with the same implicit iteration as
+    /// [`SymmetricEigen::try_new`]. The reduction costs `O(n² * b)` for a bandwidth `b` instead of
+    /// the `O(n³)` of the dense tridiagonalization, though accumulating the rotations into the
+    /// eigenvectors still costs `O(n³)`; use [`Self::symmetric_eigenvalues`] when only the
+    /// eigenvalues are needed.
+    ///
+    /// Only the lower-triangular part of the band (including its diagonal) is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::DMatrix;
+    /// # use nalgebra::linalg::BandedMatrix;
+    /// // The discrete 1D laplacian, with a bandwidth of 1.
+    /// let m = DMatrix::<f64>::from_fn(8, 8, |i, j| match i.max(j) - i.min(j) {
+    ///     0 => 2.0,
+    ///     1 => -1.0,
+    ///     _ => 0.0,
+    /// });
+    /// let eig = BandedMatrix::from_matrix(&m, 1)
+    ///     .try_symmetric_eigen(1.0e-15, 100)
+    ///     .unwrap();
+    ///
+    /// assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+    /// ```
+    pub fn try_symmetric_eigen(
+        &self,
+        eps: T,
+        max_niter: usize,
+    ) -> Option<SymmetricEigen<T, Dynamic>> {
+        let (eigenvalues, eigenvectors) = self.do_symmetric_eigen(true, eps, max_niter)?;
+
+        Some(SymmetricEigen {
+            eigenvalues,
+            eigenvectors: eigenvectors.unwrap(),
+        })
+    }
+
+    /// Computes the eigenvalues of this symmetric banded matrix.
+    ///
+    /// This costs `O(n² * b)` for a bandwidth `b`. Only the lower-triangular part of the band
+    /// (including its diagonal) is read.
+    #[must_use]
+    pub fn symmetric_eigenvalues(&self) -> DVector<T> {
+        self.do_symmetric_eigen(false, T::default_epsilon(), 0)
+            .unwrap()
+            .0
+    }
+
+    fn do_symmetric_eigen(
+        &self,
+        eigenvectors: bool,
+        eps: T,
+        max_niter: usize,
+    ) -> Option<(DVector<T>, Option<DMatrix<T>>)> {
+        let dim = self.dim();
+        let bandwidth = self.bandwidth.min(dim.saturating_sub(1));
+
+        if dim == 0 {
+            return Some((
+                DVector::zeros(0),
+                eigenvectors.then(|| DMatrix::zeros(0, 0)),
+            ));
+        }
+
+        // The lower part of the band, with one extra diagonal for the bulge created by each
+        // rotation.
+        let mut band = LowerBand {
+            data: DMatrix::from_fn(bandwidth + 2, dim, |k, j| {
+                if k <= bandwidth && j + k < dim {
+                    self.get(j + k, j)
+                } else {
+                    T::zero()
+                }
+            }),
+        };
+        let mut q_mat = eigenvectors.then(|| DMatrix::identity(dim, dim));
+
+        let m_amax = band.data.camax();
+        if !m_amax.is_zero() {
+            band.data.unscale_mut(m_amax);
+        }
+
+        for j in 0..dim.saturating_sub(2) {
+            for k in (j + 2..=(j + bandwidth).min(dim - 1)).rev() {
+                // Cancel `(k, j)` against `(k - 1, j)`, then chase the bulge created at
+                // `(k + bandwidth, k - 1)` down the band.
+                let (mut row, mut col) = (k, j);
+
+                while row < dim {
+                    let x = band.get(row - 1, col);
+                    let y = band.get(row, col);
+
+                    if y.is_zero() {
+                        break;
+                    }
+
+                    let r = x.hypot(y);
+                    let (c, s) = (x / r, y / r);
+                    band.rotate(row - 1, c, s);
+                    band.set(row - 1, col, r);
+                    band.set(row, col, T::zero());
+
+                    if let Some(q) = q_mat.as_mut() {
+                        for i in 0..dim {
+                            let (qp, qq) = (q[(i, row - 1)], q[(i, row)]);
+                            q[(i, row - 1)] = c * qp + s * qq;
+                            q[(i, row)] = c * qq - s * qp;
+                        }
+                    }
+
+                    col = row - 1;
+                    row += bandwidth;
+                }
+            }
+        }
+
+        let mut diag = band.data.row(0).transpose();
+        let mut off_diag = band.data.row(1).columns(0, dim - 1).transpose();
+
+        SymmetricEigen::<T, Dynamic>::solve_tridiagonal(
+            &mut diag,
+            &mut off_diag,
+            q_mat.as_mut(),
+            m_amax,
+            SymmetricEigenConfig {
+                eps,
+                max_niter,
+                ..SymmetricEigenConfig::default()
+            },
+        )
+        .ok()
+        .map(|_| (diag, q_mat))
+    }
+}
+
+/// The lower part of a symmetric band, the element at `(i, j)` with `i >= j` being stored at
+/// `(i - j, j)`.
+struct LowerBand<T> {
+    data: DMatrix<T>,
+}
+
+impl<T: RealField> LowerBand<T> {
+    /// The element at `(i, j)`, or zero if it lies outside of the stored band.
+    fn get(&self, i: usize, j: usize) -> T {
+        let (i, j) = if i >= j { (i, j) } else { (j, i) };
+
+        if i - j < self.data.nrows() {
+            self.data[(i - j, j)]
+        } else {
+            T::zero()
+        }
+    }
+
+    fn set(&mut self, i: usize, j: usize, val: T) {
+        let (i, j) = if i >= j { (i, j) } else { (j, i) };
+
+        if i - j < self.data.nrows() {
+            self.data[(i - j, j)] = val;
+        }
+    }
+
+    /// Replaces this matrix `A` by `G * A * Gᵀ`, where `G` is the rotation of the plane
+    /// `(p, p + 1)` mapping `(x, y)` to `(c * x + s * y, c * y - s * x)`.
+    fn rotate(&mut self, p: usize, c: T, s: T) {
+        let q = p + 1;
+        let width = self.data.nrows() - 1;
+        let dim = self.data.ncols();
+
+        for k in q.saturating_sub(width)..(p + width + 1).min(dim) {
+            if k != p && k != q {
+                let (akp, akq) = (self.get(k, p), self.get(k, q));
+                self.set(k, p, c * akp + s * akq);
+                self.set(k, q, c * akq - s * akp);
+            }
+        }
+
+        let (app, aqq, apq) = (self.get(p, p), self.get(q, q), self.get(q, p));
+        let (cc, ss, cs) = (c * c, s * s, c * s);
+        let b = cs * apq * crate::convert(2.0);
+
+        self.set(p, p, cc * app + ss * aqq + b);
+        self.set(q, q, ss * app + cc * aqq - b);
+        self.set(q, p, cs * (aqq - app) + (cc - ss) * apq);
+    }
+}
@@ -2,20 +2,103 @@ use std::cmp;
 
 use crate::base::allocator::Allocator;
 use crate::base::default_allocator::DefaultAllocator;
-use crate::base::dimension::{Const, Dim, DimAdd, DimDiff, DimSub, DimSum};
+use crate::base::dimension::{Const, Dim, DimAdd, DimDiff, DimSub, DimSum, Dynamic};
 use crate::storage::Storage;
-use crate::{zero, OVector, RealField, Vector, U1};
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::{zero, DMatrix, DVector, RealField};
+use crate::{ComplexField, Matrix, OMatrix, OVector, Vector, U1};
 
-impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::{vec, vec::Vec};
+
+/// How the elements outside of the bounds of a matrix are obtained by 2D convolutions.
+///
+/// The examples below show how the row `[a, b, c, d]` is extended on both sides.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum BorderMode {
+    /// Out-of-bounds elements are zero: `0 0 0 | a b c d | 0 0 0`.
+    ///
+    /// This is the default, and the mode used by the convolutions without a `BorderMode` argument.
+    Zero,
+    /// Out-of-bounds elements replicate the closest border element: `a a a | a b c d | d d d`.
+    Clamp,
+    /// Out-of-bounds elements mirror the matrix, the border element being repeated:
+    /// `c b a | a b c d | d c b`.
+    Reflect,
+    /// Out-of-bounds elements are read periodically from the other side of the matrix:
+    /// `b c d | a b c d | a b c`.
+    Wrap,
+}
+
+impl Default for BorderMode {
+    #[inline]
+    fn default() -> Self {
+        BorderMode::Zero
+    }
+}
+
+impl BorderMode {
+    /// Resolves the possibly out-of-bounds index `i` into an index in `0..len`, or `None` if the
+    /// corresponding element is zero.
+    fn resolve(self, i: isize, len: usize) -> Option<usize> {
+        let len = len as isize;
+
+        let i = match self {
+            BorderMode::Zero if i < 0 || i >= len => return None,
+            BorderMode::Zero => i,
+            BorderMode::Clamp => i.max(0).min(len - 1),
+            BorderMode::Reflect => {
+                let i = i.rem_euclid(2 * len);
+                if i < len {
+                    i
+                } else {
+                    2 * len - 1 - i
+                }
+            }
+            BorderMode::Wrap => i.rem_euclid(len),
+        };
+
+        Some(i as usize)
+    }
+}
+
+/// Which part of the ‘full’ convolution of a vector and a kernel is returned by
+/// [`Matrix::convolve`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConvolveMode {
+    /// The whole convolution, as computed by `convolve_full`.
+    Full,
+    /// Only the elements that do not rely on the zero-padding, as computed by `convolve_valid`.
+    Valid,
+    /// The elements centered with respect to the ‘full’ output, with the same size as the
+    /// vector, as computed by `convolve_same`.
+    Same,
+}
+
+impl<T: ComplexField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
     /// Returns the convolution of the target vector and a kernel.
     ///
+    /// The ‘full’ convolution is symmetric in its two arguments, so the kernel may be longer than
+    /// the target vector, in which case the result is the same as `kernel.convolve_full(self)`.
+    ///
     /// # Arguments
     ///
     /// * `kernel` - A Vector with size > 0
     ///
     /// # Errors
-    /// Inputs must satisfy `vector.len() >= kernel.len() > 0`.
+    /// Inputs must satisfy `vector.len() > 0` and `kernel.len() > 0`.
     ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{SVector, Vector3, Vector5};
+    /// let vec = Vector3::new(1.0, 2.0, 3.0);
+    /// let kernel = Vector5::new(1.0, 0.0, -1.0, 2.0, 1.0);
+    ///
+    /// let conv = vec.convolve_full(kernel);
+    /// let expected = SVector::<f64, 7>::from([1.0, 2.0, 2.0, 0.0, 2.0, 8.0, 3.0]);
+    /// assert_eq!(conv, expected);
+    /// assert_eq!(conv, kernel.convolve_full(vec));
+    /// ```
     pub fn convolve_full<D2, S2>(
         &self,
         kernel: Vector<T, D2, S2>,
@@ -26,41 +109,228 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
         DimSum<D1, D2>: DimSub<U1>,
         S2: Storage<T, D2>,
         DefaultAllocator: Allocator<T, DimDiff<DimSum<D1, D2>, U1>>,
+    {
+        self.do_full(kernel, false, "convolve_full")
+    }
+
+    /// Returns the convolution of the target vector and a kernel.
+    ///
+    /// The output convolution consists only of those elements that do not rely on the zero-padding.
+    /// # Arguments
+    ///
+    /// * `kernel` - A Vector with size > 0
+    ///
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.len() >= kernel.len() > 0`: unlike for `convolve_full`, a
+    /// kernel longer than the target vector is rejected since every element of the output would
+    /// rely on the zero-padding.
+    ///
+    pub fn convolve_valid<D2, S2>(
+        &self,
+        kernel: Vector<T, D2, S2>,
+    ) -> OVector<T, DimDiff<DimSum<D1, U1>, D2>>
+    where
+        D1: DimAdd<U1>,
+        D2: Dim,
+        DimSum<D1, U1>: DimSub<D2>,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<D1, U1>, D2>>,
+    {
+        self.do_valid(kernel, false, "convolve_valid")
+    }
+
+    /// Returns the strided and dilated convolution of the target vector and a kernel.
+    ///
+    /// The kernel taps are spaced by `dilation` elements of the target vector, and only one
+    /// output element every `stride` elements of the ‘valid’ convolution is computed. The output
+    /// has `(self.len() - dilation * (kernel.len() - 1) - 1) / stride + 1` elements, its `i`-th
+    /// element being `Σⱼ self[i * stride + j * dilation] * kernel[kernel.len() - 1 - j]`. With
+    /// `stride == dilation == 1`, this is the same as `convolve_valid`.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A Vector with size > 0
+    /// * `stride` - The step between two consecutive output elements, at least 1.
+    /// * `dilation` - The spacing between two consecutive kernel taps, at least 1.
+    ///
+    /// # Errors
+    /// Inputs must satisfy `stride >= 1`, `dilation >= 1` and
+    /// `self.len() >= dilation * (kernel.len() - 1) + 1 > 0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DVector, Vector2};
+    /// let vec = DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+    /// let kernel = Vector2::new(1.0, 10.0);
+    ///
+    /// // Taps on elements `2i` and `2i + 3`.
+    /// let conv = vec.convolve_valid_strided(kernel, 2, 3);
+    /// assert_eq!(conv, DVector::from_vec(vec![14.0, 36.0]));
+    /// ```
+    pub fn convolve_valid_strided<D2, S2>(
+        &self,
+        kernel: Vector<T, D2, S2>,
+        stride: usize,
+        dilation: usize,
+    ) -> OVector<T, Dynamic>
+    where
+        D2: Dim,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, Dynamic>,
     {
         let vec = self.len();
         let ker = kernel.len();
 
-        if ker == 0 || ker > vec {
-            panic!("convolve_full expects `self.len() >= kernel.len() > 0`, received {} and {} respectively.", vec, ker);
+        if stride == 0 || dilation == 0 {
+            panic!(
+                "convolve_valid_strided expects `stride >= 1` and `dilation >= 1`, received {} and {} respectively.",
+                stride, dilation
+            );
         }
 
-        let result_len = self
-            .data
-            .shape()
-            .0
-            .add(kernel.data.shape().0)
-            .sub(Const::<1>);
-        let mut conv = OVector::zeros_generic(result_len, Const::<1>);
+        if ker == 0 || dilation * (ker - 1) + 1 > vec {
+            panic!(
+                "convolve_valid_strided expects `self.len() >= dilation * (kernel.len() - 1) + 1 > 0`, received {}, {} and {} respectively.",
+                vec, dilation, ker
+            );
+        }
 
-        for i in 0..(vec + ker - 1) {
-            let u_i = if i > vec { i - ker } else { 0 };
-            let u_f = cmp::min(i, vec - 1);
+        let result_len = (vec - dilation * (ker - 1) - 1) / stride + 1;
+        let mut conv = OVector::zeros_generic(Dynamic::new(result_len), Const::<1>);
 
-            if u_i == u_f {
-                conv[i] += self[u_i] * kernel[(i - u_i)];
-            } else {
-                for u in u_i..(u_f + 1) {
-                    if i - u < ker {
-                        conv[i] += self[u] * kernel[(i - u)];
-                    }
-                }
+        for i in 0..result_len {
+            for j in 0..ker {
+                conv[i] += self[i * stride + j * dilation] * kernel[ker - j - 1];
             }
         }
+
         conv
     }
+
     /// Returns the convolution of the target vector and a kernel.
     ///
-    /// The output convolution consists only of those elements that do not rely on the zero-padding.
+    /// The output convolution is the same size as vector, centered with respect to the ‘full’ output:
+    /// it starts at the element `(kernel.len() - 1) / 2` of the ‘full’ convolution, like
+    /// `mat_convolve_same`.
+    /// # Arguments
+    ///
+    /// * `kernel` - A Vector with size > 0
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.len() >= kernel.len() > 0`.
+    #[must_use]
+    pub fn convolve_same<D2, S2>(&self, kernel: Vector<T, D2, S2>) -> OVector<T, D1>
+    where
+        D2: Dim,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, D1>,
+    {
+        self.do_same(kernel, false, "convolve_same")
+    }
+
+    /// Returns the convolution of the target vector and a kernel, where the part of the ‘full’
+    /// convolution that is returned is chosen at runtime by `mode`.
+    ///
+    /// The result is the same as the one of `convolve_full`, `convolve_valid`, or
+    /// `convolve_same`, but it is always dynamically-sized. This avoids having to deal with the
+    /// different output dimension types of these methods when the mode is not known at
+    /// compile-time.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A Vector with size > 0
+    /// * `mode` - The part of the ‘full’ convolution to return.
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.len() >= kernel.len() > 0`, except for `ConvolveMode::Full`
+    /// which only requires `self.len() > 0` and `kernel.len() > 0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{ConvolveMode, DVector, Vector2, Vector4};
+    /// let vec = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    /// let kernel = Vector2::new(1.0, 2.0);
+    ///
+    /// let conv = vec.convolve(kernel, ConvolveMode::Valid);
+    /// assert_eq!(conv, DVector::from_vec(vec![4.0, 7.0, 10.0]));
+    /// ```
+    #[must_use]
+    pub fn convolve<D2, S2>(
+        &self,
+        kernel: Vector<T, D2, S2>,
+        mode: ConvolveMode,
+    ) -> OVector<T, Dynamic>
+    where
+        D2: Dim,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, D1> + Allocator<T, Dynamic>,
+    {
+        if mode == ConvolveMode::Same {
+            let conv = self.do_same(kernel, false, "convolve");
+            return OVector::from_iterator_generic(
+                Dynamic::new(conv.len()),
+                Const::<1>,
+                conv.iter().copied(),
+            );
+        }
+
+        let full = mode == ConvolveMode::Full;
+        let kernel_at = self.kernel_reader(&kernel, false, full, "convolve");
+        let vec = self.len();
+        let ker = kernel.len();
+
+        let (len, offset) = if full {
+            (vec + ker - 1, 0)
+        } else {
+            (vec - ker + 1, ker - 1)
+        };
+
+        OVector::from_fn_generic(Dynamic::new(len), Const::<1>, |i, _| {
+            // Index of the element of the ‘full’ convolution.
+            let full_i = i + offset;
+            let mut res = T::zero();
+
+            // Only iterate on the kernel elements that don't hit the zero-padding.
+            for k in (full_i + 1).saturating_sub(vec)..cmp::min(ker, full_i + 1) {
+                res += self[full_i - k] * kernel_at(k);
+            }
+
+            res
+        })
+    }
+
+    /// Returns the cross-correlation of the target vector and a kernel.
+    ///
+    /// This is the same as `self.convolve_full(k)` where `k` is the conjugate of `kernel` with
+    /// its elements in reverse order, i.e., the kernel is not flipped. Like for `convolve_full`,
+    /// the kernel may be longer than the target vector.
+    /// # Arguments
+    ///
+    /// * `kernel` - A Vector with size > 0
+    ///
+    /// # Errors
+    /// Inputs must satisfy `vector.len() > 0` and `kernel.len() > 0`.
+    ///
+    pub fn correlate_full<D2, S2>(
+        &self,
+        kernel: Vector<T, D2, S2>,
+    ) -> OVector<T, DimDiff<DimSum<D1, D2>, U1>>
+    where
+        D1: DimAdd<D2>,
+        D2: DimAdd<D1, Output = DimSum<D1, D2>>,
+        DimSum<D1, D2>: DimSub<U1>,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<D1, D2>, U1>>,
+    {
+        self.do_full(kernel, true, "correlate_full")
+    }
+
+    /// Returns the cross-correlation of the target vector and a kernel.
+    ///
+    /// This is the same as `self.convolve_valid(k)` where `k` is the conjugate of `kernel` with
+    /// its elements in reverse order, i.e., the kernel is not flipped.
+    /// The output correlation consists only of those elements that do not rely on the zero-padding.
     /// # Arguments
     ///
     /// * `kernel` - A Vector with size > 0
@@ -69,7 +339,7 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
     /// # Errors
     /// Inputs must satisfy `self.len() >= kernel.len() > 0`.
     ///
-    pub fn convolve_valid<D2, S2>(
+    pub fn correlate_valid<D2, S2>(
         &self,
         kernel: Vector<T, D2, S2>,
     ) -> OVector<T, DimDiff<DimSum<D1, U1>, D2>>
@@ -80,12 +350,118 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
         S2: Storage<T, D2>,
         DefaultAllocator: Allocator<T, DimDiff<DimSum<D1, U1>, D2>>,
     {
+        self.do_valid(kernel, true, "correlate_valid")
+    }
+
+    /// Returns the cross-correlation of the target vector and a kernel.
+    ///
+    /// This is the same as `self.convolve_same(k)` where `k` is the conjugate of `kernel` with
+    /// its elements in reverse order, i.e., the kernel is not flipped.
+    /// The output correlation is the same size as vector, centered with respect to the ‘full’ output.
+    /// # Arguments
+    ///
+    /// * `kernel` - A Vector with size > 0
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.len() >= kernel.len() > 0`.
+    #[must_use]
+    pub fn correlate_same<D2, S2>(&self, kernel: Vector<T, D2, S2>) -> OVector<T, D1>
+    where
+        D2: Dim,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, D1>,
+    {
+        self.do_same(kernel, true, "correlate_same")
+    }
+
+    /// Checks the input lengths and returns a closure reading the `i`-th kernel element, as
+    /// used by the convolution. If `correlate` is `true`, the kernel is conjugated and
+    /// reversed so that the convolution computes a cross-correlation instead. If `full` is
+    /// `true`, the kernel may be longer than the target vector.
+    fn kernel_reader<'a, D2: Dim, S2: Storage<T, D2>>(
+        &self,
+        kernel: &'a Vector<T, D2, S2>,
+        correlate: bool,
+        full: bool,
+        name: &str,
+    ) -> impl Fn(usize) -> T + 'a {
         let vec = self.len();
         let ker = kernel.len();
 
-        if ker == 0 || ker > vec {
-            panic!("convolve_valid expects `self.len() >= kernel.len() > 0`, received {} and {} respectively.",vec,ker);
+        if full && (vec == 0 || ker == 0) {
+            panic!(
+                "{} expects `self.len() > 0` and `kernel.len() > 0`, received {} and {} respectively.",
+                name, vec, ker
+            );
+        }
+
+        if !full && (ker == 0 || ker > vec) {
+            panic!(
+                "{} expects `self.len() >= kernel.len() > 0`, received {} and {} respectively.",
+                name, vec, ker
+            );
+        }
+
+        move |i| {
+            if correlate {
+                kernel[ker - i - 1].conjugate()
+            } else {
+                kernel[i]
+            }
         }
+    }
+
+    fn do_full<D2, S2>(
+        &self,
+        kernel: Vector<T, D2, S2>,
+        correlate: bool,
+        name: &str,
+    ) -> OVector<T, DimDiff<DimSum<D1, D2>, U1>>
+    where
+        D1: DimAdd<D2>,
+        D2: DimAdd<D1, Output = DimSum<D1, D2>>,
+        DimSum<D1, D2>: DimSub<U1>,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<D1, D2>, U1>>,
+    {
+        let kernel_at = self.kernel_reader(&kernel, correlate, true, name);
+        let vec = self.len();
+        let ker = kernel.len();
+
+        let result_len = self
+            .data
+            .shape()
+            .0
+            .add(kernel.data.shape().0)
+            .sub(Const::<1>);
+        let mut conv = OVector::zeros_generic(result_len, Const::<1>);
+
+        for i in 0..(vec + ker - 1) {
+            // Only iterate on the elements of `self` for which the kernel element is in bounds,
+            // which is valid whichever of the vector or the kernel is the longest.
+            for u in (i + 1).saturating_sub(ker)..cmp::min(vec, i + 1) {
+                conv[i] += self[u] * kernel_at(i - u);
+            }
+        }
+        conv
+    }
+
+    fn do_valid<D2, S2>(
+        &self,
+        kernel: Vector<T, D2, S2>,
+        correlate: bool,
+        name: &str,
+    ) -> OVector<T, DimDiff<DimSum<D1, U1>, D2>>
+    where
+        D1: DimAdd<U1>,
+        D2: Dim,
+        DimSum<D1, U1>: DimSub<D2>,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, DimDiff<DimSum<D1, U1>, D2>>,
+    {
+        let kernel_at = self.kernel_reader(&kernel, correlate, false, name);
+        let vec = self.len();
+        let ker = kernel.len();
 
         let result_len = self
             .data
@@ -97,48 +473,624 @@ impl<T: RealField, D1: Dim, S1: Storage<T, D1>> Vector<T, D1, S1> {
 
         for i in 0..(vec - ker + 1) {
             for j in 0..ker {
-                conv[i] += self[i + j] * kernel[ker - j - 1];
+                conv[i] += self[i + j] * kernel_at(ker - j - 1);
             }
         }
         conv
     }
 
-    /// Returns the convolution of the target vector and a kernel.
+    fn do_same<D2, S2>(
+        &self,
+        kernel: Vector<T, D2, S2>,
+        correlate: bool,
+        name: &str,
+    ) -> OVector<T, D1>
+    where
+        D2: Dim,
+        S2: Storage<T, D2>,
+        DefaultAllocator: Allocator<T, D1>,
+    {
+        let kernel_at = self.kernel_reader(&kernel, correlate, false, name);
+        let vec = self.len();
+        let ker = kernel.len();
+
+        // Same centering as `mat_convolve_same`: the window of the ‘full’ convolution starts at
+        // `(ker - 1) / 2`.
+        let offset = (ker - 1) / 2;
+
+        OVector::from_fn_generic(self.data.shape().0, Const::<1>, |i, _| {
+            // Index of the element of the ‘full’ convolution.
+            let full_i = i + offset;
+            let mut res = T::zero();
+
+            // Only iterate on the kernel elements that don't hit the zero-padding.
+            for k in (full_i + 1).saturating_sub(vec)..cmp::min(ker, full_i + 1) {
+                res += self[full_i - k] * kernel_at(k);
+            }
+
+            res
+        })
+    }
+}
+
+/// The state of the convolution of a signal processed in consecutive chunks with a kernel.
+///
+/// Each chunk is convolved with the kernel, and the last `kernel.len() - 1` elements of its
+/// ‘full’ convolution, which overlap with the next chunks, are kept and added to their
+/// convolution (overlap-add). Concatenating the outputs of [`Self::process_chunk`] for every
+/// chunk, then of [`Self::flush`], gives the ‘full’ convolution of the whole signal, as computed
+/// by [`Matrix::convolve_full`], so that a signal can be convolved without being kept in memory.
+///
+/// # Example
+/// ```
+/// # use nalgebra::{DVector, Vector3};
+/// # use nalgebra::linalg::ConvolutionState;
+/// let kernel = Vector3::new(1.0, 0.0, -1.0);
+/// let signal = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+///
+/// let mut state = ConvolutionState::new(kernel);
+/// let mut output = Vec::new();
+/// for chunk in signal.chunks(3) {
+///     output.extend(state.process_chunk(chunk));
+/// }
+/// output.extend(state.flush());
+///
+/// let expected = DVector::from_column_slice(&signal).convolve_full(kernel);
+/// assert_eq!(output, expected.as_slice());
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConvolutionState<T: ComplexField> {
+    kernel: DVector<T>,
+    // The contributions of the previous chunks to the next outputs, of length
+    // `kernel.len() - 1`.
+    tail: Vec<T>,
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ComplexField> ConvolutionState<T> {
+    /// Initializes the convolution of a signal with `kernel`.
+    ///
+    /// # Errors
+    /// Inputs must satisfy `kernel.len() > 0`.
+    pub fn new<D: Dim, S: Storage<T, D>>(kernel: Vector<T, D, S>) -> Self {
+        let ker = kernel.len();
+
+        if ker == 0 {
+            panic!(
+                "ConvolutionState::new expects `kernel.len() > 0`, received {}.",
+                ker
+            );
+        }
+
+        Self {
+            kernel: DVector::from_iterator(ker, kernel.iter().copied()),
+            tail: vec![zero(); ker - 1],
+        }
+    }
+
+    /// The kernel the signal is convolved with.
+    #[inline]
+    #[must_use]
+    pub fn kernel(&self) -> &DVector<T> {
+        &self.kernel
+    }
+
+    /// Convolves the next chunk of the signal, and returns as many elements of the convolution
+    /// as `input` has.
+    ///
+    /// The returned elements are final: they include the contributions of every chunk, whether
+    /// processed before or after this one.
+    pub fn process_chunk(&mut self, input: &[T]) -> Vec<T> {
+        let ker = self.kernel.len();
+        let mut conv = core::mem::take(&mut self.tail);
+        conv.resize(input.len() + ker - 1, zero());
+
+        for (i, x) in input.iter().enumerate() {
+            for (j, k) in self.kernel.iter().enumerate() {
+                conv[i + j] += *x * *k;
+            }
+        }
+
+        self.tail = conv.split_off(input.len());
+        conv
+    }
+
+    /// Returns the last `kernel.len() - 1` elements of the convolution, once the whole signal
+    /// was processed, and resets the state for a new signal.
+    pub fn flush(&mut self) -> Vec<T> {
+        let ker = self.kernel.len();
+        core::mem::replace(&mut self.tail, vec![zero(); ker - 1])
+    }
+
+    /// Discards the contributions of the chunks processed so far, to start a new signal.
+    pub fn reset(&mut self) {
+        self.tail.iter_mut().for_each(|e| *e = zero());
+    }
+}
+
+impl<T: ComplexField, R1: Dim, C1: Dim, S1: Storage<T, R1, C1>> Matrix<T, R1, C1, S1> {
+    /// Returns the 2D convolution of the target matrix and a kernel.
+    ///
+    /// The output convolution is the same size as the target matrix, centered with respect to
+    /// the ‘full’ output: for a kernel with `n` rows, the first `(n - 1) / 2` rows of the ‘full’
+    /// output are discarded (and similarly for the columns). For odd-sized kernels, this means
+    /// the kernel is centered on each element of the target matrix.
     ///
-    /// The output convolution is the same size as vector, centered with respect to the ‘full’ output.
     /// # Arguments
     ///
-    /// * `kernel` - A Vector with size > 0
+    /// * `kernel` - A Matrix with size > 0
     ///
     /// # Errors
-    /// Inputs must satisfy `self.len() >= kernel.len() > 0`.
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
     #[must_use]
-    pub fn convolve_same<D2, S2>(&self, kernel: Vector<T, D2, S2>) -> OVector<T, D1>
+    pub fn mat_convolve_same<R2, C2, S2>(&self, kernel: Matrix<T, R2, C2, S2>) -> OMatrix<T, R1, C1>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R1, C1>,
+    {
+        let (ker_rows, ker_cols) = check_mat_kernel_shape(self, &kernel, "mat_convolve_same");
+
+        mat_convolve_window(
+            self,
+            &kernel,
+            self.data.shape(),
+            ((ker_rows - 1) / 2, (ker_cols - 1) / 2),
+        )
+    }
+
+    /// Returns the 2D convolution of the target matrix and a kernel, where the elements outside
+    /// of the target matrix are obtained as specified by `border`.
+    ///
+    /// The output has the same size as the target matrix, as for `mat_convolve_same`. Using
+    /// `BorderMode::Zero` is equivalent to `mat_convolve_same`.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A Matrix with size > 0
+    /// * `border` - How the target matrix is extended past its borders.
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
+    #[must_use]
+    pub fn mat_convolve_same_with_border<R2, C2, S2>(
+        &self,
+        kernel: Matrix<T, R2, C2, S2>,
+        border: BorderMode,
+    ) -> OMatrix<T, R1, C1>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+        DefaultAllocator: Allocator<T, R1, C1>,
+    {
+        let (ker_rows, ker_cols) =
+            check_mat_kernel_shape(self, &kernel, "mat_convolve_same_with_border");
+
+        mat_convolve_window_with_border(
+            self,
+            &kernel,
+            self.data.shape(),
+            ((ker_rows - 1) / 2, (ker_cols - 1) / 2),
+            border,
+        )
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: ComplexField> DMatrix<T> {
+    /// Returns the 2D convolution of the target matrix and a kernel.
+    ///
+    /// The output has `self.nrows() + kernel.nrows() - 1` rows and
+    /// `self.ncols() + kernel.ncols() - 1` columns, the target matrix being zero-padded.
+    /// Only implemented for `DMatrix` for now.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A Matrix with size > 0
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
+    pub fn mat_convolve_full<R2, C2, S2>(&self, kernel: Matrix<T, R2, C2, S2>) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+    {
+        let (mat_rows, mat_cols) = self.shape();
+        let (ker_rows, ker_cols) = check_mat_kernel_shape(self, &kernel, "mat_convolve_full");
+
+        mat_convolve_window(
+            self,
+            &kernel,
+            (
+                Dynamic::new(mat_rows + ker_rows - 1),
+                Dynamic::new(mat_cols + ker_cols - 1),
+            ),
+            (0, 0),
+        )
+    }
+
+    /// Returns the 2D convolution of the target matrix and a kernel, where the elements outside
+    /// of the target matrix are obtained as specified by `border`.
+    ///
+    /// The output has `self.nrows() + kernel.nrows() - 1` rows and
+    /// `self.ncols() + kernel.ncols() - 1` columns, as for `mat_convolve_full`. Using
+    /// `BorderMode::Zero` is equivalent to `mat_convolve_full`.
+    /// Only implemented for `DMatrix` for now.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A Matrix with size > 0
+    /// * `border` - How the target matrix is extended past its borders.
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{BorderMode, DMatrix, Matrix1x2};
+    /// let m = DMatrix::from_row_slice(1, 3, &[1.0, 2.0, 3.0]);
+    /// let kernel = Matrix1x2::new(1.0, 1.0);
+    ///
+    /// let conv = m.mat_convolve_full_with_border(kernel, BorderMode::Clamp);
+    /// assert_eq!(conv, DMatrix::from_row_slice(1, 4, &[2.0, 3.0, 5.0, 6.0]));
+    ///
+    /// let conv = m.mat_convolve_full_with_border(kernel, BorderMode::Wrap);
+    /// assert_eq!(conv, DMatrix::from_row_slice(1, 4, &[4.0, 3.0, 5.0, 4.0]));
+    /// ```
+    pub fn mat_convolve_full_with_border<R2, C2, S2>(
+        &self,
+        kernel: Matrix<T, R2, C2, S2>,
+        border: BorderMode,
+    ) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+    {
+        let (mat_rows, mat_cols) = self.shape();
+        let (ker_rows, ker_cols) =
+            check_mat_kernel_shape(self, &kernel, "mat_convolve_full_with_border");
+
+        mat_convolve_window_with_border(
+            self,
+            &kernel,
+            (
+                Dynamic::new(mat_rows + ker_rows - 1),
+                Dynamic::new(mat_cols + ker_cols - 1),
+            ),
+            (0, 0),
+            border,
+        )
+    }
+
+    /// Returns the 2D convolution of the target matrix and a kernel.
+    ///
+    /// The output convolution consists only of those elements that do not rely on the
+    /// zero-padding. It has `self.nrows() - kernel.nrows() + 1` rows and
+    /// `self.ncols() - kernel.ncols() + 1` columns.
+    /// Only implemented for `DMatrix` for now.
+    ///
+    /// # Arguments
+    ///
+    /// * `kernel` - A Matrix with size > 0
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= kernel.nrows() > 0` and
+    /// `self.ncols() >= kernel.ncols() > 0`.
+    pub fn mat_convolve_valid<R2, C2, S2>(&self, kernel: Matrix<T, R2, C2, S2>) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+    {
+        let (mat_rows, mat_cols) = self.shape();
+        let (ker_rows, ker_cols) = check_mat_kernel_shape(self, &kernel, "mat_convolve_valid");
+
+        mat_convolve_window(
+            self,
+            &kernel,
+            (
+                Dynamic::new(mat_rows - ker_rows + 1),
+                Dynamic::new(mat_cols - ker_cols + 1),
+            ),
+            (ker_rows - 1, ker_cols - 1),
+        )
+    }
+
+    /// Returns the 2D cross-correlation of the target matrix and a template.
+    ///
+    /// The template is slid over the target matrix without being flipped, and the element at
+    /// `(i, j)` of the output is the dot product of the conjugate of `template` with the window of
+    /// `self` whose top-left corner is at `(i, j)`. This is the same as
+    /// `self.mat_convolve_valid(k)` where `k` is the conjugate of `template` with its rows and
+    /// columns in reverse order. The output has `self.nrows() - template.nrows() + 1` rows and
+    /// `self.ncols() - template.ncols() + 1` columns.
+    /// Only implemented for `DMatrix` for now.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - A Matrix with size > 0
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= template.nrows() > 0` and
+    /// `self.ncols() >= template.ncols() > 0`.
+    pub fn mat_correlate_valid<R2, C2, S2>(&self, template: Matrix<T, R2, C2, S2>) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+    {
+        let (mat_rows, mat_cols) = self.shape();
+        let (tpl_rows, tpl_cols) = check_mat_kernel_shape(self, &template, "mat_correlate_valid");
+        let kernel = DMatrix::from_fn(tpl_rows, tpl_cols, |i, j| {
+            template[(tpl_rows - 1 - i, tpl_cols - 1 - j)].conjugate()
+        });
+
+        mat_convolve_window(
+            self,
+            &kernel,
+            (
+                Dynamic::new(mat_rows - tpl_rows + 1),
+                Dynamic::new(mat_cols - tpl_cols + 1),
+            ),
+            (tpl_rows - 1, tpl_cols - 1),
+        )
+    }
+
+    /// Returns the 2D convolution of the target matrix and the separable kernel
+    /// `col_kernel * row_kernel.transpose()`.
+    ///
+    /// The result is the same as the one of `mat_convolve_full` with that kernel, but
+    /// computed by convolving each row with `row_kernel`, and then each column with
+    /// `col_kernel`. This is much faster for large kernels.
+    /// Only implemented for `DMatrix` for now.
+    ///
+    /// # Arguments
+    ///
+    /// * `row_kernel` - A Vector with size > 0, convolved with each row of the target matrix.
+    /// * `col_kernel` - A Vector with size > 0, convolved with each column of the target matrix.
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= col_kernel.len() > 0` and
+    /// `self.ncols() >= row_kernel.len() > 0`.
+    pub fn mat_convolve_separable<D2, S2, D3, S3>(
+        &self,
+        row_kernel: Vector<T, D2, S2>,
+        col_kernel: Vector<T, D3, S3>,
+    ) -> DMatrix<T>
     where
         D2: Dim,
         S2: Storage<T, D2>,
-        DefaultAllocator: Allocator<T, D1>,
+        D3: Dim,
+        S3: Storage<T, D3>,
     {
-        let vec = self.len();
-        let ker = kernel.len();
+        let (mat_rows, mat_cols) = self.shape();
+        let (ker_rows, ker_cols) = (col_kernel.len(), row_kernel.len());
 
-        if ker == 0 || ker > vec {
-            panic!("convolve_same expects `self.len() >= kernel.len() > 0`, received {} and {} respectively.",vec,ker);
+        if ker_rows == 0 || ker_cols == 0 || ker_rows > mat_rows || ker_cols > mat_cols {
+            panic!(
+                "mat_convolve_separable expects `self.shape() >= (col_kernel.len(), row_kernel.len()) > 0`, received {:?} and {:?} respectively.",
+                (mat_rows, mat_cols),
+                (ker_rows, ker_cols)
+            );
         }
 
-        let mut conv = OVector::zeros_generic(self.data.shape().0, Const::<1>);
+        // Convolve the rows.
+        let mut tmp = DMatrix::zeros(mat_rows, mat_cols + ker_cols - 1);
 
-        for i in 0..vec {
-            for j in 0..ker {
-                let val = if i + j < 1 || i + j >= vec + 1 {
-                    zero::<T>()
-                } else {
-                    self[i + j - 1]
-                };
-                conv[i] += val * kernel[ker - j - 1];
+        for j in 0..mat_cols {
+            for k_j in 0..ker_cols {
+                tmp.column_mut(j + k_j)
+                    .axpy(row_kernel[k_j], &self.column(j), T::one());
+            }
+        }
+
+        // Convolve the columns.
+        let mut conv = DMatrix::zeros(mat_rows + ker_rows - 1, mat_cols + ker_cols - 1);
+
+        for j in 0..conv.ncols() {
+            let tmp_col = tmp.column(j);
+            let mut conv_col = conv.column_mut(j);
+
+            for k_i in 0..ker_rows {
+                conv_col
+                    .rows_mut(k_i, mat_rows)
+                    .axpy(col_kernel[k_i], &tmp_col, T::one());
             }
         }
 
         conv
     }
 }
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: RealField> DMatrix<T> {
+    /// Returns the normalized cross-correlation of the target matrix and a template.
+    ///
+    /// This is the output of `self.mat_correlate_valid(template)` where the element at `(i, j)`
+    /// is divided by the norm of `template` and by the norm of the window of `self` whose top-left
+    /// corner is at `(i, j)`. Each element is thus the cosine of the angle between the template
+    /// and a window, which lies in `[-1, 1]` and is `1` where the window is a positive multiple of
+    /// the template, so the position of the largest element locates the best match. The means of
+    /// the windows and of the template are not subtracted. Elements for which one of the norms is
+    /// zero are set to zero.
+    /// Only implemented for `DMatrix` for now.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - A Matrix with size > 0
+    ///
+    /// # Errors
+    /// Inputs must satisfy `self.nrows() >= template.nrows() > 0` and
+    /// `self.ncols() >= template.ncols() > 0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{DMatrix, Matrix2};
+    /// let image = DMatrix::from_fn(6, 7, |i, j| ((3 * i + 5 * j) % 4) as f64);
+    /// let template = image.slice((2, 3), (2, 2)).clone_owned();
+    /// let ncc = image.mat_ncc(template);
+    ///
+    /// assert_eq!(ncc.shape(), (5, 6));
+    /// assert!(ncc.iter().all(|e| (-1.0..=1.0).contains(e)));
+    /// assert!((ncc[(2, 3)] - 1.0).abs() < 1.0e-12);
+    /// ```
+    pub fn mat_ncc<R2, C2, S2>(&self, template: Matrix<T, R2, C2, S2>) -> DMatrix<T>
+    where
+        R2: Dim,
+        C2: Dim,
+        S2: Storage<T, R2, C2>,
+    {
+        let (mat_rows, mat_cols) = self.shape();
+        let (tpl_rows, tpl_cols) = check_mat_kernel_shape(self, &template, "mat_ncc");
+        let tpl_norm = template.norm();
+
+        DMatrix::from_fn(mat_rows - tpl_rows + 1, mat_cols - tpl_cols + 1, |i, j| {
+            let mut dot = T::zero();
+            let mut norm_squared = T::zero();
+
+            for t_j in 0..tpl_cols {
+                for t_i in 0..tpl_rows {
+                    let e = self[(i + t_i, j + t_j)];
+                    dot += e * template[(t_i, t_j)];
+                    norm_squared += e * e;
+                }
+            }
+
+            let denom = norm_squared.sqrt() * tpl_norm;
+
+            if denom == T::zero() {
+                T::zero()
+            } else {
+                // Rounding errors may push the quotient slightly out of `[-1, 1]`.
+                (dot / denom).clamp(-T::one(), T::one())
+            }
+        })
+    }
+}
+
+/// Checks that the kernel is non-empty and not larger than the target matrix, and returns its
+/// shape.
+fn check_mat_kernel_shape<T, R1, C1, S1, R2, C2, S2>(
+    mat: &Matrix<T, R1, C1, S1>,
+    kernel: &Matrix<T, R2, C2, S2>,
+    name: &str,
+) -> (usize, usize)
+where
+    T: ComplexField,
+    R1: Dim,
+    C1: Dim,
+    S1: Storage<T, R1, C1>,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<T, R2, C2>,
+{
+    let (mat_rows, mat_cols) = mat.shape();
+    let (ker_rows, ker_cols) = kernel.shape();
+
+    if ker_rows == 0 || ker_cols == 0 || ker_rows > mat_rows || ker_cols > mat_cols {
+        panic!(
+            "{} expects `self.shape() >= kernel.shape() > 0`, received {:?} and {:?} respectively.",
+            name,
+            (mat_rows, mat_cols),
+            (ker_rows, ker_cols)
+        );
+    }
+
+    (ker_rows, ker_cols)
+}
+
+/// Computes the `shape.0 x shape.1` window of the ‘full’ convolution of the zero-padded `mat`
+/// and `kernel`, starting at the row and column `offset`.
+fn mat_convolve_window<T, R1, C1, S1, R2, C2, S2, R3, C3>(
+    mat: &Matrix<T, R1, C1, S1>,
+    kernel: &Matrix<T, R2, C2, S2>,
+    shape: (R3, C3),
+    offset: (usize, usize),
+) -> OMatrix<T, R3, C3>
+where
+    T: ComplexField,
+    R1: Dim,
+    C1: Dim,
+    S1: Storage<T, R1, C1>,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<T, R2, C2>,
+    R3: Dim,
+    C3: Dim,
+    DefaultAllocator: Allocator<T, R3, C3>,
+{
+    let (mat_rows, mat_cols) = mat.shape();
+    let (ker_rows, ker_cols) = kernel.shape();
+
+    OMatrix::from_fn_generic(shape.0, shape.1, |i, j| {
+        // Indices of the element of the ‘full’ convolution.
+        let (full_i, full_j) = (i + offset.0, j + offset.1);
+        let mut res = T::zero();
+
+        // Only iterate on the kernel elements that don't hit the zero-padding.
+        for k_j in (full_j + 1).saturating_sub(mat_cols)..cmp::min(ker_cols, full_j + 1) {
+            for k_i in (full_i + 1).saturating_sub(mat_rows)..cmp::min(ker_rows, full_i + 1) {
+                res += mat[(full_i - k_i, full_j - k_j)] * kernel[(k_i, k_j)];
+            }
+        }
+
+        res
+    })
+}
+
+/// Computes the `shape.0 x shape.1` window of the ‘full’ convolution of `mat` and `kernel`,
+/// starting at the row and column `offset`, where `mat` is extended past its borders as
+/// specified by `border`.
+fn mat_convolve_window_with_border<T, R1, C1, S1, R2, C2, S2, R3, C3>(
+    mat: &Matrix<T, R1, C1, S1>,
+    kernel: &Matrix<T, R2, C2, S2>,
+    shape: (R3, C3),
+    offset: (usize, usize),
+    border: BorderMode,
+) -> OMatrix<T, R3, C3>
+where
+    T: ComplexField,
+    R1: Dim,
+    C1: Dim,
+    S1: Storage<T, R1, C1>,
+    R2: Dim,
+    C2: Dim,
+    S2: Storage<T, R2, C2>,
+    R3: Dim,
+    C3: Dim,
+    DefaultAllocator: Allocator<T, R3, C3>,
+{
+    if border == BorderMode::Zero {
+        return mat_convolve_window(mat, kernel, shape, offset);
+    }
+
+    let (mat_rows, mat_cols) = mat.shape();
+    let (ker_rows, ker_cols) = kernel.shape();
+
+    OMatrix::from_fn_generic(shape.0, shape.1, |i, j| {
+        // Indices of the element of the ‘full’ convolution.
+        let (full_i, full_j) = ((i + offset.0) as isize, (j + offset.1) as isize);
+        let mut res = T::zero();
+
+        for k_j in 0..ker_cols {
+            if let Some(mat_j) = border.resolve(full_j - k_j as isize, mat_cols) {
+                for k_i in 0..ker_rows {
+                    if let Some(mat_i) = border.resolve(full_i - k_i as isize, mat_rows) {
+                        res += mat[(mat_i, mat_j)] * kernel[(k_i, k_j)];
+                    }
+                }
+            }
+        }
+
+        res
+    })
+}
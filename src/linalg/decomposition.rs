@@ -1,8 +1,9 @@
 use crate::storage::Storage;
 use crate::{
     Allocator, Bidiagonal, Cholesky, ColPivQR, ComplexField, DefaultAllocator, Dim, DimDiff,
-    DimMin, DimMinimum, DimSub, FullPivLU, Hessenberg, Matrix, RealField, Schur, SymmetricEigen,
-    SymmetricTridiagonal, LU, QR, SVD, U1, UDU,
+    DimMin, DimMinimum, DimSub, EigenStats, FullPivLU, GeneralizedSymmetricEigen, Hessenberg,
+    Matrix, OMatrix, OVector, RealField, Schur, SchurStats, SymmetricEigen, SymmetricTridiagonal,
+    LU, QR, SVD, U1, UDU,
 };
 
 /// # Rectangular matrix decomposition
@@ -205,6 +206,30 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
         Schur::try_new(self.into_owned(), eps, max_niter)
     }
 
+    /// Attempts to compute the Schur decomposition of a square matrix, and reports convergence
+    /// statistics.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `Err` is returned with the statistics of the last
+    /// iteration. If `niter == 0`, then the algorithm continues indefinitely until convergence.
+    pub fn try_schur_with_stats(
+        self,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Result<(Schur<T, D>, SchurStats), SchurStats>
+    where
+        D: DimSub<U1>, // For Hessenberg.
+        DefaultAllocator: Allocator<T, D, DimDiff<D, U1>>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T, D, D>
+            + Allocator<T, D>,
+    {
+        Schur::try_new_with_stats(self.into_owned(), eps, max_niter)
+    }
+
     /// Computes the eigendecomposition of this symmetric matrix.
     ///
     /// Only the lower-triangular part (including the diagonal) of `m` is read.
@@ -245,6 +270,51 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
         SymmetricEigen::try_new(self.into_owned(), eps, max_niter)
     }
 
+    /// Computes the eigendecomposition of the given symmetric matrix with user-specified
+    /// convergence parameters, and reports convergence statistics.
+    ///
+    /// Only the lower-triangular part (including the diagonal) of `m` is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `Err` is returned with the statistics of the last
+    /// iteration. If `niter == 0`, then the algorithm continues indefinitely until convergence.
+    pub fn try_symmetric_eigen_with_stats(
+        self,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Result<(SymmetricEigen<T, D>, EigenStats<T::RealField>), EigenStats<T::RealField>>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, D, D>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T::RealField, D>
+            + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        SymmetricEigen::try_new_with_stats(self.into_owned(), eps, max_niter)
+    }
+
+    /// Computes the eigendecomposition of the generalized eigenproblem `self * x = λ * b * x`,
+    /// where `self` is symmetric and `b` is symmetric definite-positive.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `self` and `b` are read.
+    /// Returns `None` if `b` is not definite-positive.
+    pub fn symmetric_eigen_generalized<S2: Storage<T, D, D>>(
+        self,
+        b: Matrix<T, D, D, S2>,
+    ) -> Option<GeneralizedSymmetricEigen<T, D>>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, D, D>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T::RealField, D>
+            + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        GeneralizedSymmetricEigen::new(self.into_owned(), b.into_owned())
+    }
+
     /// Computes the tridiagonalization of this symmetric matrix.
     ///
     /// Only the lower-triangular part (including the diagonal) of `m` is read.
@@ -255,4 +325,47 @@ impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> Matrix<T, D, D, S> {
     {
         SymmetricTridiagonal::new(self.into_owned())
     }
+
+    /// Computes the tridiagonalization `Q * T * Qᴴ` of this symmetric matrix, and returns the
+    /// diagonal and the off-diagonal of the real symmetric tridiagonal matrix `T`, and the
+    /// unitary matrix `Q`.
+    ///
+    /// This is the same as `self.clone_owned().symmetric_tridiagonalize().unpack()`, except for
+    /// the order of the returned components. `T` can then be used by a custom eigenvalue
+    /// algorithm, e.g. by bisection.
+    ///
+    /// Only the lower-triangular part (including the diagonal) of `m` is read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(4.0, 1.0, 2.0,
+    ///                      1.0, 3.0, 0.5,
+    ///                      2.0, 0.5, 1.0);
+    /// let (diag, off_diag, q) = m.symmetric_tridiagonal_parts();
+    ///
+    /// let t = Matrix3::new(diag[0],     off_diag[0], 0.0,
+    ///                      off_diag[0], diag[1],     off_diag[1],
+    ///                      0.0,         off_diag[1], diag[2]);
+    /// assert_relative_eq!(q * t * q.transpose(), m, epsilon = 1.0e-12);
+    /// ```
+    #[must_use]
+    pub fn symmetric_tridiagonal_parts(
+        &self,
+    ) -> (
+        OVector<T::RealField, D>,
+        OVector<T::RealField, DimDiff<D, U1>>,
+        OMatrix<T, D, D>,
+    )
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, D, D>
+            + Allocator<T, DimDiff<D, U1>>
+            + Allocator<T::RealField, D>
+            + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        let (q, diag, off_diag) = SymmetricTridiagonal::new(self.clone_owned()).unpack();
+        (diag, off_diag, q)
+    }
 }
@@ -435,6 +435,20 @@ where
         + Allocator<T::RealField, D, D>,
 {
     /// Computes exponential of this matrix
+    ///
+    /// This uses the scaling-and-squaring algorithm with Padé approximants from Al-Mohy and
+    /// Higham (2009), where the order of the approximant and the number of squarings are chosen
+    /// from estimates of the 1-norm of powers of the matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Rotation3, Vector3};
+    /// // The exponential of a skew-symmetric matrix is a rotation matrix.
+    /// let axisangle = Vector3::new(0.1, 0.2, 0.3);
+    /// let rot = axisangle.cross_matrix().exp();
+    /// assert_relative_eq!(rot, *Rotation3::new(axisangle).matrix(), epsilon = 1.0e-7);
+    /// ```
     #[must_use]
     pub fn exp(&self) -> Self {
         // Simple case
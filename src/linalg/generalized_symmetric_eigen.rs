@@ -0,0 +1,118 @@
+#[cfg(feature = "serde-serialize-no-std")]
+use serde::{Deserialize, Serialize};
+
+use approx::AbsDiffEq;
+use simba::scalar::ComplexField;
+
+use crate::allocator::Allocator;
+use crate::base::{DefaultAllocator, OMatrix, OVector};
+use crate::dimension::{Dim, DimDiff, DimSub, U1};
+
+use crate::linalg::{Cholesky, SymmetricEigen};
+
+/// Eigendecomposition of a generalized symmetric-definite eigenproblem `A x = λ B x`, where `A`
+/// is symmetric and `B` is symmetric definite-positive.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(serialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T::RealField, D>,
+         OVector<T::RealField, D>: Serialize,
+         OMatrix<T, D, D>: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde-serialize-no-std",
+    serde(bound(deserialize = "DefaultAllocator: Allocator<T, D, D> +
+                           Allocator<T::RealField, D>,
+         OVector<T::RealField, D>: Deserialize<'de>,
+         OMatrix<T, D, D>: Deserialize<'de>"))
+)]
+#[derive(Clone, Debug)]
+pub struct GeneralizedSymmetricEigen<T: ComplexField, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T::RealField, D>,
+{
+    /// The `B`-orthonormal eigenvectors of the decomposed problem, i.e., such that
+    /// `eigenvectors.adjoint() * B * eigenvectors` is the identity matrix.
+    pub eigenvectors: OMatrix<T, D, D>,
+
+    /// The unsorted eigenvalues of the decomposed problem.
+    pub eigenvalues: OVector<T::RealField, D>,
+}
+
+impl<T: ComplexField, D: Dim> Copy for GeneralizedSymmetricEigen<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T::RealField, D>,
+    OMatrix<T, D, D>: Copy,
+    OVector<T::RealField, D>: Copy,
+{
+}
+
+impl<T: ComplexField, D: Dim> GeneralizedSymmetricEigen<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T::RealField, D>,
+{
+    /// Computes the eigendecomposition of the generalized eigenproblem `a x = λ b x`.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `a` and `b` are read.
+    /// Returns `None` if `b` is not definite-positive.
+    pub fn new(a: OMatrix<T, D, D>, b: OMatrix<T, D, D>) -> Option<Self>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        Self::try_new(a, b, T::RealField::default_epsilon(), 0)
+    }
+
+    /// Computes the eigendecomposition of the generalized eigenproblem `a x = λ b x` with
+    /// user-specified convergence parameters.
+    ///
+    /// Only the lower-triangular parts (including the diagonals) of `a` and `b` are read.
+    /// Returns `None` if `b` is not definite-positive.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
+    /// continues indefinitely until convergence.
+    pub fn try_new(
+        mut a: OMatrix<T, D, D>,
+        b: OMatrix<T, D, D>,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Option<Self>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        assert!(
+            a.is_square() && a.shape() == b.shape(),
+            "Unable to compute the generalized eigendecomposition of non-square or mismatched matrices."
+        );
+
+        let chol = Cholesky::new(b)?;
+        let l = chol.l_dirty();
+
+        // Reduce to the standard eigenproblem `C y = λ y` with `C = L⁻¹ A L⁻ᴴ` and `x = L⁻ᴴ y`.
+        let dim = a.nrows();
+        for j in 1..dim {
+            for i in 0..j {
+                a[(i, j)] = a[(j, i)].conjugate();
+            }
+        }
+
+        l.solve_lower_triangular_unchecked_mut(&mut a);
+        a.adjoint_mut();
+        l.solve_lower_triangular_unchecked_mut(&mut a);
+
+        let eig = SymmetricEigen::try_new(a, eps, max_niter)?;
+        let mut eigenvectors = eig.eigenvectors;
+        l.ad_solve_lower_triangular_unchecked_mut(&mut eigenvectors);
+
+        Some(Self {
+            eigenvectors,
+            eigenvalues: eig.eigenvalues,
+        })
+    }
+}
@@ -162,3 +162,76 @@ impl<T: ComplexField> GivensRotation<T> {
         }
     }
 }
+
+impl<T: ComplexField, R: Dim, C: Dim, S: StorageMut<T, R, C>> Matrix<T, R, C, S> {
+    /// Applies the Givens rotation `rot` to the rows `i` and `j` of this matrix, in-place.
+    ///
+    /// This performs the multiplication `self = G * self`, where `G` is the identity matrix
+    /// except for `G[(i, i)] = G[(j, j)] = c`, `G[(i, j)] = -conjugate(s)` and `G[(j, i)] = s`. With
+    /// `i = 0` and `j = 1`, this matches [`GivensRotation::rotate`] applied to the first two rows.
+    ///
+    /// # Panics
+    /// Panics if `i == j`, or if `i` or `j` is not a valid row index.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector2};
+    /// # use nalgebra::linalg::givens::GivensRotation;
+    /// let mut m = Matrix3::new(1.0, 2.0, 3.0,
+    ///                          4.0, 5.0, 6.0,
+    ///                          3.0, 8.0, 9.0);
+    /// // Cancel the element at (2, 0) against the one at (1, 0).
+    /// let (rot, norm) = GivensRotation::cancel_y(&Vector2::new(m[(1, 0)], m[(2, 0)])).unwrap();
+    /// m.apply_givens_rows(1, 2, &rot);
+    ///
+    /// assert_relative_eq!(norm, 5.0);
+    /// assert_relative_eq!(m[(1, 0)], 5.0);
+    /// assert_relative_eq!(m[(2, 0)], 0.0, epsilon = 1.0e-12);
+    /// ```
+    pub fn apply_givens_rows(&mut self, i: usize, j: usize, rot: &GivensRotation<T>) {
+        assert!(
+            i != j && i < self.nrows() && j < self.nrows(),
+            "Givens rotation: the row indices must be distinct and in bounds."
+        );
+        let s = rot.s();
+        let c = rot.c();
+
+        for k in 0..self.ncols() {
+            unsafe {
+                let a = *self.get_unchecked((i, k));
+                let b = *self.get_unchecked((j, k));
+
+                *self.get_unchecked_mut((i, k)) = a.scale(c) - s.conjugate() * b;
+                *self.get_unchecked_mut((j, k)) = s * a + b.scale(c);
+            }
+        }
+    }
+
+    /// Applies the Givens rotation `rot` to the columns `i` and `j` of this matrix, in-place.
+    ///
+    /// This performs the multiplication `self = self * G`, with the same matrix `G` as
+    /// [`Matrix::apply_givens_rows`]. With `i = 0` and `j = 1`, this matches
+    /// [`GivensRotation::rotate_rows`] applied to the first two columns.
+    ///
+    /// # Panics
+    /// Panics if `i == j`, or if `i` or `j` is not a valid column index.
+    pub fn apply_givens_columns(&mut self, i: usize, j: usize, rot: &GivensRotation<T>) {
+        assert!(
+            i != j && i < self.ncols() && j < self.ncols(),
+            "Givens rotation: the column indices must be distinct and in bounds."
+        );
+        let s = rot.s();
+        let c = rot.c();
+
+        for k in 0..self.nrows() {
+            unsafe {
+                let a = *self.get_unchecked((k, i));
+                let b = *self.get_unchecked((k, j));
+
+                *self.get_unchecked_mut((k, i)) = a.scale(c) + s * b;
+                *self.get_unchecked_mut((k, j)) = -s.conjugate() * a + b.scale(c);
+            }
+        }
+    }
+}
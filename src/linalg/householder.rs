@@ -115,17 +115,32 @@ where
     assert!(m.is_square());
     let dim = m.data.shape().0;
 
+    let mut res = OMatrix::identity_generic(dim, dim);
+    assemble_q_mut(m, signs, &mut res);
+    res
+}
+
+/// Computes the orthogonal transformation described by the elementary reflector axii stored on
+/// the lower-diagonal element of the given matrix, and stores it into `out`.
+pub(crate) fn assemble_q_mut<T: ComplexField, D: Dim>(
+    m: &OMatrix<T, D, D>,
+    signs: &[T],
+    out: &mut OMatrix<T, D, D>,
+) where
+    DefaultAllocator: Allocator<T, D, D>,
+{
+    assert!(m.is_square());
+    assert_eq!(m.shape(), out.shape(), "Q matrix dimension mismatch.");
+
     // NOTE: we could build the identity matrix and call p_mult on it.
     // Instead we don't so that we take in account the matrix sparseness.
-    let mut res = OMatrix::identity_generic(dim, dim);
+    out.fill_with_identity();
 
-    for i in (0..dim.value() - 1).rev() {
+    for i in (0..m.nrows() - 1).rev() {
         let axis = m.slice_range(i + 1.., i);
         let refl = Reflection::new(Unit::new_unchecked(axis), T::zero());
 
-        let mut res_rows = res.slice_range_mut(i + 1.., i..);
+        let mut res_rows = out.slice_range_mut(i + 1.., i..);
         refl.reflect_with_sign(&mut res_rows, signs[i].signum());
     }
-
-    res
 }
@@ -0,0 +1,128 @@
+//! This module provides the principal logarithm of square matrices.
+
+use num_complex::Complex as NumComplex;
+use simba::scalar::RealField;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimDiff, DimSub, U1};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, OMatrix, SquareMatrix};
+
+/// Maximum number of square roots taken before giving up.
+const MAX_INVERSE_SCALING_ITERATIONS: usize = 64;
+
+/// Nodes and weights of the 8-point Gauss–Legendre quadrature rule on `[0, 1]`.
+///
+/// Evaluating `log(I + X) = ∫₀¹ X (I + tX)⁻¹ dt` with this rule is equivalent to the `[8/8]` Padé
+/// approximant of `log(I + X)`.
+const GAUSS_LEGENDRE_8: [(f64, f64); 8] = [
+    (1.985_507_175_123_188_4e-2, 5.061_426_814_518_813e-2),
+    (1.016_667_612_931_866_3e-1, 1.111_905_172_266_872_4e-1),
+    (2.372_337_950_418_355e-1, 1.568_533_229_389_436_4e-1),
+    (4.082_826_787_521_751e-1, 1.813_418_916_891_81e-1),
+    (5.917_173_212_478_25e-1, 1.813_418_916_891_81e-1),
+    (7.627_662_049_581_645e-1, 1.568_533_229_389_436_4e-1),
+    (8.983_332_387_068_134e-1, 1.111_905_172_266_872_4e-1),
+    (9.801_449_282_487_681e-1, 5.061_426_814_518_813e-2),
+];
+
+impl<T: RealField, D: DimSub<U1>, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, D>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T, D, DimDiff<D, U1>>
+        + Allocator<NumComplex<T>, D>,
+{
+    /// Computes the principal logarithm of this matrix, i.e., the unique matrix `X` with
+    /// eigenvalues whose imaginary parts lie in `]-π, π[` such that `X.exp() == self`.
+    ///
+    /// Returns `None` if this matrix has an eigenvalue on the closed negative real axis (including
+    /// zero), in which case the principal logarithm is not defined.
+    ///
+    /// If this matrix is symmetric, its logarithm is computed from its eigendecomposition by
+    /// taking the logarithm of each eigenvalue. Otherwise, the eigenvalues are checked with the
+    /// Schur decomposition, and the inverse scaling-and-squaring method is used: square roots
+    /// are taken until the matrix is close to the identity, then the logarithm is evaluated with
+    /// an `[8/8]` Padé approximant and scaled back.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(1.0, 2.0, 0.0,
+    ///                      -1.0, 0.5, 0.3,
+    ///                      0.0, 0.2, 0.8);
+    /// let log = m.exp().log().unwrap();
+    /// assert_relative_eq!(log, m, epsilon = 1.0e-10);
+    ///
+    /// // The principal logarithm is not defined for negative eigenvalues.
+    /// assert!(Matrix3::from_diagonal_element(-1.0).log().is_none());
+    /// ```
+    #[must_use]
+    pub fn log(&self) -> Option<OMatrix<T, D, D>> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the logarithm of a non-square matrix."
+        );
+
+        if self.is_empty() {
+            return Some(self.clone_owned());
+        }
+
+        if self.is_symmetric(T::default_epsilon()) {
+            return self.symmetric_log();
+        }
+
+        let tol = T::default_epsilon().sqrt() * self.norm();
+
+        for val in self.complex_eigenvalues().iter() {
+            if val.re <= tol && val.im.abs() <= tol {
+                return None;
+            }
+        }
+
+        let (nrows, ncols) = self.data.shape();
+        let identity = OMatrix::identity_generic(nrows, ncols);
+        let threshold: T = crate::convert(0.25);
+        let mut x = self.clone_owned();
+        let mut scale = T::one();
+        let mut nsqrts = 0;
+
+        // log(A) = 2ᵏ log(A^(1/2ᵏ)), and the Padé approximant is accurate near the identity.
+        while (&x - &identity).norm() > threshold {
+            if nsqrts == MAX_INVERSE_SCALING_ITERATIONS {
+                return None;
+            }
+
+            x = x.sqrt()?;
+            scale *= crate::convert(2.0);
+            nsqrts += 1;
+        }
+
+        x -= &identity;
+
+        let mut res = OMatrix::zeros_generic(nrows, ncols);
+
+        for (node, weight) in GAUSS_LEGENDRE_8.iter() {
+            let denom = &identity + &x * crate::convert::<f64, T>(*node);
+            res += denom.try_inverse()? * &x * crate::convert::<f64, T>(*weight);
+        }
+
+        Some(res * scale)
+    }
+
+    fn symmetric_log(&self) -> Option<OMatrix<T, D, D>> {
+        let mut eigen = self.clone_owned().symmetric_eigen();
+
+        for val in eigen.eigenvalues.iter_mut() {
+            if *val <= T::zero() {
+                return None;
+            }
+
+            *val = val.ln();
+        }
+
+        Some(eigen.recompose())
+    }
+}
@@ -1,6 +1,8 @@
 //! [Reexported at the root of this crate.] Factorization of real matrices.
 
 pub mod balancing;
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod banded;
 mod bidiagonal;
 mod cholesky;
 mod convolution;
@@ -13,16 +15,20 @@ mod decomposition;
 #[cfg(feature = "std")]
 mod exp;
 mod full_piv_lu;
+mod generalized_symmetric_eigen;
 pub mod givens;
 mod hessenberg;
 pub mod householder;
 mod inverse;
+mod log;
 mod lu;
 mod permutation_sequence;
 mod pow;
+mod power_iteration;
 mod qr;
 mod schur;
 mod solve;
+mod sqrt;
 mod svd;
 mod symmetric_eigen;
 mod symmetric_tridiagonal;
@@ -32,6 +38,8 @@ mod udu;
 //// This handles only cases where each eigenvalue has multiplicity one.
 // mod eigen;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub use self::banded::*;
 pub use self::bidiagonal::*;
 pub use self::cholesky::*;
 pub use self::col_piv_qr::*;
@@ -39,6 +47,7 @@ pub use self::convolution::*;
 #[cfg(feature = "std")]
 pub use self::exp::*;
 pub use self::full_piv_lu::*;
+pub use self::generalized_symmetric_eigen::*;
 pub use self::hessenberg::*;
 pub use self::lu::*;
 pub use self::permutation_sequence::*;
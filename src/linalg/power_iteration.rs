@@ -0,0 +1,98 @@
+//! This module provides the power iteration for estimating the dominant eigenpair of square matrices.
+
+use num::Zero;
+use simba::scalar::ComplexField;
+use std::mem;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::Dim;
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, OVector, SquareMatrix};
+
+impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    DefaultAllocator: Allocator<T, D>,
+{
+    /// Estimates the eigenvalue of largest magnitude of this matrix, and an associated unit
+    /// eigenvector, with the power iteration.
+    ///
+    /// Starting from `initial`, each iterate is multiplied by this matrix then normalized. The
+    /// eigenvalue is estimated by the Rayleigh quotient `ρ = xᴴ * A * x` of the current iterate,
+    /// and the iteration stops as soon as two successive estimates differ by at most `eps` times
+    /// the magnitude of the latest one. The residual `|A * x - ρ * x|` must also be at most
+    /// `sqrt(eps) * |A * x|`, so that an estimate that stagnates without `x` being an
+    /// eigenvector is not mistaken for convergence.
+    ///
+    /// Each iteration costs one matrix-vector product, and the error decreases like `|λ₂ / λ₁|ᵏ`,
+    /// where `λ₁` and `λ₂` are the two eigenvalues of largest magnitude. This makes it a cheap
+    /// way of estimating the spectral radius of a matrix.
+    ///
+    /// Returns `None` if `initial` is zero, if an iterate is mapped to zero by this matrix, or if
+    /// the estimates did not converge after `max_niter` iterations (e.g. because the two
+    /// eigenvalues of largest magnitude are distinct but have the same modulus). Note that if
+    /// `initial` is exactly orthogonal to the dominant eigenvector, the iteration may converge
+    /// to another eigenpair instead.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square, or if the dimension of `initial` does not match.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let m = Matrix3::new(2.0, 1.0, 0.0,
+    ///                      1.0, 3.0, 1.0,
+    ///                      0.0, 1.0, 4.0);
+    /// let (value, vector) = m.power_iteration(Vector3::repeat(1.0), 1000, 1.0e-14).unwrap();
+    /// assert_relative_eq!(value, 3.0 + 3.0f64.sqrt(), epsilon = 1.0e-10);
+    /// assert_relative_eq!(m * vector, vector * value, epsilon = 1.0e-6);
+    /// ```
+    #[must_use]
+    pub fn power_iteration(
+        &self,
+        initial: OVector<T, D>,
+        max_niter: usize,
+        eps: T::RealField,
+    ) -> Option<(T, OVector<T, D>)> {
+        assert!(
+            self.is_square(),
+            "Unable to run the power iteration on a non-square matrix."
+        );
+        assert_eq!(
+            initial.nrows(),
+            self.nrows(),
+            "The initial vector must have the same dimension as the matrix."
+        );
+
+        let mut x = initial.try_normalize(T::RealField::zero())?;
+        let mut y = x.clone();
+        let mut prev_rho: Option<T> = None;
+
+        for _ in 0..max_niter {
+            y.gemv(T::one(), self, &x, T::zero());
+            let rho = x.dotc(&y);
+            let norm_squared = y.norm_squared();
+
+            if norm_squared.is_zero() {
+                return None;
+            }
+
+            // Since `x` has a unit norm, `|A * x - rho * x|² = |A * x|² - |rho|²`.
+            let residual_squared = norm_squared - rho.modulus_squared();
+            y.unscale_mut(norm_squared.sqrt());
+            mem::swap(&mut x, &mut y);
+
+            if let Some(prev_rho) = prev_rho {
+                if (rho - prev_rho).modulus() <= eps * rho.modulus()
+                    && residual_squared <= eps * norm_squared
+                {
+                    return Some((rho, x));
+                }
+            }
+
+            prev_rho = Some(rho);
+        }
+
+        None
+    }
+}
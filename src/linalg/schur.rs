@@ -47,6 +47,18 @@ where
 {
 }
 
+/// Convergence statistics of the Schur decomposition algorithm.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SchurStats {
+    /// The number of implicit double-shift QR sweeps performed by the algorithm.
+    pub qr_sweeps: usize,
+
+    /// The number of times a converged eigenvalue, or a converged 2x2 block, was split off the
+    /// active subproblem.
+    pub deflations: usize,
+}
+
 impl<T: ComplexField, D: Dim> Schur<T, D>
 where
     D: DimSub<U1>, // For Hessenberg.
@@ -72,11 +84,30 @@ where
     /// number of iteration is exceeded, `None` is returned. If `niter == 0`, then the algorithm
     /// continues indefinitely until convergence.
     pub fn try_new(m: OMatrix<T, D, D>, eps: T::RealField, max_niter: usize) -> Option<Self> {
+        Self::try_new_with_stats(m, eps, max_niter)
+            .ok()
+            .map(|(schur, _)| schur)
+    }
+
+    /// Attempts to compute the Schur decomposition of a square matrix, and reports convergence
+    /// statistics.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `Err` is returned with the statistics of the last
+    /// iteration. If `niter == 0`, then the algorithm continues indefinitely until convergence.
+    pub fn try_new_with_stats(
+        m: OMatrix<T, D, D>,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Result<(Self, SchurStats), SchurStats> {
         let mut work =
             unsafe { crate::unimplemented_or_uninitialized_generic!(m.data.shape().0, Const::<1>) };
 
         Self::do_decompose(m, &mut work, eps, max_niter, true)
-            .map(|(q, t)| Schur { q: q.unwrap(), t })
+            .map(|(q, t, stats)| (Schur { q: q.unwrap(), t }, stats))
     }
 
     fn do_decompose(
@@ -85,28 +116,32 @@ where
         eps: T::RealField,
         max_niter: usize,
         compute_q: bool,
-    ) -> Option<(Option<OMatrix<T, D, D>>, OMatrix<T, D, D>)> {
+    ) -> Result<(Option<OMatrix<T, D, D>>, OMatrix<T, D, D>, SchurStats), SchurStats> {
         assert!(
             m.is_square(),
             "Unable to compute the eigenvectors and eigenvalues of a non-square matrix."
         );
 
         let dim = m.data.shape().0;
+        let mut stats = SchurStats {
+            qr_sweeps: 0,
+            deflations: 0,
+        };
 
         // Specialization would make this easier.
         if dim.value() == 0 {
             let vecs = Some(OMatrix::from_element_generic(dim, dim, T::zero()));
             let vals = OMatrix::from_element_generic(dim, dim, T::zero());
-            return Some((vecs, vals));
+            return Ok((vecs, vals, stats));
         } else if dim.value() == 1 {
             if compute_q {
                 let q = OMatrix::from_element_generic(dim, dim, T::one());
-                return Some((Some(q), m));
+                return Ok((Some(q), m, stats));
             } else {
-                return Some((None, m));
+                return Ok((None, m, stats));
             }
         } else if dim.value() == 2 {
-            return decompose_2x2(m, compute_q);
+            return Ok(decompose_2x2(m, compute_q));
         }
 
         let amax_m = m.camax();
@@ -129,12 +164,15 @@ where
 
         // Implicit double-shift QR method.
         let mut niter = 0;
-        let (mut start, mut end) = Self::delimit_subproblem(&mut t, eps, dim.value() - 1);
+        let (mut start, mut end) =
+            Self::delimit_subproblem(&mut t, eps, dim.value() - 1, &mut stats.deflations);
 
         while end != start {
             let subdim = end - start + 1;
 
             if subdim > 2 {
+                stats.qr_sweeps += 1;
+
                 let m = end - 1;
                 let n = end;
 
@@ -245,6 +283,8 @@ where
                     }
                 }
 
+                stats.deflations += 1;
+
                 // Check if we reached the beginning of the matrix.
                 if end > 2 {
                     end -= 2;
@@ -253,20 +293,20 @@ where
                 }
             }
 
-            let sub = Self::delimit_subproblem(&mut t, eps, end);
+            let sub = Self::delimit_subproblem(&mut t, eps, end, &mut stats.deflations);
 
             start = sub.0;
             end = sub.1;
 
             niter += 1;
             if niter == max_niter {
-                return None;
+                return Err(stats);
             }
         }
 
         t.scale_mut(amax_m);
 
-        Some((q, t))
+        Ok((q, t, stats))
     }
 
     /// Computes the eigenvalues of the decomposed matrix.
@@ -336,7 +376,12 @@ where
         }
     }
 
-    fn delimit_subproblem(t: &mut OMatrix<T, D, D>, eps: T::RealField, end: usize) -> (usize, usize)
+    fn delimit_subproblem(
+        t: &mut OMatrix<T, D, D>,
+        eps: T::RealField,
+        end: usize,
+        deflations: &mut usize,
+    ) -> (usize, usize)
     where
         D: DimSub<U1>,
         DefaultAllocator: Allocator<T, DimDiff<D, U1>>,
@@ -348,6 +393,7 @@ where
 
             if t[(n, m)].norm1() <= eps * (t[(n, n)].norm1() + t[(m, m)].norm1()) {
                 t[(n, m)] = T::zero();
+                *deflations += 1;
             } else {
                 break;
             }
@@ -416,14 +462,21 @@ where
 fn decompose_2x2<T: ComplexField, D: Dim>(
     mut m: OMatrix<T, D, D>,
     compute_q: bool,
-) -> Option<(Option<OMatrix<T, D, D>>, OMatrix<T, D, D>)>
+) -> (Option<OMatrix<T, D, D>>, OMatrix<T, D, D>, SchurStats)
 where
     DefaultAllocator: Allocator<T, D, D>,
 {
     let dim = m.data.shape().0;
     let mut q = None;
+    let mut stats = SchurStats {
+        qr_sweeps: 0,
+        deflations: 0,
+    };
+
     match compute_2x2_basis(&m.fixed_slice::<2, 2>(0, 0)) {
         Some(rot) => {
+            stats.deflations = 1;
+
             let mut m = m.fixed_slice_mut::<2, 2>(0, 0);
             let inv_rot = rot.inverse();
             inv_rot.rotate(&mut m);
@@ -448,7 +501,7 @@ where
         }
     };
 
-    Some((q, m))
+    (q, m, stats)
 }
 
 fn compute_2x2_eigvals<T: ComplexField, S: Storage<T, U2, U2>>(
@@ -558,6 +611,29 @@ where
     #[must_use]
     pub fn complex_eigenvalues(&self) -> OVector<NumComplex<T>, D>
     // TODO: add balancing?
+    where
+        T: RealField,
+        DefaultAllocator: Allocator<NumComplex<T>, D>,
+    {
+        self.try_complex_eigenvalues_with_stats(T::default_epsilon(), 0)
+            .unwrap()
+            .0
+    }
+
+    /// Attempts to compute the complex eigenvalues of this matrix, and reports the convergence
+    /// statistics of the underlying Schur decomposition.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `Err` is returned with the statistics of the last
+    /// iteration. If `niter == 0`, then the algorithm continues indefinitely until convergence.
+    pub fn try_complex_eigenvalues_with_stats(
+        &self,
+        eps: T,
+        max_niter: usize,
+    ) -> Result<(OVector<NumComplex<T>, D>, SchurStats), SchurStats>
     where
         T: RealField,
         DefaultAllocator: Allocator<NumComplex<T>, D>,
@@ -565,16 +641,9 @@ where
         let dim = self.data.shape().0;
         let mut work = unsafe { crate::unimplemented_or_uninitialized_generic!(dim, Const::<1>) };
 
-        let schur = Schur::do_decompose(
-            self.clone_owned(),
-            &mut work,
-            T::default_epsilon(),
-            0,
-            false,
-        )
-        .unwrap();
+        let schur = Schur::do_decompose(self.clone_owned(), &mut work, eps, max_niter, false)?;
         let mut eig = unsafe { crate::unimplemented_or_uninitialized_generic!(dim, Const::<1>) };
         Schur::do_complex_eigenvalues(&schur.1, &mut eig);
-        eig
+        Ok((eig, schur.2))
     }
 }
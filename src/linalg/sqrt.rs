@@ -0,0 +1,114 @@
+//! This module provides the principal square root of square matrices.
+
+use simba::scalar::RealField;
+
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{DimDiff, DimSub, U1};
+use crate::base::storage::Storage;
+use crate::base::{DefaultAllocator, OMatrix, SquareMatrix};
+
+/// Maximum number of Denman–Beavers iterations before giving up.
+const MAX_DENMAN_BEAVERS_ITERATIONS: usize = 100;
+
+impl<T: RealField, D: DimSub<U1>, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D> + Allocator<T, DimDiff<D, U1>>,
+{
+    /// Computes the principal square root of this matrix, i.e., the unique matrix `X` with
+    /// eigenvalues in the open right half-plane such that `X * X == self`.
+    ///
+    /// If this matrix is symmetric, its square root is computed from its eigendecomposition by
+    /// taking the square root of each eigenvalue. `None` is returned if one of the eigenvalues
+    /// is negative, up to a tolerance of `sqrt(ε)` relative to the largest eigenvalue magnitude
+    /// (in which case it is treated as zero). Eigenvalues smaller than the rounding error of the
+    /// eigendecomposition are treated as zero as well, so singular positive semi-definite
+    /// matrices are supported by this path.
+    ///
+    /// Other matrices use the Denman–Beavers iteration, which converges quadratically when the
+    /// matrix has no eigenvalue on the closed negative real axis. `None` is returned if the
+    /// matrix is singular, or if the iteration does not converge because no real principal
+    /// square root exists.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(4.0, 1.0,
+    ///                      0.0, 9.0);
+    /// let sqrt = m.sqrt().unwrap();
+    /// assert_relative_eq!(sqrt, Matrix2::new(2.0, 0.2, 0.0, 3.0), epsilon = 1.0e-10);
+    ///
+    /// // No real square root exists.
+    /// assert!(Matrix2::new(-1.0, 0.0, 0.0, 4.0).sqrt().is_none());
+    /// ```
+    #[must_use]
+    pub fn sqrt(&self) -> Option<OMatrix<T, D, D>> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the square root of a non-square matrix."
+        );
+
+        if self.is_empty() {
+            return Some(self.clone_owned());
+        }
+
+        if self.is_symmetric(T::default_epsilon()) {
+            self.symmetric_sqrt()
+        } else {
+            self.denman_beavers_sqrt()
+        }
+    }
+
+    fn symmetric_sqrt(&self) -> Option<OMatrix<T, D, D>> {
+        let mut eigen = self.clone_owned().symmetric_eigen();
+        let amax = eigen.eigenvalues.amax();
+        let tol = amax * T::default_epsilon().sqrt();
+        // Eigenvalues below the rounding error of the eigensolver are indistinguishable from zero,
+        // and would otherwise be amplified by the square root.
+        let noise = amax * T::default_epsilon() * crate::convert(self.nrows() as f64);
+
+        for val in eigen.eigenvalues.iter_mut() {
+            if *val < -tol {
+                return None;
+            }
+
+            *val = if *val <= noise { T::zero() } else { val.sqrt() };
+        }
+
+        Some(eigen.recompose())
+    }
+
+    fn denman_beavers_sqrt(&self) -> Option<OMatrix<T, D, D>> {
+        let half: T = crate::convert(0.5);
+        let tol = T::default_epsilon().sqrt();
+        let (nrows, ncols) = self.data.shape();
+
+        let mut y = self.clone_owned();
+        let mut z = OMatrix::identity_generic(nrows, ncols);
+        let mut converged = false;
+
+        for _ in 0..MAX_DENMAN_BEAVERS_ITERATIONS {
+            let y_inv = y.clone().try_inverse()?;
+            let z_inv = z.clone().try_inverse()?;
+            let y_next = (&y + z_inv) * half;
+            z = (z + y_inv) * half;
+
+            let delta = (&y_next - &y).norm();
+            y = y_next;
+
+            // The convergence is quadratic: one more iteration after reaching `sqrt(ε)`
+            // brings the error down to `ε`.
+            if converged {
+                break;
+            }
+
+            converged = delta <= tol * y.norm();
+        }
+
+        if converged && (&y * &y - self).norm() <= tol * self.norm() {
+            Some(y)
+        } else {
+            None
+        }
+    }
+}
@@ -2,15 +2,29 @@
 use serde::{Deserialize, Serialize};
 
 use approx::AbsDiffEq;
-use num::Zero;
+use num::{One, Zero};
+use std::cmp::Ordering;
+use std::fmt;
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, Matrix2, OMatrix, OVector, SquareMatrix, Vector2};
-use crate::dimension::{Dim, DimDiff, DimSub, U1};
-use crate::storage::Storage;
-use simba::scalar::ComplexField;
+use crate::base::{
+    DefaultAllocator, Matrix2, Matrix3, MatrixSlice, OMatrix, OVector, SliceRange, SquareMatrix,
+    Vector2, Vector3, VectorSlice,
+};
+use crate::dimension::{Const, Dim, DimDiff, DimMin, DimSub, U1, U2, U3};
+use crate::storage::{Owned, Storage, StorageMut};
+use simba::scalar::{ComplexField, RealField};
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::Vector;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::dimension::Dynamic;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
 
 use crate::linalg::givens::GivensRotation;
+use crate::linalg::householder;
 use crate::linalg::SymmetricTridiagonal;
 
 /// Eigendecomposition of a symmetric matrix.
@@ -41,6 +55,191 @@ where
     pub eigenvalues: OVector<T::RealField, D>,
 }
 
+/// Convergence statistics of the symmetric eigendecomposition algorithm.
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct EigenStats<T> {
+    /// The number of iterations performed by the algorithm.
+    pub iterations: usize,
+
+    /// The greatest absolute value of the off-diagonal elements of the tridiagonal matrix
+    /// remaining after the last iteration.
+    pub max_offdiagonal: T,
+}
+
+/// Error returned by [`SymmetricEigen::recompose_checked`] when the eigenvectors are not
+/// orthonormal.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NonOrthogonalError<T> {
+    /// The greatest absolute value of the components of `Uᴴ * U - I`, where `U` is the matrix
+    /// of eigenvectors.
+    pub deviation: T,
+}
+
+impl<T: fmt::Display> fmt::Display for NonOrthogonalError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the eigenvectors are not orthonormal: Uᴴ * U deviates from the identity by {}",
+            self.deviation
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + fmt::Display> std::error::Error for NonOrthogonalError<T> {}
+
+/// The eigenvalues selected by [`Matrix::symmetric_eigen_partial`](crate::Matrix::symmetric_eigen_partial).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Extreme {
+    /// The eigenvalues with the largest absolute values.
+    LargestMagnitude,
+    /// The eigenvalues with the smallest absolute values.
+    SmallestMagnitude,
+}
+
+/// The criterion used by [`SymmetricEigen`] to decide that an off-diagonal entry `e` of the
+/// tridiagonal matrix, between the diagonal entries `a` and `b`, converged to zero.
+///
+/// See [`SymmetricEigenConfig`].
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DeflationCriterion {
+    /// `|e| <= eps * (|a| + |b|)`. This is the default.
+    ///
+    /// The threshold adapts to the magnitude of the neighboring diagonal entries, so an entry
+    /// between small diagonal entries keeps being reduced until it is negligible relative to
+    /// them. On graded matrices, this resolves the small eigenvalues with a higher relative
+    /// precision than [`DeflationCriterion::MatrixNorm`].
+    Neighbors,
+    /// `|e| <= eps * m_amax`, where `m_amax` is the greatest component magnitude of the
+    /// decomposed matrix.
+    ///
+    /// The threshold is the same for every entry, so the eigenvalues are only guaranteed to have
+    /// an absolute error of the order of `eps * m_amax`. This matches the accuracy of the
+    /// reduction to tridiagonal form for general matrices, so nothing is lost for them, and the
+    /// iteration may stop earlier. However, eigenvalues much smaller than `m_amax` may lose
+    /// their relative precision: with the eigenvalues `{1e8, 1.0, 1e-10}`, the one close to `1.0`
+    /// is then only accurate to about `1e-8` instead of `1e-16`.
+    MatrixNorm,
+}
+
+impl DeflationCriterion {
+    /// Whether the off-diagonal entry `e` between the diagonal entries `a` and `b` of the
+    /// tridiagonal matrix, scaled by `m_amax`, is negligible.
+    fn is_negligible<T: RealField>(self, e: T, a: T, b: T, eps: T) -> bool {
+        match self {
+            DeflationCriterion::Neighbors => e.norm1() <= eps * (a.norm1() + b.norm1()),
+            // The tridiagonal matrix is scaled so that `m_amax` is 1.
+            DeflationCriterion::MatrixNorm => e.norm1() <= eps,
+        }
+    }
+}
+
+/// Convergence parameters of the iterative symmetric eigendecomposition algorithm.
+///
+/// See [`SymmetricEigen::try_new_with_config`].
+#[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SymmetricEigenConfig<T> {
+    /// Tolerance used to determine when a value converged to 0.
+    pub eps: T,
+
+    /// Maximum total number of iterations performed by the algorithm, or `0` to continue
+    /// indefinitely until convergence.
+    pub max_niter: usize,
+
+    /// The criterion used to determine when an off-diagonal value converged to 0.
+    pub criterion: DeflationCriterion,
+
+    /// If `Some(k)`, the Wilkinson shift is replaced by an exceptional shift every `k`
+    /// consecutive iterations without deflation. `None` by default.
+    ///
+    /// The exceptional shift is the Rayleigh quotient shift, i.e. the last diagonal entry of the
+    /// unreduced block. The Wilkinson shift already guarantees the convergence of the iteration,
+    /// and rarely goes more than a few iterations without deflation, even on tightly clustered
+    /// eigenvalues, so this is only a safeguard against stagnation and seldom changes the number
+    /// of iterations.
+    ///
+    /// The decompositions panic if this is `Some(0)`.
+    pub exceptional_shift: Option<usize>,
+
+    /// Whether to symmetrically permute the rows and columns of the matrix so that its diagonal
+    /// is sorted by decreasing magnitude before the reduction to tridiagonal form. `false` by
+    /// default.
+    ///
+    /// The Householder reduction proceeds from the first column, so it keeps the small
+    /// eigenvalues of graded matrices, whose entries span many orders of magnitude along the
+    /// diagonal, accurate to a small relative error only when the large entries come first. On
+    /// a matrix graded the other way, the relative error of its smallest eigenvalues may
+    /// otherwise be several orders of magnitude larger than the machine epsilon. The
+    /// permutation is an orthogonal similarity, so the eigenvalues are unchanged and the
+    /// eigenvectors are permuted back by swapping their rows.
+    ///
+    /// Unlike the balancing of non-symmetric matrices, no diagonal scaling is applied: the
+    /// symmetric scaling `D * A * D` does not preserve the eigenvalues, and the similarity
+    /// `D⁻¹ * A * D` does not preserve the symmetry. This only applies to the decompositions of
+    /// dense matrices, and is ignored without the `std` or `alloc` feature, which is needed to
+    /// record the permutation.
+    pub balance: bool,
+}
+
+impl<T: RealField> Default for SymmetricEigenConfig<T> {
+    /// Machine epsilon, no iteration limit, [`DeflationCriterion::Neighbors`], no exceptional
+    /// shifts, and no balancing, as used by [`SymmetricEigen::new`].
+    fn default() -> Self {
+        Self {
+            eps: T::default_epsilon(),
+            max_niter: 0,
+            criterion: DeflationCriterion::Neighbors,
+            exceptional_shift: None,
+            balance: false,
+        }
+    }
+}
+
+/// Preallocated buffers for computing repeatedly, without allocation, the eigendecomposition of
+/// symmetric matrices with the same dimensions.
+///
+/// See [`SymmetricEigen::decompose_into`].
+#[derive(Clone, Debug)]
+pub struct SymmetricEigenWorkspace<T: ComplexField, D: DimSub<U1>>
+where
+    DefaultAllocator:
+        Allocator<T, D, D> + Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+{
+    tri: OMatrix<T, D, D>,
+    householder_off_diag: OVector<T, DimDiff<D, U1>>,
+    p: OVector<T, DimDiff<D, U1>>,
+    off_diag: OVector<T::RealField, DimDiff<D, U1>>,
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    swaps: Vec<usize>,
+}
+
+impl<T: ComplexField, D: DimSub<U1>> SymmetricEigenWorkspace<T, D>
+where
+    DefaultAllocator:
+        Allocator<T, D, D> + Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+{
+    /// Allocates the buffers needed to decompose `dim × dim` symmetric matrices.
+    pub fn new(dim: D) -> Self {
+        assert!(
+            dim.value() != 0,
+            "Unable to compute the eigendecomposition of an empty matrix."
+        );
+        let sub_dim = dim.sub(Const::<1>);
+
+        Self {
+            tri: OMatrix::zeros_generic(dim, dim),
+            householder_off_diag: OVector::zeros_generic(sub_dim, Const::<1>),
+            p: OVector::zeros_generic(sub_dim, Const::<1>),
+            off_diag: OVector::zeros_generic(sub_dim, Const::<1>),
+            #[cfg(any(feature = "std", feature = "alloc"))]
+            swaps: Vec::with_capacity(dim.value()),
+        }
+    }
+}
+
 impl<T: ComplexField, D: Dim> Copy for SymmetricEigen<T, D>
 where
     DefaultAllocator: Allocator<T, D, D> + Allocator<T::RealField, D>,
@@ -55,6 +254,14 @@ where
 {
     /// Computes the eigendecomposition of the given symmetric matrix.
     ///
+    /// This always uses the iterative algorithm, and is equivalent to
+    /// `SymmetricEigen::try_new(m, T::RealField::default_epsilon(), 0).unwrap()`. Real 2x2 and 3x3
+    /// matrices are not sent to the closed forms of [`SymmetricEigen::new_2x2`] and
+    /// [`SymmetricEigen::new_3x3`]: those order the eigenvalues and choose the signs of the
+    /// eigenvectors differently, and their rounding errors differ, so doing so would change the
+    /// results of existing callers and make `new` disagree with `try_new`. Call them explicitly
+    /// to use the closed forms.
+    ///
     /// Only the lower-triangular parts (including its diagonal) of `m` is read.
     pub fn new(m: OMatrix<T, D, D>) -> Self
     where
@@ -64,6 +271,20 @@ where
         Self::try_new(m, T::RealField::default_epsilon(), 0).unwrap()
     }
 
+    /// Computes the eigendecomposition of the given symmetric matrix, with the eigenvalues
+    /// sorted in decreasing order.
+    ///
+    /// Only the lower-triangular parts (including its diagonal) of `m` is read.
+    pub fn new_sorted(m: OMatrix<T, D, D>) -> Self
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        let mut res = Self::new(m);
+        res.sort_descending();
+        res
+    }
+
     /// Computes the eigendecomposition of the given symmetric matrix with user-specified
     /// convergence parameters.
     ///
@@ -80,18 +301,129 @@ where
         D: DimSub<U1>,
         DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
     {
-        Self::do_decompose(m, true, eps, max_niter).map(|(vals, vecs)| SymmetricEigen {
-            eigenvectors: vecs.unwrap(),
-            eigenvalues: vals,
+        Self::try_new_with_stats(m, eps, max_niter)
+            .ok()
+            .map(|(eig, _)| eig)
+    }
+
+    /// Same as [`SymmetricEigen::try_new`], except that the whole matrix `m` is read to check
+    /// that it is symmetric (or hermitian) first.
+    ///
+    /// This is useful to catch matrices that are only approximately symmetric, e.g. because of
+    /// rounding errors, for which the other constructors silently ignore the upper-triangular
+    /// part.
+    ///
+    /// Panics
+    /// ------
+    /// Panics, naming the offending components, if `m` is not symmetric up to a tolerance of
+    /// `sqrt(ε)` relative to its greatest component, where `ε` is the machine epsilon.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// # use nalgebra::Matrix2;
+    /// # use nalgebra::linalg::SymmetricEigen;
+    /// let m = Matrix2::new(1.0, 2.0,
+    ///                      2.5, 1.0);
+    /// let _ = SymmetricEigen::try_new_checked(m, f64::EPSILON, 0);
+    /// ```
+    pub fn try_new_checked(m: OMatrix<T, D, D>, eps: T::RealField, max_niter: usize) -> Option<Self>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        assert!(
+            m.is_square(),
+            "Unable to compute the eigendecomposition of a non-square matrix."
+        );
+        Self::assert_symmetric(&m);
+        Self::try_new(m, eps, max_niter)
+    }
+
+    /// Computes the eigendecomposition of the given symmetric matrix with the given convergence
+    /// parameters, including the criterion used to decide that an off-diagonal value is
+    /// negligible.
+    ///
+    /// Unlike [`SymmetricEigen::new`], this always uses the iterative algorithm. Returns `None`
+    /// if `config.max_niter` is non-zero and exceeded.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// # use nalgebra::linalg::{DeflationCriterion, SymmetricEigen, SymmetricEigenConfig};
+    /// let m = Matrix3::new(1.0e8, 1.0e3, 0.0,
+    ///                      1.0e3, 1.0,   1.0e-4,
+    ///                      0.0,   1.0e-4, 1.0e-8);
+    /// let config = SymmetricEigenConfig {
+    ///     criterion: DeflationCriterion::MatrixNorm,
+    ///     ..SymmetricEigenConfig::default()
+    /// };
+    /// let eig = SymmetricEigen::try_new_with_config(m, config).unwrap();
+    /// assert!((eig.recompose() - m).amax() <= 1.0e-7);
+    /// ```
+    pub fn try_new_with_config(
+        m: OMatrix<T, D, D>,
+        config: SymmetricEigenConfig<T::RealField>,
+    ) -> Option<Self>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        Self::do_decompose(m, true, config)
+            .ok()
+            .map(|(vals, vecs, _)| SymmetricEigen {
+                eigenvectors: vecs.unwrap(),
+                eigenvalues: vals,
+            })
+    }
+
+    /// Computes the eigendecomposition of the given symmetric matrix with user-specified
+    /// convergence parameters, and reports convergence statistics.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps`       − tolerance used to determine when a value converged to 0.
+    /// * `max_niter` − maximum total number of iterations performed by the algorithm. If this
+    /// number of iteration is exceeded, `Err` is returned with the statistics of the last
+    /// iteration. If `niter == 0`, then the algorithm continues indefinitely until convergence.
+    pub fn try_new_with_stats(
+        m: OMatrix<T, D, D>,
+        eps: T::RealField,
+        max_niter: usize,
+    ) -> Result<(Self, EigenStats<T::RealField>), EigenStats<T::RealField>>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        let config = SymmetricEigenConfig {
+            eps,
+            max_niter,
+            ..SymmetricEigenConfig::default()
+        };
+        Self::do_decompose(m, true, config).map(|(vals, vecs, stats)| {
+            let eig = SymmetricEigen {
+                eigenvectors: vecs.unwrap(),
+                eigenvalues: vals,
+            };
+            (eig, stats)
         })
     }
 
     fn do_decompose(
         mut matrix: OMatrix<T, D, D>,
         eigenvectors: bool,
-        eps: T::RealField,
-        max_niter: usize,
-    ) -> Option<(OVector<T::RealField, D>, Option<OMatrix<T, D, D>>)>
+        config: SymmetricEigenConfig<T::RealField>,
+    ) -> Result<
+        (
+            OVector<T::RealField, D>,
+            Option<OMatrix<T, D, D>>,
+            EigenStats<T::RealField>,
+        ),
+        EigenStats<T::RealField>,
+    >
     where
         D: DimSub<U1>,
         DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
@@ -100,7 +432,14 @@ where
             matrix.is_square(),
             "Unable to compute the eigendecomposition of a non-square matrix."
         );
-        let dim = matrix.nrows();
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        let swaps = if config.balance {
+            let mut swaps = Vec::with_capacity(matrix.nrows());
+            Self::balance_mut(&mut matrix, &mut swaps);
+            Some(swaps)
+        } else {
+            None
+        };
         let m_amax = matrix.camax();
 
         if !m_amax.is_zero() {
@@ -121,26 +460,127 @@ where
             off_diag = res.1;
         }
 
+        let stats =
+            Self::solve_tridiagonal(&mut diag, &mut off_diag, q_mat.as_mut(), m_amax, config)?;
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        if let (Some(swaps), Some(q)) = (swaps, q_mat.as_mut()) {
+            Self::unbalance_eigenvectors(q, &swaps);
+        }
+
+        Ok((diag, q_mat, stats))
+    }
+
+    /// Symmetrically permutes the rows and columns of `matrix` so that its diagonal is sorted by
+    /// decreasing magnitude, after filling its upper-triangular part from the lower-triangular
+    /// one.
+    ///
+    /// Replaces the content of `swaps` by the swaps performed, where the rows and columns `i` and
+    /// `swaps[i]` were swapped at the `i`-th step. Applying them in order to the rows of the
+    /// identity gives the permutation matrix `P` such that `matrix` was replaced by
+    /// `P * matrix * Pᵀ`.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn balance_mut(matrix: &mut OMatrix<T, D, D>, swaps: &mut Vec<usize>) {
+        let dim = matrix.nrows();
+        swaps.clear();
+
+        for j in 0..dim {
+            for i in 0..j {
+                matrix[(i, j)] = matrix[(j, i)].conjugate();
+            }
+        }
+
+        for i in 0..dim {
+            let mut k = i;
+
+            for j in i + 1..dim {
+                if matrix[(j, j)].modulus() > matrix[(k, k)].modulus() {
+                    k = j;
+                }
+            }
+
+            if k != i {
+                matrix.swap_rows(i, k);
+                matrix.swap_columns(i, k);
+            }
+
+            swaps.push(k);
+        }
+    }
+
+    /// Replaces the eigenvectors `q` of the matrix balanced by [`Self::balance_mut`] with the
+    /// given `swaps` by the eigenvectors `Pᵀ * q` of the original matrix.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn unbalance_eigenvectors(q: &mut OMatrix<T, D, D>, swaps: &[usize]) {
+        // Undo the swaps in reverse order.
+        for (i, &k) in swaps.iter().enumerate().rev() {
+            q.swap_rows(i, k);
+        }
+    }
+
+    /// Diagonalizes the (unscaled) symmetric tridiagonal matrix given by `diag` and `off_diag`,
+    /// accumulating the rotations into `q_mat`.
+    ///
+    /// On success, `diag` contains the eigenvalues scaled by `m_amax`.
+    pub(crate) fn solve_tridiagonal(
+        diag: &mut OVector<T::RealField, D>,
+        off_diag: &mut OVector<T::RealField, DimDiff<D, U1>>,
+        mut q_mat: Option<&mut OMatrix<T, D, D>>,
+        m_amax: T::RealField,
+        config: SymmetricEigenConfig<T::RealField>,
+    ) -> Result<EigenStats<T::RealField>, EigenStats<T::RealField>>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        let SymmetricEigenConfig {
+            eps,
+            max_niter,
+            criterion,
+            exceptional_shift,
+            ..
+        } = config;
+        assert!(
+            exceptional_shift != Some(0),
+            "SymmetricEigenConfig::exceptional_shift must not be Some(0)."
+        );
+        let dim = diag.len();
+
         if dim == 1 {
             diag.scale_mut(m_amax);
-            return Some((diag, q_mat));
+            let stats = EigenStats {
+                iterations: 0,
+                max_offdiagonal: T::RealField::zero(),
+            };
+            return Ok(stats);
         }
 
         let mut niter = 0;
-        let (mut start, mut end) = Self::delimit_subproblem(&diag, &mut off_diag, dim - 1, eps);
+        // The number of consecutive iterations without deflation.
+        let mut stagnation = 0;
+        let (mut start, mut end) =
+            Self::delimit_subproblem(diag, off_diag, dim - 1, eps, criterion);
 
         while end != start {
             let subdim = end - start + 1;
+            let (prev_start, prev_end) = (start, end);
 
             #[allow(clippy::comparison_chain)]
             if subdim > 2 {
                 let m = end - 1;
                 let n = end;
 
-                let mut vec = Vector2::new(
-                    diag[start] - wilkinson_shift(diag[m], diag[n], off_diag[m]),
-                    off_diag[start],
-                );
+                let exceptional = match exceptional_shift {
+                    Some(k) => stagnation > 0 && stagnation % k == 0,
+                    None => false,
+                };
+                let shift = if exceptional {
+                    diag[n]
+                } else {
+                    wilkinson_shift(diag[m], diag[n], off_diag[m])
+                };
+
+                let mut vec = Vector2::new(diag[start] - shift, off_diag[start]);
 
                 for i in start..n {
                     let j = i + 1;
@@ -171,7 +611,7 @@ where
                             off_diag[i + 1] *= rot.c();
                         }
 
-                        if let Some(ref mut q) = q_mat {
+                        if let Some(q) = q_mat.as_mut() {
                             let rot = GivensRotation::new_unchecked(rot.c(), T::from_real(rot.s()));
                             rot.inverse().rotate_rows(&mut q.fixed_columns_mut::<2>(i));
                         }
@@ -180,7 +620,7 @@ where
                     }
                 }
 
-                if off_diag[m].norm1() <= eps * (diag[m].norm1() + diag[n].norm1()) {
+                if criterion.is_negligible(off_diag[m], diag[m], diag[n], eps) {
                     end -= 1;
                 }
             } else if subdim == 2 {
@@ -191,12 +631,19 @@ where
                     diag[start + 1],
                 );
                 let eigvals = m.eigenvalues().unwrap();
-                let basis = Vector2::new(eigvals.x - diag[start + 1], off_diag[start]);
+                // Both vectors are eigenvectors associated to `eigvals.x`. Pick the one whose
+                // computation does not suffer from cancellation.
+                let basis = if diag[start] >= diag[start + 1] {
+                    Vector2::new(eigvals.x - diag[start + 1], off_diag[start])
+                } else {
+                    Vector2::new(off_diag[start], eigvals.x - diag[start])
+                };
 
                 diag[start] = eigvals[0];
                 diag[start + 1] = eigvals[1];
+                off_diag[start] = T::RealField::zero();
 
-                if let Some(ref mut q) = q_mat {
+                if let Some(q) = q_mat.as_mut() {
                     if let Some((rot, _)) = GivensRotation::try_new(basis.x, basis.y, eps) {
                         let rot = GivensRotation::new_unchecked(rot.c(), T::from_real(rot.s()));
                         rot.rotate_rows(&mut q.fixed_columns_mut::<2>(start));
@@ -207,20 +654,62 @@ where
             }
 
             // Re-delimit the subproblem in case some decoupling occurred.
-            let sub = Self::delimit_subproblem(&diag, &mut off_diag, end, eps);
+            let sub = Self::delimit_subproblem(diag, off_diag, end, eps, criterion);
 
             start = sub.0;
             end = sub.1;
 
+            if (start, end) == (prev_start, prev_end) {
+                stagnation += 1;
+            } else {
+                stagnation = 0;
+            }
+
             niter += 1;
             if niter == max_niter {
-                return None;
+                return Err(Self::stats(off_diag, niter, m_amax));
             }
         }
 
         diag.scale_mut(m_amax);
 
-        Some((diag, q_mat))
+        Ok(Self::stats(off_diag, niter, m_amax))
+    }
+
+    fn stats(
+        off_diag: &OVector<T::RealField, DimDiff<D, U1>>,
+        iterations: usize,
+        m_amax: T::RealField,
+    ) -> EigenStats<T::RealField>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        EigenStats {
+            iterations,
+            max_offdiagonal: off_diag.camax() * m_amax,
+        }
+    }
+
+    /// Panics if the square matrix `m` is not symmetric (or hermitian), up to a tolerance
+    /// relative to its greatest component.
+    fn assert_symmetric<S: Storage<T, D, D>>(m: &SquareMatrix<T, D, S>) {
+        let tol = m.camax() * T::RealField::default_epsilon().sqrt();
+
+        for j in 0..m.ncols() {
+            for i in j + 1..m.nrows() {
+                let diff = (m[(i, j)] - m[(j, i)].conjugate()).modulus();
+
+                // NOTE: written this way so that NaNs don't trigger the assertion.
+                if diff > tol {
+                    panic!(
+                        "Unable to compute the eigendecomposition of a non-symmetric matrix: \
+                         the components at ({}, {}) and ({}, {}) differ.",
+                        i, j, j, i
+                    );
+                }
+            }
+        }
     }
 
     fn delimit_subproblem(
@@ -228,6 +717,7 @@ where
         off_diag: &mut OVector<T::RealField, DimDiff<D, U1>>,
         end: usize,
         eps: T::RealField,
+        criterion: DeflationCriterion,
     ) -> (usize, usize)
     where
         D: DimSub<U1>,
@@ -238,7 +728,7 @@ where
         while n > 0 {
             let m = n - 1;
 
-            if off_diag[m].norm1() > eps * (diag[n].norm1() + diag[m].norm1()) {
+            if !criterion.is_negligible(off_diag[m], diag[m], diag[n], eps) {
                 break;
             }
 
@@ -254,7 +744,7 @@ where
             let m = new_start - 1;
 
             if off_diag[m].is_zero()
-                || off_diag[m].norm1() <= eps * (diag[new_start].norm1() + diag[m].norm1())
+                || criterion.is_negligible(off_diag[m], diag[m], diag[new_start], eps)
             {
                 off_diag[m] = T::RealField::zero();
                 break;
@@ -266,63 +756,1633 @@ where
         (new_start, n)
     }
 
-    /// Rebuild the original matrix.
+    /// Computes the eigendecomposition of the symmetric matrix `m` and stores it into `self`,
+    /// using the buffers of `workspace` instead of allocating new ones.
     ///
-    /// This is useful if some of the eigenvalues have been manually modified.
-    #[must_use]
-    pub fn recompose(&self) -> OMatrix<T, D, D> {
-        let mut u_t = self.eigenvectors.clone();
-        for i in 0..self.eigenvalues.len() {
-            let val = self.eigenvalues[i];
-            u_t.column_mut(i).scale_mut(val);
+    /// Returns the convergence statistics. Since there is no iteration limit, the algorithm
+    /// always converges.
+    ///
+    /// The dimensions of `self`, `m`, and `workspace` must match.
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    pub fn decompose_into<S: Storage<T, D, D>>(
+        &mut self,
+        m: &SquareMatrix<T, D, S>,
+        workspace: &mut SymmetricEigenWorkspace<T, D>,
+    ) -> EigenStats<T::RealField>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        self.try_decompose_into(m, workspace, SymmetricEigenConfig::default())
+            .unwrap()
+    }
+
+    /// Computes the eigendecomposition of the symmetric matrix `m` with the given convergence
+    /// parameters and stores it into `self`, using the buffers of `workspace` instead of
+    /// allocating new ones.
+    ///
+    /// On success, returns the convergence statistics. If `config.max_niter` is non-zero and
+    /// exceeded, returns `Err` with the statistics of the last iteration, and the content of
+    /// `self` is unspecified. All the fields of `config` are honored as by
+    /// [`SymmetricEigen::try_new_with_config`], including `balance`, whose permutation is recorded
+    /// in `workspace`.
+    ///
+    /// The dimensions of `self`, `m`, and `workspace` must match.
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    pub fn try_decompose_into<S: Storage<T, D, D>>(
+        &mut self,
+        m: &SquareMatrix<T, D, S>,
+        workspace: &mut SymmetricEigenWorkspace<T, D>,
+        config: SymmetricEigenConfig<T::RealField>,
+    ) -> Result<EigenStats<T::RealField>, EigenStats<T::RealField>>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T, DimDiff<D, U1>> + Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        assert!(
+            m.is_square(),
+            "Unable to compute the eigendecomposition of a non-square matrix."
+        );
+        assert!(
+            m.shape() == self.eigenvectors.shape() && m.shape() == workspace.tri.shape(),
+            "Eigendecomposition: dimension mismatch."
+        );
+
+        let ws = workspace;
+        let dim = m.nrows();
+
+        ws.tri.copy_from(m);
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        if config.balance {
+            Self::balance_mut(&mut ws.tri, &mut ws.swaps);
         }
-        u_t.adjoint_mut();
-        &self.eigenvectors * u_t
+
+        let m_amax = ws.tri.camax();
+
+        if !m_amax.is_zero() {
+            ws.tri.unscale_mut(m_amax);
+        }
+
+        SymmetricTridiagonal::tridiagonalize_mut(
+            &mut ws.tri,
+            &mut ws.householder_off_diag,
+            &mut ws.p,
+        );
+        householder::assemble_q_mut(
+            &ws.tri,
+            ws.householder_off_diag.as_slice(),
+            &mut self.eigenvectors,
+        );
+
+        for i in 0..dim {
+            self.eigenvalues[i] = ws.tri[(i, i)].real();
+        }
+
+        for i in 0..dim - 1 {
+            ws.off_diag[i] = ws.householder_off_diag[i].modulus();
+        }
+
+        let stats = Self::solve_tridiagonal(
+            &mut self.eigenvalues,
+            &mut ws.off_diag,
+            Some(&mut self.eigenvectors),
+            m_amax,
+            config,
+        )?;
+
+        #[cfg(any(feature = "std", feature = "alloc"))]
+        if config.balance {
+            Self::unbalance_eigenvectors(&mut self.eigenvectors, &ws.swaps);
+        }
+
+        Ok(stats)
     }
-}
 
-/// Computes the wilkinson shift, i.e., the 2x2 symmetric matrix eigenvalue to its tailing
-/// component `tnn`.
-///
-/// The inputs are interpreted as the 2x2 matrix:
-///     tmm  tmn
-///     tmn  tnn
-pub fn wilkinson_shift<T: ComplexField>(tmm: T, tnn: T, tmn: T) -> T {
-    let sq_tmn = tmn * tmn;
-    if !sq_tmn.is_zero() {
-        // We have the guarantee that the denominator won't be zero.
-        let d = (tmm - tnn) * crate::convert(0.5);
-        tnn - sq_tmn / (d + d.signum() * (d * d + sq_tmn).sqrt())
-    } else {
-        tnn
+    /// Sorts the eigenvalues in increasing order, and permutes the eigenvectors accordingly.
+    ///
+    /// The permutation is performed in-place, without allocating.
+    pub fn sort_ascending(&mut self) {
+        self.sort_by(|a, b| a < b)
     }
-}
 
-/*
- *
- * Computations of eigenvalues for symmetric matrices.
- *
- */
-impl<T: ComplexField, D: DimSub<U1>, S: Storage<T, D, D>> SquareMatrix<T, D, S>
-where
-    DefaultAllocator: Allocator<T, D, D>
-        + Allocator<T, DimDiff<D, U1>>
-        + Allocator<T::RealField, D>
-        + Allocator<T::RealField, DimDiff<D, U1>>,
-{
-    /// Computes the eigenvalues of this symmetric matrix.
+    /// Sorts the eigenvalues in decreasing order, and permutes the eigenvectors accordingly.
     ///
-    /// Only the lower-triangular part of the matrix is read.
-    #[must_use]
-    pub fn symmetric_eigenvalues(&self) -> OVector<T::RealField, D> {
-        SymmetricEigen::do_decompose(
-            self.clone_owned(),
-            false,
-            T::RealField::default_epsilon(),
-            0,
-        )
-        .unwrap()
-        .0
+    /// The permutation is performed in-place, without allocating.
+    pub fn sort_descending(&mut self) {
+        self.sort_by(|a, b| a > b)
+    }
+
+    /// Normalizes the sign of each eigenvector, to make the decomposition deterministic.
+    ///
+    /// Eigenvectors are only defined up to their sign, or up to a unit complex factor for complex
+    /// matrices. The pivot of each eigenvector is its first component whose modulus is at least
+    /// `1 - sqrt(ε)` times the largest modulus of its components, where `ε` is the machine epsilon.
+    /// The eigenvector is then multiplied by the unit scalar that makes its pivot real and
+    /// positive. The relative tolerance makes the choice of the pivot robust to rounding errors
+    /// when several components have the same magnitude, e.g., for `[1, -1] / √2`. Zero
+    /// eigenvectors are left unchanged.
+    ///
+    /// For real matrices, this only flips the signs of some eigenvectors, which is exact: the
+    /// eigenvectors of two decompositions computed up to a sign are then bitwise identical.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix2, Vector2};
+    /// let m = Matrix2::new(2.0, 1.0,
+    ///                      1.0, 2.0);
+    /// let mut eig = m.symmetric_eigen();
+    /// eig.sort_ascending();
+    /// eig.canonicalize_signs();
+    ///
+    /// let s = 0.5f64.sqrt();
+    /// assert!((eig.eigenvectors.column(0) - Vector2::new(s, -s)).norm() < 1.0e-12);
+    /// assert!((eig.eigenvectors.column(1) - Vector2::new(s, s)).norm() < 1.0e-12);
+    /// ```
+    pub fn canonicalize_signs(&mut self) {
+        let tol = T::RealField::one() - T::RealField::default_epsilon().sqrt();
+
+        for mut column in self.eigenvectors.column_iter_mut() {
+            let max = column
+                .iter()
+                .fold(T::RealField::zero(), |max, e| max.max(e.modulus()));
+
+            if max.is_zero() {
+                continue;
+            }
+
+            if let Some(pivot) = column.iter().find(|e| e.modulus() >= max * tol) {
+                let phase = pivot.to_exp().1;
+                column *= phase.conjugate();
+            }
+        }
+    }
+
+    /// Selection sort of the eigenvalues, where `goes_first(a, b)` returns `true` if `a` must
+    /// be placed before `b`. Each swap of eigenvalues is mirrored on the eigenvector columns.
+    fn sort_by(&mut self, goes_first: impl Fn(T::RealField, T::RealField) -> bool) {
+        let dim = self.eigenvalues.len();
+
+        for i in 0..dim {
+            let mut best = i;
+
+            for j in i + 1..dim {
+                if goes_first(self.eigenvalues[j], self.eigenvalues[best]) {
+                    best = j;
+                }
+            }
+
+            if best != i {
+                self.eigenvalues.swap_rows(i, best);
+                self.eigenvectors.swap_columns(i, best);
+            }
+        }
+    }
+
+    /// Computes the 2-norm condition number of the decomposed matrix, i.e., the ratio between
+    /// its greatest and smallest eigenvalue magnitudes.
+    ///
+    /// Returns an infinite value if the decomposed matrix is singular.
+    #[must_use]
+    pub fn condition_number(&self) -> T::RealField {
+        let max = self.eigenvalues.camax();
+        let min = self.eigenvalues.camin();
+
+        if min.is_zero() {
+            T::RealField::one() / min
+        } else {
+            max / min
+        }
+    }
+
+    /// Computes the determinant of the decomposed matrix, i.e., the product of its eigenvalues.
+    ///
+    /// For large matrices, this product may overflow or underflow: use
+    /// [`SymmetricEigen::log_determinant`] instead.
+    #[must_use]
+    pub fn determinant(&self) -> T::RealField {
+        self.eigenvalues
+            .iter()
+            .fold(T::RealField::one(), |acc, val| acc * *val)
+    }
+
+    /// Computes the trace of the decomposed matrix, i.e., the sum of its eigenvalues.
+    #[must_use]
+    pub fn trace(&self) -> T::RealField {
+        self.eigenvalues.sum()
+    }
+
+    /// Computes the natural logarithm of the determinant of the decomposed matrix, i.e., the sum
+    /// of the logarithms of its eigenvalues.
+    ///
+    /// Unlike [`SymmetricEigen::determinant`], this does not overflow nor underflow for large
+    /// matrices, which makes it suitable for, e.g., the log-likelihood of a Gaussian distribution
+    /// with a positive-definite covariance matrix.
+    ///
+    /// Returns `-inf` if one eigenvalue is zero (and none is negative), and `NaN` if one
+    /// eigenvalue is negative.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::DMatrix;
+    /// let m = DMatrix::<f64>::from_diagonal_element(200, 200, 1.0e-3);
+    /// let eig = m.symmetric_eigen();
+    /// assert_eq!(eig.determinant(), 0.0); // Underflow.
+    /// assert_relative_eq!(eig.log_determinant(), 200.0 * 1.0e-3f64.ln(), epsilon = 1.0e-9);
+    /// ```
+    #[must_use]
+    pub fn log_determinant(&self) -> T::RealField {
+        self.eigenvalues
+            .iter()
+            .fold(T::RealField::zero(), |acc, val| acc + val.ln())
+    }
+
+    /// Computes the differences between consecutive eigenvalues, sorted in increasing order.
+    ///
+    /// The `i`-th gap separates the `i + 1` smallest eigenvalues from the others, and is never
+    /// negative. The eigenvalues are sorted on a copy, so `self` does not need to be sorted
+    /// with [`SymmetricEigen::sort_ascending`] beforehand.
+    ///
+    /// Panics if the decomposed matrix is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix4, Vector3, Vector4};
+    /// let m = Matrix4::from_diagonal(&Vector4::new(5.0, 0.0, 5.5, 0.5));
+    /// let eig = m.symmetric_eigen();
+    /// assert_relative_eq!(eig.spectral_gaps(), Vector3::new(0.5, 4.5, 0.5));
+    /// ```
+    #[must_use]
+    pub fn spectral_gaps(&self) -> OVector<T::RealField, DimDiff<D, U1>>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        let (dim, _) = self.eigenvalues.data.shape();
+        assert!(
+            dim.value() != 0,
+            "Unable to compute the spectral gaps of an empty matrix."
+        );
+
+        let mut sorted = self.eigenvalues.clone();
+        sorted
+            .as_mut_slice()
+            .sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+        OVector::from_fn_generic(dim.sub(Const::<1>), Const::<1>, |i, _| {
+            sorted[i + 1] - sorted[i]
+        })
+    }
+
+    /// Computes the index of the largest gap between consecutive eigenvalues sorted in increasing
+    /// order, i.e., the index of the greatest component of [`SymmetricEigen::spectral_gaps`].
+    ///
+    /// If `k` is returned, the `k + 1` smallest eigenvalues are the ones separated from the
+    /// others by the largest jump. For the Laplacian of a graph, this is the usual eigengap
+    /// heuristic for the number of clusters, `k + 1`. Ties are resolved in favor of the smallest
+    /// index, and `None` is returned if there are less than two eigenvalues.
+    #[must_use]
+    pub fn largest_gap_index(&self) -> Option<usize>
+    where
+        D: DimSub<U1>,
+        DefaultAllocator: Allocator<T::RealField, DimDiff<D, U1>>,
+    {
+        if self.eigenvalues.len() < 2 {
+            None
+        } else {
+            Some(self.spectral_gaps().imax())
+        }
+    }
+
+    /// Views of the eigenvalues indexed by `range`, and of the corresponding eigenvectors.
+    ///
+    /// The range indexes the eigenvalues in the order they are stored, so the decomposition
+    /// should be sorted first (with [`SymmetricEigen::sort_ascending`],
+    /// [`SymmetricEigen::sort_descending`], or [`SymmetricEigen::new_sorted`]) for the range to
+    /// select, e.g., the smallest or greatest eigenvalues. Nothing is copied: the `k`-th column
+    /// of the returned matrix is the eigenvector associated to the `k`-th returned eigenvalue.
+    ///
+    /// # Panics
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Matrix4, Vector4};
+    /// let m = Matrix4::from_diagonal(&Vector4::new(5.0, 0.0, 5.5, 0.5));
+    /// let mut eig = m.symmetric_eigen();
+    /// eig.sort_ascending();
+    ///
+    /// // The two smallest eigenpairs.
+    /// let (values, vectors) = eig.eigenpairs_in_range(..2);
+    /// assert_eq!(values.as_slice(), &[0.0, 0.5]);
+    /// assert_eq!(vectors.column(0).iamax(), 1);
+    /// assert_eq!(vectors.column(1).iamax(), 3);
+    /// ```
+    #[must_use]
+    pub fn eigenpairs_in_range<Range: SliceRange<D>>(
+        &self,
+        range: Range,
+    ) -> (
+        VectorSlice<
+            '_,
+            T::RealField,
+            Range::Size,
+            <Owned<T::RealField, D> as Storage<T::RealField, D>>::RStride,
+            <Owned<T::RealField, D> as Storage<T::RealField, D>>::CStride,
+        >,
+        MatrixSlice<
+            '_,
+            T,
+            D,
+            Range::Size,
+            <Owned<T, D, D> as Storage<T, D, D>>::RStride,
+            <Owned<T, D, D> as Storage<T, D, D>>::CStride,
+        >,
+    ) {
+        let (dim, _) = self.eigenvalues.data.shape();
+        let (start, size) = (range.begin(dim), range.size(dim));
+
+        (
+            self.eigenvalues
+                .generic_slice((start, 0), (size, Const::<1>)),
+            self.eigenvectors.generic_slice((0, start), (dim, size)),
+        )
+    }
+
+    /// Computes the pseudo-inverse of the decomposed matrix.
+    ///
+    /// Any eigenvalue with a magnitude smaller than `eps` is assumed to be zero.
+    /// Returns `Err` if `eps` is negative.
+    pub fn pseudo_inverse(&self, eps: T::RealField) -> Result<OMatrix<T, D, D>, &'static str> {
+        if eps < T::RealField::zero() {
+            Err("SymmetricEigen pseudo inverse: the epsilon must be non-negative.")
+        } else {
+            Ok(self.recompose_map(|val| {
+                if val.abs() > eps {
+                    T::RealField::one() / val
+                } else {
+                    T::RealField::zero()
+                }
+            }))
+        }
+    }
+
+    /// Rebuild the original matrix.
+    ///
+    /// This is useful if some of the eigenvalues have been manually modified.
+    #[must_use]
+    pub fn recompose(&self) -> OMatrix<T, D, D> {
+        self.recompose_map(|val| val)
+    }
+
+    /// Rebuild the original matrix, after checking that the eigenvectors are still orthonormal.
+    ///
+    /// [`recompose`](Self::recompose) assumes that the eigenvectors are orthonormal, as they are
+    /// when returned by the decomposition. If they have been modified since, the result of
+    /// `recompose` may silently lose its symmetry. This instead returns an error if any
+    /// component of `Uᴴ * U - I` exceeds `sqrt(ε)` in absolute value, where `U` is the matrix of
+    /// eigenvectors and `ε` the machine epsilon. This check costs an additional matrix
+    /// multiplication.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(1.0f64, 2.0,
+    ///                      2.0, 1.0);
+    /// let mut eig = m.symmetric_eigen();
+    /// assert_relative_eq!(eig.recompose_checked().unwrap(), m, epsilon = 1.0e-7);
+    ///
+    /// // Scaling an eigenvector breaks the orthonormality.
+    /// eig.eigenvectors.column_mut(0).scale_mut(2.0);
+    /// assert!(eig.recompose_checked().is_err());
+    /// ```
+    pub fn recompose_checked(&self) -> Result<OMatrix<T, D, D>, NonOrthogonalError<T::RealField>> {
+        let dim = self.eigenvectors.data.shape().1;
+        let mut gram = self.eigenvectors.ad_mul(&self.eigenvectors);
+        gram -= OMatrix::identity_generic(dim, dim);
+        // NOTE: unlike `camax`, this propagates NaNs so that they are reported as errors.
+        let deviation = gram.iter().fold(T::RealField::zero(), |acc, e| {
+            let e = e.modulus();
+            if e > acc || !e.is_finite() {
+                e
+            } else {
+                acc
+            }
+        });
+
+        if deviation <= T::RealField::default_epsilon().sqrt() {
+            Ok(self.recompose())
+        } else {
+            Err(NonOrthogonalError { deviation })
+        }
+    }
+
+    /// Rebuild a matrix from the eigenvectors and the eigenvalues transformed by `f`.
+    ///
+    /// This computes `U f(Λ) Uᴴ` where `U` are the eigenvectors and `Λ` the eigenvalues, i.e.,
+    /// the matrix function `f` applied to the decomposed matrix. The eigenvalues stored in `self`
+    /// are left untouched.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(1.0f64, 2.0,
+    ///                      2.0, 1.0);
+    /// let eig = m.symmetric_eigen();
+    ///
+    /// // Projection onto the cone of positive semi-definite matrices.
+    /// let psd = eig.recompose_map(|val| val.max(0.0));
+    /// assert_relative_eq!(psd, Matrix2::new(1.5, 1.5, 1.5, 1.5), epsilon = 1.0e-7);
+    ///
+    /// // Squaring the eigenvalues yields the squared matrix.
+    /// assert_relative_eq!(eig.recompose_map(|val| val * val), m * m, epsilon = 1.0e-7);
+    /// ```
+    #[must_use]
+    pub fn recompose_map<F>(&self, mut f: F) -> OMatrix<T, D, D>
+    where
+        F: FnMut(T::RealField) -> T::RealField,
+    {
+        // Write the adjoint directly instead of cloning then transposing in-place.
+        let mut u_t = self.eigenvectors.adjoint();
+        for i in 0..self.eigenvalues.len() {
+            let val = f(self.eigenvalues[i]);
+            u_t.row_mut(i).scale_mut(val);
+        }
+        &self.eigenvectors * u_t
+    }
+
+    /// Computes the eigendecomposition of `m + rho * v * vᴴ`, where `m` is the matrix decomposed
+    /// by `self`, without decomposing it from scratch.
+    ///
+    /// In the eigenvector basis, the updated matrix is the diagonal of eigenvalues plus the
+    /// rank-1 term `rho * z * zᴴ`, with `z = Uᴴ * v`. Its eigenvalues are the roots of the secular
+    /// equation `1 + rho * Σ |zᵢ|² / (λᵢ - λ) = 0`, each of them isolated between two consecutive
+    /// eigenvalues of `m`, and found by bisection. This is the core of divide-and-conquer
+    /// eigensolvers: no QR iteration is performed, and the cost is dominated by the `O(n³)`
+    /// assembly of the new eigenvectors, whose orthogonality is preserved by recomputing `z` with
+    /// Löwner's formula.
+    ///
+    /// Eigenpairs for which the component of `z` is negligible, or whose eigenvalue is numerically
+    /// equal to another one, are deflated and left unchanged (up to a rotation of the eigenvectors
+    /// of a repeated eigenvalue). As for the other constructors, the eigenvalues of the result are
+    /// not sorted.
+    ///
+    /// # Panics
+    /// Panics if the dimension of `v` does not match the dimension of the decomposed matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let m = Matrix3::new(2.0, 1.0, 0.0,
+    ///                      1.0, 3.0, 1.0,
+    ///                      0.0, 1.0, 4.0);
+    /// let v = Vector3::new(1.0, -1.0, 2.0);
+    /// let updated = m.symmetric_eigen().rank_one_update(0.5, &v);
+    ///
+    /// assert_relative_eq!(updated.recompose(), m + v * v.transpose() * 0.5, epsilon = 1.0e-10);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[must_use]
+    pub fn rank_one_update<S2>(&self, rho: T::RealField, v: &Vector<T, D, S2>) -> Self
+    where
+        S2: Storage<T, D>,
+        DefaultAllocator: Allocator<T, D>,
+    {
+        let dim = self.eigenvalues.len();
+        assert_eq!(
+            v.len(),
+            dim,
+            "The perturbation vector must have the same dimension as the decomposed matrix."
+        );
+
+        let mut res = self.clone();
+        let mut z = self.eigenvectors.ad_mul(v);
+        let z_norm = z.norm();
+
+        if rho.is_zero() || z_norm.is_zero() {
+            return res;
+        }
+
+        z.unscale_mut(z_norm);
+
+        // Work with a positive `rho` by negating the spectrum if needed. This leaves the
+        // eigenvectors unchanged.
+        let zero = T::RealField::zero();
+        let half: T::RealField = crate::convert(0.5);
+        let sign = if rho < zero {
+            -T::RealField::one()
+        } else {
+            T::RealField::one()
+        };
+        let rho = rho.abs() * z_norm * z_norm;
+        let d: Vec<T::RealField> = self.eigenvalues.iter().map(|&e| e * sign).collect();
+
+        let tol = crate::convert::<_, T::RealField>(8.0)
+            * T::RealField::default_epsilon()
+            * self.eigenvalues.amax().max(rho);
+
+        let mut order: Vec<usize> = (0..dim).collect();
+        order.sort_unstable_by(|&i, &j| d[i].partial_cmp(&d[j]).unwrap_or(Ordering::Equal));
+
+        // Deflation. The remaining indices are sorted by increasing eigenvalue.
+        let mut active: Vec<usize> = Vec::with_capacity(dim);
+        for &j in &order {
+            if rho * z[j].modulus() <= tol {
+                continue;
+            }
+
+            if let Some(&i) = active.last() {
+                if d[j] - d[i] <= tol {
+                    // Rotate the eigenvectors of both equal eigenvalues so that the component of
+                    // `z` along the `i`-th one vanishes.
+                    let r = (z[i].modulus_squared() + z[j].modulus_squared()).sqrt();
+                    let (zi, zj) = (z[i].unscale(r), z[j].unscale(r));
+                    let ui = res.eigenvectors.column(i).clone_owned();
+                    let uj = res.eigenvectors.column(j).clone_owned();
+                    res.eigenvectors
+                        .set_column(i, &(&ui * zj.conjugate() - &uj * zi.conjugate()));
+                    res.eigenvectors.set_column(j, &(ui * zi + uj * zj));
+                    z[i] = T::zero();
+                    z[j] = T::from_real(r);
+                    let _ = active.pop();
+                }
+            }
+
+            active.push(j);
+        }
+
+        let k = active.len();
+        if k == 0 {
+            return res;
+        }
+
+        let dk: Vec<T::RealField> = active.iter().map(|&j| d[j]).collect();
+        let zk: Vec<T::RealField> = active.iter().map(|&j| z[j].modulus()).collect();
+        let z_sq = zk.iter().fold(zero, |acc, z| acc + *z * *z);
+
+        // The secular function at `dk[origin] + mu`. The distances to the poles are computed
+        // relative to `origin`, which must be the pole closest to the evaluation point.
+        let secular = |origin: usize, mu: T::RealField| {
+            zk.iter()
+                .zip(&dk)
+                .fold(T::RealField::one(), |acc, (z, dj)| {
+                    acc + rho * *z * *z / ((*dj - dk[origin]) - mu)
+                })
+        };
+
+        // Each root `λᵢ` is stored as `(origin, mu)` with `λᵢ = dk[origin] + mu`.
+        let mut roots = Vec::with_capacity(k);
+        for i in 0..k {
+            let (origin, mut lo, mut hi) = if i + 1 == k {
+                (i, zero, rho * z_sq)
+            } else {
+                let half_gap = (dk[i + 1] - dk[i]) * half;
+                if secular(i, half_gap) >= zero {
+                    (i, zero, half_gap)
+                } else {
+                    (i + 1, -half_gap, zero)
+                }
+            };
+
+            // The secular function is increasing between two consecutive poles.
+            loop {
+                let mid = (lo + hi) * half;
+                if mid <= lo || mid >= hi {
+                    break;
+                }
+
+                if secular(origin, mid) >= zero {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+            }
+
+            roots.push((origin, (lo + hi) * half));
+        }
+
+        let lambda_minus_d = |i: usize, j: usize| {
+            let (origin, mu) = roots[i];
+            (dk[origin] - dk[j]) + mu
+        };
+
+        // Löwner's formula: the vector `z` for which the computed roots are the exact
+        // eigenvalues. Using it instead of `z` keeps the eigenvectors orthogonal.
+        let mut z_hat = Vec::with_capacity(k);
+        for j in 0..k {
+            let mut prod = lambda_minus_d(k - 1, j) / rho;
+            for i in 0..j {
+                prod *= lambda_minus_d(i, j) / (dk[i] - dk[j]);
+            }
+            for i in j..k - 1 {
+                prod *= lambda_minus_d(i, j) / (dk[i + 1] - dk[j]);
+            }
+
+            let phase = z[active[j]].unscale(zk[j]);
+            z_hat.push(phase.scale(prod.abs().sqrt()));
+        }
+
+        let old_eigenvectors = res.eigenvectors.clone();
+        let mut w = Vec::with_capacity(k);
+        for i in 0..k {
+            w.clear();
+            w.extend((0..k).map(|j| z_hat[j].unscale(-lambda_minus_d(i, j))));
+            let w_norm = w
+                .iter()
+                .fold(zero, |acc, wj: &T| acc + wj.modulus_squared())
+                .sqrt();
+
+            let mut eigenvector = res.eigenvectors.column_mut(active[i]);
+            eigenvector.fill(T::zero());
+            for (wj, &j) in w.iter().zip(&active) {
+                eigenvector.axpy(wj.unscale(w_norm), &old_eigenvectors.column(j), T::one());
+            }
+
+            let (origin, mu) = roots[i];
+            res.eigenvalues[active[i]] = (dk[origin] + mu) * sign;
+        }
+
+        res
+    }
+}
+
+/// Computes the wilkinson shift, i.e., the 2x2 symmetric matrix eigenvalue to its tailing
+/// component `tnn`.
+///
+/// The inputs are interpreted as the 2x2 matrix:
+///     tmm  tmn
+///     tmn  tnn
+pub fn wilkinson_shift<T: ComplexField>(tmm: T, tnn: T, tmn: T) -> T {
+    let sq_tmn = tmn * tmn;
+    if !sq_tmn.is_zero() {
+        // We have the guarantee that the denominator won't be zero.
+        let d = (tmm - tnn) * crate::convert(0.5);
+        tnn - sq_tmn / (d + d.signum() * (d * d + sq_tmn).sqrt())
+    } else {
+        tnn
+    }
+}
+
+impl<T: RealField> SymmetricEigen<T, U2> {
+    /// Computes the eigendecomposition of the given real 2x2 symmetric matrix in closed form.
+    ///
+    /// This computes a single Jacobi rotation instead of running the iterative algorithm of
+    /// [`SymmetricEigen::new`]. The eigenvalues are not sorted.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// # use nalgebra::linalg::SymmetricEigen;
+    /// let m = Matrix2::new(2.0, 1.0,
+    ///                      1.0, 2.0);
+    /// let eig = SymmetricEigen::new_2x2(m);
+    ///
+    /// assert_relative_eq!(eig.eigenvalues.min(), 1.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(eig.eigenvalues.max(), 3.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+    /// ```
+    pub fn new_2x2(m: Matrix2<T>) -> Self {
+        symmetric_eigen_2x2(&m)
+    }
+}
+
+impl<T: RealField> SymmetricEigen<T, U3> {
+    /// Computes the eigendecomposition of the given real 3x3 symmetric matrix in closed form.
+    ///
+    /// The eigenvalues are the trigonometric solutions of the characteristic polynomial, which
+    /// is typically faster than the iterative algorithm of [`SymmetricEigen::new`] when
+    /// decomposing many small matrices (e.g. inertia or stress tensors). Repeated eigenvalues
+    /// are supported. The eigenvalues are not sorted.
+    ///
+    /// Only the lower-triangular part (including its diagonal) of `m` is read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix3;
+    /// # use nalgebra::linalg::SymmetricEigen;
+    /// let m = Matrix3::new(3.0, 2.0, 0.0,
+    ///                      2.0, 3.0, 0.0,
+    ///                      0.0, 0.0, 3.0);
+    /// let eig = SymmetricEigen::new_3x3(m);
+    ///
+    /// assert_relative_eq!(eig.eigenvalues.min(), 1.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(eig.eigenvalues.max(), 5.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+    /// ```
+    pub fn new_3x3(m: Matrix3<T>) -> Self {
+        symmetric_eigen_3x3(&m)
+    }
+}
+
+/// Computes the eigendecompositions of a slice of 3x3 symmetric matrices.
+///
+/// This is equivalent to calling [`SymmetricEigen::new_3x3`] on each matrix, which uses a
+/// closed-form solution instead of the generic tridiagonalization path. This is convenient when
+/// decomposing many small matrices (e.g. inertia or stress tensors).
+///
+/// Only the lower-triangular part (including the diagonal) of each matrix is read.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # use nalgebra::{symmetric_eigen_batch, Matrix3};
+/// let mats = [
+///     Matrix3::new(2.0, 1.0, 0.0,
+///                  1.0, 2.0, 0.0,
+///                  0.0, 0.0, 5.0),
+///     Matrix3::from_diagonal_element(4.0),
+/// ];
+/// let eigs = symmetric_eigen_batch(&mats);
+///
+/// for (m, eig) in mats.iter().zip(eigs.iter()) {
+///     assert_relative_eq!(*m, eig.recompose(), epsilon = 1.0e-10);
+/// }
+/// ```
+#[cfg(any(feature = "std", feature = "alloc"))]
+pub fn symmetric_eigen_batch<T: RealField>(mats: &[Matrix3<T>]) -> Vec<SymmetricEigen<T, U3>> {
+    mats.iter().map(symmetric_eigen_3x3).collect()
+}
+
+/// Computes the Jacobi rotation annihilating the off-diagonal component of the 2x2 symmetric
+/// matrix:
+///     app  apq
+///     apq  aqq
+///
+/// Returns `(t, c, s)` where `c` and `s` are the cosine and sine of the rotation, and `t = s / c`.
+/// The eigenvalues of the matrix are then `app - t * apq` and `aqq + t * apq`.
+fn jacobi_rotation<T: RealField>(app: T, aqq: T, apq: T) -> (T, T, T) {
+    let theta = (aqq - app) / (apq * crate::convert(2.0));
+    let t = if theta.abs() < T::one() / T::default_epsilon() {
+        theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt())
+    } else {
+        // Avoid overflowing `theta * theta`.
+        T::one() / (theta * crate::convert(2.0))
+    };
+    let c = T::one() / (t * t + T::one()).sqrt();
+
+    (t, c, t * c)
+}
+
+/// Computes the eigendecomposition of a 2x2 symmetric matrix in closed form.
+fn symmetric_eigen_2x2<T: RealField>(m: &Matrix2<T>) -> SymmetricEigen<T, U2> {
+    if m.m21.is_zero() {
+        return SymmetricEigen {
+            eigenvectors: Matrix2::identity(),
+            eigenvalues: m.diagonal(),
+        };
+    }
+
+    let (t, c, s) = jacobi_rotation(m.m11, m.m22, m.m21);
+
+    SymmetricEigen {
+        eigenvectors: Matrix2::new(c, s, -s, c),
+        eigenvalues: Vector2::new(m.m11 - t * m.m21, m.m22 + t * m.m21),
+    }
+}
+
+/// Computes the eigendecomposition of a 3x3 symmetric matrix in closed form.
+///
+/// The eigenvalues are computed with the trigonometric solution of the characteristic
+/// polynomial. The eigenvector of the most isolated eigenvalue is then obtained as a cross
+/// product of two rows of `m - λ * I`, and the two remaining eigenpairs are computed from the 2x2
+/// restriction of `m` to the orthogonal complement of that eigenvector. This handles repeated
+/// eigenvalues gracefully.
+fn symmetric_eigen_3x3<T: RealField>(m: &Matrix3<T>) -> SymmetricEigen<T, U3> {
+    let mut a = m.lower_triangle();
+    a.fill_upper_triangle_with_lower_triangle();
+
+    let diagonal = SymmetricEigen {
+        eigenvectors: Matrix3::identity(),
+        eigenvalues: a.diagonal(),
+    };
+    let amax = a.amax();
+
+    if amax.is_zero() {
+        return diagonal;
+    }
+
+    a.unscale_mut(amax);
+    let off = a.m21 * a.m21 + a.m31 * a.m31 + a.m32 * a.m32;
+
+    if off.is_zero() {
+        return diagonal;
+    }
+
+    let three: T = crate::convert(3.0);
+    let q = a.trace() / three;
+    let shifted = a - Matrix3::from_diagonal_element(q);
+    let p = ((shifted.diagonal().norm_squared() + off * crate::convert(2.0)) / crate::convert(6.0))
+        .sqrt();
+    let r = (shifted / p).determinant() * crate::convert(0.5);
+    let r = r.max(-T::one()).min(T::one());
+    let phi = r.acos() / three;
+
+    let largest = q + p * phi.cos() * crate::convert(2.0);
+    let smallest = q + p * (phi + T::two_pi() / three).cos() * crate::convert(2.0);
+    let middle = q * three - largest - smallest;
+
+    let isolated = if largest - middle >= middle - smallest {
+        largest
+    } else {
+        smallest
+    };
+
+    // The rows of `a - isolated * I` span the orthogonal complement of the eigenvector.
+    let rows = a - Matrix3::from_diagonal_element(isolated);
+    let (r0, r1, r2) = (
+        rows.row(0).transpose(),
+        rows.row(1).transpose(),
+        rows.row(2).transpose(),
+    );
+    let candidates = [r0.cross(&r1), r0.cross(&r2), r1.cross(&r2)];
+    let mut w = candidates[0];
+
+    for candidate in &candidates[1..] {
+        if candidate.norm_squared() > w.norm_squared() {
+            w = *candidate;
+        }
+    }
+
+    let w = w.try_normalize(T::zero()).unwrap_or_else(Vector3::x);
+
+    // Orthonormal basis of the orthogonal complement of `w`.
+    let u = if w.x.abs() > w.y.abs() {
+        Vector3::new(-w.z, T::zero(), w.x)
+    } else {
+        Vector3::new(T::zero(), w.z, -w.y)
+    }
+    .normalize();
+    let v = w.cross(&u);
+
+    let au = a * u;
+    let av = a * v;
+    let uv = u.dot(&av);
+    let sub = symmetric_eigen_2x2(&Matrix2::new(u.dot(&au), uv, uv, v.dot(&av)));
+    let e1 = u * sub.eigenvectors.m11 + v * sub.eigenvectors.m21;
+    let e2 = u * sub.eigenvectors.m12 + v * sub.eigenvectors.m22;
+
+    SymmetricEigen {
+        eigenvectors: Matrix3::from_columns(&[w, e1, e2]),
+        eigenvalues: Vector3::new(w.dot(&(a * w)), sub.eigenvalues.x, sub.eigenvalues.y) * amax,
+    }
+}
+
+/// Computes a unit eigenvector of the real symmetric tridiagonal matrix with diagonal `diag` and
+/// off-diagonal `off_diag`, for its eigenvalue `eigenvalue`, by inverse iteration.
+///
+/// The result is kept orthogonal to the unit vectors of `previous`, which separates the
+/// eigenvectors of close or repeated eigenvalues. `seed` selects the starting vector.
+#[cfg(any(feature = "std", feature = "alloc"))]
+fn tridiagonal_inverse_iteration<T: RealField>(
+    diag: &[T],
+    off_diag: &[T],
+    eigenvalue: T,
+    seed: usize,
+    previous: &[Vec<T>],
+) -> Vec<T> {
+    let n = diag.len();
+    let tiny = T::default_epsilon();
+
+    // LU factorization with partial pivoting of `diag - eigenvalue * I`. The upper factor has
+    // two super-diagonals `du` and `du2`, and `dl` stores the multipliers.
+    let mut d: Vec<T> = diag.iter().map(|&d| d - eigenvalue).collect();
+    let mut dl = off_diag.to_vec();
+    let mut du = off_diag.to_vec();
+    let mut du2: Vec<T> = (2..n).map(|_| T::zero()).collect();
+    let mut swapped: Vec<bool> = (1..n).map(|_| false).collect();
+
+    for i in 0..n - 1 {
+        if d[i].abs() >= dl[i].abs() {
+            if !d[i].is_zero() {
+                let fact = dl[i] / d[i];
+                dl[i] = fact;
+                d[i + 1] -= fact * du[i];
+            }
+        } else {
+            let fact = d[i] / dl[i];
+            d[i] = dl[i];
+            dl[i] = fact;
+            let temp = du[i];
+            du[i] = d[i + 1];
+            d[i + 1] = temp - fact * d[i + 1];
+            if i + 2 < n {
+                du2[i] = du[i + 1];
+                du[i + 1] *= -fact;
+            }
+            swapped[i] = true;
+        }
+    }
+
+    // The shifted matrix is singular up to rounding errors: perturb the vanishing pivots.
+    for d_i in &mut d {
+        if d_i.abs() < tiny {
+            *d_i = if d_i.is_sign_negative() { -tiny } else { tiny };
+        }
+    }
+
+    // Deterministic pseudo-random starting vector.
+    let mut state = (seed as u32).wrapping_mul(2_654_435_761).wrapping_add(1);
+    let mut z: Vec<T> = (0..n)
+        .map(|_| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            crate::convert(f64::from((state >> 16) & 0x7fff) / 32768.0 - 0.5)
+        })
+        .collect();
+
+    let orthonormalize = |z: &mut Vec<T>| {
+        for p in previous {
+            let dot = z.iter().zip(p).fold(T::zero(), |acc, (a, b)| acc + *a * *b);
+            z.iter_mut().zip(p).for_each(|(a, b)| *a -= dot * *b);
+        }
+
+        let norm = z.iter().fold(T::zero(), |acc, a| acc + *a * *a).sqrt();
+        if !norm.is_zero() {
+            z.iter_mut().for_each(|a| *a /= norm);
+        }
+    };
+
+    orthonormalize(&mut z);
+
+    // The shift is accurate to working precision, so a few iterations are enough.
+    for _ in 0..3 {
+        for i in 0..n - 1 {
+            if swapped[i] {
+                let temp = z[i];
+                z[i] = z[i + 1];
+                z[i + 1] = temp - dl[i] * z[i];
+            } else {
+                let z_i = z[i];
+                z[i + 1] -= dl[i] * z_i;
+            }
+        }
+
+        for i in (0..n).rev() {
+            let mut z_i = z[i];
+            if i + 1 < n {
+                z_i -= du[i] * z[i + 1];
+            }
+            if i + 2 < n {
+                z_i -= du2[i] * z[i + 2];
+            }
+            z[i] = z_i / d[i];
+        }
+
+        orthonormalize(&mut z);
+    }
+
+    z
+}
+
+/*
+ *
+ * Computations of eigenvalues for symmetric matrices.
+ *
+ */
+impl<T: ComplexField, D: DimSub<U1>, S: Storage<T, D, D>> SquareMatrix<T, D, S>
+where
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T::RealField, D>
+        + Allocator<T::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the eigenvalues of this symmetric matrix.
+    ///
+    /// Neither the orthogonal matrix of the reduction to tridiagonal form nor the rotations of
+    /// the iterative algorithm are accumulated: apart from a working copy of the matrix, only
+    /// vectors are allocated. This is several times faster than computing the full
+    /// [`SymmetricEigen`] decomposition for large matrices.
+    ///
+    /// Only the lower-triangular part of the matrix is read.
+    #[must_use]
+    pub fn symmetric_eigenvalues(&self) -> OVector<T::RealField, D> {
+        SymmetricEigen::do_decompose(self.clone_owned(), false, SymmetricEigenConfig::default())
+            .ok()
+            .unwrap()
+            .0
+    }
+
+    /// Computes the inertia of this symmetric matrix, i.e., the numbers of its positive, negative,
+    /// and zero eigenvalues, returned in that order.
+    ///
+    /// The matrix is positive-definite if all its eigenvalues are positive, and indefinite if it
+    /// has both positive and negative eigenvalues. The sign of its determinant is also given by
+    /// its inertia: it is zero if there is at least one zero eigenvalue, and `(-1)ⁿ` otherwise,
+    /// where `n` is the number of negative eigenvalues.
+    ///
+    /// The eigenvalues are computed with [`symmetric_eigenvalues`](Self::symmetric_eigenvalues),
+    /// since a `LDLᵀ` factorization without pivoting, which would be cheaper, breaks down on
+    /// indefinite matrices with zero leading principal minors.
+    ///
+    /// Only the lower-triangular part of the matrix is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps` − eigenvalues with an absolute value smaller than or equal to `eps` are counted as
+    /// zero.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// // A saddle-point matrix.
+    /// let m = Matrix3::new(2.0, 0.0, 1.0,
+    ///                      0.0, 2.0, 1.0,
+    ///                      1.0, 1.0, 0.0);
+    /// assert_eq!(m.symmetric_inertia(1.0e-10), (2, 1, 0));
+    /// ```
+    #[must_use]
+    pub fn symmetric_inertia(&self, eps: T::RealField) -> (usize, usize, usize) {
+        let mut inertia = (0, 0, 0);
+
+        for val in self.symmetric_eigenvalues().iter() {
+            if val.abs() <= eps {
+                inertia.2 += 1;
+            } else if val.is_sign_positive() {
+                inertia.0 += 1;
+            } else {
+                inertia.1 += 1;
+            }
+        }
+
+        inertia
+    }
+
+    /// Counts the eigenvalues of this symmetric matrix within the half-open interval
+    /// `(lower, upper]`.
+    ///
+    /// The matrix is reduced to tridiagonal form, then the number of eigenvalues smaller than or
+    /// equal to each bound is given by the number of sign changes of the Sturm sequence of the
+    /// tridiagonal matrix, i.e., by the number of negative pivots of its shifted `LDLᵀ`
+    /// factorization, in `O(n)` each. The interval is half-open so that adjacent intervals never
+    /// count the same eigenvalue twice, but eigenvalues within rounding errors of a bound may be
+    /// counted on either of its sides. The bounds may be infinite.
+    ///
+    /// Only the lower-triangular part of the matrix is read.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// // The eigenvalues are 1, 3 and 5.
+    /// let m = Matrix3::new(3.0, 2.0, 0.0,
+    ///                      2.0, 3.0, 0.0,
+    ///                      0.0, 0.0, 3.0);
+    /// assert_eq!(m.symmetric_count_eigenvalues_in(0.0, 4.0), 2);
+    /// assert_eq!(m.symmetric_count_eigenvalues_in(f64::NEG_INFINITY, 2.0), 1);
+    /// assert_eq!(m.symmetric_count_eigenvalues_in(5.5, 10.0), 0);
+    /// ```
+    #[must_use]
+    pub fn symmetric_count_eigenvalues_in(
+        &self,
+        lower: T::RealField,
+        upper: T::RealField,
+    ) -> usize {
+        let sturm = SturmSequence::new(self.clone_owned());
+        let (lower, upper) = (sturm.unscale(lower), sturm.unscale(upper));
+        sturm
+            .count_up_to(upper)
+            .saturating_sub(sturm.count_up_to(lower))
+    }
+
+    /// Computes the eigenvalues of this symmetric matrix within the half-open interval
+    /// `(lower, upper]`, sorted in increasing order and repeated according to their
+    /// multiplicities.
+    ///
+    /// Each eigenvalue is isolated by bisection, using the same Sturm sequence count as
+    /// [`symmetric_count_eigenvalues_in`](Self::symmetric_count_eigenvalues_in), until it is known
+    /// within an absolute error of `eps`. After the `O(n³)` reduction to tridiagonal form, this
+    /// costs `O(n)` per bisection step and only for the eigenvalues of the interval, which makes it
+    /// cheaper than [`symmetric_eigenvalues`](Self::symmetric_eigenvalues) when only a small part
+    /// of the spectrum is needed. The bounds may be infinite.
+    ///
+    /// Only the lower-triangular part of the matrix is read.
+    ///
+    /// # Arguments
+    ///
+    /// * `eps` − the absolute error on the computed eigenvalues. It is not worth setting it
+    /// below the rounding errors of the tridiagonal reduction, in which case the bisection simply
+    /// stops once the floating-point numbers are exhausted.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix3;
+    /// // The eigenvalues are 1, 3 and 5.
+    /// let m = Matrix3::new(3.0, 2.0, 0.0,
+    ///                      2.0, 3.0, 0.0,
+    ///                      0.0, 0.0, 3.0);
+    /// let values = m.symmetric_eigenvalues_in_interval(2.0, 10.0, 1.0e-12);
+    /// assert_eq!(values.len(), 2);
+    /// assert_relative_eq!(values[0], 3.0, epsilon = 1.0e-10);
+    /// assert_relative_eq!(values[1], 5.0, epsilon = 1.0e-10);
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[must_use]
+    pub fn symmetric_eigenvalues_in_interval(
+        &self,
+        lower: T::RealField,
+        upper: T::RealField,
+        eps: T::RealField,
+    ) -> Vec<T::RealField> {
+        let sturm = SturmSequence::new(self.clone_owned());
+        let (lower, upper) = (sturm.unscale(lower), sturm.unscale(upper));
+        let eps = sturm.unscale(eps);
+        let first = sturm.count_up_to(lower);
+        let last = sturm.count_up_to(upper);
+
+        // Finite bounds for the bisection, which may be tighter than the requested interval.
+        let (min, max) = sturm.bounds();
+        let (lower, upper) = (lower.max(min), upper.min(max));
+
+        (first..last)
+            .map(|k| {
+                // The `k`-th smallest eigenvalue always lies within `(lo, hi]`.
+                let (mut lo, mut hi) = (lower, upper);
+
+                while hi - lo > eps {
+                    let mid = (lo + hi) * crate::convert(0.5);
+
+                    if mid <= lo || mid >= hi {
+                        break;
+                    }
+
+                    if sturm.count_up_to(mid) > k {
+                        hi = mid;
+                    } else {
+                        lo = mid;
+                    }
+                }
+
+                sturm.scale((lo + hi) * crate::convert(0.5))
+            })
+            .collect()
+    }
+
+    /// Computes `k` eigenpairs of this symmetric matrix, selected by the absolute value of their
+    /// eigenvalues.
+    ///
+    /// Returns the `k` selected eigenvalues, sorted from the most to the least extreme according
+    /// to `which`, and the matrix whose columns are the corresponding unit eigenvectors.
+    ///
+    /// The matrix is still fully reduced to tridiagonal form, which costs `O(n³)`, and all its
+    /// eigenvalues are computed in `O(n²)`. However, the eigenvectors are then only computed for
+    /// the selected eigenvalues, by inverse iteration on the tridiagonal matrix and
+    /// back-transformation in `O(n² k)`, instead of accumulating every rotation into a full
+    /// `n x n` basis. This is typically two to three times faster than the full decomposition
+    /// when `k` is much smaller than `n`, but has no advantage when most of the eigenpairs are
+    /// needed.
+    ///
+    /// Only the lower-triangular part of the matrix is read.
+    ///
+    /// Panics
+    /// ------
+    /// Panics if `k` is greater than the dimension of this matrix.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Extreme, Matrix4};
+    /// let m = Matrix4::new(4.0f64, 1.0, 0.0, 0.0,
+    ///                      1.0, 3.0, 1.0, 0.0,
+    ///                      0.0, 1.0, -2.0, 1.0,
+    ///                      0.0, 0.0, 1.0, 0.5);
+    /// let (values, vectors) = m.symmetric_eigen_partial(2, Extreme::LargestMagnitude);
+    /// assert_eq!(values.len(), 2);
+    /// assert!(values[0].abs() >= values[1].abs());
+    ///
+    /// for (value, vector) in values.iter().zip(vectors.column_iter()) {
+    ///     assert_relative_eq!(m * vector, vector * *value, epsilon = 1.0e-10);
+    /// }
+    /// ```
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    #[must_use]
+    pub fn symmetric_eigen_partial(
+        &self,
+        k: usize,
+        which: Extreme,
+    ) -> (OVector<T::RealField, Dynamic>, OMatrix<T, D, Dynamic>)
+    where
+        DefaultAllocator: Allocator<T, D, Dynamic> + Allocator<T::RealField, Dynamic>,
+    {
+        let dim = self.data.shape().0;
+        assert!(
+            k <= dim.value(),
+            "Cannot compute more eigenpairs than the dimension of the matrix."
+        );
+
+        if k == 0 {
+            return (
+                OVector::zeros_generic(Dynamic::new(0), Const::<1>),
+                OMatrix::zeros_generic(dim, Dynamic::new(0)),
+            );
+        }
+
+        let mut matrix = self.clone_owned();
+        let m_amax = matrix.camax();
+
+        if !m_amax.is_zero() {
+            matrix.unscale_mut(m_amax);
+        }
+
+        let tri = SymmetricTridiagonal::new(matrix);
+        let (diag, off_diag) = (tri.diagonal(), tri.off_diagonal());
+        let (mut eigenvalues, mut work) = (diag.clone(), off_diag.clone());
+        // Without an iteration limit, this cannot fail.
+        let _ = SymmetricEigen::<T, D>::solve_tridiagonal(
+            &mut eigenvalues,
+            &mut work,
+            None,
+            T::RealField::one(),
+            SymmetricEigenConfig::default(),
+        );
+
+        let mut selected: Vec<usize> = (0..eigenvalues.len()).collect();
+        selected.sort_by(|&a, &b| {
+            let (a, b) = (eigenvalues[a].abs(), eigenvalues[b].abs());
+            let ordering = match which {
+                Extreme::LargestMagnitude => b.partial_cmp(&a),
+                Extreme::SmallestMagnitude => a.partial_cmp(&b),
+            };
+            ordering.unwrap_or(Ordering::Equal)
+        });
+        selected.truncate(k);
+
+        let mut vectors: Vec<Vec<T::RealField>> = Vec::with_capacity(k);
+        for &i in &selected {
+            let vector = tridiagonal_inverse_iteration(
+                diag.as_slice(),
+                off_diag.as_slice(),
+                eigenvalues[i],
+                i,
+                &vectors,
+            );
+            vectors.push(vector);
+        }
+
+        let mut eigenvectors =
+            OMatrix::from_fn_generic(dim, Dynamic::new(k), |r, c| T::from_real(vectors[c][r]));
+        tri.q_mul_mut(&mut eigenvectors);
+
+        let eigenvalues = OVector::from_iterator_generic(
+            Dynamic::new(k),
+            Const::<1>,
+            selected.iter().map(|&i| eigenvalues[i] * m_amax),
+        );
+
+        (eigenvalues, eigenvectors)
+    }
+
+    /// Computes the nearest symmetric positive semi-definite matrix to this matrix, in the
+    /// Frobenius norm.
+    ///
+    /// This is Higham's projection: the eigenvalues of the symmetric part of this matrix that are
+    /// negative are clamped to zero. This is useful to repair a covariance matrix that became
+    /// slightly indefinite because of rounding errors. If a strictly positive-definite result is
+    /// needed, use `.symmetric_eigen().recompose_map(|val| val.max(eps))` with a small positive
+    /// `eps` instead.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::Matrix2;
+    /// let m = Matrix2::new(1.0, 2.0,
+    ///                      2.0, 1.0);
+    /// let spd = m.nearest_spd();
+    /// assert_relative_eq!(spd, Matrix2::new(1.5, 1.5, 1.5, 1.5), epsilon = 1.0e-7);
+    /// ```
+    #[must_use]
+    pub fn nearest_spd(&self) -> OMatrix<T, D, D> {
+        self.hermitian_part()
+            .symmetric_eigen()
+            .recompose_map(|val| {
+                if val < T::RealField::zero() {
+                    T::RealField::zero()
+                } else {
+                    val
+                }
+            })
+    }
+
+    /// Refines an approximate eigenvector of this symmetric matrix into an eigenpair, using the
+    /// Rayleigh quotient iteration.
+    ///
+    /// Each iteration performs one step of inverse iteration shifted by the Rayleigh quotient
+    /// `xᴴ * self * x` of the current unit vector `x`, which converges cubically once `initial` is
+    /// close enough to an eigenvector. The eigenpair that is found is usually the one whose
+    /// eigenvector is the closest to `initial`, which makes this suitable to track a mode of a
+    /// matrix that changes slightly over time.
+    ///
+    /// Returns the eigenvalue and the unit eigenvector once the residual norm
+    /// `|self * x - λ * x|` is at most `eps`. The sign (or phase) of the eigenvector is chosen
+    /// so that its dot product with `initial` is a non-negative real number. Returns `None` if
+    /// `initial` is zero, if the shifted matrix is exactly singular before convergence, or if
+    /// convergence is not reached after `max_niter` iterations.
+    ///
+    /// Only the lower-triangular part (including the diagonal) of `self` is read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let m = Matrix3::new(2.0, 1.0, 0.0,
+    ///                      1.0, 3.0, 1.0,
+    ///                      0.0, 1.0, 4.0);
+    /// let (value, vector) = m
+    ///     .rayleigh_quotient_iteration(Vector3::new(0.1, 0.5, 1.0), 10, 1.0e-12)
+    ///     .unwrap();
+    /// assert_relative_eq!(m * vector, vector * value, epsilon = 1.0e-12);
+    /// ```
+    pub fn rayleigh_quotient_iteration(
+        &self,
+        initial: OVector<T, D>,
+        max_niter: usize,
+        eps: T::RealField,
+    ) -> Option<(T::RealField, OVector<T, D>)>
+    where
+        D: DimMin<D, Output = D>,
+        DefaultAllocator: Allocator<T, D> + Allocator<(usize, usize), D>,
+    {
+        let initial = initial.try_normalize(T::RealField::zero())?;
+        let mut x = initial.clone();
+
+        let mut m = self.clone_owned();
+        for j in 0..m.ncols() {
+            for i in 0..j {
+                m[(i, j)] = m[(j, i)].conjugate();
+            }
+        }
+
+        let mut niter = 0;
+
+        loop {
+            let mx = &m * &x;
+            let rho = x.dotc(&mx).real();
+            let residual = (mx - x.scale(rho)).norm();
+
+            if residual <= eps {
+                // Inverse iteration may flip the sign (or phase) of the vector.
+                let dot = x.dotc(&initial);
+                if !dot.is_zero() {
+                    x *= dot.signum();
+                }
+
+                return Some((rho, x));
+            }
+
+            if niter == max_niter {
+                return None;
+            }
+
+            niter += 1;
+
+            let mut shifted = m.clone();
+            for i in 0..shifted.nrows() {
+                shifted[(i, i)] -= T::from_real(rho);
+            }
+
+            x = shifted
+                .lu()
+                .solve(&x)?
+                .try_normalize(T::RealField::zero())?;
+        }
+    }
+}
+
+/// The symmetric tridiagonal form of a matrix, scaled so that its largest element is at most 1,
+/// for counting its eigenvalues with Sturm sequences.
+struct SturmSequence<T: RealField, D: DimSub<U1>>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, DimDiff<D, U1>>,
+{
+    diag: OVector<T, D>,
+    off_diag_sq: OVector<T, DimDiff<D, U1>>,
+    scale: T,
+    pivmin: T,
+}
+
+impl<T: RealField, D: DimSub<U1>> SturmSequence<T, D>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, DimDiff<D, U1>>,
+{
+    fn new<N: ComplexField<RealField = T>>(mut matrix: OMatrix<N, D, D>) -> Self
+    where
+        DefaultAllocator: Allocator<N, D, D> + Allocator<N, DimDiff<D, U1>>,
+    {
+        let m_amax = matrix.camax();
+
+        if !m_amax.is_zero() {
+            matrix.unscale_mut(m_amax);
+        }
+
+        let (diag, off_diag) = SymmetricTridiagonal::new(matrix).unpack_tridiagonal();
+        let scale = if m_amax.is_zero() { T::one() } else { m_amax };
+
+        Self {
+            diag,
+            off_diag_sq: off_diag.map(|e| e * e),
+            scale,
+            pivmin: T::default_epsilon() * T::default_epsilon(),
+        }
+    }
+
+    fn unscale(&self, val: T) -> T {
+        val / self.scale
+    }
+
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn scale(&self, val: T) -> T {
+        val * self.scale
+    }
+
+    /// The number of eigenvalues smaller than or equal to `x`.
+    ///
+    /// This is the number of negative pivots of the `LDLᵀ` factorization of the tridiagonal
+    /// matrix shifted by `-x`, where pivots too close to zero are replaced by a tiny negative
+    /// value, as in LAPACK's `dstebz`.
+    fn count_up_to(&self, x: T) -> usize {
+        let mut count = 0;
+        let mut pivot = T::one();
+
+        for i in 0..self.diag.len() {
+            pivot = if i == 0 {
+                self.diag[i] - x
+            } else {
+                self.diag[i] - x - self.off_diag_sq[i - 1] / pivot
+            };
+
+            if pivot.abs() < self.pivmin {
+                pivot = -self.pivmin;
+            }
+
+            if pivot.is_sign_negative() {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Finite bounds below and above every eigenvalue, from the Gershgorin discs of the
+    /// tridiagonal matrix, slightly widened to account for rounding errors in `count_up_to`.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    fn bounds(&self) -> (T, T) {
+        let dim = self.diag.len();
+        let (mut min, mut max) = (self.diag[0], self.diag[0]);
+
+        for i in 0..dim {
+            let mut radius = T::zero();
+
+            if i > 0 {
+                radius += self.off_diag_sq[i - 1].sqrt();
+            }
+            if i + 1 < dim {
+                radius += self.off_diag_sq[i].sqrt();
+            }
+
+            min = min.min(self.diag[i] - radius);
+            max = max.max(self.diag[i] + radius);
+        }
+
+        let margin =
+            T::default_epsilon() * min.abs().max(max.abs()) * crate::convert(2.0 * dim as f64)
+                + self.pivmin * crate::convert(2.0);
+
+        (min - margin, max + margin)
+    }
+}
+
+impl<T: ComplexField, D: DimSub<U1>, S: StorageMut<T, D, D>> SquareMatrix<T, D, S>
+where
+    DefaultAllocator: Allocator<T, D, D>
+        + Allocator<T, DimDiff<D, U1>>
+        + Allocator<T::RealField, D>
+        + Allocator<T::RealField, DimDiff<D, U1>>,
+{
+    /// Computes the eigenvalues of this symmetric matrix, using its storage as the workspace of
+    /// the reduction to tridiagonal form.
+    ///
+    /// This is the same as [`symmetric_eigenvalues`](Self::symmetric_eigenvalues), except that
+    /// the matrix is not copied first. The content of this matrix is unspecified afterward, so
+    /// this should only be used when the matrix is no longer needed.
+    ///
+    /// Only the lower-triangular part of the matrix is read.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate approx;
+    /// # use nalgebra::{Matrix3, Vector3};
+    /// let m = Matrix3::new(2.0f64, 1.0, 0.0,
+    ///                      1.0, 3.0, 1.0,
+    ///                      0.0, 1.0, 4.0);
+    /// let mut workspace = m;
+    /// let mut eigenvalues = workspace.symmetric_eigenvalues_destructive();
+    /// eigenvalues.as_mut_slice().sort_by(|a, b| a.partial_cmp(b).unwrap());
+    ///
+    /// let s3 = 3.0f64.sqrt();
+    /// assert_relative_eq!(eigenvalues, Vector3::new(3.0 - s3, 3.0, 3.0 + s3), epsilon = 1.0e-10);
+    /// ```
+    #[must_use]
+    pub fn symmetric_eigenvalues_destructive(&mut self) -> OVector<T::RealField, D> {
+        assert!(
+            self.is_square(),
+            "Unable to compute the eigenvalues of a non-square matrix."
+        );
+
+        let dim = self.data.shape().0;
+        let m_amax = self.camax();
+
+        if !m_amax.is_zero() {
+            self.unscale_mut(m_amax);
+        }
+
+        let sub_dim = dim.sub(Const::<1>);
+        let mut householder_off_diag = OVector::zeros_generic(sub_dim, Const::<1>);
+        let mut p = OVector::zeros_generic(sub_dim, Const::<1>);
+        SymmetricTridiagonal::tridiagonalize_mut(self, &mut householder_off_diag, &mut p);
+
+        let mut diag = self.map_diagonal(|e| e.real());
+        let mut off_diag = householder_off_diag.map(T::modulus);
+
+        let _ = SymmetricEigen::<T, D>::solve_tridiagonal(
+            &mut diag,
+            &mut off_diag,
+            None,
+            m_amax,
+            SymmetricEigenConfig::default(),
+        );
+
+        diag
+    }
+}
+
+impl<T: ComplexField, D: Dim, S: Storage<T, D, D>> SquareMatrix<T, D, S> {
+    /// Computes an interval containing the real part of every eigenvalue of this matrix, using
+    /// Gershgorin's circle theorem.
+    ///
+    /// Every eigenvalue lies in one of the discs centered at a diagonal element `a_ii`, with a
+    /// radius equal to the sum of the moduli of the off-diagonal elements of the `i`-th row.
+    /// This returns the smallest and largest real parts covered by these discs. For a symmetric
+    /// (or hermitian) matrix, the eigenvalues are real, so they are all guaranteed to lie within
+    /// the returned bounds.
+    ///
+    /// This only costs one pass over the matrix, so it is useful for choosing shifts or scaling
+    /// factors before running an iterative eigensolver.
+    ///
+    /// # Panics
+    /// Panics if the matrix is not square or is empty.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::Matrix3;
+    /// let m = Matrix3::new(4.0, 1.0, 0.0,
+    ///                      1.0, 2.0, -1.0,
+    ///                      0.0, -1.0, 6.0);
+    /// let (min, max) = m.gershgorin_bounds();
+    /// assert_eq!((min, max), (0.0, 7.0));
+    ///
+    /// for val in m.symmetric_eigenvalues().iter() {
+    ///     assert!(min <= *val && *val <= max);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn gershgorin_bounds(&self) -> (T::RealField, T::RealField) {
+        assert!(
+            self.is_square(),
+            "Unable to compute the Gershgorin bounds of a non-square matrix."
+        );
+        assert!(
+            !self.is_empty(),
+            "Unable to compute the Gershgorin bounds of an empty matrix."
+        );
+
+        let dim = self.nrows();
+        let mut bounds: Option<(T::RealField, T::RealField)> = None;
+
+        for i in 0..dim {
+            let center = self[(i, i)].real();
+            let mut radius = T::RealField::zero();
+
+            for j in (0..dim).filter(|j| *j != i) {
+                radius += self[(i, j)].modulus();
+            }
+
+            let lo = center - radius;
+            let hi = center + radius;
+
+            bounds = Some(match bounds {
+                Some((min, max)) => (min.min(lo), max.max(hi)),
+                None => (lo, hi),
+            });
+        }
+
+        bounds.unwrap()
     }
 }
 
@@ -2,14 +2,32 @@
 use serde::{Deserialize, Serialize};
 
 use crate::allocator::Allocator;
-use crate::base::{DefaultAllocator, OMatrix, OVector};
+use crate::base::{DefaultAllocator, Matrix, OMatrix, OVector};
 use crate::dimension::{Const, DimDiff, DimSub, U1};
-use crate::storage::Storage;
+use crate::storage::{Storage, StorageMut};
 use simba::scalar::ComplexField;
 
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::{base::Unit, dimension::Dim, geometry::Reflection};
+
 use crate::linalg::householder;
 
 /// Tridiagonalization of a symmetric matrix.
+///
+/// The decomposition `m = Q * T * Qᴴ` is computed with `n - 1` Householder reflections
+/// `Hᵢ = I - 2 * vᵢ * vᵢᴴ`, where `vᵢ` is a unit vector whose first `i + 1` components are zero.
+/// The `i`-th reflection maps the part `x` of the `i`-th column of the partially reduced matrix
+/// that lies below the diagonal to `βᵢ * e₁`, where `βᵢ = -sign(x₀) * |x|` and
+/// `sign(x₀) = x₀ / |x₀|` (`1` if `x₀` is zero). The reflections and the raw, possibly negative
+/// or complex, off-diagonal elements `βᵢ` are available through
+/// [`householder_q`](Self::householder_q) and
+/// [`householder_off_diagonal`](Self::householder_off_diagonal), which follow the same
+/// convention as LAPACK's `?sytrd`/`?hetrd` up to the normalization of the reflection vectors.
+///
+/// However, [`q`](Self::q) absorbs the phase of each `βᵢ` into the columns of `Q`, so that the
+/// off-diagonal elements of `T` returned by [`off_diagonal`](Self::off_diagonal), [`unpack`](Self::unpack),
+/// and [`unpack_tridiagonal`](Self::unpack_tridiagonal) are the non-negative reals `|βᵢ|`. This is
+/// the form used by [`SymmetricEigen`](crate::linalg::SymmetricEigen).
 #[cfg_attr(feature = "serde-serialize-no-std", derive(Serialize, Deserialize))]
 #[cfg_attr(
     feature = "serde-serialize-no-std",
@@ -68,7 +86,22 @@ where
             crate::unimplemented_or_uninitialized_generic!(dim.sub(Const::<1>), Const::<1>)
         };
 
-        for i in 0..dim.value() - 1 {
+        Self::tridiagonalize_mut(&mut m, &mut off_diagonal, &mut p);
+
+        Self {
+            tri: m,
+            off_diagonal,
+        }
+    }
+
+    /// Tridiagonalizes `m` in-place, storing the householder reflection axii on its
+    /// lower-triangular part. `p` is used as a workspace.
+    pub(crate) fn tridiagonalize_mut<S: StorageMut<T, D, D>>(
+        m: &mut Matrix<T, D, D, S>,
+        off_diagonal: &mut OVector<T, DimDiff<D, U1>>,
+        p: &mut OVector<T, DimDiff<D, U1>>,
+    ) {
+        for i in 0..m.nrows() - 1 {
             let mut m = m.rows_range_mut(i + 1..);
             let (mut axis, mut m) = m.columns_range_pair_mut(i, i + 1..);
 
@@ -86,11 +119,6 @@ where
                 m.hegerc(dot * crate::convert(2.0), &axis, &axis, T::one());
             }
         }
-
-        Self {
-            tri: m,
-            off_diagonal,
-        }
     }
 
     #[doc(hidden)]
@@ -148,12 +176,56 @@ where
         self.off_diagonal.map(T::modulus)
     }
 
+    /// The raw off-diagonal elements `βᵢ` computed by the Householder reflections.
+    ///
+    /// Unlike [`off_diagonal`](Self::off_diagonal), these may be negative or complex: they are the
+    /// sub-diagonal elements of `householder_q()ᴴ * m * householder_q()`, where `m` is the
+    /// decomposed matrix. See [`SymmetricTridiagonal`] for the exact convention.
+    #[must_use]
+    pub fn householder_off_diagonal(&self) -> &OVector<T, DimDiff<D, U1>> {
+        &self.off_diagonal
+    }
+
+    /// Computes the product `H₀ * H₁ * ... * Hₙ₋₂` of the Householder reflections of this
+    /// decomposition.
+    ///
+    /// Unlike [`q`](Self::q), the phases of the off-diagonal elements are not absorbed into this
+    /// matrix, so the sub-diagonal of `householder_q()ᴴ * m * householder_q()` is given by
+    /// [`householder_off_diagonal`](Self::householder_off_diagonal).
+    #[must_use]
+    pub fn householder_q(&self) -> OMatrix<T, D, D> {
+        let signs = OVector::<T, DimDiff<D, U1>>::from_element_generic(
+            self.off_diagonal.data.shape().0,
+            Const::<1>,
+            T::one(),
+        );
+        householder::assemble_q(&self.tri, signs.as_slice())
+    }
+
     /// Computes the orthogonal matrix `Q` of this decomposition.
+    ///
+    /// This is [`householder_q`](Self::householder_q) with its `i + 1`-th column multiplied by
+    /// the product of the phases `βₖ / |βₖ|` of the raw off-diagonal elements `β₀, ..., βᵢ`, so
+    /// that the off-diagonal elements of `Qᴴ * m * Q` are the non-negative reals returned by
+    /// [`off_diagonal`](Self::off_diagonal).
     #[must_use]
     pub fn q(&self) -> OMatrix<T, D, D> {
         householder::assemble_q(&self.tri, self.off_diagonal.as_slice())
     }
 
+    /// Computes `Q * rhs` in-place, where `Q` is the orthogonal matrix of this decomposition,
+    /// without assembling `Q`.
+    #[cfg(any(feature = "std", feature = "alloc"))]
+    pub(crate) fn q_mul_mut<C: Dim, S: StorageMut<T, D, C>>(&self, rhs: &mut Matrix<T, D, C, S>) {
+        for i in (0..self.tri.nrows() - 1).rev() {
+            let axis = self.tri.slice_range(i + 1.., i);
+            let refl = Reflection::new(Unit::new_unchecked(axis), T::zero());
+
+            let mut rhs_rows = rhs.rows_range_mut(i + 1..);
+            refl.reflect_with_sign(&mut rhs_rows, self.off_diagonal[i].signum());
+        }
+    }
+
     /// Recomputes the original symmetric matrix.
     pub fn recompose(mut self) -> OMatrix<T, D, D> {
         let q = self.q();
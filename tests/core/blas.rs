@@ -91,6 +91,47 @@ mod blas_proptest {
             prop_assert!(relative_eq!(a1.lower_triangle(), a2))
         }
 
+        #[test]
+        fn syr(n in PROPTEST_MATRIX_DIM, alpha in PROPTEST_F64) {
+            let a = DMatrix::<f64>::new_random(n, n);
+            let mut a1 = &a * a.transpose();
+            let mut a2 = a1.lower_triangle();
+
+            let x = DVector::new_random(n);
+
+            a1.ger(alpha, &x, &x, 1.0);
+            a2.syr(alpha, &x);
+
+            prop_assert!(relative_eq!(a1.lower_triangle(), a2, epsilon = 1.0e-10));
+
+            a2.fill_upper_triangle_with_lower_triangle();
+            prop_assert!(relative_eq!(a1, a2, epsilon = 1.0e-10))
+        }
+
+        #[test]
+        fn syrk(n in PROPTEST_MATRIX_DIM, k in 0..8usize, alpha in PROPTEST_F64, beta in PROPTEST_F64) {
+            let a = DMatrix::<f64>::new_random(n, n);
+            let a = &a * a.transpose();
+            let mut a2 = a.lower_triangle();
+
+            let x = DMatrix::<f64>::new_random(n, k);
+            let a1 = &x * x.transpose() * alpha + &a * beta;
+
+            a2.syrk(alpha, &x, beta);
+
+            prop_assert!(relative_eq!(a1.lower_triangle(), a2, epsilon = 1.0e-10));
+
+            a2.fill_upper_triangle_with_lower_triangle();
+            prop_assert!(relative_eq!(a1, a2, epsilon = 1.0e-10));
+
+            let a1 = &x * x.transpose() * alpha;
+            a2.fill(f64::NAN);
+            a2.syrk(alpha, &x, 0.0);
+            a2.fill_upper_triangle_with_lower_triangle();
+
+            prop_assert!(relative_eq!(a1, a2, epsilon = 1.0e-10))
+        }
+
         #[test]
         fn quadform(n in PROPTEST_MATRIX_DIM, alpha in PROPTEST_F64, beta in PROPTEST_F64) {
             let rhs     = DMatrix::<f64>::new_random(6, n);
@@ -0,0 +1,78 @@
+use na::io::CsvError;
+use na::{DMatrix, Matrix2x3};
+
+#[test]
+fn csv_read_tsv_with_crlf_and_empty_lines() {
+    let data = "a\tb\r\n\r\n 1 \t-2.5\r\n3\t4\r\n\n";
+    let m = DMatrix::<f64>::from_csv_reader(data.as_bytes(), '\t', true).unwrap();
+    assert_eq!(m, DMatrix::from_row_slice(2, 2, &[1.0, -2.5, 3.0, 4.0]));
+}
+
+#[test]
+fn csv_read_empty() {
+    let m = DMatrix::<f64>::from_csv_reader("".as_bytes(), ',', false).unwrap();
+    assert_eq!(m.shape(), (0, 0));
+
+    let m = DMatrix::<f64>::from_csv_reader("x,y\n".as_bytes(), ',', true).unwrap();
+    assert_eq!(m.shape(), (0, 0));
+}
+
+#[test]
+fn csv_read_ragged_row() {
+    let data = "1,2,3\n\n4,5\n";
+    match DMatrix::<i32>::from_csv_reader(data.as_bytes(), ',', false) {
+        Err(CsvError::RaggedRow {
+            line,
+            expected,
+            found,
+        }) => assert_eq!((line, expected, found), (3, 3, 2)),
+        res => panic!("unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+fn csv_read_invalid_value() {
+    let data = "h1;h2\n1;2\n3;\n";
+    match DMatrix::<i32>::from_csv_reader(data.as_bytes(), ';', true) {
+        Err(CsvError::InvalidValue {
+            line,
+            column,
+            value,
+        }) => assert_eq!((line, column, value.as_str()), (3, 2, "")),
+        res => panic!("unexpected result: {:?}", res),
+    }
+}
+
+#[test]
+fn csv_write_read_roundtrip() {
+    let m = Matrix2x3::new(1.0 / 3.0, -2.0e-300, 3.0, f64::MAX, 0.1, -0.0);
+    let mut out = Vec::new();
+    m.to_csv_writer(&mut out, ',').unwrap();
+
+    let read = DMatrix::<f64>::from_csv_reader(out.as_slice(), ',', false).unwrap();
+    assert_eq!(read, m);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use crate::proptest::*;
+    use na::DMatrix;
+    use proptest::{prop_assert_eq, proptest};
+
+    proptest! {
+        #[test]
+        fn csv_write_read_roundtrip(m in dmatrix()) {
+            for &delimiter in &[',', '\t', ' '] {
+                let mut out = Vec::new();
+                m.to_csv_writer(&mut out, delimiter).unwrap();
+
+                let read = DMatrix::<f64>::from_csv_reader(out.as_slice(), delimiter, false).unwrap();
+                if m.nrows() == 0 || m.ncols() == 0 {
+                    prop_assert_eq!(read.len(), 0);
+                } else {
+                    prop_assert_eq!(read, m.clone());
+                }
+            }
+        }
+    }
+}
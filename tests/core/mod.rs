@@ -3,6 +3,8 @@ mod abomonation;
 mod blas;
 mod cg;
 mod conversion;
+#[cfg(all(feature = "io", feature = "sparse"))]
+mod csv;
 mod edition;
 mod empty;
 mod matrix;
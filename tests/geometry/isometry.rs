@@ -1,7 +1,10 @@
 #![cfg(feature = "proptest-support")]
 #![allow(non_snake_case)]
 
-use na::{Isometry3, Point3, Vector3};
+use na::{
+    Isometry2, Isometry3, IsometryMatrix2, IsometryMatrix3, Matrix3, Matrix4, Point3, Rotation3,
+    UnitQuaternion, Vector2, Vector3, Vector6,
+};
 
 use crate::proptest::*;
 use proptest::{prop_assert, prop_assert_eq, proptest};
@@ -48,6 +51,154 @@ proptest!(
             ))
     }
 
+    #[test]
+    fn try_look_at_rh_3_matches_unchecked(eye in point3(), target in point3(), up in vector3()) {
+        let viewmatrix = Isometry3::look_at_rh(&eye, &target, &up);
+
+        match Isometry3::try_look_at_rh(&eye, &target, &up, 1.0e-7) {
+            Some(checked) => prop_assert!(relative_eq!(checked, viewmatrix, epsilon = 1.0e-7)),
+            None => {
+                let dir = target - eye;
+                prop_assert!(dir.norm() <= 1.0e-7 || up.cross(&dir.normalize()).norm() <= 1.0e-7)
+            }
+        }
+    }
+
+    #[test]
+    fn try_observer_frame_3_rejects_collinear_up(eye in point3(), target in point3(), s in -10.0..10.0f64) {
+        let up = (target - eye) * s;
+
+        prop_assert!(Isometry3::try_face_towards(&eye, &target, &up, 1.0e-7).is_none());
+        prop_assert!(IsometryMatrix3::try_face_towards(&eye, &target, &up, 1.0e-7).is_none());
+    }
+
+    #[test]
+    fn lerp_slerp_endpoints_3(a in isometry3(), b in isometry3()) {
+        prop_assert!(relative_eq!(a.lerp_slerp(&b, 0.0), a, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(a.lerp_slerp(&b, 1.0), b, epsilon = 1.0e-7));
+
+        let (ma, mb): (IsometryMatrix3<f64>, IsometryMatrix3<f64>) = (na::convert(a), na::convert(b));
+        prop_assert!(relative_eq!(ma.lerp_slerp(&mb, 0.0), ma, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(ma.lerp_slerp(&mb, 1.0), mb, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn lerp_slerp_endpoints_2(a in isometry2(), b in isometry2()) {
+        prop_assert!(relative_eq!(a.lerp_slerp(&b, 0.0), a, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(a.lerp_slerp(&b, 1.0), b, epsilon = 1.0e-7));
+
+        let (ma, mb): (IsometryMatrix2<f64>, IsometryMatrix2<f64>) = (na::convert(a), na::convert(b));
+        prop_assert!(relative_eq!(ma.lerp_slerp(&mb, 0.0), ma, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(ma.lerp_slerp(&mb, 1.0), mb, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn try_lerp_slerp_endpoints_3(a in isometry3(), b in isometry3()) {
+        if let Some(start) = a.try_lerp_slerp(&b, 0.0, 1.0e-7) {
+            prop_assert!(relative_eq!(start, a, epsilon = 1.0e-7));
+        }
+        if let Some(end) = a.try_lerp_slerp(&b, 1.0, 1.0e-7) {
+            prop_assert!(relative_eq!(end, b, epsilon = 1.0e-7));
+        }
+    }
+
+    #[test]
+    fn screw_exp_log_roundtrip(i in isometry3()) {
+        let twist = i.log();
+
+        prop_assert!(relative_eq!(Isometry3::exp(&twist), i, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(
+            IsometryMatrix3::exp(&twist),
+            na::convert::<_, IsometryMatrix3<f64>>(i),
+            epsilon = 1.0e-7
+        ));
+    }
+
+    #[test]
+    fn screw_powf(i in isometry3()) {
+        let half = i.powf(0.5);
+
+        prop_assert!(relative_eq!(i.powf(0.0), Isometry3::identity(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(i.powf(1.0), i, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(half * half, i, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(i.powf(-1.0), i.inverse(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn adjoint_is_homomorphism_3(a in isometry3(), b in isometry3()) {
+        prop_assert!(relative_eq!((a * b).adjoint(), a.adjoint() * b.adjoint(), epsilon = 1.0e-7));
+
+        let (ma, mb): (IsometryMatrix3<f64>, IsometryMatrix3<f64>) = (na::convert(a), na::convert(b));
+        prop_assert!(relative_eq!(ma.adjoint(), a.adjoint(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((ma * mb).adjoint(), ma.adjoint() * mb.adjoint(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(a.inverse().adjoint(), a.adjoint().try_inverse().unwrap(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn adjoint_maps_twists_3(g in isometry3(), b in isometry3()) {
+        // Use a twist with a rotation angle small enough for the logarithm to be unique.
+        let twist = b.log() * 0.1;
+        let conjugated = g * Isometry3::exp(&twist) * g.inverse();
+
+        prop_assert!(relative_eq!(Isometry3::exp(&(g.adjoint() * twist)), conjugated, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn adjoint_is_homomorphism_2(a in isometry2(), b in isometry2()) {
+        prop_assert!(relative_eq!((a * b).adjoint(), a.adjoint() * b.adjoint(), epsilon = 1.0e-7));
+
+        let (ma, mb): (IsometryMatrix2<f64>, IsometryMatrix2<f64>) = (na::convert(a), na::convert(b));
+        prop_assert!(relative_eq!(ma.adjoint(), a.adjoint(), epsilon = 1.0e-7));
+        prop_assert!(relative_eq!((ma * mb).adjoint(), ma.adjoint() * mb.adjoint(), epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn adjoint_conjugates_twist_matrices_2(g in isometry2(), twist in vector3()) {
+        // The matrix of the twist `(vx, vy, ω)` in homogeneous coordinates.
+        let hat = |xi: Vector3<f64>| Matrix3::new(0.0, -xi.z, xi.x, xi.z, 0.0, xi.y, 0.0, 0.0, 0.0);
+        let h = g.to_homogeneous();
+        let expected = h * hat(twist) * g.inverse().to_homogeneous();
+
+        prop_assert!(relative_eq!(hat(g.adjoint() * twist), expected, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn mean_of_single_isometry(i in isometry3(), w in 0.1..10.0f64) {
+        prop_assert!(relative_eq!(Isometry3::mean(&[i], None).unwrap(), i, epsilon = 1.0e-7));
+        prop_assert!(relative_eq!(Isometry3::mean(&[i, i], Some(&[w, 1.0])).unwrap(), i, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn mean_is_equivariant(a in isometry3(), b in isometry3(), c in isometry3(), g in isometry3()) {
+        // Averaging rotated copies of the inputs rotates the averaged rotation.
+        let rot = Isometry3::from_parts(na::Translation3::identity(), g.rotation);
+        let mean = Isometry3::mean(&[a, b, c], Some(&[1.0, 2.0, 3.0])).unwrap();
+        let moved = [rot * a, rot * b, rot * c];
+        let moved_mean = Isometry3::mean(&moved, Some(&[1.0, 2.0, 3.0])).unwrap();
+
+        prop_assert!(relative_eq!(moved_mean, rot * mean, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn try_from_homogeneous_roundtrip_3(i in isometry3()) {
+        let m = i.to_homogeneous();
+        let extracted = Isometry3::try_from_homogeneous(&m, 1.0e-7).unwrap();
+        prop_assert!(relative_eq!(extracted, i, epsilon = 1.0e-7));
+
+        let extracted = IsometryMatrix3::try_from_homogeneous(&m, 1.0e-7).unwrap();
+        prop_assert!(relative_eq!(extracted.to_homogeneous(), m, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn try_from_homogeneous_roundtrip_2(i in isometry2()) {
+        let m = i.to_homogeneous();
+        let extracted = Isometry2::try_from_homogeneous(&m, 1.0e-7).unwrap();
+        prop_assert!(relative_eq!(extracted, i, epsilon = 1.0e-7));
+
+        let extracted = IsometryMatrix2::try_from_homogeneous(&m, 1.0e-7).unwrap();
+        prop_assert!(relative_eq!(extracted.to_homogeneous(), m, epsilon = 1.0e-7));
+    }
+
     #[test]
     fn inverse_is_identity(i in isometry3(), p in point3(), v in vector3()) {
         let ii = i.inverse();
@@ -274,3 +425,150 @@ proptest!(
             && uqMt == &uq * t)
     }
 );
+
+#[test]
+fn lerp_slerp_extrapolates_translation() {
+    let a = Isometry2::new(Vector2::new(1.0, 2.0), 0.0);
+    let b = Isometry2::new(Vector2::new(3.0, 6.0), 0.5);
+    let c = a.lerp_slerp(&b, 2.0);
+
+    assert_relative_eq!(c.translation.vector, Vector2::new(5.0, 10.0));
+    assert_relative_eq!(c.rotation.angle(), 1.0);
+}
+
+#[test]
+fn try_from_homogeneous_rejects_non_rigid_transforms() {
+    let iso = Isometry3::new(Vector3::new(1.0, -2.0, 3.0), Vector3::new(0.1, 0.2, 0.3));
+    let m = iso.to_homogeneous();
+
+    // Small perturbations are accepted within the tolerance.
+    let mut noisy = m;
+    noisy[(0, 1)] += 1.0e-9;
+    noisy[(3, 3)] += 1.0e-9;
+    assert!(Isometry3::try_from_homogeneous(&noisy, 1.0e-7).is_some());
+    assert!(Isometry3::try_from_homogeneous(&noisy, 1.0e-12).is_none());
+
+    // Scaling, shear, reflection and projection.
+    let scaling = m * Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, 1.0, 1.5));
+    let mut shear = m;
+    shear[(0, 1)] += 0.3;
+    let reflection = m * Matrix4::new_nonuniform_scaling(&Vector3::new(1.0, -1.0, 1.0));
+    let mut projection = m;
+    projection[(3, 0)] = 0.2;
+    let mut homogeneous_scaling = m;
+    homogeneous_scaling[(3, 3)] = 2.0;
+
+    for bad in &[scaling, shear, reflection, projection, homogeneous_scaling] {
+        assert!(Isometry3::try_from_homogeneous(bad, 1.0e-7).is_none());
+        assert!(IsometryMatrix3::try_from_homogeneous(bad, 1.0e-7).is_none());
+    }
+
+    let m2 = Isometry2::new(Vector2::new(1.0, 2.0), 0.7).to_homogeneous();
+    let reflection = m2 * Matrix3::new_nonuniform_scaling(&Vector2::new(-1.0, 1.0));
+    assert!(Isometry2::try_from_homogeneous(&reflection, 1.0e-7).is_none());
+}
+
+#[test]
+fn screw_log_exp_singularities() {
+    // Pure translation: the twist has no angular part.
+    let iso = Isometry3::translation(1.0, 2.0, 3.0);
+    assert_relative_eq!(iso.log(), Vector6::new(1.0, 2.0, 3.0, 0.0, 0.0, 0.0));
+    assert_relative_eq!(Isometry3::exp(&iso.log()), iso);
+
+    // Tiny rotation angle.
+    let twist = Vector6::new(1.0, -2.0, 0.5, 1.0e-12, 0.0, -2.0e-12);
+    assert_relative_eq!(Isometry3::exp(&twist).log(), twist, epsilon = 1.0e-10);
+
+    // Small rotation angles, on both sides of the switch to the Taylor expansions.
+    for &angle in &[1.0e-1, 2.0e-2, 1.0e-2, 1.0e-3, 1.0e-5] {
+        let twist = Vector6::new(1.0, -2.0, 0.5, angle, 0.0, -angle * 2.0);
+        assert_relative_eq!(Isometry3::exp(&twist).log(), twist, epsilon = 1.0e-14);
+    }
+
+    // Half-turn: the isometry is recovered even if the screw axis direction is arbitrary.
+    let twist = Vector6::new(1.0, 2.0, 3.0, 0.0, 0.0, std::f64::consts::PI);
+    let iso = Isometry3::exp(&twist);
+    let iso_mat = IsometryMatrix3::exp(&twist);
+    assert_relative_eq!(Isometry3::exp(&iso.log()), iso, epsilon = 1.0e-7);
+    assert_relative_eq!(
+        IsometryMatrix3::exp(&iso_mat.log()),
+        iso_mat,
+        epsilon = 1.0e-7
+    );
+
+    // A screw motion along the z axis: quarter turn and a translation of 1 along the axis.
+    let twist = Vector6::new(0.0, 0.0, 1.0, 0.0, 0.0, std::f64::consts::FRAC_PI_2);
+    let iso = Isometry3::exp(&twist);
+    assert_relative_eq!(iso.translation.vector, Vector3::z(), epsilon = 1.0e-7);
+    assert_relative_eq!(
+        iso.powf(2.0),
+        Isometry3::exp(&(twist * 2.0)),
+        epsilon = 1.0e-7
+    );
+}
+
+#[test]
+fn mean_of_isometries() {
+    let axis = Vector3::new(1.0, 2.0, 3.0).normalize();
+    let a = Isometry3::new(Vector3::new(1.0, 0.0, 0.0), axis * 0.2);
+    let b = Isometry3::new(Vector3::new(0.0, 3.0, 0.0), axis * 0.8);
+
+    // Opposite quaternion signs must not cancel each other.
+    let mut b_flipped = b;
+    b_flipped.rotation = na::Unit::new_unchecked(-b.rotation.into_inner());
+
+    let mean = Isometry3::mean(&[a, b_flipped], None).unwrap();
+    assert_relative_eq!(
+        mean,
+        Isometry3::new(Vector3::new(0.5, 1.5, 0.0), axis * 0.5),
+        epsilon = 1.0e-10
+    );
+
+    let mean = Isometry3::mean(&[a, b], Some(&[0.0, 2.0])).unwrap();
+    assert_relative_eq!(mean, b, epsilon = 1.0e-10);
+
+    assert!(Isometry3::<f64>::mean(&[], None).is_none());
+    assert!(Isometry3::mean(&[a, b], Some(&[1.0, 2.0, 3.0])).is_none());
+    assert!(Isometry3::mean(&[a, b], Some(&[1.0, -2.0])).is_none());
+    assert!(Isometry3::mean(&[a, b], Some(&[0.0, 0.0])).is_none());
+}
+
+#[test]
+fn try_look_at_collinear_returns_none() {
+    let eye = Point3::new(1.0f64, 2.0, 3.0);
+    let target = Point3::new(1.0, 5.0, 3.0);
+    let up = Vector3::y();
+    let dir = target - eye;
+
+    // The unchecked versions silently produce NaNs for these inputs.
+    assert!(Isometry3::look_at_rh(&eye, &target, &up)
+        .translation
+        .vector
+        .x
+        .is_nan());
+
+    assert!(Rotation3::try_face_towards(&dir, &up, 1.0e-7).is_none());
+    assert!(Rotation3::try_look_at_rh(&dir, &-up, 1.0e-7).is_none());
+    assert!(Rotation3::try_look_at_lh(&dir, &up, 1.0e-7).is_none());
+    assert!(UnitQuaternion::try_face_towards(&dir, &up, 1.0e-7).is_none());
+    assert!(UnitQuaternion::try_look_at_rh(&dir, &up, 1.0e-7).is_none());
+    assert!(UnitQuaternion::try_look_at_lh(&dir, &up, 1.0e-7).is_none());
+    assert!(Isometry3::try_face_towards(&eye, &target, &up, 1.0e-7).is_none());
+    assert!(Isometry3::try_look_at_rh(&eye, &target, &up, 1.0e-7).is_none());
+    assert!(Isometry3::try_look_at_lh(&eye, &target, &up, 1.0e-7).is_none());
+    assert!(IsometryMatrix3::try_look_at_rh(&eye, &target, &up, 1.0e-7).is_none());
+    assert!(IsometryMatrix3::try_look_at_lh(&eye, &target, &up, 1.0e-7).is_none());
+
+    // Coincident eye and target.
+    assert!(Isometry3::try_look_at_rh(&eye, &eye, &up, 1.0e-7).is_none());
+    assert!(Isometry3::try_look_at(&eye, &eye, &up).is_none());
+
+    // Collinear, but not exactly axis-aligned, vectors.
+    let up = Vector3::new(1.0, 2.0, 3.0);
+    assert!(Isometry3::try_look_at(&eye, &(eye + up * 0.7), &up).is_none());
+    assert!(IsometryMatrix3::try_look_at(&eye, &(eye - up * 3.0), &up).is_none());
+    assert!(Isometry3::try_look_at(&eye, &target, &up).is_some());
+
+    let iso = Isometry3::try_look_at_lh(&eye, &target, &Vector3::x(), 1.0e-7).unwrap();
+    assert_relative_eq!(iso, Isometry3::look_at_lh(&eye, &target, &Vector3::x()));
+}
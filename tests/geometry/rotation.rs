@@ -1,4 +1,4 @@
-use na::{Quaternion, RealField, UnitQuaternion, Vector2, Vector3};
+use na::{Matrix3, Quaternion, RealField, Rotation3, Unit, UnitQuaternion, Vector2, Vector3};
 
 #[test]
 fn angle_2() {
@@ -30,15 +30,114 @@ fn quaternion_euler_angles_issue_494() {
     assert_eq!(angs.2, 0.0);
 }
 
+#[test]
+fn axis_angle_3_edge_cases() {
+    let axis = Unit::new_normalize(Vector3::new(1.0, -2.0, 3.0));
+    let pi = f64::pi();
+    let angles = [
+        1.0e-12,
+        1.0e-7,
+        f64::frac_pi_2() - 1.0e-9,
+        f64::frac_pi_2(),
+        f64::frac_pi_2() + 1.0e-9,
+        pi - 1.0e-7,
+        pi - 1.0e-12,
+    ];
+
+    for &angle in angles.iter() {
+        let rot = Rotation3::from_axis_angle(&axis, angle);
+        let (found_axis, found_angle) = rot.axis_angle().unwrap();
+
+        assert_relative_eq!(found_angle, angle, epsilon = 1.0e-15, max_relative = 1.0e-7);
+        assert_relative_eq!(found_axis, axis, epsilon = 1.0e-7);
+        assert_relative_eq!(
+            Rotation3::from_axis_angle(&found_axis, found_angle),
+            rot,
+            epsilon = 1.0e-14
+        );
+    }
+
+    // A rotation by exactly pi has two valid axes.
+    let rot = Rotation3::from_axis_angle(&axis, pi);
+    let (found_axis, found_angle) = rot.to_axis_angle();
+    assert_relative_eq!(found_angle, pi, epsilon = 1.0e-14);
+    assert!(
+        relative_eq!(found_axis, axis, epsilon = 1.0e-14)
+            || relative_eq!(found_axis, -axis, epsilon = 1.0e-14)
+    );
+    assert_relative_eq!(
+        Rotation3::from_axis_angle(&found_axis, found_angle),
+        rot,
+        epsilon = 1.0e-14
+    );
+
+    assert!(Rotation3::from_axis_angle(&axis, 0.0)
+        .axis_angle()
+        .is_none());
+    assert_eq!(Rotation3::from_axis_angle(&axis, 0.0).angle(), 0.0);
+    let (_, found_angle) = Rotation3::from_axis_angle(&axis, 0.0).to_axis_angle();
+    assert_eq!(found_angle, 0.0);
+
+    // `axis` keeps returning `None` when the skew-symmetric part vanishes at pi.
+    let half_turn =
+        Rotation3::from_matrix_unchecked(Matrix3::from_diagonal(&Vector3::new(-1.0, 1.0, -1.0)));
+    assert!(half_turn.axis().is_none());
+    let (found_axis, found_angle) = half_turn.to_axis_angle();
+    assert_eq!(found_angle, pi);
+    assert_relative_eq!(found_axis.y.abs(), 1.0);
+    assert_relative_eq!(
+        Rotation3::from_axis_angle(&found_axis, found_angle),
+        half_turn,
+        epsilon = 1.0e-14
+    );
+
+    // `to_axis_angle` agrees with `axis_angle` wherever the latter succeeds.
+    for &angle in angles.iter() {
+        let rot = Rotation3::from_axis_angle(&axis, angle);
+        let (found_axis, found_angle) = rot.to_axis_angle();
+        let (expected_axis, expected_angle) = rot.axis_angle().unwrap();
+        assert_eq!(found_axis, expected_axis);
+        assert_eq!(found_angle, expected_angle);
+    }
+}
+
+#[test]
+fn cayley_transform_half_turn() {
+    let rot = Rotation3::from_axis_angle(&Vector3::y_axis(), f64::pi());
+    assert!(rot.try_to_cayley(1.0e-7).is_none());
+    assert!(!rot.to_cayley().iter().all(|e| e.is_finite()));
+
+    assert_eq!(Rotation3::<f64>::identity().to_cayley(), Matrix3::zeros());
+    assert_eq!(
+        Rotation3::from_cayley(&Matrix3::<f64>::zeros()),
+        Rotation3::identity()
+    );
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
-    use na::{self, Rotation2, Rotation3, Unit};
+    use na::{self, EulerOrder, Matrix3, Rotation2, Rotation3, Unit};
     use simba::scalar::RealField;
     use std::f64;
 
     use crate::proptest::*;
     use proptest::{prop_assert, prop_assert_eq, proptest};
 
+    const EULER_ORDERS: [EulerOrder; 12] = [
+        EulerOrder::XYZ,
+        EulerOrder::XZY,
+        EulerOrder::YXZ,
+        EulerOrder::YZX,
+        EulerOrder::ZXY,
+        EulerOrder::ZYX,
+        EulerOrder::XYX,
+        EulerOrder::XZX,
+        EulerOrder::YXY,
+        EulerOrder::YZY,
+        EulerOrder::ZXZ,
+        EulerOrder::ZYZ,
+    ];
+
     proptest! {
         /*
          *
@@ -76,6 +175,82 @@ mod proptest_tests {
             prop_assert!(relative_eq!(Rotation3::from_euler_angles(neg_r, neg_p, neg_y), neg, epsilon = 1.0e-7));
         }
 
+        #[test]
+        fn euler_angles_ordered(a in -3.0..3.0f64, b in 0.01..1.5f64, c in -3.0..3.0f64) {
+            for &order in EULER_ORDERS.iter() {
+                // Keep the middle angle inside the range returned by `euler_angles_ordered`.
+                let b = if order.is_proper() { b * 2.0 } else { b - 0.75 };
+                let rot = Rotation3::from_euler_angles_ordered(a, b, c, order);
+                let (ea, eb, ec) = rot.euler_angles_ordered(order);
+
+                prop_assert!(relative_eq!(ea, a, epsilon = 1.0e-7), "{:?}", order);
+                prop_assert!(relative_eq!(eb, b, epsilon = 1.0e-7), "{:?}", order);
+                prop_assert!(relative_eq!(ec, c, epsilon = 1.0e-7), "{:?}", order);
+            }
+        }
+
+        #[test]
+        fn euler_angles_ordered_roundtrip(r in rotation3()) {
+            for &order in EULER_ORDERS.iter() {
+                let (a, b, c) = r.euler_angles_ordered(order);
+                prop_assert!(relative_eq!(Rotation3::from_euler_angles_ordered(a, b, c, order), r, epsilon = 1.0e-7), "{:?}", order);
+            }
+        }
+
+        #[test]
+        fn euler_angles_ordered_gimbal_lock(a in PROPTEST_F64, c in PROPTEST_F64) {
+            for &order in EULER_ORDERS.iter() {
+                let locks = if order.is_proper() {
+                    [0.0, f64::pi()]
+                } else {
+                    [f64::frac_pi_2(), -f64::frac_pi_2()]
+                };
+
+                for &b in locks.iter() {
+                    let rot = Rotation3::from_euler_angles_ordered(a, b, c, order);
+                    let (ea, eb, ec) = rot.euler_angles_ordered(order);
+                    prop_assert_eq!(ea, 0.0);
+                    prop_assert!(relative_eq!(eb, b, epsilon = 1.0e-7), "{:?}", order);
+                    prop_assert!(relative_eq!(Rotation3::from_euler_angles_ordered(ea, eb, ec, order), rot, epsilon = 1.0e-7), "{:?}", order);
+                }
+            }
+        }
+
+        #[test]
+        fn from_euler_angles_ordered_zyx(r in PROPTEST_F64, p in PROPTEST_F64, y in PROPTEST_F64) {
+            let expected = Rotation3::from_euler_angles(r, p, y);
+            let rot = Rotation3::from_euler_angles_ordered(y, p, r, EulerOrder::ZYX);
+            prop_assert!(relative_eq!(rot, expected, epsilon = 1.0e-7));
+        }
+
+        /*
+         *
+         * Cayley transform.
+         *
+         */
+        #[test]
+        fn cayley_transform(c in vector3()) {
+            let skew = c.cross_matrix();
+            let rot = Rotation3::from_cayley(&skew);
+            let id = Matrix3::identity();
+            let expected = (id - skew).try_inverse().unwrap() * (id + skew);
+
+            prop_assert!(relative_eq!(*rot.matrix(), expected, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(rot.matrix() * rot.matrix().transpose(), id, epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(rot.angle(), 2.0 * c.norm().atan(), epsilon = 1.0e-7));
+            // Only the skew-symmetric part is read.
+            prop_assert!(relative_eq!(Rotation3::from_cayley(&(skew + id * 3.0)), rot));
+        }
+
+        #[test]
+        fn cayley_transform_roundtrip(r in rotation3()) {
+            if let Some(skew) = r.try_to_cayley(1.0e-3) {
+                prop_assert_eq!(skew, r.to_cayley());
+                prop_assert!(relative_eq!(skew, -skew.transpose()));
+                prop_assert!(relative_eq!(Rotation3::from_cayley(&skew), r, epsilon = 1.0e-7));
+            }
+        }
+
         /*
          *
          * Inversion is transposition.
@@ -198,6 +373,19 @@ mod proptest_tests {
             }
         }
 
+        #[test]
+        fn axis_angle_rotation_3(v in vector3(), angle in 1.0e-6..f64::pi()) {
+            if let Some(axis) = Unit::try_new(v, 1.0e-3) {
+                let r = Rotation3::from_axis_angle(&axis, angle);
+                let (found_axis, found_angle) = r.axis_angle().unwrap();
+
+                prop_assert!(relative_eq!(found_angle, angle, epsilon = 1.0e-10));
+                prop_assert!(relative_eq!(found_axis, axis, epsilon = 1.0e-7) ||
+                    relative_eq!(found_angle, f64::pi(), epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(Rotation3::from_axis_angle(&found_axis, found_angle), r, epsilon = 1.0e-10));
+            }
+        }
+
         /*
          *
          * Rotation pow.
@@ -0,0 +1,111 @@
+use na::linalg::BandedMatrix;
+use na::DMatrix;
+
+/// A symmetric matrix of bandwidth `b` with deterministic, non-trivial elements.
+fn symmetric_band(n: usize, b: usize) -> DMatrix<f64> {
+    DMatrix::from_fn(n, n, |i, j| {
+        let (i, j) = (i.max(j), i.min(j));
+        if i - j <= b {
+            ((i * 7 + j * 3) % 11) as f64 - 5.0 + 1.0 / (1.0 + (i - j) as f64)
+        } else {
+            0.0
+        }
+    })
+}
+
+#[test]
+fn banded_matrix_from_matrix() {
+    let m = DMatrix::<f64>::from_fn(5, 5, |i, j| (i * 5 + j) as f64 + 1.0);
+    let mut band = BandedMatrix::from_matrix(&m, 1);
+
+    assert_eq!(band.dim(), 5);
+    assert_eq!(band.bandwidth(), 1);
+    assert_eq!(band.get(2, 1), m[(2, 1)]);
+    assert_eq!(band.get(1, 2), m[(1, 2)]);
+    assert_eq!(band.get(3, 1), 0.0);
+    assert!(band.get_mut(0, 2).is_none());
+
+    *band.get_mut(4, 3).unwrap() = -1.0;
+    let expected = DMatrix::from_fn(5, 5, |i, j| match (i, j) {
+        (4, 3) => -1.0,
+        _ if i.max(j) - i.min(j) <= 1 => m[(i, j)],
+        _ => 0.0,
+    });
+    assert_eq!(band.to_matrix(), expected);
+}
+
+#[test]
+fn banded_symmetric_eigen() {
+    for &(n, b) in &[
+        (1, 0),
+        (2, 1),
+        (6, 0),
+        (10, 1),
+        (10, 2),
+        (12, 3),
+        (15, 5),
+        (9, 8),
+        (7, 20),
+    ] {
+        let m = symmetric_band(n, b);
+        let band = BandedMatrix::from_matrix(&m, b);
+
+        let mut eig = band.symmetric_eigen();
+        let mut expected = m.clone().symmetric_eigen();
+        eig.sort_ascending();
+        expected.sort_ascending();
+
+        assert_relative_eq!(eig.eigenvalues, expected.eigenvalues, epsilon = 1.0e-10);
+        assert_relative_eq!(
+            eig.eigenvectors.transpose() * &eig.eigenvectors,
+            DMatrix::identity(n, n),
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+
+        let mut values = band.symmetric_eigenvalues();
+        values
+            .as_mut_slice()
+            .sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_relative_eq!(values, expected.eigenvalues, epsilon = 1.0e-10);
+    }
+}
+
+#[test]
+fn banded_symmetric_eigen_reads_lower_band() {
+    let m = symmetric_band(8, 2);
+    let mut upper_garbage = m.clone();
+    upper_garbage[(1, 3)] = 100.0;
+    upper_garbage[(0, 1)] = -100.0;
+
+    let eig = BandedMatrix::from_matrix(&upper_garbage, 2).symmetric_eigen();
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn banded_symmetric_eigen_empty() {
+    let band = BandedMatrix::<f64>::zeros(0, 2);
+    assert_eq!(band.symmetric_eigen().eigenvalues.len(), 0);
+    assert_eq!(band.symmetric_eigenvalues().len(), 0);
+}
+
+#[cfg(feature = "proptest-support")]
+mod proptest_tests {
+    use crate::proptest::*;
+    use na::linalg::BandedMatrix;
+    use na::DMatrix;
+    use proptest::{prop_assert, proptest};
+
+    proptest! {
+        #[test]
+        fn banded_symmetric_eigen(m in dmatrix(), b in 0usize..6) {
+            let n = m.nrows().min(m.ncols());
+            let m = m.slice((0, 0), (n, n)).symmetric_part();
+            let m = DMatrix::from_fn(n, n, |i, j| if i.max(j) - i.min(j) <= b { m[(i, j)] } else { 0.0 });
+            let eig = BandedMatrix::from_matrix(&m, b).symmetric_eigen();
+
+            prop_assert!(relative_eq!(eig.eigenvectors.transpose() * &eig.eigenvectors, DMatrix::identity(n, n), epsilon = 1.0e-7));
+            prop_assert!(relative_eq!(eig.recompose(), m, epsilon = 1.0e-7));
+        }
+    }
+}
@@ -1,4 +1,8 @@
-use na::{DVector, Vector2, Vector3, Vector4, Vector5};
+use na::{
+    BorderMode, ConvolutionState, ConvolveMode, DMatrix, DVector, Matrix1x3, Matrix2, Matrix2x3,
+    Matrix3, Matrix3x5, SMatrix, Vector1, Vector2, Vector3, Vector4, Vector5, Vector6,
+};
+use num_complex::Complex;
 use std::panic;
 
 //
@@ -44,6 +48,87 @@ fn convolve_same_check() {
     .is_err());
 }
 
+// >>> convolve([1,2,3,4,5,6],[1,2,3,4],"same")
+// array([ 4, 10, 20, 30, 40, 43])
+// >>> convolve([1,2,3,4,5,6],[1,-1,2,0.5,3],"same")
+// array([ 3. ,  5.5, 11. , 16.5, 15. , 26.5])
+#[test]
+fn convolve_same_longer_kernels() {
+    let vec = Vector6::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0);
+
+    // Even-length kernel.
+    let ker = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let expected = Vector6::new(4.0, 10.0, 20.0, 30.0, 40.0, 43.0);
+    assert!(relative_eq!(
+        vec.convolve_same(ker),
+        expected,
+        epsilon = 1.0e-7
+    ));
+    let full = vec.convolve_full(ker);
+    assert!(relative_eq!(
+        vec.convolve_same(ker),
+        full.fixed_rows::<6>(1).into_owned(),
+        epsilon = 1.0e-7
+    ));
+
+    // Odd-length kernel longer than 3.
+    let ker = Vector5::new(1.0, -1.0, 2.0, 0.5, 3.0);
+    let expected = Vector6::new(3.0, 5.5, 11.0, 16.5, 15.0, 26.5);
+    assert!(relative_eq!(
+        vec.convolve_same(ker),
+        expected,
+        epsilon = 1.0e-7
+    ));
+    let full = vec.convolve_full(ker);
+    assert!(relative_eq!(
+        vec.convolve_same(ker),
+        full.fixed_rows::<6>(2).into_owned(),
+        epsilon = 1.0e-7
+    ));
+
+    // The 1D and 2D convolutions agree on row vectors.
+    let mat = vec.transpose();
+    assert!(relative_eq!(
+        mat.mat_convolve_same(ker.transpose()),
+        expected.transpose(),
+        epsilon = 1.0e-7
+    ));
+
+    // The cross-correlation flips the kernel, but keeps the same centering.
+    let ker = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let expected = Vector6::new(11.0, 20.0, 30.0, 40.0, 50.0, 32.0);
+    assert!(relative_eq!(
+        vec.correlate_same(ker),
+        expected,
+        epsilon = 1.0e-7
+    ));
+}
+
+#[test]
+fn convolve_same_numpy_centering() {
+    // Pins the centering of 4-tap kernels, which used to start at index 2 of the full
+    // convolution ([2, 4, 6, 2, 3] here) instead of index 1.
+    // >>> numpy.convolve([1, 2, 3, 4, 5], [1, 0, -1, 2], mode='same')
+    // array([2, 2, 4, 6, 2])
+    let vec = Vector5::new(1.0, 2.0, 3.0, 4.0, 5.0);
+    let ker = Vector4::new(1.0, 0.0, -1.0, 2.0);
+    let expected = Vector5::new(2.0, 2.0, 4.0, 6.0, 2.0);
+    assert!(relative_eq!(
+        vec.convolve_same(ker),
+        expected,
+        epsilon = 1.0e-7
+    ));
+
+    // Single-tap kernels used to shift the output by one ([0, 2, 4, 6, 8] here).
+    let ker = Vector1::new(2.0);
+    let expected = Vector5::new(2.0, 4.0, 6.0, 8.0, 10.0);
+    assert!(relative_eq!(
+        vec.convolve_same(ker),
+        expected,
+        epsilon = 1.0e-7
+    ));
+}
+
 // >>> convolve([1,2,3,4],[1,2],"full")
 // array([ 1, 4,  7, 10, 8])
 #[test]
@@ -61,11 +146,28 @@ fn convolve_full_check() {
 
     assert!(relative_eq!(actual_d, expected_d, epsilon = 1.0e-7));
 
+    // A kernel longer than the vector.
+    // >>> convolve([1, 2, 3], [1, 0, -1, 2, 1], "full")
+    // array([1, 2, 2, 0, 2, 8, 3])
+    let vec = Vector3::new(1.0, 2.0, 3.0);
+    let kernel = Vector5::new(1.0, 0.0, -1.0, 2.0, 1.0);
+    let expected = [1.0, 2.0, 2.0, 0.0, 2.0, 8.0, 3.0];
+    assert_eq!(vec.convolve_full(kernel).as_slice(), &expected);
+    assert_eq!(vec.convolve_full(kernel), kernel.convolve_full(vec));
+
+    let vec = DVector::from_column_slice(vec.as_slice());
+    let kernel = DVector::from_column_slice(kernel.as_slice());
+    assert_eq!(vec.convolve_full(kernel.clone()).as_slice(), &expected);
+    assert_eq!(
+        vec.convolve(kernel.clone(), ConvolveMode::Full).as_slice(),
+        &expected
+    );
+
     // Panic Tests
     // These really only apply to dynamic sized vectors
     assert!(panic::catch_unwind(|| {
-        DVector::from_vec(vec![1.0, 2.0])
-            .convolve_full(DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0]));
+        DVector::from_vec(vec![1.0, 2.0, 3.0])
+            .convolve_valid(DVector::from_vec(vec![1.0, 0.0, -1.0, 2.0, 1.0]));
     })
     .is_err());
 
@@ -117,3 +219,618 @@ fn convolve_valid_check() {
     })
     .is_err());
 }
+
+#[test]
+fn convolve_mode_check() {
+    let vec = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let kernel = Vector2::new(1.0, 2.0);
+
+    // Static Tests
+    let full = vec.convolve(kernel, ConvolveMode::Full);
+    assert_eq!(
+        full,
+        DVector::from_column_slice(vec.convolve_full(kernel).as_slice())
+    );
+    let valid = vec.convolve(kernel, ConvolveMode::Valid);
+    assert_eq!(
+        valid,
+        DVector::from_column_slice(vec.convolve_valid(kernel).as_slice())
+    );
+    let same = vec.convolve(kernel, ConvolveMode::Same);
+    assert_eq!(
+        same,
+        DVector::from_column_slice(vec.convolve_same(kernel).as_slice())
+    );
+
+    // Dynamic Tests
+    let vec = DVector::from_vec(vec![1.0, -2.0, 3.0, 0.5, 4.0]);
+    let kernel = DVector::from_vec(vec![1.0, 2.0, -1.0]);
+
+    for &mode in &[ConvolveMode::Full, ConvolveMode::Valid, ConvolveMode::Same] {
+        let expected = match mode {
+            ConvolveMode::Full => vec.convolve_full(kernel.clone()),
+            ConvolveMode::Valid => vec.convolve_valid(kernel.clone()),
+            ConvolveMode::Same => vec.convolve_same(kernel.clone()),
+        };
+        assert!(relative_eq!(
+            vec.convolve(kernel.clone(), mode),
+            expected,
+            epsilon = 1.0e-7
+        ));
+    }
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| {
+        let _ = DVector::from_vec(vec![1.0, 2.0])
+            .convolve(DVector::from_vec(vec![1.0, 2.0, 3.0]), ConvolveMode::Valid);
+    })
+    .is_err());
+
+    assert!(panic::catch_unwind(|| {
+        let _ = DVector::from_vec(vec![1.0, 2.0])
+            .convolve(DVector::<f64>::from_vec(vec![]), ConvolveMode::Full);
+    })
+    .is_err());
+}
+
+#[test]
+fn convolve_valid_strided_check() {
+    let vec = DVector::from_fn(11, |i, _| ((i * 7) % 5) as f64 - 1.5);
+    let ker = Vector3::new(1.0, -2.0, 0.5);
+
+    // Manual reference, following the definition of the dilated convolution.
+    let reference = |stride: usize, dilation: usize| {
+        let len = (vec.len() - dilation * (ker.len() - 1) - 1) / stride + 1;
+        DVector::from_fn(len, |i, _| {
+            (0..ker.len())
+                .map(|j| vec[i * stride + j * dilation] * ker[ker.len() - 1 - j])
+                .sum::<f64>()
+        })
+    };
+
+    for &(stride, dilation) in &[(1, 1), (2, 1), (1, 2), (3, 2), (2, 5), (20, 1)] {
+        assert_eq!(
+            vec.convolve_valid_strided(ker, stride, dilation),
+            reference(stride, dilation)
+        );
+    }
+
+    // No stride nor dilation is the ‘valid’ convolution.
+    assert_eq!(
+        vec.convolve_valid_strided(ker, 1, 1),
+        vec.convolve_valid(DVector::from_column_slice(ker.as_slice()))
+    );
+
+    // A stride decimates the ‘valid’ convolution.
+    let valid = vec.convolve_valid(DVector::from_column_slice(ker.as_slice()));
+    let strided = vec.convolve_valid_strided(ker, 3, 1);
+    assert_eq!(strided.len(), 3);
+    for i in 0..strided.len() {
+        assert_eq!(strided[i], valid[3 * i]);
+    }
+
+    // A dilation is the same as inserting zeros between the kernel taps.
+    let upsampled = DVector::from_vec(vec![1.0, 0.0, -2.0, 0.0, 0.5]);
+    assert_eq!(
+        vec.convolve_valid_strided(ker, 1, 2),
+        vec.convolve_valid(upsampled)
+    );
+
+    // The largest dilation fitting the input yields a single element.
+    assert_eq!(vec.convolve_valid_strided(ker, 1, 5).len(), 1);
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| vec.convolve_valid_strided(ker, 0, 1)).is_err());
+    assert!(panic::catch_unwind(|| vec.convolve_valid_strided(ker, 1, 0)).is_err());
+    assert!(panic::catch_unwind(|| vec.convolve_valid_strided(ker, 1, 6)).is_err());
+    assert!(panic::catch_unwind(|| {
+        vec.convolve_valid_strided(DVector::<f64>::from_vec(vec![]), 1, 1)
+    })
+    .is_err());
+}
+
+// >>> a = [1 + 1j, 2, 3 - 1j]
+// >>> k = [1j, 2]
+// >>> convolve(a, k, "full")
+// array([-1.+1.j,  2.+4.j,  5.+3.j,  6.-2.j])
+// >>> convolve(a, k, "same")
+// array([-1.+1.j,  2.+4.j,  5.+3.j])
+// >>> convolve(a, k, "valid")
+// array([2.+4.j, 5.+3.j])
+#[test]
+fn convolve_complex_check() {
+    let vec = Vector3::new(
+        Complex::new(1.0, 1.0),
+        Complex::new(2.0, 0.0),
+        Complex::new(3.0, -1.0),
+    );
+    let ker = Vector2::new(Complex::new(0.0, 1.0), Complex::new(2.0, 0.0));
+
+    let actual_full = Vector4::new(
+        Complex::new(-1.0, 1.0),
+        Complex::new(2.0, 4.0),
+        Complex::new(5.0, 3.0),
+        Complex::new(6.0, -2.0),
+    );
+    let actual_same = Vector3::new(
+        Complex::new(-1.0, 1.0),
+        Complex::new(2.0, 4.0),
+        Complex::new(5.0, 3.0),
+    );
+    let actual_valid = Vector2::new(Complex::new(2.0, 4.0), Complex::new(5.0, 3.0));
+
+    assert!(relative_eq!(
+        actual_full,
+        vec.convolve_full(ker),
+        epsilon = 1.0e-7
+    ));
+    assert!(relative_eq!(
+        actual_same,
+        vec.convolve_same(ker),
+        epsilon = 1.0e-7
+    ));
+    assert!(relative_eq!(
+        actual_valid,
+        vec.convolve_valid(ker),
+        epsilon = 1.0e-7
+    ));
+}
+
+//
+// Should mimic calculations in Python's scipy library
+// >>>from scipy.signal import correlate
+//
+
+// >>> correlate([1, 2, 3, 4], [1, 2], "full")
+// array([ 2,  5,  8, 11,  4])
+// >>> correlate([1, 2, 3, 4], [1, 2], "same")
+// array([ 2,  5,  8, 11])
+// >>> correlate([1, 2, 3, 4], [1, 2], "valid")
+// array([ 5,  8, 11])
+#[test]
+fn correlate_check() {
+    let vec = Vector4::new(1.0, 2.0, 3.0, 4.0);
+    let ker = Vector2::new(1.0, 2.0);
+
+    let actual_full = Vector5::new(2.0, 5.0, 8.0, 11.0, 4.0);
+    let actual_same = Vector4::new(2.0, 5.0, 8.0, 11.0);
+    let actual_valid = Vector3::new(5.0, 8.0, 11.0);
+
+    assert!(relative_eq!(
+        actual_full,
+        vec.correlate_full(ker),
+        epsilon = 1.0e-7
+    ));
+    assert!(relative_eq!(
+        actual_same,
+        vec.correlate_same(ker),
+        epsilon = 1.0e-7
+    ));
+    assert!(relative_eq!(
+        actual_valid,
+        vec.correlate_valid(ker),
+        epsilon = 1.0e-7
+    ));
+
+    // Dynamic Tests
+    let actual_d = DVector::from_vec(vec![5.0, 8.0, 11.0]);
+    let expected_d = DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0])
+        .correlate_valid(DVector::from_vec(vec![1.0, 2.0]));
+
+    assert!(relative_eq!(actual_d, expected_d, epsilon = 1.0e-7));
+
+    // Kernel longer than the vector.
+    // >>> correlate([1, 2], [1, 2, 3, 4], "full")
+    // array([ 4, 11,  8,  5,  2])
+    let actual_long = DVector::from_vec(vec![4.0, 11.0, 8.0, 5.0, 2.0]);
+    let expected_long = DVector::from_vec(vec![1.0, 2.0])
+        .correlate_full(DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0]));
+
+    assert!(relative_eq!(actual_long, expected_long, epsilon = 1.0e-7));
+    assert!(relative_eq!(
+        Vector2::new(1.0, 2.0).correlate_full(Vector4::new(1.0, 2.0, 3.0, 4.0)),
+        Vector5::new(4.0, 11.0, 8.0, 5.0, 2.0),
+        epsilon = 1.0e-7
+    ));
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| {
+        DVector::<f64>::from_vec(vec![])
+            .correlate_full(DVector::from_vec(vec![1.0, 2.0, 3.0, 4.0]));
+    })
+    .is_err());
+}
+
+// >>> correlate([1 + 1j, 2, 3 - 1j], [1j, 2], "full")
+// array([ 2.+2.j,  5.-1.j,  6.-4.j, -1.-3.j])
+#[test]
+fn correlate_complex_check() {
+    let vec = Vector3::new(
+        Complex::new(1.0, 1.0),
+        Complex::new(2.0, 0.0),
+        Complex::new(3.0, -1.0),
+    );
+    let ker = Vector2::new(Complex::new(0.0, 1.0), Complex::new(2.0, 0.0));
+
+    let actual_full = Vector4::new(
+        Complex::new(2.0, 2.0),
+        Complex::new(5.0, -1.0),
+        Complex::new(6.0, -4.0),
+        Complex::new(-1.0, -3.0),
+    );
+
+    assert!(relative_eq!(
+        actual_full,
+        vec.correlate_full(ker),
+        epsilon = 1.0e-7
+    ));
+}
+
+#[test]
+fn convolution_state_check() {
+    let signal = DVector::from_fn(23, |i, _| ((7 * i) % 11) as f64 - 5.0);
+    let kernel = Vector5::new(1.0, -2.0, 0.0, 3.0, 1.0);
+    let expected = signal.convolve_full(kernel);
+
+    let splits: [&[usize]; 6] = [
+        &[23],
+        &[1; 23],
+        &[2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1],
+        &[7, 7, 7, 2],
+        &[0, 4, 1, 10, 0, 8],
+        &[3, 20],
+    ];
+    let mut state = ConvolutionState::new(kernel);
+
+    // The state is reset by `flush`, so it is reused for every split.
+    for split in splits.iter() {
+        let mut output = Vec::new();
+        let mut start = 0;
+        for &len in split.iter() {
+            let chunk = state.process_chunk(&signal.as_slice()[start..start + len]);
+            assert_eq!(chunk.len(), len);
+            output.extend(chunk);
+            start += len;
+        }
+        output.extend(state.flush());
+
+        assert_eq!(output, expected.as_slice());
+    }
+
+    // Discard a partially processed signal.
+    let _ = state.process_chunk(&[1.0, 2.0]);
+    state.reset();
+    let mut output = state.process_chunk(signal.as_slice());
+    output.extend(state.flush());
+    assert_eq!(output, expected.as_slice());
+    assert_eq!(
+        state.kernel(),
+        &DVector::from_column_slice(kernel.as_slice())
+    );
+
+    // Kernel must not be empty.
+    let result = panic::catch_unwind(|| ConvolutionState::new(DVector::<f64>::zeros(0)));
+    assert!(result.is_err());
+}
+
+//
+// Should mimic calculations in Python's scipy library
+// >>>from scipy.signal import convolve2d
+//
+
+fn mat_convolve_input() -> (DMatrix<f64>, Matrix3x5<f64>) {
+    let mat = DMatrix::from_fn(10, 8, |i, j| ((3 * i + 5 * j) % 7) as f64 - 3.0);
+    let ker = Matrix3x5::new(
+        1.0, -2.0, 0.0, 3.0, 1.0, 2.0, 1.0, -1.0, 0.0, 2.0, 0.0, 3.0, 1.0, -2.0, 1.0,
+    );
+    (mat, ker)
+}
+
+// >>> convolve2d(mat, ker, "full")
+#[test]
+#[rustfmt::skip]
+fn mat_convolve_full_check() {
+    let (mat, ker) = mat_convolve_input();
+    let actual = DMatrix::from_row_slice(12, 12, &[
+        -3.0,   8.0,  -4.0, -11.0,  10.0,  -3.0,  -9.0,   6.0,  12.0,  -2.0, -10.0,  -3.0,
+        -6.0,  -1.0,  12.0, -11.0, -11.0,  17.0,  10.0, -18.0,  -6.0,   8.0,   0.0,  -6.0,
+         3.0, -18.0,   4.0,  23.0,  -3.0,  -9.0,   6.0,   7.0, -17.0, -12.0,  16.0,   0.0,
+         5.0,   4.0,  -2.0,  -8.0,   6.0,   7.0, -20.0,  -5.0,  25.0,  -4.0,  -4.0,   5.0,
+         0.0,  -2.0,   6.0,  10.0, -20.0,  -5.0,  24.0,  -3.0,  -3.0,   4.0,  -3.0,   3.0,
+         2.0,   6.0, -21.0,   0.0,  24.0,  -3.0,  -9.0,   6.0,   4.0,  -2.0,  -9.0,   1.0,
+        -3.0,   7.0,   8.0,  -3.0,  -9.0,   6.0,   7.0, -20.0, -10.0,  20.0,  -1.0,  -1.0,
+        -1.0,   1.0,  -5.0,   1.0,   7.0, -20.0,  -5.0,  24.0,  -3.0,  -7.0,   0.0,  -3.0,
+        -6.0,   2.0,  10.0, -23.0,  -5.0,  24.0,  -3.0,  -9.0,   4.0,   1.0,   1.0,  -5.0,
+         3.0, -18.0,   4.0,  23.0,  -3.0,  -9.0,   6.0,   7.0, -17.0, -12.0,  16.0,   0.0,
+         6.0,   5.0, -10.0,  -1.0,  18.0,  -3.0, -17.0,   4.0,  16.0, -10.0,  -2.0,   6.0,
+         0.0,   9.0,   6.0,  -8.0,  -9.0,   6.0,   7.0, -13.0,   9.0,   7.0,  -8.0,   3.0,
+    ]);
+
+    let expected = mat.mat_convolve_full(ker);
+    assert!(relative_eq!(actual, expected, epsilon = 1.0e-7));
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| {
+        let _ = DMatrix::<f64>::zeros(2, 8).mat_convolve_full(ker);
+    })
+    .is_err());
+
+    assert!(panic::catch_unwind(|| {
+        let _ = mat.mat_convolve_full(DMatrix::<f64>::zeros(0, 0));
+    })
+    .is_err());
+}
+
+// >>> convolve2d(mat, ker, "valid")
+#[test]
+#[rustfmt::skip]
+fn mat_convolve_valid_check() {
+    let (mat, ker) = mat_convolve_input();
+    let actual = DMatrix::from_row_slice(8, 4, &[
+        -3.0,  -9.0,   6.0,   7.0,
+         6.0,   7.0, -20.0,  -5.0,
+       -20.0,  -5.0,  24.0,  -3.0,
+        24.0,  -3.0,  -9.0,   6.0,
+        -9.0,   6.0,   7.0, -20.0,
+         7.0, -20.0,  -5.0,  24.0,
+        -5.0,  24.0,  -3.0,  -9.0,
+        -3.0,  -9.0,   6.0,   7.0,
+    ]);
+
+    let expected = mat.mat_convolve_valid(ker);
+    assert!(relative_eq!(actual, expected, epsilon = 1.0e-7));
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| {
+        let _ = DMatrix::<f64>::zeros(10, 4).mat_convolve_valid(ker);
+    })
+    .is_err());
+}
+
+// >>> correlate2d(mat, ker, "valid")
+#[test]
+#[rustfmt::skip]
+fn mat_correlate_valid_check() {
+    let (mat, ker) = mat_convolve_input();
+    let actual = DMatrix::from_row_slice(8, 4, &[
+         -7.0,  -6.0,   9.0,   3.0,
+          9.0,   3.0, -24.0,   5.0,
+        -24.0,   5.0,  20.0,  -7.0,
+         20.0,  -7.0,  -6.0,   9.0,
+         -6.0,   9.0,   3.0, -24.0,
+          3.0, -24.0,   5.0,  20.0,
+          5.0,  20.0,  -7.0,  -6.0,
+         -7.0,  -6.0,   9.0,   3.0,
+    ]);
+
+    let expected = mat.mat_correlate_valid(ker);
+    assert!(relative_eq!(actual, expected, epsilon = 1.0e-7));
+
+    // The template is conjugated, but not flipped.
+    let mat = DMatrix::from_fn(3, 4, |i, j| Complex::new(i as f64, j as f64 - 1.0));
+    let ker = Matrix2::new(
+        Complex::new(1.0, 2.0), Complex::new(0.0, -1.0),
+        Complex::new(3.0, 0.0), Complex::new(-1.0, 1.0),
+    );
+    let expected = mat.mat_correlate_valid(ker);
+    assert_eq!(expected.shape(), (2, 3));
+
+    for i in 0..2 {
+        for j in 0..3 {
+            let window = mat.slice((i, j), (2, 2));
+            assert_eq!(expected[(i, j)], ker.dotc(&window));
+        }
+    }
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| {
+        let _ = DMatrix::<f64>::zeros(10, 4).mat_correlate_valid(Matrix3x5::<f64>::zeros());
+    })
+    .is_err());
+}
+
+#[test]
+fn mat_ncc_check() {
+    let (mat, ker) = mat_convolve_input();
+    let ncc = mat.mat_ncc(ker);
+    let corr = mat.mat_correlate_valid(ker);
+    assert_eq!(ncc.shape(), corr.shape());
+
+    for i in 0..ncc.nrows() {
+        for j in 0..ncc.ncols() {
+            let window = mat.slice((i, j), (3, 5));
+            let expected = corr[(i, j)] / (window.norm() * ker.norm());
+            assert!(relative_eq!(ncc[(i, j)], expected, epsilon = 1.0e-12));
+            assert!((-1.0..=1.0).contains(&ncc[(i, j)]));
+        }
+    }
+
+    // The peak locates the template, even when scaled.
+    let template = mat.slice((4, 2), (3, 5)) * 2.5;
+    let ncc = mat.mat_ncc(template);
+    assert!(relative_eq!(ncc[(4, 2)], 1.0, epsilon = 1.0e-12));
+    assert!(relative_eq!(ncc.max(), 1.0, epsilon = 1.0e-12));
+
+    // Windows and templates with a zero norm.
+    let mut mat = DMatrix::from_element(3, 3, 1.0);
+    mat.row_mut(0).fill(0.0);
+    let ncc = mat.mat_ncc(Matrix2::new(1.0, 1.0, 1.0, 1.0));
+    let expected = DMatrix::from_row_slice(2, 2, &[0.5f64.sqrt(), 0.5f64.sqrt(), 1.0, 1.0]);
+    assert!(relative_eq!(ncc, expected, epsilon = 1.0e-12));
+    assert_eq!(mat.mat_ncc(Matrix2::zeros()), DMatrix::zeros(2, 2));
+    assert_eq!(
+        DMatrix::<f64>::zeros(3, 3).mat_ncc(Matrix2::identity()),
+        DMatrix::zeros(2, 2)
+    );
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| {
+        let _ = DMatrix::<f64>::zeros(2, 4).mat_ncc(Matrix3x5::<f64>::zeros());
+    })
+    .is_err());
+}
+
+// >>> convolve2d(mat, ker, "same")
+#[test]
+#[rustfmt::skip]
+fn mat_convolve_same_check() {
+    let (mat, ker) = mat_convolve_input();
+    let actual = DMatrix::from_row_slice(10, 8, &[
+         12.0, -11.0, -11.0,  17.0,  10.0, -18.0,  -6.0,   8.0,
+          4.0,  23.0,  -3.0,  -9.0,   6.0,   7.0, -17.0, -12.0,
+         -2.0,  -8.0,   6.0,   7.0, -20.0,  -5.0,  25.0,  -4.0,
+          6.0,  10.0, -20.0,  -5.0,  24.0,  -3.0,  -3.0,   4.0,
+        -21.0,   0.0,  24.0,  -3.0,  -9.0,   6.0,   4.0,  -2.0,
+          8.0,  -3.0,  -9.0,   6.0,   7.0, -20.0, -10.0,  20.0,
+         -5.0,   1.0,   7.0, -20.0,  -5.0,  24.0,  -3.0,  -7.0,
+         10.0, -23.0,  -5.0,  24.0,  -3.0,  -9.0,   4.0,   1.0,
+          4.0,  23.0,  -3.0,  -9.0,   6.0,   7.0, -17.0, -12.0,
+        -10.0,  -1.0,  18.0,  -3.0, -17.0,   4.0,  16.0, -10.0,
+    ]);
+
+    let expected = mat.mat_convolve_same(ker);
+    assert!(relative_eq!(actual, expected, epsilon = 1.0e-7));
+
+    // Even-sized kernel.
+    let actual = DMatrix::from_row_slice(10, 8, &[
+        -3.0, -4.0,  4.0, -2.0, -1.0,  7.0,  1.0, -5.0,
+        -9.0, -8.0,  7.0,  1.0,  2.0, 10.0, -3.0, -9.0,
+         3.0,  1.0,  2.0, 10.0, -3.0, -9.0, -8.0,  7.0,
+         8.0, 10.0, -3.0, -9.0, -8.0,  7.0,  1.0,  2.0,
+        -1.0, -9.0, -8.0,  7.0,  1.0,  2.0, 10.0, -3.0,
+         4.0,  7.0,  1.0,  2.0, 10.0, -3.0, -9.0, -8.0,
+        -5.0,  2.0, 10.0, -3.0, -9.0, -8.0,  7.0,  1.0,
+         0.0, -3.0, -9.0, -8.0,  7.0,  1.0,  2.0, 10.0,
+        -9.0, -8.0,  7.0,  1.0,  2.0, 10.0, -3.0, -9.0,
+         3.0,  1.0,  2.0, 10.0, -3.0, -9.0, -8.0,  7.0,
+    ]);
+
+    let expected = mat.mat_convolve_same(Matrix2::new(1.0, 2.0, 3.0, 4.0));
+    assert!(relative_eq!(actual, expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn mat_convolve_separable_check() {
+    let (mat, _) = mat_convolve_input();
+    let row_ker = Vector4::new(1.0, -2.0, 0.5, 3.0);
+    let col_ker = Vector3::new(2.0, 1.0, -1.0);
+
+    let expected = mat.mat_convolve_separable(row_ker, col_ker);
+    let actual = mat.mat_convolve_full(col_ker * row_ker.transpose());
+
+    assert!(relative_eq!(actual, expected, epsilon = 1.0e-7));
+
+    // Panic Tests
+    assert!(panic::catch_unwind(|| {
+        let _ = mat.mat_convolve_separable(DVector::<f64>::zeros(9), col_ker);
+    })
+    .is_err());
+}
+
+// Regression test: the output of a ‘full’ convolution of a non-square matrix must have
+// `mat_rows + ker_rows - 1` rows and `mat_cols + ker_cols - 1` columns.
+// >>> convolve2d(np.arange(1, 29).reshape(4, 7), [[1, 0, -1], [2, 1, 0]], "full")
+#[test]
+#[rustfmt::skip]
+fn mat_convolve_full_rectangular() {
+    let mat = DMatrix::from_fn(4, 7, |i, j| (i * 7 + j + 1) as f64);
+    let ker = Matrix2x3::new(1.0, 0.0, -1.0, 2.0, 1.0, 0.0);
+    let actual = DMatrix::from_row_slice(5, 9, &[
+         1.0,  2.0,  2.0,  2.0,  2.0,  2.0,  2.0, -6.0,  -7.0,
+        10.0, 14.0, 10.0, 13.0, 16.0, 19.0, 22.0, -6.0, -14.0,
+        31.0, 42.0, 31.0, 34.0, 37.0, 40.0, 43.0, -6.0, -21.0,
+        52.0, 70.0, 52.0, 55.0, 58.0, 61.0, 64.0, -6.0, -28.0,
+        44.0, 68.0, 71.0, 74.0, 77.0, 80.0, 83.0, 28.0,   0.0,
+    ]);
+
+    let expected = mat.mat_convolve_full(ker);
+    assert_eq!(expected.shape(), (5, 9));
+    assert!(relative_eq!(actual, expected, epsilon = 1.0e-7));
+}
+
+#[test]
+fn mat_convolve_same_static() {
+    let mat = SMatrix::<f64, 8, 8>::from_fn(|i, j| ((3 * i + 5 * j) % 7) as f64 - 3.0);
+    let ker = Matrix3::new(1.0, -2.0, 0.0, 2.0, 1.0, -1.0, 0.0, 3.0, 1.0);
+
+    let expected: SMatrix<f64, 8, 8> = mat.mat_convolve_same(ker);
+    let actual = DMatrix::from_column_slice(8, 8, mat.as_slice())
+        .mat_convolve_same(DMatrix::from_column_slice(3, 3, ker.as_slice()));
+
+    assert!(relative_eq!(
+        DMatrix::from_column_slice(8, 8, expected.as_slice()),
+        actual,
+        epsilon = 1.0e-7
+    ));
+}
+
+#[test]
+fn mat_convolve_full_with_border_check() {
+    let row = DMatrix::from_row_slice(1, 4, &[1.0, 2.0, 3.0, 4.0]);
+    // Shifting kernels: the ‘full’ output reads the row extended by two elements on the left
+    // (resp. on the right).
+    let left = Matrix1x3::new(0.0, 0.0, 1.0);
+    let right = Matrix1x3::new(1.0, 0.0, 0.0);
+
+    let cases = [
+        (BorderMode::Zero, [0.0, 0.0], [0.0, 0.0]),
+        (BorderMode::Clamp, [1.0, 1.0], [4.0, 4.0]),
+        (BorderMode::Reflect, [2.0, 1.0], [4.0, 3.0]),
+        (BorderMode::Wrap, [3.0, 4.0], [1.0, 2.0]),
+    ];
+
+    for (mode, l, r) in cases.iter() {
+        let expected = DMatrix::from_row_slice(1, 6, &[l[0], l[1], 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(row.mat_convolve_full_with_border(left, *mode), expected);
+
+        let expected = DMatrix::from_row_slice(1, 6, &[1.0, 2.0, 3.0, 4.0, r[0], r[1]]);
+        assert_eq!(row.mat_convolve_full_with_border(right, *mode), expected);
+
+        // The columns are extended the same way.
+        let col = row.transpose();
+        assert_eq!(
+            col.mat_convolve_full_with_border(left.transpose(), *mode),
+            row.mat_convolve_full_with_border(left, *mode).transpose()
+        );
+    }
+
+    let (mat, ker) = mat_convolve_input();
+    assert_eq!(
+        mat.mat_convolve_full_with_border(ker, BorderMode::default()),
+        mat.mat_convolve_full(ker)
+    );
+}
+
+#[test]
+#[rustfmt::skip]
+fn mat_convolve_same_with_border_check() {
+    let mat = Matrix3::new(
+        1.0, 2.0, 3.0,
+        4.0, 5.0, 6.0,
+        7.0, 8.0, 9.0,
+    );
+    let ker = Matrix3::repeat(1.0);
+
+    // Box filter: the corners sum the 3x3 neighborhood of the extended matrix.
+    let zero = Matrix3::new(
+        12.0, 21.0, 16.0,
+        27.0, 45.0, 33.0,
+        24.0, 39.0, 28.0,
+    );
+    let clamp = Matrix3::new(
+        21.0, 27.0, 33.0,
+        39.0, 45.0, 51.0,
+        57.0, 63.0, 69.0,
+    );
+
+    assert_eq!(mat.mat_convolve_same_with_border(ker, BorderMode::Zero), zero);
+    assert_eq!(mat.mat_convolve_same(ker), zero);
+    assert_eq!(mat.mat_convolve_same_with_border(ker, BorderMode::Clamp), clamp);
+    // With a one-element border, reflecting repeats the border element like clamping.
+    assert_eq!(mat.mat_convolve_same_with_border(ker, BorderMode::Reflect), clamp);
+    // Wrapping a 3x3 matrix puts all its elements in every 3x3 neighborhood.
+    assert_eq!(
+        mat.mat_convolve_same_with_border(ker, BorderMode::Wrap),
+        Matrix3::repeat(45.0)
+    );
+}
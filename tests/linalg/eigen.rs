@@ -1,11 +1,15 @@
-use na::DMatrix;
+use na::linalg::{DeflationCriterion, SymmetricEigenConfig};
+use na::{
+    DMatrix, Dynamic, Matrix2, Matrix3, Matrix4, Rotation3, SymmetricEigen,
+    SymmetricEigenWorkspace, Vector2, Vector3, Vector4,
+};
 
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     macro_rules! gen_tests(
         ($module: ident, $scalar: expr, $scalar_type: ty) => {
             mod $module {
-                use na::DMatrix;
+                use na::{ComplexField, DMatrix};
                 #[allow(unused_imports)]
                 use crate::core::helper::{RandScalar, RandComplex};
                 use std::cmp;
@@ -24,6 +28,17 @@ mod proptest_tests {
                         prop_assert!(relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5))
                     }
 
+                    #[test]
+                    fn symmetric_eigen_balanced(n in PROPTEST_MATRIX_DIM) {
+                        let n      = cmp::max(1, cmp::min(n, 10));
+                        let m      = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let config = na::linalg::SymmetricEigenConfig { balance: true, ..Default::default() };
+                        let eig    = na::SymmetricEigen::try_new_with_config(m.clone(), config).unwrap();
+                        let recomp = eig.recompose();
+
+                        prop_assert!(relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5))
+                    }
+
                     #[test]
                     fn symmetric_eigen_singular(n in PROPTEST_MATRIX_DIM) {
                         let n      = cmp::max(1, cmp::min(n, 10));
@@ -36,6 +51,207 @@ mod proptest_tests {
                         prop_assert!(relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5))
                     }
 
+                    #[test]
+                    fn symmetric_eigen_sorted(n in PROPTEST_MATRIX_DIM) {
+                        let n       = cmp::max(1, cmp::min(n, 10));
+                        let m       = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let mut eig = m.clone().symmetric_eigen();
+
+                        eig.sort_ascending();
+                        prop_assert!(eig.eigenvalues.as_slice().windows(2).all(|w| w[0] <= w[1]));
+                        prop_assert!(relative_eq!(m.lower_triangle(), eig.recompose().lower_triangle(), epsilon = 1.0e-5));
+
+                        eig.sort_descending();
+                        prop_assert!(eig.eigenvalues.as_slice().windows(2).all(|w| w[0] >= w[1]));
+                        prop_assert!(relative_eq!(m.lower_triangle(), eig.recompose().lower_triangle(), epsilon = 1.0e-5));
+                    }
+
+                    #[test]
+                    fn symmetric_eigen_recompose_map(n in PROPTEST_MATRIX_DIM) {
+                        let n   = cmp::max(1, cmp::min(n, 10));
+                        let m   = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let eig = m.clone().symmetric_eigen();
+
+                        let squared = eig.recompose_map(|val| val * val);
+                        let doubled = eig.recompose_map(|val| val * 2.0);
+
+                        prop_assert!(relative_eq!((&m * &m).lower_triangle(), squared.lower_triangle(), epsilon = 1.0e-5));
+                        prop_assert!(relative_eq!((&m + &m).lower_triangle(), doubled.lower_triangle(), epsilon = 1.0e-5));
+                        prop_assert!(relative_eq!(eig.recompose_map(|val| val), eig.recompose()));
+                    }
+
+                    #[test]
+                    fn symmetric_eigen_rank_one_update(n in PROPTEST_MATRIX_DIM, rho in PROPTEST_F64) {
+                        let n       = cmp::max(1, cmp::min(n, 10));
+                        let m       = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let v       = na::DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                        let updated = &m + (&v * v.adjoint()).scale(rho);
+
+                        let mut eig      = m.symmetric_eigen().rank_one_update(rho, &v);
+                        let mut expected = updated.clone().symmetric_eigen();
+                        eig.sort_ascending();
+                        expected.sort_ascending();
+
+                        prop_assert!(relative_eq!(eig.eigenvalues, expected.eigenvalues, epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(eig.eigenvectors.adjoint() * &eig.eigenvectors, DMatrix::identity(n, n), epsilon = 1.0e-7));
+                        prop_assert!(relative_eq!(updated.lower_triangle(), eig.recompose().lower_triangle(), epsilon = 1.0e-5));
+                    }
+
+                    #[test]
+                    fn symmetric_eigen_canonicalize_signs(n in PROPTEST_MATRIX_DIM) {
+                        let n       = cmp::max(1, cmp::min(n, 10));
+                        let m       = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let mut eig = m.clone().symmetric_eigen();
+                        eig.canonicalize_signs();
+
+                        prop_assert!(relative_eq!(m.lower_triangle(), eig.recompose().lower_triangle(), epsilon = 1.0e-5));
+
+                        for column in eig.eigenvectors.column_iter() {
+                            let max = column.iter().map(|e| e.modulus()).fold(0.0, f64::max);
+                            let pivot = column.iter().find(|e| e.modulus() >= max * (1.0 - f64::EPSILON.sqrt())).unwrap();
+                            prop_assert!(pivot.real() > 0.0);
+                            prop_assert!(pivot.imaginary().abs() <= 1.0e-12);
+                        }
+                    }
+
+                    #[test]
+                    fn symmetric_eigenvalues_destructive(n in PROPTEST_MATRIX_DIM) {
+                        let n       = cmp::max(1, cmp::min(n, 10));
+                        let m       = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let mut big = DMatrix::<$scalar_type>::new_random(n + 1, n + 1).map(|e| e.0);
+                        big.slice_mut((1, 1), (n, n)).copy_from(&m);
+
+                        let eigenvalues = big.slice_mut((1, 1), (n, n)).symmetric_eigenvalues_destructive();
+                        prop_assert!(relative_eq!(eigenvalues, m.symmetric_eigenvalues(), epsilon = 1.0e-12));
+                    }
+
+                    #[test]
+                    fn symmetric_inertia(n in PROPTEST_MATRIX_DIM) {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let (n_positive, n_negative, n_zero) = m.symmetric_inertia(1.0e-10);
+
+                        prop_assert!(n_positive + n_negative + n_zero == n);
+                        prop_assert!((-m.clone()).symmetric_inertia(1.0e-10) == (n_negative, n_positive, n_zero));
+                        prop_assert!((&m * m.adjoint()).symmetric_inertia(1.0e-7).1 == 0);
+                    }
+
+                    #[test]
+                    fn symmetric_eigenvalues_in_interval(n in PROPTEST_MATRIX_DIM, lower in -2.0f64..2.0, width in 0.0f64..3.0) {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let upper = lower + width;
+                        let mut expected: Vec<_> = m
+                            .symmetric_eigenvalues()
+                            .iter()
+                            .copied()
+                            .filter(|val| lower < *val && *val <= upper)
+                            .collect();
+                        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+                        let values = m.symmetric_eigenvalues_in_interval(lower, upper, 1.0e-12);
+                        prop_assert!(m.symmetric_count_eigenvalues_in(lower, upper) == values.len());
+                        prop_assert!(values.len() == expected.len());
+                        for (val, exp) in values.iter().zip(expected.iter()) {
+                            prop_assert!(relative_eq!(val, exp, epsilon = 1.0e-10));
+                        }
+
+                        let all = m.symmetric_eigenvalues_in_interval(f64::NEG_INFINITY, f64::INFINITY, 1.0e-12);
+                        prop_assert!(all.len() == n);
+                        prop_assert!(m.symmetric_count_eigenvalues_in(f64::NEG_INFINITY, f64::INFINITY) == n);
+                    }
+
+                    #[test]
+                    fn nearest_spd(n in PROPTEST_MATRIX_DIM) {
+                        let n   = cmp::max(1, cmp::min(n, 10));
+                        let m   = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let spd = m.nearest_spd();
+                        let tol = 1.0e-7 * spd.norm();
+
+                        prop_assert!(relative_eq!(spd.adjoint(), spd.clone(), epsilon = 1.0e-7));
+                        prop_assert!(spd.symmetric_eigenvalues().iter().all(|val| *val >= -tol));
+                        prop_assert!(relative_eq!(spd.nearest_spd(), spd, epsilon = 1.0e-5));
+                    }
+
+                    #[test]
+                    fn symmetric_eigen_generalized(n in PROPTEST_MATRIX_DIM) {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let a = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let b = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0);
+                        let b = &b * b.adjoint() + DMatrix::identity(n, n);
+                        let eig = a.clone().symmetric_eigen_generalized(b.clone()).unwrap();
+
+                        let vecs = &eig.eigenvectors;
+                        let vals = DMatrix::from_diagonal(&eig.eigenvalues.map(na::convert));
+                        prop_assert!(relative_eq!(&a * vecs, &b * vecs * vals, epsilon = 1.0e-5));
+                        prop_assert!(relative_eq!(vecs.adjoint() * &b * vecs, DMatrix::identity(n, n), epsilon = 1.0e-5));
+                    }
+
+                    #[test]
+                    fn symmetric_eigen_decompose_into(n in PROPTEST_MATRIX_DIM) {
+                        let n         = cmp::max(1, cmp::min(n, 10));
+                        let mut ws    = na::SymmetricEigenWorkspace::new(na::Dynamic::new(n));
+                        let mut eig   = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part().symmetric_eigen();
+
+                        for _ in 0..3 {
+                            let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                            eig.decompose_into(&m, &mut ws);
+                            let recomp = eig.recompose();
+
+                            prop_assert!(relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-5));
+                        }
+                    }
+
+                    #[test]
+                    fn symmetric_eigen_partial(n in PROPTEST_MATRIX_DIM, k in PROPTEST_MATRIX_DIM) {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let k = cmp::min(k, n);
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let mut magnitudes: Vec<f64> = m.symmetric_eigenvalues().iter().map(|v| v.abs()).collect();
+                        magnitudes.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+                        for &which in [na::Extreme::SmallestMagnitude, na::Extreme::LargestMagnitude].iter() {
+                            let (vals, vecs) = m.symmetric_eigen_partial(k, which);
+                            let expected = match which {
+                                na::Extreme::SmallestMagnitude => magnitudes[..k].to_vec(),
+                                na::Extreme::LargestMagnitude => magnitudes.iter().rev().take(k).cloned().collect(),
+                            };
+
+                            prop_assert!(relative_eq!(na::DVector::from_iterator(k, vals.iter().map(|v| v.abs())), na::DVector::from_vec(expected), epsilon = 1.0e-7));
+                            prop_assert!(relative_eq!(vecs.adjoint() * &vecs, DMatrix::identity(k, k), epsilon = 1.0e-7));
+
+                            for (val, vec) in vals.iter().zip(vecs.column_iter()) {
+                                prop_assert!(relative_eq!(&m * vec, vec.scale(*val), epsilon = 1.0e-7));
+                            }
+                        }
+                    }
+
+                    #[test]
+                    fn rayleigh_quotient_iteration(n in PROPTEST_MATRIX_DIM) {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let initial = na::DVector::<$scalar_type>::new_random(n).map(|e| e.0);
+                        let eigenvalues = m.symmetric_eigenvalues();
+
+                        if let Some((val, vec)) = m.rayleigh_quotient_iteration(initial, 50, 1.0e-10) {
+                            prop_assert!(relative_eq!(vec.norm(), 1.0, epsilon = 1.0e-10));
+                            prop_assert!((&m * &vec - vec.scale(val)).norm() <= 1.0e-10);
+                            prop_assert!(eigenvalues.iter().any(|e| relative_eq!(*e, val, epsilon = 1.0e-7)));
+                        }
+                    }
+
+                    #[test]
+                    fn gershgorin_bounds(n in PROPTEST_MATRIX_DIM) {
+                        let n = cmp::max(1, cmp::min(n, 10));
+                        let m = DMatrix::<$scalar_type>::new_random(n, n).map(|e| e.0).hermitian_part();
+                        let (min, max) = m.gershgorin_bounds();
+
+                        prop_assert!(min <= max);
+                        for val in m.symmetric_eigenvalues().iter() {
+                            prop_assert!(min - 1.0e-10 <= *val && *val <= max + 1.0e-10);
+                        }
+                    }
+
                     #[test]
                     fn symmetric_eigen_static_square_4x4(m in matrix4_($scalar)) {
                         let m      = m.hermitian_part();
@@ -69,6 +285,148 @@ mod proptest_tests {
 
     gen_tests!(complex, complex_f64(), RandComplex<f64>);
     gen_tests!(f64, PROPTEST_F64, RandScalar<f64>);
+
+    mod batch {
+        use crate::proptest::*;
+        use na::{Matrix3, SymmetricEigen};
+        use proptest::{prop_assert, proptest};
+
+        proptest! {
+            #[test]
+            fn symmetric_eigen_closed_form_spd(m3 in matrix3(), m2 in matrix2()) {
+                let m3 = m3 * m3.transpose();
+                let m2 = m2 * m2.transpose();
+
+                let mut closed3 = SymmetricEigen::new_3x3(m3);
+                closed3.sort_descending();
+                let mut iter3 = SymmetricEigen::try_new(m3, 1.0e-15, 0).unwrap();
+                iter3.sort_descending();
+                prop_assert!(relative_eq!(closed3.eigenvalues, iter3.eigenvalues, epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(m3, closed3.recompose(), epsilon = 1.0e-7));
+
+                let mut closed2 = SymmetricEigen::new_2x2(m2);
+                closed2.sort_descending();
+                let mut iter2 = SymmetricEigen::try_new(m2, 1.0e-15, 0).unwrap();
+                iter2.sort_descending();
+                prop_assert!(relative_eq!(closed2.eigenvalues, iter2.eigenvalues, epsilon = 1.0e-7));
+                prop_assert!(relative_eq!(m2, closed2.recompose(), epsilon = 1.0e-7));
+            }
+
+            #[test]
+            fn symmetric_eigen_batch(m1 in matrix3(), m2 in matrix3()) {
+                let mats = [m1.hermitian_part(), m2.hermitian_part()];
+                let eigs = na::symmetric_eigen_batch(&mats);
+                prop_assert!(eigs.len() == 2);
+
+                for (m, eig) in mats.iter().zip(eigs.iter()) {
+                    let mut expected = m.symmetric_eigenvalues();
+                    let mut computed = eig.eigenvalues;
+                    expected.as_mut_slice().sort_by(|a, b| a.partial_cmp(b).unwrap());
+                    computed.as_mut_slice().sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+                    prop_assert!(relative_eq!(*m, eig.recompose(), epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(computed, expected, epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(
+                        eig.eigenvectors.transpose() * eig.eigenvectors,
+                        Matrix3::identity(),
+                        epsilon = 1.0e-7
+                    ));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn symmetric_eigen_closed_form_vs_iterative() {
+    let x = Matrix3::new(1.0, -2.0, 0.5, 3.0, 0.25, -1.0, 0.0, 2.0, 1.5);
+    let spd3 = x * x.transpose();
+    let spd2 = x.fixed_slice::<2, 2>(0, 0) * x.fixed_slice::<2, 2>(0, 0).transpose();
+
+    let mut closed3 = SymmetricEigen::new_3x3(spd3);
+    closed3.sort_descending();
+    let mut iter3 = SymmetricEigen::try_new(spd3, 1.0e-15, 0).unwrap();
+    iter3.sort_descending();
+    assert_relative_eq!(closed3.eigenvalues, iter3.eigenvalues, epsilon = 1.0e-10);
+    assert_relative_eq!(spd3, closed3.recompose(), epsilon = 1.0e-10);
+
+    let mut closed2 = SymmetricEigen::new_2x2(spd2);
+    closed2.sort_descending();
+    let mut iter2 = SymmetricEigen::try_new(spd2, 1.0e-15, 0).unwrap();
+    iter2.sort_descending();
+    assert_relative_eq!(closed2.eigenvalues, iter2.eigenvalues, epsilon = 1.0e-10);
+    assert_relative_eq!(spd2, closed2.recompose(), epsilon = 1.0e-10);
+}
+
+#[test]
+fn symmetric_eigen_closed_form_repeated_eigenvalues() {
+    let rot = Rotation3::new(Vector3::new(0.3, -1.2, 0.7));
+    let diags = [
+        Vector3::<f64>::new(2.0, 2.0, 5.0),
+        Vector3::new(-1.0, 3.0, 3.0),
+        Vector3::new(4.0, 4.0, 4.0),
+        Vector3::new(0.0, 0.0, 1.0e-3),
+    ];
+
+    for diag in diags.iter() {
+        let m = rot * Matrix3::from_diagonal(diag) * rot.transpose();
+        let eig = SymmetricEigen::new_3x3(m);
+        let mut vals = eig.eigenvalues;
+        vals.as_mut_slice()
+            .sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let mut expected = *diag;
+        expected
+            .as_mut_slice()
+            .sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_relative_eq!(vals, expected, epsilon = 1.0e-10);
+        assert_relative_eq!(m, eig.recompose(), epsilon = 1.0e-10);
+        assert_relative_eq!(
+            eig.eigenvectors.transpose() * eig.eigenvectors,
+            Matrix3::identity(),
+            epsilon = 1.0e-10
+        );
+    }
+}
+
+#[test]
+fn symmetric_eigen_batch_degenerate() {
+    let mats = [
+        Matrix3::<f64>::zeros(),
+        Matrix3::from_diagonal_element(2.0),
+        Matrix3::new(2.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 2.0),
+    ];
+    let eigs = na::symmetric_eigen_batch(&mats);
+
+    for (m, eig) in mats.iter().zip(eigs.iter()) {
+        assert_relative_eq!(*m, eig.recompose(), epsilon = 1.0e-10);
+    }
+
+    let mut vals = eigs[2].eigenvalues;
+    vals.as_mut_slice()
+        .sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_relative_eq!(vals, Vector3::new(1.0, 1.0, 4.0), epsilon = 1.0e-10);
+    assert!(na::symmetric_eigen_batch::<f64>(&[]).is_empty());
+}
+
+#[test]
+fn nearest_spd_of_indefinite_covariance() {
+    let rot = na::Rotation3::new(na::Vector3::new(0.3, -1.2, 0.7));
+    let q = rot.matrix();
+    let eigenvalues = na::Vector3::new(3.0, 1.0, -1.0e-6);
+    let m = q * na::Matrix3::from_diagonal(&eigenvalues) * q.transpose();
+    assert!(m.symmetric_eigenvalues().min() < 0.0);
+
+    let spd = m.nearest_spd();
+    let spd_eigenvalues = spd.symmetric_eigenvalues();
+
+    assert!(spd_eigenvalues.min() > -1.0e-12);
+    assert_relative_eq!((spd - m).norm(), 1.0e-6, epsilon = 1.0e-9);
+    assert_relative_eq!(spd, m, epsilon = 1.0e-5);
+
+    // Positive-definite matrices are left untouched.
+    let m = q * na::Matrix3::from_diagonal(&na::Vector3::new(3.0, 1.0, 0.5)) * q.transpose();
+    assert_relative_eq!(m.nearest_spd(), m, epsilon = 1.0e-10);
 }
 
 // Test proposed on the issue #176 of rulinalg.
@@ -116,6 +474,765 @@ fn symmetric_eigen_singular_24x24() {
     );
 }
 
+#[test]
+fn symmetric_eigen_with_stats() {
+    let m = Matrix4::new(
+        4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+    );
+
+    let (eig, stats) = m.try_symmetric_eigen_with_stats(1.0e-12, 0).unwrap();
+    assert!(stats.iterations > 0);
+    assert!(stats.max_offdiagonal <= 1.0e-10);
+    assert_relative_eq!(m, eig.recompose(), epsilon = 1.0e-7);
+
+    let stats = m.try_symmetric_eigen_with_stats(1.0e-12, 1).unwrap_err();
+    assert_eq!(stats.iterations, 1);
+    assert!(stats.max_offdiagonal > 0.0);
+}
+
+#[test]
+fn symmetric_eigen_deflation_criteria() {
+    // Graded matrix with eigenvalues close to `{1e8, 1.0, -1e-10}`.
+    let m = Matrix3::new(
+        1.0e8f64, 1.0e3, 0.0, 1.0e3, 1.0, 1.0e-4, 0.0, 1.0e-4, 1.0e-8,
+    );
+    // Computed with 50 significant digits.
+    let expected = Vector3::new(
+        1.0000000001e8,
+        0.990000010002010099,
+        -1.01010099979491627e-10,
+    );
+    let eigenvalues = |criterion| {
+        let config = SymmetricEigenConfig {
+            criterion,
+            ..SymmetricEigenConfig::default()
+        };
+        let mut eig = SymmetricEigen::try_new_with_config(m, config).unwrap();
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-7);
+        eig.sort_descending();
+        eig.eigenvalues
+    };
+
+    // The default criterion resolves every eigenvalue to nearly full relative precision.
+    let neighbors = eigenvalues(DeflationCriterion::Neighbors);
+    for i in 0..3 {
+        assert_relative_eq!(neighbors[i], expected[i], max_relative = 1.0e-12);
+    }
+
+    // Scaling the threshold by the matrix norm only guarantees an absolute accuracy.
+    let matrix_norm = eigenvalues(DeflationCriterion::MatrixNorm);
+    assert_relative_eq!(matrix_norm, expected, epsilon = 1.0e8 * f64::EPSILON);
+    assert!((neighbors[1] - expected[1]).abs() < (matrix_norm[1] - expected[1]).abs());
+
+    let default = SymmetricEigen::try_new_with_config(m, SymmetricEigenConfig::default()).unwrap();
+    let iterative = SymmetricEigen::try_new(m, f64::EPSILON, 0).unwrap();
+    assert_eq!(default.eigenvalues, iterative.eigenvalues);
+}
+
+#[test]
+fn symmetric_eigen_exceptional_shifts() {
+    // Tightly clustered eigenvalues, hidden by an orthogonal change of basis.
+    let q = DMatrix::from_fn(12, 12, |i, j| ((i * 31 + j * 17) % 13) as f64 - 6.0)
+        .qr()
+        .q();
+    let d = DMatrix::from_fn(12, 12, |i, j| {
+        if i == j {
+            1.0 + 1.0e-12 * i as f64
+        } else {
+            0.0
+        }
+    });
+    let m = &q * d * q.transpose();
+    let m = (&m + m.transpose()) * 0.5;
+
+    let mut expected = SymmetricEigen::new(m.clone());
+    expected.sort_ascending();
+    assert_eq!(
+        SymmetricEigenConfig::<f64>::default().exceptional_shift,
+        None
+    );
+
+    for k in 1..5 {
+        let config = SymmetricEigenConfig {
+            exceptional_shift: Some(k),
+            ..SymmetricEigenConfig::default()
+        };
+        let mut eig = SymmetricEigen::try_new_with_config(m.clone(), config).unwrap();
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-12);
+
+        eig.sort_ascending();
+        assert_relative_eq!(eig.eigenvalues, expected.eigenvalues, epsilon = 1.0e-14);
+    }
+}
+
+#[test]
+#[should_panic]
+fn symmetric_eigen_exceptional_shift_zero() {
+    let config = SymmetricEigenConfig {
+        exceptional_shift: Some(0),
+        ..SymmetricEigenConfig::default()
+    };
+    let _ = SymmetricEigen::try_new_with_config(Matrix3::<f64>::identity(), config);
+}
+
+#[test]
+fn symmetric_eigen_try_decompose_into_config() {
+    let m = DMatrix::<f64>::from_fn(8, 8, |i, j| 1.0 / (1.0 + i as f64 + j as f64));
+    let mut ws = SymmetricEigenWorkspace::new(Dynamic::new(8));
+    let mut eig = SymmetricEigen::new(DMatrix::identity(8, 8));
+
+    let config = SymmetricEigenConfig {
+        max_niter: 1,
+        ..SymmetricEigenConfig::default()
+    };
+    let stats = eig.try_decompose_into(&m, &mut ws, config).unwrap_err();
+    assert_eq!(stats.iterations, 1);
+
+    let stats = eig
+        .try_decompose_into(&m, &mut ws, SymmetricEigenConfig::default())
+        .unwrap();
+    let expected = SymmetricEigen::try_new_with_stats(m.clone(), f64::EPSILON, 0).unwrap();
+    assert_eq!(stats, expected.1);
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-12);
+}
+
+#[test]
+fn symmetric_eigen_balance_graded_matrix() {
+    // Graded with its largest entries at the bottom-right corner.
+    let m = DMatrix::<f64>::from_fn(6, 6, |i, j| {
+        let h = if i == j {
+            2.0
+        } else {
+            1.0 / (1.0 + (i as f64 - j as f64).abs())
+        };
+        h * 10.0f64.powi((i + j) as i32)
+    });
+    // The two smallest eigenvalues, computed with 60 significant digits.
+    let expected = [1.83760660299974155, 184.040841138796139];
+    let smallest = |balance| {
+        let config = SymmetricEigenConfig {
+            balance,
+            ..SymmetricEigenConfig::default()
+        };
+        let mut eig = SymmetricEigen::try_new_with_config(m.clone(), config).unwrap();
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-14 * m.norm());
+        assert!((eig.eigenvectors.transpose() * &eig.eigenvectors).is_identity(1.0e-12));
+        eig.sort_ascending();
+        [eig.eigenvalues[0], eig.eigenvalues[1]]
+    };
+
+    let default = smallest(false);
+    let balanced = smallest(true);
+    for i in 0..2 {
+        assert_relative_eq!(balanced[i], expected[i], max_relative = 1.0e-13);
+        let balanced_error = (balanced[i] - expected[i]).abs();
+        assert!(balanced_error < (default[i] - expected[i]).abs());
+    }
+
+    // Only the lower-triangular part is read.
+    let mut lower = m.lower_triangle();
+    lower.fill_upper_triangle(f64::NAN, 1);
+    let config = SymmetricEigenConfig {
+        balance: true,
+        ..SymmetricEigenConfig::default()
+    };
+    let eig = SymmetricEigen::try_new_with_config(lower, config).unwrap();
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-14 * m.norm());
+}
+
+#[test]
+fn symmetric_eigen_try_decompose_into_balance() {
+    // Graded with its largest entries at the bottom-right corner.
+    let m = DMatrix::<f64>::from_fn(6, 6, |i, j| {
+        let h = if i == j {
+            2.0
+        } else {
+            1.0 / (1.0 + (i as f64 - j as f64).abs())
+        };
+        h * 10.0f64.powi((i + j) as i32)
+    });
+    let config = SymmetricEigenConfig {
+        balance: true,
+        ..SymmetricEigenConfig::default()
+    };
+    let expected = SymmetricEigen::try_new_with_config(m.clone(), config).unwrap();
+
+    let mut lower = m.lower_triangle();
+    lower.fill_upper_triangle(f64::NAN, 1);
+    let mut ws = SymmetricEigenWorkspace::new(Dynamic::new(6));
+    let mut eig = SymmetricEigen::new(DMatrix::identity(6, 6));
+
+    // Run twice to check that the workspace is correctly reused.
+    for _ in 0..2 {
+        let _ = eig.try_decompose_into(&lower, &mut ws, config).unwrap();
+        assert_relative_eq!(
+            eig.eigenvalues,
+            expected.eigenvalues,
+            max_relative = 1.0e-14
+        );
+        assert_relative_eq!(eig.eigenvectors, expected.eigenvectors, epsilon = 1.0e-14);
+        assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-14 * m.norm());
+    }
+}
+
+#[test]
+fn symmetric_eigen_generalized_not_definite_positive() {
+    let a = Matrix4::<f64>::identity();
+    let b = -Matrix4::<f64>::identity();
+    assert!(a.symmetric_eigen_generalized(b).is_none());
+}
+
+#[test]
+#[should_panic]
+fn symmetric_eigen_non_symmetric() {
+    let m = Matrix4::new(
+        4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, 2.0, -1.0,
+    );
+    assert!(!m.is_symmetric(1.0e-7));
+    let _ = SymmetricEigen::try_new_checked(m, f64::EPSILON, 0);
+}
+
+#[test]
+fn symmetric_eigen_reads_lower_triangle_only() {
+    let m = Matrix4::new(
+        4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+    );
+    let mut lower = m.lower_triangle();
+    lower.fill_upper_triangle(f64::NAN, 1);
+
+    assert!(m.is_symmetric(1.0e-7));
+    assert_relative_eq!(lower.symmetric_eigen().recompose(), m, epsilon = 1.0e-12);
+    assert!(SymmetricEigen::try_new_checked(m, f64::EPSILON, 0).is_some());
+}
+
+#[test]
+fn is_symmetric_relative_tolerance() {
+    // The asymmetry is small relative to the magnitude of the entries.
+    let m = Matrix2::new(1.0e10, 1.0e10 + 1.0, 1.0e10, 1.0);
+    assert!(m.is_symmetric(1.0e-7));
+    assert!(!m.is_symmetric(1.0e-12));
+
+    // Entries close to zero are compared with an absolute tolerance.
+    let m = Matrix2::new(1.0, 1.0e-8, 0.0, 1.0);
+    assert!(m.is_symmetric(1.0e-7));
+    assert!(!m.is_symmetric(1.0e-9));
+}
+
+#[test]
+fn symmetric_eigen_condition_number() {
+    let m = Matrix4::from_diagonal(&Vector4::new(4.0, -2.0, 1.0, -0.5));
+    let eig = m.symmetric_eigen();
+    assert_relative_eq!(eig.condition_number(), 8.0, epsilon = 1.0e-7);
+
+    let singular = Matrix4::from_diagonal(&Vector4::new(4.0f64, -2.0, 1.0, 0.0));
+    assert!(singular.symmetric_eigen().condition_number().is_infinite());
+}
+
+#[test]
+fn symmetric_eigen_determinant_and_trace() {
+    let m = Matrix4::<f64>::new(
+        4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+    );
+    let eig = m.symmetric_eigen();
+    assert_relative_eq!(eig.determinant(), m.determinant(), epsilon = 1.0e-10);
+    assert_relative_eq!(eig.trace(), m.trace(), epsilon = 1.0e-10);
+
+    let spd = m * m.transpose();
+    let eig = spd.symmetric_eigen();
+    assert_relative_eq!(
+        eig.log_determinant(),
+        spd.determinant().ln(),
+        epsilon = 1.0e-10
+    );
+
+    let singular = Matrix4::from_diagonal(&Vector4::new(4.0f64, 2.0, 1.0, 0.0));
+    assert_eq!(
+        singular.symmetric_eigen().log_determinant(),
+        f64::NEG_INFINITY
+    );
+
+    let indefinite = Matrix4::from_diagonal(&Vector4::new(4.0f64, -2.0, 1.0, 0.5));
+    assert!(indefinite.symmetric_eigen().log_determinant().is_nan());
+}
+
+#[test]
+fn symmetric_inertia_known() {
+    // Sylvester's law of inertia: congruent matrices have the same inertia.
+    let rot = Rotation3::from_euler_angles(0.3, -1.2, 2.0);
+    let congruent = |diag: Vector3<f64>| rot * Matrix3::from_diagonal(&diag) * rot.transpose();
+
+    assert_eq!(
+        congruent(Vector3::new(1.0, 2.0, 3.0)).symmetric_inertia(1.0e-10),
+        (3, 0, 0)
+    );
+    assert_eq!(
+        congruent(Vector3::new(-1.0, -2.0, -3.0)).symmetric_inertia(1.0e-10),
+        (0, 3, 0)
+    );
+    assert_eq!(
+        congruent(Vector3::new(1.0, -2.0, 3.0)).symmetric_inertia(1.0e-10),
+        (2, 1, 0)
+    );
+    assert_eq!(
+        congruent(Vector3::new(0.0, -2.0, 3.0)).symmetric_inertia(1.0e-10),
+        (1, 1, 1)
+    );
+    assert_eq!(
+        congruent(Vector3::new(1.0e-12, -2.0, 0.0)).symmetric_inertia(1.0e-10),
+        (0, 1, 2)
+    );
+    assert_eq!(Matrix3::<f64>::zeros().symmetric_inertia(0.0), (0, 0, 3));
+
+    // The KKT matrix of an equality-constrained quadratic program, with an SPD hessian and
+    // `k` linearly independent constraints, has the inertia `(n, k, 0)`.
+    let hessian = Matrix4::new(
+        4.0, 1.0, 0.0, 0.0, 1.0, 3.0, 1.0, 0.0, 0.0, 1.0, 2.0, 0.5, 0.0, 0.0, 0.5, 1.0,
+    );
+    let constraints = DMatrix::from_row_slice(2, 4, &[1.0, 1.0, 1.0, 1.0, 1.0, -1.0, 0.0, 2.0]);
+    let mut kkt = DMatrix::zeros(6, 6);
+    kkt.slice_mut((0, 0), (4, 4)).copy_from(&hessian);
+    kkt.slice_mut((4, 0), (2, 4)).copy_from(&constraints);
+    kkt.slice_mut((0, 4), (4, 2))
+        .copy_from(&constraints.transpose());
+
+    assert_eq!(kkt.symmetric_inertia(1.0e-10), (4, 2, 0));
+}
+
+#[test]
+fn symmetric_eigen_eigenpairs_in_range() {
+    let m = DMatrix::<f64>::from_fn(6, 6, |i, j| 1.0 / (1.0 + i as f64 + j as f64));
+    let mut eig = m.clone().symmetric_eigen();
+    eig.sort_ascending();
+
+    // The three smallest eigenpairs, viewed without copying.
+    let (values, vectors) = eig.eigenpairs_in_range(..3);
+    assert_eq!(values.len(), 3);
+    assert_eq!(vectors.shape(), (6, 3));
+    assert!(values.as_ptr() == eig.eigenvalues.as_ptr());
+    assert_relative_eq!(
+        &m * &vectors,
+        &vectors * DMatrix::from_diagonal(&values),
+        epsilon = 1.0e-12
+    );
+
+    let (values, vectors) = eig.eigenpairs_in_range(4..);
+    assert_eq!(values, eig.eigenvalues.rows(4, 2));
+    assert_eq!(vectors, eig.eigenvectors.columns(4, 2));
+
+    let (values, vectors) = eig.eigenpairs_in_range(2);
+    assert_eq!(values[0], eig.eigenvalues[2]);
+    assert_eq!(vectors, eig.eigenvectors.column(2));
+}
+
+#[test]
+fn symmetric_eigen_recompose_checked() {
+    let m = DMatrix::<f64>::from_fn(5, 5, |i, j| 1.0 / (1.0 + i as f64 + j as f64));
+    let mut eig = m.clone().symmetric_eigen();
+    assert_eq!(eig.recompose_checked(), Ok(eig.recompose()));
+
+    // Mixing two eigenvectors without renormalizing them.
+    let u0 = eig.eigenvectors.column(0).clone_owned();
+    eig.eigenvectors.column_mut(1).axpy(0.5, &u0, 1.0);
+    let err = eig.recompose_checked().unwrap_err();
+    assert_relative_eq!(err.deviation, 0.5, epsilon = 1.0e-12);
+
+    eig.eigenvectors[(0, 0)] = f64::NAN;
+    assert!(eig.recompose_checked().unwrap_err().deviation.is_nan());
+}
+
+#[test]
+fn symmetric_eigen_canonicalize_signs() {
+    let m = DMatrix::<f64>::from_fn(5, 5, |i, j| 1.0 / (1.0 + i as f64 + j as f64) - 0.1);
+    let mut eig1 = m.clone().symmetric_eigen();
+    let mut eig2 = m.clone().symmetric_eigen();
+    eig2.eigenvectors.column_mut(0).neg_mut();
+    eig2.eigenvectors.column_mut(3).neg_mut();
+
+    eig1.canonicalize_signs();
+    eig2.canonicalize_signs();
+    assert_eq!(eig1.eigenvectors, eig2.eigenvectors);
+    assert_eq!(eig1.eigenvalues, eig2.eigenvalues);
+    assert_relative_eq!(eig1.recompose(), m, epsilon = 1.0e-12);
+
+    for column in eig1.eigenvectors.column_iter() {
+        assert!(column[column.iamax()] > 0.0);
+    }
+
+    // Components with the same magnitude up to rounding errors: the first one is the pivot.
+    let mut eig = SymmetricEigen {
+        eigenvalues: Vector2::new(1.0, 3.0),
+        eigenvectors: na::Matrix2::new(
+            -0.5f64.sqrt(),
+            -0.5f64.sqrt(),
+            0.5f64.sqrt() * (1.0 + f64::EPSILON),
+            -0.5f64.sqrt() * (1.0 - f64::EPSILON),
+        ),
+    };
+    eig.canonicalize_signs();
+    assert!(eig.eigenvectors.row(0).iter().all(|e| *e > 0.0));
+
+    // Zero eigenvectors are left unchanged.
+    eig.eigenvectors.column_mut(1).fill(0.0);
+    eig.canonicalize_signs();
+    assert_eq!(eig.eigenvectors.column(1), Vector2::zeros());
+}
+
+#[test]
+fn symmetric_eigen_tridiagonal_2x2_block_cancellation() {
+    // Deflates a 2x2 block whose first diagonal entry is the smallest, for which the
+    // eigenvector `(λ - d, e)` used to suffer from catastrophic cancellation.
+    let d = [
+        19.101650224357257,
+        -142.22295174641812,
+        -133.2839048054934,
+        -56.7073228601784,
+        19.436446382813518,
+        -33.21760215422675,
+        19.78722515924908,
+        -9.88423260027979,
+        -28.8205198002339,
+        -11.690361284834538,
+    ];
+    let e = [
+        66.20446351681744,
+        171.20683387124143,
+        65.70096520484925,
+        92.75150674652355,
+        32.59838629745946,
+        57.51784735506692,
+        22.739154346869242,
+        44.52689400444883,
+        40.75038458294869,
+    ];
+    let m = DMatrix::from_fn(10, 10, |i, j| match (i, j) {
+        _ if i == j => d[i],
+        _ if i == j + 1 => e[j],
+        _ if j == i + 1 => e[i],
+        _ => 0.0,
+    });
+    let eig = SymmetricEigen::try_new(m.clone(), f64::EPSILON, 0).unwrap();
+
+    assert_relative_eq!(eig.recompose(), m, epsilon = 1.0e-11);
+}
+
+#[test]
+fn symmetric_eigen_2x2_block_close_diagonal() {
+    // The eigenvector `(λ - d₂, e)` of the 2x2 block used to suffer from catastrophic
+    // cancellation when `d₁ < d₂`, losing the off-diagonal entries entirely.
+    let (d1, d2, e) = (1.0, 1.0 + 1.0e-6, 1.0e-12);
+
+    for m in &[Matrix2::new(d1, e, e, d2), Matrix2::new(d2, e, e, d1)] {
+        let eig = SymmetricEigen::new(*m);
+        assert_relative_eq!(eig.recompose(), *m, epsilon = 1.0e-15);
+        assert!((eig.eigenvectors.transpose() * eig.eigenvectors).is_identity(1.0e-15));
+    }
+}
+
+#[test]
+fn symmetric_eigen_rank_one_update_deflation() {
+    // Repeated eigenvalues, and a perturbation orthogonal to one of the eigenvectors.
+    let m = DMatrix::<f64>::from_diagonal(&na::DVector::from_vec(vec![1.0, 2.0, 2.0, 2.0, 5.0]));
+    let v = na::DVector::from_vec(vec![1.0, 0.5, -1.0, 0.0, 2.0]);
+
+    for &rho in &[0.0, 0.75, -0.75, -10.0] {
+        let updated = &m + &v * v.transpose() * rho;
+        let mut eig = m.clone().symmetric_eigen().rank_one_update(rho, &v);
+        let mut expected = updated.clone().symmetric_eigen();
+        eig.sort_ascending();
+        expected.sort_ascending();
+
+        assert_relative_eq!(eig.eigenvalues, expected.eigenvalues, epsilon = 1.0e-10);
+        assert_relative_eq!(
+            eig.eigenvectors.transpose() * &eig.eigenvectors,
+            DMatrix::identity(5, 5),
+            epsilon = 1.0e-10
+        );
+        assert_relative_eq!(eig.recompose(), updated, epsilon = 1.0e-10);
+    }
+
+    // A perturbation along an eigenvector only shifts its eigenvalue.
+    let v = na::DVector::from_vec(vec![0.0, 0.0, 0.0, 0.0, 3.0]);
+    let mut eig = m.clone().symmetric_eigen().rank_one_update(2.0, &v);
+    eig.sort_ascending();
+    assert_relative_eq!(eig.eigenvalues[4], 23.0, epsilon = 1.0e-10);
+}
+
+#[test]
+fn symmetric_eigen_spectral_gaps() {
+    // Laplacian of two triangles joined by a weak edge: two clusters.
+    let mut adjacency = DMatrix::<f64>::zeros(6, 6);
+    for &(i, j, w) in &[
+        (0, 1, 1.0),
+        (1, 2, 1.0),
+        (0, 2, 1.0),
+        (3, 4, 1.0),
+        (4, 5, 1.0),
+        (3, 5, 1.0),
+        (2, 3, 0.01),
+    ] {
+        adjacency[(i, j)] = w;
+        adjacency[(j, i)] = w;
+    }
+    let degrees = adjacency.row_sum().transpose();
+    let laplacian = DMatrix::from_diagonal(&degrees) - adjacency;
+
+    let eig = laplacian.symmetric_eigen();
+    let gaps = eig.spectral_gaps();
+    assert_eq!(gaps.len(), 5);
+    assert!(gaps.iter().all(|&gap| gap >= 0.0));
+    assert_eq!(eig.largest_gap_index(), Some(1));
+
+    let mut sorted = eig.clone();
+    sorted.sort_ascending();
+    for i in 0..5 {
+        assert_relative_eq!(
+            gaps[i],
+            sorted.eigenvalues[i + 1] - sorted.eigenvalues[i],
+            epsilon = 1.0e-12
+        );
+    }
+
+    // Ties are resolved in favor of the smallest index.
+    let eig = Matrix3::from_diagonal(&Vector3::new(2.0f64, 0.0, 1.0)).symmetric_eigen();
+    assert_eq!(eig.spectral_gaps(), Vector2::new(1.0, 1.0));
+    assert_eq!(eig.largest_gap_index(), Some(0));
+
+    let eig = DMatrix::from_element(1, 1, 3.0f64).symmetric_eigen();
+    assert!(eig.spectral_gaps().is_empty());
+    assert_eq!(eig.largest_gap_index(), None);
+}
+
+#[test]
+fn symmetric_eigen_pseudo_inverse() {
+    let m = Matrix4::new(
+        4.0, 1.0, -2.0, 2.0, 1.0, 2.0, 0.0, 1.0, -2.0, 0.0, 3.0, -2.0, 2.0, 1.0, -2.0, -1.0,
+    );
+    let pinv = m.symmetric_eigen().pseudo_inverse(1.0e-10).unwrap();
+    assert_relative_eq!(pinv, m.try_inverse().unwrap(), epsilon = 1.0e-7);
+
+    let mut singular = m;
+    singular.row_mut(2).fill(0.0);
+    singular.column_mut(2).fill(0.0);
+    let eig = singular.symmetric_eigen();
+    let pinv = eig.pseudo_inverse(1.0e-10).unwrap();
+    assert_relative_eq!(singular * pinv * singular, singular, epsilon = 1.0e-7);
+    assert!(eig.pseudo_inverse(-1.0).is_err());
+}
+
+#[test]
+fn rayleigh_quotient_iteration_tracks_mode() {
+    let n = 12;
+    let a = DMatrix::<f64>::from_fn(n, n, |i, j| {
+        let (i, j) = (i as f64, j as f64);
+        (i * j + 1.0).sin() + (i + j).cos()
+    });
+    let e = DMatrix::<f64>::from_fn(n, n, |i, j| {
+        ((i + 2 * j) as f64).cos() + ((2 * i + j) as f64).cos()
+    });
+
+    // Follow the dominant mode while the matrix changes slightly.
+    let mut eig = a.clone().symmetric_eigen();
+    eig.sort_descending();
+    let mut vector = eig.eigenvectors.column(0).into_owned();
+
+    for step in 1..=5 {
+        let m = &a + &e * (step as f64 * 1.0e-3);
+        let (value, refined) = m
+            .rayleigh_quotient_iteration(vector.clone(), 4, 1.0e-10)
+            .unwrap();
+
+        let mut expected = m.clone().symmetric_eigen();
+        expected.sort_descending();
+        assert_relative_eq!(value, expected.eigenvalues[0], epsilon = 1.0e-9);
+        assert!(refined.dot(&vector) > 0.99);
+        vector = refined;
+    }
+}
+
+#[test]
+fn rayleigh_quotient_iteration_failures() {
+    let m = Matrix3::new(2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0);
+
+    assert!(m
+        .rayleigh_quotient_iteration(Vector3::zeros(), 10, 1.0e-10)
+        .is_none());
+    assert!(m
+        .rayleigh_quotient_iteration(Vector3::new(1.0, 0.0, 0.0), 0, 1.0e-10)
+        .is_none());
+
+    // An exact eigenvector converges without any iteration.
+    let (value, vector) = Matrix3::from_diagonal(&Vector3::new(1.0, 2.0, 3.0))
+        .rayleigh_quotient_iteration(Vector3::new(0.0, 2.0, 0.0), 0, 1.0e-10)
+        .unwrap();
+    assert_eq!(value, 2.0);
+    assert_eq!(vector, Vector3::y());
+}
+
+#[test]
+fn rayleigh_quotient_iteration_reads_lower_triangle_only() {
+    let m = Matrix3::new(2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0);
+    let mut garbage = m;
+    garbage.m12 = 100.0;
+    garbage.m13 = -7.0;
+    garbage.m23 = f64::NAN;
+
+    let initial = Vector3::new(0.1, 0.5, 1.0);
+    let expected = m.rayleigh_quotient_iteration(initial, 10, 1.0e-12).unwrap();
+    let found = garbage
+        .rayleigh_quotient_iteration(initial, 10, 1.0e-12)
+        .unwrap();
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn symmetric_eigen_partial_repeated_eigenvalues() {
+    // Eigenvalues 3 (three times), -3, 1 and 0 (three times), in a rotated basis.
+    let rot = Rotation3::from_axis_angle(&Vector3::x_axis(), 0.3)
+        * Rotation3::from_axis_angle(&Vector3::y_axis(), -1.1);
+    let mut basis = DMatrix::<f64>::identity(8, 8);
+    basis.fixed_slice_mut::<3, 3>(0, 0).copy_from(rot.matrix());
+    basis.fixed_slice_mut::<3, 3>(4, 4).copy_from(rot.matrix());
+    let values = na::DVector::from_vec(vec![3.0, 1.0, 0.0, 3.0, -3.0, 3.0, 0.0, 0.0]);
+    let m = &basis * DMatrix::from_diagonal(&values) * basis.transpose();
+
+    let (vals, vecs) = m.symmetric_eigen_partial(4, na::Extreme::LargestMagnitude);
+    assert_relative_eq!(
+        na::DVector::from_iterator(4, vals.iter().map(|v| v.abs())),
+        na::DVector::from_element(4, 3.0),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        &m * &vecs,
+        &vecs * DMatrix::from_diagonal(&vals),
+        epsilon = 1.0e-10
+    );
+    assert_relative_eq!(
+        vecs.transpose() * &vecs,
+        DMatrix::identity(4, 4),
+        epsilon = 1.0e-10
+    );
+
+    let (vals, vecs) = m.symmetric_eigen_partial(3, na::Extreme::SmallestMagnitude);
+    assert_relative_eq!(vals, na::DVector::zeros(3), epsilon = 1.0e-10);
+    assert_relative_eq!(&m * &vecs, DMatrix::zeros(8, 3), epsilon = 1.0e-10);
+    assert_relative_eq!(
+        vecs.transpose() * &vecs,
+        DMatrix::identity(3, 3),
+        epsilon = 1.0e-10
+    );
+
+    let (vals, vecs) = m.symmetric_eigen_partial(0, na::Extreme::SmallestMagnitude);
+    assert_eq!((vals.len(), vecs.shape()), (0, (8, 0)));
+}
+
+#[test]
+fn symmetric_eigen_partial_large() {
+    let n = 60;
+    let m = DMatrix::<f64>::from_fn(n, n, |i, j| {
+        let (i, j) = (i as f64, j as f64);
+        (i * j + 1.0).sin() + (i + j).cos()
+    });
+    let mut full = m.clone().symmetric_eigen();
+    full.sort_descending();
+
+    let (vals, vecs) = m.symmetric_eigen_partial(5, na::Extreme::LargestMagnitude);
+    let mut expected: Vec<f64> = full.eigenvalues.iter().cloned().collect();
+    expected.sort_by(|a, b| b.abs().partial_cmp(&a.abs()).unwrap());
+    assert_relative_eq!(
+        vals,
+        na::DVector::from_column_slice(&expected[..5]),
+        epsilon = 1.0e-9
+    );
+    assert_relative_eq!(
+        &m * &vecs,
+        &vecs * DMatrix::from_diagonal(&vals),
+        epsilon = 1.0e-9
+    );
+}
+
+#[test]
+fn gershgorin_bounds_disjoint_discs() {
+    // The discs are disjoint, so each one contains exactly one eigenvalue.
+    let m = Matrix3::new(10.0f64, 0.5, 0.2, 0.5, -3.0, 0.1, 0.2, 0.1, 1.0);
+    let (min, max) = m.gershgorin_bounds();
+    assert_relative_eq!(min, -3.6);
+    assert_relative_eq!(max, 10.7);
+
+    let mut eigenvalues = m.symmetric_eigenvalues();
+    eigenvalues
+        .as_mut_slice()
+        .sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert!((-3.6..=-2.4).contains(&eigenvalues[0]));
+    assert!((0.7..=1.3).contains(&eigenvalues[1]));
+    assert!((9.3..=10.7).contains(&eigenvalues[2]));
+
+    assert_eq!(
+        DMatrix::<f64>::identity(4, 4).gershgorin_bounds(),
+        (1.0, 1.0)
+    );
+}
+
+#[test]
+#[should_panic]
+fn gershgorin_bounds_empty() {
+    let _ = DMatrix::<f64>::zeros(0, 0).gershgorin_bounds();
+}
+
+#[test]
+fn power_iteration_dominant_eigenvalue() {
+    // Column-stochastic transition matrix of a small web graph: its dominant eigenvalue is 1 and
+    // the associated eigenvector is the stationary distribution (PageRank without damping).
+    let m = Matrix3::new(0.0, 0.5, 1.0, 0.5, 0.0, 0.0, 0.5, 0.5, 0.0);
+    let (value, vector) = m
+        .power_iteration(Vector3::new(1.0, 0.0, 0.0), 1000, 1.0e-14)
+        .unwrap();
+    assert_relative_eq!(value, 1.0, epsilon = 1.0e-12);
+    assert_relative_eq!(
+        vector / vector.sum(),
+        Vector3::new(4.0, 2.0, 3.0) / 9.0,
+        epsilon = 1.0e-8
+    );
+
+    // The dominant eigenvalue is negative, so the iterates alternate in sign.
+    let m = Matrix4::new(
+        -6.0f64, 1.0, 0.0, 0.0, 0.0, 3.0, 1.0, 0.0, 0.0, 0.0, 2.0, 1.0, 0.0, 0.0, 0.0, 1.0,
+    );
+    let (value, vector) = m
+        .power_iteration(Vector4::repeat(1.0), 1000, 1.0e-14)
+        .unwrap();
+    assert_relative_eq!(value, -6.0, epsilon = 1.0e-12);
+    assert_relative_eq!(vector.x.abs(), 1.0, epsilon = 1.0e-8);
+
+    let m = DMatrix::from_diagonal_element(5, 5, 2.5);
+    let (value, _) = m
+        .power_iteration(na::DVector::repeat(5, 1.0), 10, 1.0e-14)
+        .unwrap();
+    assert_relative_eq!(value, 2.5, epsilon = 1.0e-14);
+}
+
+#[test]
+fn power_iteration_failures() {
+    let m = Matrix3::new(2.0, 1.0, 0.0, 1.0, 3.0, 1.0, 0.0, 1.0, 4.0);
+    assert!(m.power_iteration(Vector3::zeros(), 100, 1.0e-12).is_none());
+    // Not enough iterations to converge.
+    assert!(m
+        .power_iteration(Vector3::new(1.0, 0.0, 0.0), 3, 1.0e-12)
+        .is_none());
+
+    // The iterate is mapped to zero by a nilpotent matrix.
+    let nilpotent = Matrix3::new(0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0);
+    assert!(nilpotent
+        .power_iteration(Vector3::new(0.0, 1.0, 0.0), 100, 1.0e-12)
+        .is_none());
+
+    // All the eigenvalues of a rotation have a unit modulus. The Rayleigh quotient is constant
+    // for iterates in the rotation plane, but they are not eigenvectors.
+    let rotation = Rotation3::from_axis_angle(&Vector3::z_axis(), 0.3).into_inner();
+    assert!(rotation
+        .power_iteration(Vector3::new(1.0, 0.0, 0.0), 1000, 1.0e-12)
+        .is_none());
+}
+
 //  #[cfg(feature = "arbitrary")]
 //  quickcheck! {
 // TODO: full eigendecomposition is not implemented yet because of its complexity when some
@@ -98,6 +98,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn exp_diagonal() {
+        use nalgebra::{DMatrix, DVector, Matrix4, Vector4};
+
+        let d = Vector4::new(-2.0, 0.0, 0.5, 3.0);
+        let m = Matrix4::from_diagonal(&d);
+
+        assert!(relative_eq!(
+            m.exp(),
+            Matrix4::from_diagonal(&d.map(f64::exp)),
+            epsilon = 1.0e-7
+        ));
+
+        // Large entries need several squarings.
+        let d = DVector::from_row_slice(&[10.0, -10.0, 20.0, 1.0e-3, 0.0]);
+        let m = DMatrix::from_diagonal(&d);
+        let expected = DMatrix::from_diagonal(&d.map(f64::exp));
+
+        assert!(relative_eq!(m.exp(), expected, max_relative = 1.0e-10));
+    }
+
+    #[test]
+    fn exp_nilpotent() {
+        use nalgebra::{Matrix3, Matrix4};
+
+        // exp(N) = I + N + N²/2 since N³ = 0.
+        let n = Matrix3::new(0.0, 1.0, 2.0, 0.0, 0.0, 3.0, 0.0, 0.0, 0.0);
+        let expected = Matrix3::identity() + n + n * n / 2.0;
+
+        assert!(relative_eq!(n.exp(), expected, epsilon = 1.0e-7));
+
+        // Shift matrix: exp(t·N) has entries t^(j - i) / (j - i)! above the diagonal.
+        let t = 5.0;
+        let n = Matrix4::new(
+            0.0, t, 0.0, 0.0, //
+            0.0, 0.0, t, 0.0, //
+            0.0, 0.0, 0.0, t, //
+            0.0, 0.0, 0.0, 0.0,
+        );
+        let expected = Matrix4::new(
+            1.0,
+            t,
+            t * t / 2.0,
+            t * t * t / 6.0, //
+            0.0,
+            1.0,
+            t,
+            t * t / 2.0, //
+            0.0,
+            0.0,
+            1.0,
+            t, //
+            0.0,
+            0.0,
+            0.0,
+            1.0,
+        );
+
+        assert!(relative_eq!(n.exp(), expected, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn exp_rotation_generator() {
+        use nalgebra::{Matrix2, Rotation2, Rotation3, Vector3};
+
+        let angle = 2.5;
+        let generator = Matrix2::new(0.0, -angle, angle, 0.0);
+
+        assert!(relative_eq!(
+            generator.exp(),
+            *Rotation2::new(angle).matrix(),
+            epsilon = 1.0e-7
+        ));
+
+        let axisangle = Vector3::new(1.0, -2.0, 0.5);
+        let rot = axisangle.cross_matrix().exp();
+
+        assert!(relative_eq!(
+            rot,
+            *Rotation3::new(axisangle).matrix(),
+            epsilon = 1.0e-7
+        ));
+        assert!(relative_eq!(
+            rot.transpose() * rot,
+            nalgebra::Matrix3::identity(),
+            epsilon = 1.0e-7
+        ));
+    }
+
     #[test]
     fn exp_dynamic() {
         use nalgebra::DMatrix;
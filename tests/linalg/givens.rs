@@ -0,0 +1,94 @@
+#![cfg(feature = "proptest-support")]
+
+use na::linalg::givens::GivensRotation;
+use na::{DMatrix, Matrix3};
+use std::panic;
+
+#[test]
+fn givens_apply_matches_rotate() {
+    let m = Matrix3::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 10.0);
+    let (rot, _) = GivensRotation::new(3.0, 4.0);
+
+    let mut rows = m;
+    rows.apply_givens_rows(0, 1, &rot);
+    let mut expected = m;
+    rot.rotate(&mut expected.fixed_rows_mut::<2>(0));
+    assert_eq!(rows, expected);
+
+    let mut columns = m;
+    columns.apply_givens_columns(0, 1, &rot);
+    let mut expected = m;
+    rot.rotate_rows(&mut expected.fixed_columns_mut::<2>(0));
+    assert_eq!(columns, expected);
+}
+
+#[test]
+fn givens_apply_invalid_indices() {
+    let rot = GivensRotation::<f64>::identity();
+
+    for (i, j) in [(1, 1), (0, 3), (3, 0)] {
+        assert!(
+            panic::catch_unwind(|| DMatrix::<f64>::zeros(3, 3).apply_givens_rows(i, j, &rot))
+                .is_err()
+        );
+        assert!(panic::catch_unwind(
+            || DMatrix::<f64>::zeros(3, 3).apply_givens_columns(i, j, &rot)
+        )
+        .is_err());
+    }
+}
+
+macro_rules! gen_tests(
+    ($module: ident, $scalar: expr) => {
+            mod $module {
+            use crate::proptest::*;
+            use na::linalg::givens::GivensRotation;
+            use na::{ComplexField, DMatrix};
+            use proptest::{prop_assert, prop_assume, proptest};
+
+            /// The matrix of the rotation `rot` acting on the rows, or columns, `i` and `j`.
+            fn givens_matrix<T: ComplexField>(n: usize, i: usize, j: usize, rot: &GivensRotation<T>) -> DMatrix<T> {
+                let c = T::from_real(rot.c());
+                let mut g = DMatrix::identity(n, n);
+                g[(i, i)] = c;
+                g[(j, j)] = c;
+                g[(i, j)] = -rot.s().conjugate();
+                g[(j, i)] = rot.s();
+                g
+            }
+
+            proptest! {
+                #[test]
+                fn givens_apply_rows(m in dmatrix_($scalar), c in $scalar, s in $scalar) {
+                    prop_assume!(m.nrows() > 1);
+                    let (rot, _) = GivensRotation::new(c, s);
+
+                    for (i, j) in [(0, m.nrows() - 1), (m.nrows() / 2, 0)] {
+                        let mut rotated = m.clone();
+                        rotated.apply_givens_rows(i, j, &rot);
+                        let expected = givens_matrix(m.nrows(), i, j, &rot) * &m;
+
+                        prop_assert!(relative_eq!(rotated, expected, epsilon = 1.0e-7));
+                    }
+                }
+
+                #[test]
+                fn givens_apply_columns(m in dmatrix_($scalar), c in $scalar, s in $scalar) {
+                    prop_assume!(m.ncols() > 1);
+                    let (rot, _) = GivensRotation::new(c, s);
+
+                    for (i, j) in [(0, m.ncols() - 1), (m.ncols() / 2, 0)] {
+                        let mut rotated = m.clone();
+                        rotated.apply_givens_columns(i, j, &rot);
+                        let expected = &m * givens_matrix(m.ncols(), i, j, &rot);
+
+                        prop_assert!(relative_eq!(rotated, expected, epsilon = 1.0e-7));
+                    }
+                }
+            }
+        }
+    }
+);
+
+gen_tests!(complex, complex_f64());
+gen_tests!(f64, PROPTEST_F64);
@@ -0,0 +1,70 @@
+#![cfg(feature = "proptest-support")]
+
+use na::{DMatrix, Matrix2, Matrix3, Matrix4, Rotation3, Vector3};
+
+use crate::proptest::*;
+use proptest::{prop_assert, proptest};
+
+proptest! {
+    #[test]
+    fn log_exp(m in matrix4_(-0.5..0.5f64)) {
+        // The norm bound keeps the imaginary parts of the eigenvalues within ]-π, π[.
+        prop_assert!(relative_eq!(m.exp().log().unwrap(), m, epsilon = 1.0e-7));
+    }
+
+    #[test]
+    fn log_exp_symmetric(m in matrix3_(-2.0..2.0f64)) {
+        let m = m + m.transpose();
+        prop_assert!(relative_eq!(m.exp().log().unwrap(), m, epsilon = 1.0e-5));
+    }
+
+    #[test]
+    fn exp_log(m in matrix3_(-1.0..1.0f64)) {
+        // Diagonal shift keeping the eigenvalues away from the negative real axis.
+        let m = m + Matrix3::identity() * 4.0;
+        prop_assert!(relative_eq!(m.log().unwrap().exp(), m, epsilon = 1.0e-7));
+    }
+}
+
+#[test]
+fn log_closed_form() {
+    let m = Matrix2::new(1.0, 3.0, 0.0, 1.0);
+    assert_relative_eq!(
+        m.log().unwrap(),
+        Matrix2::new(0.0, 3.0, 0.0, 0.0),
+        epsilon = 1.0e-10
+    );
+
+    let m = Matrix3::from_diagonal(&Vector3::new(1.0, 2.0, 0.5));
+    assert_relative_eq!(
+        m.log().unwrap(),
+        Matrix3::from_diagonal(&Vector3::new(0.0, 2.0f64.ln(), 0.5f64.ln())),
+        epsilon = 1.0e-10
+    );
+
+    // The logarithm of a rotation is the skew-symmetric matrix of its scaled axis.
+    let axisangle = Vector3::new(0.5, -1.0, 2.0);
+    let rot = *Rotation3::new(axisangle).matrix();
+    assert_relative_eq!(
+        rot.log().unwrap(),
+        axisangle.cross_matrix(),
+        epsilon = 1.0e-7
+    );
+
+    let m = DMatrix::from_row_slice(3, 3, &[2.0, 1.0, 0.0, -0.5, 3.0, 0.2, 0.1, 0.0, 5.0]);
+    assert_relative_eq!(m.log().unwrap().exp(), m, epsilon = 1.0e-7);
+
+    // Large norm needing many square roots.
+    let m = Matrix4::from_diagonal_element(1.0e6) + Matrix4::from_fn(|i, j| (i + j) as f64);
+    assert_relative_eq!(m.log().unwrap().exp(), m, max_relative = 1.0e-7);
+}
+
+#[test]
+fn log_does_not_exist() {
+    // Negative eigenvalue.
+    assert!(Matrix2::new(1.0, 2.0, 2.0, 1.0).log().is_none());
+    assert!(Matrix2::new(-1.0, 1.0, 0.0, 4.0).log().is_none());
+    // Singular.
+    assert!(Matrix2::new(0.0, 1.0, 0.0, 0.0).log().is_none());
+    assert!(Matrix2::new(1.0, 1.0, 1.0, 1.0).log().is_none());
+}
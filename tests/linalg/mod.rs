@@ -1,4 +1,5 @@
 mod balancing;
+mod banded;
 mod bidiagonal;
 mod cholesky;
 mod col_piv_qr;
@@ -6,12 +7,15 @@ mod convolution;
 mod eigen;
 mod exp;
 mod full_piv_lu;
+mod givens;
 mod hessenberg;
 mod inverse;
+mod log;
 mod lu;
 mod qr;
 mod schur;
 mod solve;
+mod sqrt;
 mod svd;
 mod tridiagonal;
 mod udu;
@@ -13,6 +13,34 @@ fn schur_simpl_mat3() {
     assert!(relative_eq!(vecs * vals * vecs.transpose(), m, epsilon = 1.0e-7));
 }
 
+#[test]
+fn schur_with_stats() {
+    let m = Matrix4::new(
+        -2.0, -4.0, 2.0, 1.0, -2.0, 1.0, 2.0, 0.0, 4.0, 2.0, 5.0, -1.0, 1.0, 3.0, -2.0, 0.5,
+    );
+
+    let (schur, stats) = m.try_schur_with_stats(1.0e-12, 0).unwrap();
+    let (vecs, vals) = schur.unpack();
+    assert!(stats.qr_sweeps > 0);
+    assert!(stats.deflations > 0);
+    assert!(relative_eq!(
+        vecs * vals * vecs.transpose(),
+        m,
+        epsilon = 1.0e-7
+    ));
+
+    let (eigvals, eig_stats) = m.try_complex_eigenvalues_with_stats(1.0e-12, 0).unwrap();
+    assert_eq!(eig_stats, stats);
+    assert!(relative_eq!(
+        eigvals.map(|e| e.re).sum(),
+        m.trace(),
+        epsilon = 1.0e-7
+    ));
+
+    let stats = m.try_schur_with_stats(1.0e-12, 1).unwrap_err();
+    assert_eq!(stats.qr_sweeps, 1);
+}
+
 #[cfg(feature = "proptest-support")]
 mod proptest_tests {
     macro_rules! gen_tests(
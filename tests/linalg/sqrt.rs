@@ -0,0 +1,91 @@
+#![cfg(all(feature = "proptest-support", feature = "debug"))]
+
+use na::debug::RandomSDP;
+use na::dimension::{Const, Dynamic};
+use na::{DMatrix, Matrix2, Matrix3, Rotation2, Rotation3, Vector3};
+use rand::random;
+
+use crate::proptest::*;
+use proptest::{prop_assert, proptest};
+
+proptest! {
+    #[test]
+    fn sqrt_spd(n in PROPTEST_MATRIX_DIM) {
+        let m = RandomSDP::new(Dynamic::new(n), || random::<f64>()).unwrap();
+        let sqrt = m.sqrt().unwrap();
+
+        prop_assert!(relative_eq!(&sqrt * &sqrt, m, epsilon = 1.0e-5));
+        prop_assert!(relative_eq!(sqrt.transpose(), sqrt, epsilon = 1.0e-5));
+        prop_assert!(sqrt.symmetric_eigenvalues().iter().all(|e| *e > 0.0));
+    }
+
+    #[test]
+    fn sqrt_spd_static(_n in PROPTEST_MATRIX_DIM) {
+        let m = RandomSDP::new(Const::<4>, || random::<f64>()).unwrap();
+        let sqrt = m.sqrt().unwrap();
+
+        prop_assert!(relative_eq!(sqrt * sqrt, m, epsilon = 1.0e-5));
+    }
+
+    #[test]
+    fn sqrt_rotation(r in rotation3()) {
+        // Rotations by less than π have a principal square root: the half rotation.
+        let (axis, angle) = match r.axis_angle() {
+            Some(axis_angle) => axis_angle,
+            None => return Ok(()),
+        };
+        let angle = angle.min(3.0);
+        let m = *Rotation3::from_axis_angle(&axis, angle).matrix();
+        let expected = *Rotation3::from_axis_angle(&axis, angle / 2.0).matrix();
+
+        prop_assert!(relative_eq!(m.sqrt().unwrap(), expected, epsilon = 1.0e-7));
+    }
+}
+
+#[test]
+fn sqrt_non_symmetric() {
+    // Upper-triangular matrix with positive eigenvalues.
+    let m = Matrix3::new(1.0, 3.0, 0.0, 0.0, 4.0, 5.0, 0.0, 0.0, 9.0);
+    let sqrt = m.sqrt().unwrap();
+
+    assert_relative_eq!(sqrt * sqrt, m, epsilon = 1.0e-10);
+    assert_relative_eq!(
+        sqrt.diagonal(),
+        Vector3::new(1.0, 2.0, 3.0),
+        epsilon = 1.0e-10
+    );
+
+    let rot = *Rotation2::new(2.0).matrix();
+    assert_relative_eq!(
+        rot.sqrt().unwrap(),
+        *Rotation2::new(1.0).matrix(),
+        epsilon = 1.0e-10
+    );
+
+    let m = DMatrix::from_row_slice(2, 2, &[2.0, 1.0, 0.5, 3.0]);
+    let sqrt = m.sqrt().unwrap();
+    assert_relative_eq!(&sqrt * &sqrt, m, epsilon = 1.0e-10);
+}
+
+#[test]
+fn sqrt_semi_definite() {
+    let v = Vector3::new(1.0, 2.0, 3.0);
+    let m = v * v.transpose();
+    let sqrt = m.sqrt().unwrap();
+
+    assert_relative_eq!(sqrt * sqrt, m, epsilon = 1.0e-10);
+    assert_relative_eq!(sqrt, m / v.norm(), epsilon = 1.0e-10);
+    assert_eq!(Matrix2::<f64>::zeros().sqrt(), Some(Matrix2::zeros()));
+}
+
+#[test]
+fn sqrt_does_not_exist() {
+    // Symmetric with a negative eigenvalue.
+    assert!(Matrix2::new(1.0, 2.0, 2.0, 1.0).sqrt().is_none());
+    // Non-symmetric with a negative eigenvalue.
+    assert!(Matrix2::new(-1.0, 1.0, 0.0, 4.0).sqrt().is_none());
+    // Non-symmetric and singular.
+    assert!(Matrix2::new(0.0, 1.0, 0.0, 0.0).sqrt().is_none());
+    // Rotation by π.
+    assert!(Matrix2::new(-1.0, 0.0, 0.0, -1.0).sqrt().is_none());
+}
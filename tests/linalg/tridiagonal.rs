@@ -6,7 +6,8 @@ macro_rules! gen_tests(
             #[allow(unused_imports)]
             use crate::core::helper::{RandScalar, RandComplex};
             use crate::proptest::*;
-            use proptest::{prop_assert, proptest};
+            use na::ComplexField;
+            use proptest::{prop_assert, prop_assert_eq, proptest};
 
             proptest! {
                 #[test]
@@ -30,6 +31,43 @@ macro_rules! gen_tests(
                     prop_assert!(relative_eq!(m.lower_triangle(), recomp.lower_triangle(), epsilon = 1.0e-7));
                 }
 
+                #[test]
+                fn symm_tridiagonal_householder_convention(m in dmatrix_($scalar)) {
+                    let m = &m * m.adjoint();
+                    let n = m.nrows();
+                    let tri = m.clone().symmetric_tridiagonalize();
+                    let raw = tri.householder_off_diagonal().clone();
+                    let off_diagonal = tri.off_diagonal();
+
+                    let qh = tri.householder_q();
+                    let th = qh.adjoint() * &m * &qh;
+                    let q = tri.q();
+                    let t = q.adjoint() * &m * &q;
+
+                    prop_assert!(relative_eq!(th.slice((1, 0), (n - 1, n - 1)).diagonal(), raw, epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(t.slice((1, 0), (n - 1, n - 1)).diagonal(), off_diagonal.map(ComplexField::from_real), epsilon = 1.0e-7));
+                    prop_assert!(relative_eq!(raw.map(ComplexField::modulus), off_diagonal, epsilon = 1.0e-7));
+                }
+
+                #[test]
+                fn symm_tridiagonal_parts(m in dmatrix_($scalar)) {
+                    let m = &m * m.adjoint();
+                    let (diag, off_diag, q) = m.symmetric_tridiagonal_parts();
+                    let n = m.nrows();
+                    let t = na::DMatrix::from_fn(n, n, |i, j| {
+                        if i == j {
+                            ComplexField::from_real(diag[i])
+                        } else if i == j + 1 || j == i + 1 {
+                            ComplexField::from_real(off_diag[i.min(j)])
+                        } else {
+                            na::zero()
+                        }
+                    });
+
+                    prop_assert!(relative_eq!(&q * t * q.adjoint(), m, epsilon = 1.0e-7));
+                    prop_assert_eq!((diag, off_diag), m.symmetric_tridiagonalize().unpack_tridiagonal());
+                }
+
                 #[test]
                 fn symm_tridiagonal_static_square(m in matrix4_($scalar)) {
                     let m = m.hermitian_part();